@@ -0,0 +1,371 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// An arcsine distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct Arcsine {
+    a: f64,
+    b: f64,
+}
+
+impl Arcsine {
+    /// Create an arcsine distribution on interval `[a, b]`.
+    ///
+    /// It should hold that `a < b`.
+    #[inline]
+    pub fn new(a: f64, b: f64) -> Self {
+        should!(a < b);
+        Arcsine { a, b }
+    }
+
+    /// Return the left endpoint of the support.
+    #[inline(always)]
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+
+    /// Return the right endpoint of the support.
+    #[inline(always)]
+    pub fn b(&self) -> f64 {
+        self.b
+    }
+}
+
+impl Default for Arcsine {
+    #[inline]
+    fn default() -> Self {
+        Arcsine::new(0.0, 1.0)
+    }
+}
+
+impl distribution::CharacteristicFunction for Arcsine {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use core::f64::consts::FRAC_PI_2;
+        use distribution::bessel;
+        let (phase, j0) = (
+            0.5 * t * (self.a + self.b),
+            bessel::jn(0, 0.5 * t * (self.b - self.a)),
+        );
+        (j0 * (FRAC_PI_2 - phase).sin(), j0 * phase.sin())
+    }
+}
+
+impl distribution::Continuous for Arcsine {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        use core::f64::consts::PI;
+        if x < self.a || x > self.b {
+            0.0
+        } else {
+            1.0 / (PI * ((x - self.a) * (self.b - x)).sqrt())
+        }
+    }
+
+    fn log_density(&self, x: f64) -> f64 {
+        use core::f64::consts::PI;
+        if x < self.a || x > self.b {
+            f64::NEG_INFINITY
+        } else {
+            -PI.ln() - 0.5 * ((x - self.a) * (self.b - x)).ln()
+        }
+    }
+}
+
+impl distribution::Distribution for Arcsine {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        use core::f64::consts::FRAC_2_PI;
+        if x <= self.a {
+            0.0
+        } else if x >= self.b {
+            1.0
+        } else {
+            FRAC_2_PI * ((x - self.a) / (self.b - x)).sqrt().atan()
+        }
+    }
+}
+
+impl distribution::Entropy for Arcsine {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        use core::f64::consts::FRAC_PI_4;
+        (FRAC_PI_4 * (self.b - self.a)).ln()
+    }
+}
+
+impl distribution::Inverse for Arcsine {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        use core::f64::consts::FRAC_PI_2;
+        should!((0.0..=1.0).contains(&p));
+        self.a + (self.b - self.a) * (FRAC_PI_2 * p).sin().powi(2)
+    }
+}
+
+impl distribution::Kurtosis for Arcsine {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        -1.5
+    }
+}
+
+impl distribution::Mean for Arcsine {
+    #[inline]
+    fn mean(&self) -> f64 {
+        (self.a + self.b) / 2.0
+    }
+}
+
+impl distribution::Median for Arcsine {
+    #[inline]
+    fn median(&self) -> f64 {
+        (self.a + self.b) / 2.0
+    }
+}
+
+impl distribution::Mgf for Arcsine {
+    fn mgf(&self, t: f64) -> f64 {
+        use distribution::bessel;
+        let h = 0.5 * t * (self.b - self.a);
+        (0.5 * t * (self.a + self.b) + h.abs()).exp() * bessel::i0e(h)
+    }
+}
+
+impl distribution::Modes for Arcsine {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![self.a, self.b]
+    }
+}
+
+impl distribution::Moments for Arcsine {
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::moments;
+        let moment = |k: usize| {
+            (0..k).fold(1.0, |product, j| {
+                product * (j as f64 + 0.5) / (j + 1) as f64
+            })
+        };
+        moments::shift(moment, self.a, self.b - self.a, n)
+    }
+}
+
+impl distribution::Sample for Arcsine {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::Inverse;
+        self.inverse(source.read::<f64>())
+    }
+}
+
+impl distribution::Skewness for Arcsine {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        0.0
+    }
+}
+
+impl distribution::Support for Arcsine {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Included;
+        (Included(self.a), Included(self.b))
+    }
+}
+
+impl distribution::Variance for Arcsine {
+    #[inline]
+    fn variance(&self) -> f64 {
+        (self.b - self.a).powi(2) / 8.0
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::approx_constant,
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($a:expr, $b:expr) => (Arcsine::new($a, $b));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(1.0, 3.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            0.14977519735775424,
+            0.8068697593385256,
+            -0.14634477955585248,
+        ];
+        let im = vec![
+            -0.8683791777437584,
+            0.5520093019012883,
+            -0.16944110033052337,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(1.0, 3.0);
+        let x = vec![0.0, 1.25, 1.5, 2.0, 2.5, 2.75, 3.5];
+        let p = vec![
+            0.0000000000000000e+00,
+            4.8123931354033417e-01,
+            3.6755259694786135e-01,
+            3.1830988618379069e-01,
+            3.6755259694786135e-01,
+            4.8123931354033417e-01,
+            0.0000000000000000e+00,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(1.0, 3.0);
+        let x = vec![0.0, 1.25, 1.5, 2.0, 2.5, 2.75, 3.5];
+        let p = vec![
+            0.0000000000000000e+00,
+            2.3005345616261588e-01,
+            3.3333333333333331e-01,
+            5.0000000000000000e-01,
+            6.6666666666666663e-01,
+            7.6994654383738415e-01,
+            1.0000000000000000e+00,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(1.0, 3.0).entropy(), 4.5158270528945488e-01, 1e-15);
+    }
+
+    #[test]
+    fn inverse() {
+        let d = new!(1.0, 3.0);
+        let p = vec![
+            0.0, 0.01, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.99, 1.0,
+        ];
+        let x = vec![
+            1.0000000000000000e+00,
+            1.0004934396342684e+00,
+            1.0489434837048464e+00,
+            1.1909830056250525e+00,
+            1.4122147477075269e+00,
+            1.6909830056250525e+00,
+            2.0000000000000000e+00,
+            2.3090169943749475e+00,
+            2.5877852522924729e+00,
+            2.8090169943749475e+00,
+            2.9510565162951536e+00,
+            2.9995065603657314e+00,
+            3.0000000000000000e+00,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert_eq!(new!(1.0, 3.0).kurtosis(), -1.5);
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(1.0, 3.0);
+        let x = vec![-40.0, 1.5];
+        let p = vec![f64::NEG_INFINITY, -1.0008888496235098];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(1.0, 3.0).mean(), 2.0);
+    }
+
+    #[test]
+    fn median() {
+        assert_eq!(new!(1.0, 3.0).median(), 2.0);
+    }
+
+    #[test]
+    fn mgf() {
+        let d = new!(1.0, 3.0);
+        let t = vec![-0.7, -0.1, 0.2, 0.45];
+        let m = vec![
+            0.27774290479272673,
+            0.8207788595828857,
+            1.5067802817006088,
+            2.5857053347722423,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(1.0, 3.0).modes(), vec![1.0, 3.0]);
+    }
+
+    #[test]
+    fn sample() {
+        for x in Independent(&new!(1.0, 3.0), &mut source::default(42)).take(100) {
+            assert!((1.0..=3.0).contains(&x));
+        }
+    }
+
+    #[test]
+    fn skewness() {
+        assert_eq!(new!(1.0, 3.0).skewness(), 0.0);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::Included;
+        assert_eq!(new!(1.0, 3.0).support(), (Included(1.0), Included(3.0)));
+    }
+
+    #[test]
+    fn variance() {
+        assert_eq!(new!(1.0, 3.0).variance(), 0.5);
+    }
+}
@@ -0,0 +1,330 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution::{self, IrwinHall};
+use source::Source;
+
+/// A Bates distribution.
+///
+/// The distribution is that of the mean of `n` independent random variables
+/// uniformly distributed on `[a, b]`.
+#[derive(Clone, Copy, Debug)]
+pub struct Bates {
+    n: usize,
+    a: f64,
+    b: f64,
+    irwin_hall: IrwinHall,
+}
+
+impl Bates {
+    /// Create a Bates distribution with `n` summands on interval `[a, b]`.
+    ///
+    /// It should hold that `n > 0` and `a < b`.
+    #[inline]
+    pub fn new(n: usize, a: f64, b: f64) -> Self {
+        should!(n > 0 && a < b);
+        Bates {
+            n,
+            a,
+            b,
+            irwin_hall: IrwinHall::new(n),
+        }
+    }
+
+    /// Return the number of summands.
+    #[inline(always)]
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// Return the left endpoint of the support.
+    #[inline(always)]
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+
+    /// Return the right endpoint of the support.
+    #[inline(always)]
+    pub fn b(&self) -> f64 {
+        self.b
+    }
+
+    #[inline]
+    fn standardize(&self, x: f64) -> f64 {
+        self.n as f64 * (x - self.a) / (self.b - self.a)
+    }
+}
+
+impl distribution::CharacteristicFunction for Bates {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use core::f64::consts::FRAC_PI_2;
+        let &Bates { n, a, b, .. } = self;
+        let x = 0.5 * t * (b - a) / n as f64;
+        let modulus = if x == 0.0 {
+            1.0
+        } else {
+            (x.sin() / x).powi(n as i32)
+        };
+        let phase = 0.5 * t * (a + b);
+        (modulus * (FRAC_PI_2 - phase).sin(), modulus * phase.sin())
+    }
+}
+
+impl distribution::Continuous for Bates {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        let scale = self.n as f64 / (self.b - self.a);
+        scale * self.irwin_hall.density(self.standardize(x))
+    }
+
+    #[inline]
+    fn log_density(&self, x: f64) -> f64 {
+        let scale = self.n as f64 / (self.b - self.a);
+        scale.ln() + self.irwin_hall.log_density(self.standardize(x))
+    }
+}
+
+impl distribution::Distribution for Bates {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        self.irwin_hall.distribution(self.standardize(x))
+    }
+}
+
+impl distribution::Kurtosis for Bates {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        -1.2 / self.n as f64
+    }
+}
+
+impl distribution::Mean for Bates {
+    #[inline]
+    fn mean(&self) -> f64 {
+        (self.a + self.b) / 2.0
+    }
+}
+
+impl distribution::Median for Bates {
+    #[inline]
+    fn median(&self) -> f64 {
+        (self.a + self.b) / 2.0
+    }
+}
+
+impl distribution::Mgf for Bates {
+    fn mgf(&self, t: f64) -> f64 {
+        let &Bates { n, a, b, .. } = self;
+        if t == 0.0 {
+            return 1.0;
+        }
+        let s = t / n as f64;
+        ((s * a).exp() * (s * (b - a)).exp_m1() / (s * (b - a))).powi(n as i32)
+    }
+}
+
+impl distribution::Modes for Bates {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        if self.n == 1 {
+            vec![]
+        } else {
+            vec![(self.a + self.b) / 2.0]
+        }
+    }
+}
+
+impl distribution::Moments for Bates {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is computed by numerical integration.
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::{moments, Continuous, Mean, Variance};
+        moments::continuous(
+            |x| self.density(x),
+            n,
+            self.a,
+            self.b,
+            self.mean(),
+            self.deviation(),
+        )
+    }
+}
+
+impl distribution::Sample for Bates {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        self.a + (self.b - self.a) * self.irwin_hall.sample(source) / self.n as f64
+    }
+}
+
+impl distribution::Skewness for Bates {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        0.0
+    }
+}
+
+impl distribution::Support for Bates {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Included;
+        (Included(self.a), Included(self.b))
+    }
+}
+
+impl distribution::Variance for Bates {
+    #[inline]
+    fn variance(&self) -> f64 {
+        (self.b - self.a).powi(2) / (12.0 * self.n as f64)
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($n:expr, $a:expr, $b:expr) => (Bates::new($n, $a, $b));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(4, 1.0, 3.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.1665286875226662, 0.8222458238993138, -0.552517030529571];
+        let im = vec![-0.9655139655484041, 0.5625286336347617, -0.6397159768077286];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(4, 1.0, 3.0);
+        let x = vec![0.0, 1.0, 1.2, 1.5, 1.8, 2.0, 2.3, 2.6, 2.9, 3.0, 4.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            2.1333333333333319e-02,
+            3.3333333333333331e-01,
+            1.0773333333333335e+00,
+            1.3333333333333333e+00,
+            8.2933333333333381e-01,
+            1.7066666666666655e-01,
+            2.6666666666666740e-03,
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(4, 1.0, 3.0);
+        let x = vec![0.0, 1.0, 1.2, 1.5, 1.8, 2.0, 2.3, 2.6, 2.9, 3.0, 4.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            1.0666666666666656e-03,
+            4.1666666666666664e-02,
+            2.5146666666666673e-01,
+            5.0000000000000000e-01,
+            8.4419999999999984e-01,
+            9.8293333333333333e-01,
+            9.9993333333333334e-01,
+            1.0000000000000000e+00,
+            1.0000000000000000e+00,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(4, 1.0, 3.0).kurtosis(), -0.3, 1e-15);
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(200, 0.0, 1.0);
+        assert::close(d.log_density(0.0025), -990.57164139073852, 1e-11);
+        assert::close(d.log_density(0.0125), -670.29349681635254, 1e-11);
+        assert_eq!(d.log_density(-0.5), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(4, 1.0, 3.0).mean(), 2.0);
+    }
+
+    #[test]
+    fn median() {
+        assert_eq!(new!(4, 1.0, 3.0).median(), 2.0);
+    }
+
+    #[test]
+    fn mgf() {
+        let d = new!(4, 1.0, 3.0);
+        let t = vec![-0.7, -0.1, 0.2, 0.45];
+        let m = vec![
+            0.25167816394024906,
+            0.8190719548622962,
+            1.494312937757125,
+            2.4804349882576076,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(4, 1.0, 3.0).modes(), vec![2.0]);
+        assert_eq!(new!(1, 1.0, 3.0).modes(), vec![]);
+    }
+
+    #[test]
+    fn sample() {
+        for x in Independent(&new!(4, 1.0, 3.0), &mut source::default(42)).take(100) {
+            assert!((1.0..=3.0).contains(&x));
+        }
+    }
+
+    #[test]
+    fn skewness() {
+        assert_eq!(new!(4, 1.0, 3.0).skewness(), 0.0);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::Included;
+        assert_eq!(new!(4, 1.0, 3.0).support(), (Included(1.0), Included(3.0)));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(4, 1.0, 3.0).variance(), 1.0 / 12.0, 1e-15);
+    }
+}
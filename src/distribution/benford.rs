@@ -0,0 +1,363 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A Benford distribution.
+///
+/// The distribution describes the leading digit of numbers written in base
+/// `base` whose logarithms are uniformly distributed.
+#[derive(Clone, Copy, Debug)]
+pub struct Benford {
+    base: usize,
+    ln_base: f64,
+}
+
+impl Benford {
+    /// Create a Benford distribution in base `base`.
+    ///
+    /// It should hold that `base > 1`.
+    #[inline]
+    pub fn new(base: usize) -> Self {
+        should!(base > 1);
+        Benford {
+            base,
+            ln_base: (base as f64).ln(),
+        }
+    }
+
+    /// Return the base.
+    #[inline(always)]
+    pub fn base(&self) -> usize {
+        self.base
+    }
+
+    /// Compute the `k`th raw moment.
+    fn moment(&self, k: i32) -> f64 {
+        use distribution::Discrete;
+        (1..self.base)
+            .map(|d| self.mass(d) * (d as f64).powi(k))
+            .sum()
+    }
+}
+
+impl Default for Benford {
+    #[inline]
+    fn default() -> Self {
+        Benford::new(10)
+    }
+}
+
+impl distribution::CharacteristicFunction for Benford {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Discrete};
+        characteristic::discrete(|x| self.mass(x), t, 1, self.base - 1, f64::INFINITY)
+    }
+}
+
+impl distribution::Discrete for Benford {
+    #[inline]
+    fn mass(&self, x: usize) -> f64 {
+        if x < 1 || x >= self.base {
+            0.0
+        } else {
+            (1.0 / x as f64).ln_1p() / self.ln_base
+        }
+    }
+
+    #[inline]
+    fn log_mass(&self, x: usize) -> f64 {
+        if x < 1 || x >= self.base {
+            f64::NEG_INFINITY
+        } else {
+            (1.0 / x as f64).ln_1p().ln() - self.ln_base.ln()
+        }
+    }
+}
+
+impl distribution::Distribution for Benford {
+    type Value = usize;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        if x < 1.0 {
+            0.0
+        } else if x >= (self.base - 1) as f64 {
+            1.0
+        } else {
+            (x.floor() + 1.0).ln() / self.ln_base
+        }
+    }
+}
+
+impl distribution::Entropy for Benford {
+    fn entropy(&self) -> f64 {
+        use distribution::Discrete;
+        -(1..self.base)
+            .map(|d| {
+                let p = self.mass(d);
+                p * p.ln()
+            })
+            .sum::<f64>()
+    }
+}
+
+impl distribution::Inverse for Benford {
+    #[inline]
+    fn inverse(&self, p: f64) -> usize {
+        should!((0.0..=1.0).contains(&p));
+        let d = -(-(p * self.ln_base).exp_m1()).floor();
+        d.clamp(1.0, (self.base - 1) as f64) as usize
+    }
+}
+
+impl distribution::Kurtosis for Benford {
+    fn kurtosis(&self) -> f64 {
+        let (m1, m2, m3, m4) = (
+            self.moment(1),
+            self.moment(2),
+            self.moment(3),
+            self.moment(4),
+        );
+        let m1_2 = m1 * m1;
+        (m4 - 4.0 * m1 * m3 + 6.0 * m1_2 * m2 - 3.0 * m1_2 * m1_2) / (m2 - m1_2).powi(2) - 3.0
+    }
+}
+
+impl distribution::Mean for Benford {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.moment(1)
+    }
+}
+
+impl distribution::Mgf for Benford {
+    fn mgf(&self, t: f64) -> f64 {
+        use distribution::Discrete;
+        (1..self.base)
+            .map(|d| self.mass(d) * (t * d as f64).exp())
+            .sum()
+    }
+}
+
+impl distribution::Modes for Benford {
+    #[inline]
+    fn modes(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+impl distribution::Moments for Benford {
+    fn raw_moment(&self, n: usize) -> f64 {
+        self.moment(n as i32)
+    }
+}
+
+impl distribution::Sample for Benford {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained as the integer part of `base^u` where `u` is a
+    /// uniform variate.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> usize
+    where
+        S: Source,
+    {
+        let d = (source.read::<f64>() * self.ln_base).exp().floor();
+        d.clamp(1.0, (self.base - 1) as f64) as usize
+    }
+}
+
+impl distribution::Skewness for Benford {
+    fn skewness(&self) -> f64 {
+        let (m1, m2, m3) = (self.moment(1), self.moment(2), self.moment(3));
+        (m3 - 3.0 * m1 * m2 + 2.0 * m1 * m1 * m1) / (m2 - m1 * m1).powf(1.5)
+    }
+}
+
+impl distribution::Support for Benford {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Included;
+        (Included(1.0), Included((self.base - 1) as f64))
+    }
+}
+
+impl distribution::Variance for Benford {
+    #[inline]
+    fn variance(&self) -> f64 {
+        let m1 = self.moment(1);
+        self.moment(2) - m1 * m1
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::approx_constant,
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($base:expr) => (Benford::new($base));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(10);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            0.09505274785700604,
+            0.42772456486260607,
+            -0.1552995769516979,
+        ];
+        let im = vec![-0.33314941323755604, 0.6249263815763, 0.13078727023964004];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(10);
+        let x = vec![0.0, 1.0, 1.5, 2.0, 5.0, 8.9, 9.0, 10.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            3.0102999566398120e-01,
+            3.0102999566398120e-01,
+            4.7712125471966244e-01,
+            7.7815125038364363e-01,
+            9.5424250943932487e-01,
+            1.0000000000000000e+00,
+            1.0000000000000000e+00,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(10).entropy(), 1.9934331507912042e+00, 1e-15);
+        assert_eq!(new!(2).entropy(), 0.0);
+    }
+
+    #[test]
+    fn inverse() {
+        let d = new!(10);
+        let p = vec![
+            0.0, 0.01, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.99, 1.0,
+        ];
+        let x = vec![1, 1, 1, 1, 1, 2, 3, 3, 5, 6, 7, 9, 9];
+        assert_eq!(&p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(), &x);
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(10).kurtosis(), -5.4822532223844100e-01, 1e-14);
+    }
+
+    #[test]
+    fn log_mass() {
+        let d = new!(10);
+        let x = vec![0, 1, 5, 9, 10];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -1.2005453658296201,
+            -2.536015800529456,
+            -3.0843997725604011,
+            f64::NEG_INFINITY,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_mass(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn mass() {
+        let d = new!(10);
+        let p = vec![
+            0.0000000000000000e+00,
+            3.0102999566398120e-01,
+            1.7609125905568124e-01,
+            1.2493873660829995e-01,
+            9.6910013008056420e-02,
+            7.9181246047624831e-02,
+            6.6946789630613193e-02,
+            5.7991946977686754e-02,
+            5.1152522447381291e-02,
+            4.5757490560675122e-02,
+            0.0000000000000000e+00,
+        ];
+
+        assert::close(&(0..11).map(|x| d.mass(x)).collect::<Vec<_>>(), &p, 1e-15);
+        assert_eq!(new!(2).mass(1), 1.0);
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(10).mean(), 3.4402369671232060e+00, 1e-15);
+    }
+
+    #[test]
+    fn mgf() {
+        let d = new!(10);
+        let t = vec![-0.7, -0.1, 0.2, 0.45];
+        let m = vec![
+            0.2182032908818612,
+            0.7292250465433636,
+            2.2765604194345563,
+            9.572597348818087,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(10).modes(), vec![1]);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(10);
+        let mut source = source::default(42);
+
+        let mut counts = [0usize; 10];
+        for _ in 0..n {
+            counts[d.sample(&mut source)] += 1;
+        }
+        for (x, &count) in counts.iter().enumerate() {
+            assert!((count as f64 / n as f64 - d.mass(x)).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(10).skewness(), 7.9560432436477713e-01, 1e-14);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::Included;
+        assert_eq!(new!(10).support(), (Included(1.0), Included(9.0)));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(10).variance(), 6.0565126313756661e+00, 1e-14);
+    }
+}
@@ -1,4 +1,5 @@
 use alloc::{vec, vec::Vec};
+use core::ops::Bound;
 #[allow(unused_imports)]
 use special::Primitive;
 
@@ -54,6 +55,19 @@ impl Bernoulli {
     }
 }
 
+impl distribution::divergence::Bhattacharyya for Bernoulli {
+    fn bhattacharyya(&self, q: &Self) -> f64 {
+        -((self.p * q.p).sqrt() + (self.q * q.q).sqrt()).ln()
+    }
+}
+
+impl distribution::CharacteristicFunction for Bernoulli {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use core::f64::consts::FRAC_PI_2;
+        (self.q + self.p * (FRAC_PI_2 - t).sin(), self.p * t.sin())
+    }
+}
+
 impl distribution::Discrete for Bernoulli {
     #[inline]
     fn mass(&self, x: u8) -> f64 {
@@ -65,6 +79,17 @@ impl distribution::Discrete for Bernoulli {
             0.0
         }
     }
+
+    #[inline]
+    fn log_mass(&self, x: u8) -> f64 {
+        if x == 0 {
+            self.q.ln()
+        } else if x == 1 {
+            self.p.ln()
+        } else {
+            f64::NEG_INFINITY
+        }
+    }
 }
 
 impl distribution::Distribution for Bernoulli {
@@ -126,6 +151,12 @@ impl distribution::Median for Bernoulli {
     }
 }
 
+impl distribution::Mgf for Bernoulli {
+    fn mgf(&self, t: f64) -> f64 {
+        self.q + self.p * t.exp()
+    }
+}
+
 impl distribution::Modes for Bernoulli {
     fn modes(&self) -> Vec<u8> {
         use core::cmp::Ordering::*;
@@ -138,6 +169,16 @@ impl distribution::Modes for Bernoulli {
     }
 }
 
+impl distribution::Moments for Bernoulli {
+    fn raw_moment(&self, n: usize) -> f64 {
+        if n == 0 {
+            1.0
+        } else {
+            self.p
+        }
+    }
+}
+
 impl distribution::Sample for Bernoulli {
     #[inline]
     fn sample<S>(&self, source: &mut S) -> u8
@@ -159,6 +200,13 @@ impl distribution::Skewness for Bernoulli {
     }
 }
 
+impl distribution::Support for Bernoulli {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Included;
+        (Included(0.0), Included(1.0))
+    }
+}
+
 impl distribution::Variance for Bernoulli {
     #[inline]
     fn variance(&self) -> f64 {
@@ -167,6 +215,11 @@ impl distribution::Variance for Bernoulli {
 }
 
 #[cfg(test)]
+#[allow(
+    clippy::approx_constant,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
 mod tests {
     use alloc::{vec, vec::Vec};
     use assert;
@@ -177,6 +230,33 @@ mod tests {
         ($p:expr) => (Bernoulli::new($p));
     );
 
+    #[test]
+    fn bhattacharyya() {
+        let d = new!(0.3);
+        let q = vec![new!(0.3), new!(0.6), new!(0.99)];
+        assert::close(
+            &q.iter().map(|q| d.bhattacharyya(q)).collect::<Vec<_>>(),
+            &vec![0.0, 0.04770570493687759, 0.46419164536676117],
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn cf() {
+        let d = new!(0.25);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.941210546821122, 0.9888341222814014, 0.6459632908632144];
+        let im = vec![
+            -0.16105442180942275,
+            0.07388005166533489,
+            0.22732435670642043,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
     #[test]
     fn distribution() {
         let d = new!(0.25);
@@ -211,6 +291,18 @@ mod tests {
         assert_eq!(new!(0.5).kurtosis(), -2.0);
     }
 
+    #[test]
+    fn log_mass() {
+        let d = new!(0.25);
+        let x = vec![0, 1, 2];
+        let p = vec![-0.28768207245178093, -1.3862943611198906, f64::NEG_INFINITY];
+        assert::close(
+            &x.iter().map(|&x| d.log_mass(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
     #[test]
     fn mass() {
         let d = new!(0.25);
@@ -232,6 +324,19 @@ mod tests {
         assert_eq!(new!(0.75).median(), 1.0);
     }
 
+    #[test]
+    fn mgf() {
+        let d = new!(0.25);
+        let t = vec![-0.7, -0.1, 0.2, 0.45];
+        let m = vec![
+            0.8741463259478524,
+            0.9762093545089898,
+            1.0553506895400424,
+            1.1420780463725422,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+    }
+
     #[test]
     fn modes() {
         assert_eq!(new!(0.25).modes(), vec![0]);
@@ -244,7 +349,7 @@ mod tests {
         assert!(
             Independent(&new!(0.25), &mut source::default(42))
                 .take(100)
-                .fold(0, |a, b| a + b)
+                .sum::<u8>()
                 <= 100
         );
     }
@@ -254,6 +359,12 @@ mod tests {
         assert_eq!(new!(0.5).skewness(), 0.0);
     }
 
+    #[test]
+    fn support() {
+        use core::ops::Bound::Included;
+        assert_eq!(new!(0.25).support(), (Included(0.0), Included(1.0)));
+    }
+
     #[test]
     fn variance() {
         assert_eq!(new!(0.25).variance(), 0.1875);
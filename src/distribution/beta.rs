@@ -1,4 +1,5 @@
 use alloc::{vec, vec::Vec};
+use core::ops::Bound;
 #[allow(unused_imports)]
 use special::Primitive;
 
@@ -58,6 +59,28 @@ impl Beta {
     }
 }
 
+impl distribution::divergence::Bhattacharyya for Beta {
+    fn bhattacharyya(&self, q: &Self) -> f64 {
+        use distribution::divergence;
+        use special::Beta;
+        if self.a != q.a || self.b != q.b {
+            return divergence::bhattacharyya_continuous(self, q);
+        }
+        let (alpha, beta) = (0.5 * (self.alpha + q.alpha), 0.5 * (self.beta + q.beta));
+        0.5 * (self.ln_beta + q.ln_beta) - alpha.ln_beta(beta)
+    }
+}
+
+impl distribution::CharacteristicFunction for Beta {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Continuous};
+        characteristic::continuous(|x| self.density(x), t, self.a, self.b, 0.0, 0.0)
+    }
+}
+
 impl distribution::Continuous for Beta {
     fn density(&self, x: f64) -> f64 {
         if x < self.a || x > self.b {
@@ -69,6 +92,18 @@ impl distribution::Continuous for Beta {
                 / scale
         }
     }
+
+    fn log_density(&self, x: f64) -> f64 {
+        if x < self.a || x > self.b {
+            f64::NEG_INFINITY
+        } else {
+            let scale = self.b - self.a;
+            let x = (x - self.a) / scale;
+            (self.alpha - 1.0) * x.ln() + (self.beta - 1.0) * (-x).ln_1p()
+                - self.ln_beta
+                - scale.ln()
+        }
+    }
 }
 
 impl distribution::Distribution for Beta {
@@ -106,6 +141,21 @@ impl distribution::Inverse for Beta {
     }
 }
 
+impl distribution::divergence::KullbackLeibler for Beta {
+    fn kl(&self, q: &Self) -> f64 {
+        use distribution::divergence;
+        use special::Gamma;
+        if self.a != q.a || self.b != q.b {
+            return divergence::kl_continuous(self, q);
+        }
+        let (alpha, beta) = (self.alpha, self.beta);
+        q.ln_beta - self.ln_beta
+            + (alpha - q.alpha) * alpha.digamma()
+            + (beta - q.beta) * beta.digamma()
+            + (q.alpha - alpha + q.beta - beta) * (alpha + beta).digamma()
+    }
+}
+
 impl distribution::Kurtosis for Beta {
     fn kurtosis(&self) -> f64 {
         let sum = self.alpha + self.beta;
@@ -158,6 +208,21 @@ impl distribution::Modes for Beta {
     }
 }
 
+impl distribution::Moments for Beta {
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::moments;
+        let &Beta {
+            alpha, beta, a, b, ..
+        } = self;
+        let moment = |k: usize| {
+            (0..k).fold(1.0, |product, j| {
+                product * (alpha + j as f64) / (alpha + beta + j as f64)
+            })
+        };
+        moments::shift(moment, a, b - a, n)
+    }
+}
+
 impl distribution::Sample for Beta {
     #[inline]
     fn sample<S>(&self, source: &mut S) -> f64
@@ -179,6 +244,13 @@ impl distribution::Skewness for Beta {
     }
 }
 
+impl distribution::Support for Beta {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Included;
+        (Included(self.a), Included(self.b))
+    }
+}
+
 impl distribution::Variance for Beta {
     fn variance(&self) -> f64 {
         let scale = self.b - self.a;
@@ -188,6 +260,11 @@ impl distribution::Variance for Beta {
 }
 
 #[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
 mod tests {
     use alloc::{vec, vec::Vec};
     use assert;
@@ -197,6 +274,43 @@ mod tests {
         ($alpha:expr, $beta:expr, $a:expr, $b:expr) => (Beta::new($alpha, $beta, $a, $b));
     );
 
+    #[test]
+    fn bhattacharyya() {
+        let d = new!(2.0, 3.0, -1.0, 2.0);
+        let q = vec![
+            new!(2.0, 3.0, -1.0, 2.0),
+            new!(3.0, 2.0, -1.0, 2.0),
+            new!(0.5, 0.5, -1.0, 2.0),
+            new!(2.0, 3.0, -2.0, 3.0),
+        ];
+        assert::close(
+            &q.iter().map(|q| d.bhattacharyya(q)).collect::<Vec<_>>(),
+            &vec![
+                0.0,
+                0.12378143961410699,
+                0.20573175603294396,
+                0.1477947369169973,
+            ],
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn cf() {
+        let d = new!(2.0, 3.0, -1.0, 2.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.9063112142940214, 0.9821487146517475, 0.4380651389732171];
+        let im = vec![
+            -0.12435057660050589,
+            0.05872436665821798,
+            0.12952305786225243,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
     #[test]
     fn density() {
         let d = new!(2.0, 3.0, -1.0, 2.0);
@@ -328,6 +442,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn kl() {
+        let d = new!(2.0, 3.0, -1.0, 2.0);
+        let q = vec![
+            new!(2.0, 3.0, -1.0, 2.0),
+            new!(3.0, 2.0, -1.0, 2.0),
+            new!(0.5, 0.5, -1.0, 2.0),
+        ];
+        assert::close(
+            &q.iter().map(|q| d.kl(q)).collect::<Vec<_>>(),
+            &vec![0.0, 0.5, 0.5463032023040672],
+            1e-14,
+        );
+        assert::close(d.kl(&new!(2.0, 3.0, -2.0, 3.0)), 0.3308860606359585, 1e-13);
+        assert_eq!(new!(2.0, 3.0, -2.0, 3.0).kl(&d), f64::INFINITY);
+    }
+
     #[test]
     fn kurtosis() {
         assert_eq!(new!(1.0, 1.0, 0.0, 1.0).kurtosis(), -6.0 / 5.0);
@@ -335,6 +466,23 @@ mod tests {
         assert_eq!(new!(3.0, 2.0, -1.0, 2.0).kurtosis(), -0.6428571428571429);
     }
 
+    #[test]
+    fn log_density() {
+        let d = new!(2.0, 3.0, -1.0, 2.0);
+        let x = vec![-40.0, -0.5, 0.5, 1.5];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -0.7701082216960737,
+            -0.6931471805599452,
+            -2.379546134130174,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
     #[test]
     fn mean() {
         assert_eq!(new!(0.5, 0.5, 0.0, 1.0).mean(), 0.5);
@@ -377,10 +525,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn moments() {
+        let d = new!(2.0, 3.0, -1.0, 2.0);
+        let m = vec![0.5, 0.74285714285714286, 1.0181818181818182];
+        assert::close(
+            &(5..8).map(|n| d.raw_moment(n)).collect::<Vec<_>>(),
+            &m,
+            1e-13,
+        );
+    }
+
     #[test]
     fn sample() {
         for x in Independent(&new!(1.0, 2.0, 7.0, 42.0), &mut source::default(42)).take(100) {
-            assert!(7.0 <= x && x <= 42.0);
+            assert!((7.0..=42.0).contains(&x));
         }
     }
 
@@ -391,6 +550,15 @@ mod tests {
         assert_eq!(new!(3.0, 2.0, -1.0, 2.0).skewness(), -0.28571428571428575);
     }
 
+    #[test]
+    fn support() {
+        use core::ops::Bound::Included;
+        assert_eq!(
+            new!(2.0, 3.0, -1.0, 2.0).support(),
+            (Included(-1.0), Included(2.0))
+        );
+    }
+
     #[test]
     fn variance() {
         assert_eq!(new!(1.0, 1.0, 0.0, 1.0).variance(), 1.0 / 12.0);
@@ -0,0 +1,349 @@
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution::{self, Beta, Binomial};
+use source::Source;
+
+/// A beta-binomial distribution.
+///
+/// The distribution is a binomial distribution whose success probability is
+/// drawn from a beta distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct BetaBinomial {
+    n: usize,
+    alpha: f64,
+    beta: f64,
+    ln_beta: f64,
+    mixing: Beta,
+}
+
+impl BetaBinomial {
+    /// Create a beta-binomial distribution with `n` trials and shape
+    /// parameters `alpha` and `beta`.
+    ///
+    /// It should hold that `alpha > 0` and `beta > 0`.
+    #[inline]
+    pub fn new(n: usize, alpha: f64, beta: f64) -> Self {
+        use special::Beta as SpecialBeta;
+        should!(alpha > 0.0 && beta > 0.0);
+        BetaBinomial {
+            n,
+            alpha,
+            beta,
+            ln_beta: alpha.ln_beta(beta),
+            mixing: Beta::new(alpha, beta, 0.0, 1.0),
+        }
+    }
+
+    /// Return the number of trials.
+    #[inline(always)]
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// Return the first shape parameter.
+    #[inline(always)]
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Return the second shape parameter.
+    #[inline(always)]
+    pub fn beta(&self) -> f64 {
+        self.beta
+    }
+}
+
+impl distribution::CharacteristicFunction for BetaBinomial {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Discrete};
+        characteristic::discrete(|x| self.mass(x), t, 0, self.n, f64::INFINITY)
+    }
+}
+
+impl distribution::Discrete for BetaBinomial {
+    #[inline]
+    fn mass(&self, x: usize) -> f64 {
+        self.log_mass(x).exp()
+    }
+
+    /// Compute the natural logarithm of the probability mass function.
+    ///
+    /// The function is evaluated via logarithms of beta functions.
+    fn log_mass(&self, x: usize) -> f64 {
+        use special::{Beta, Gamma};
+        if x > self.n {
+            return f64::NEG_INFINITY;
+        }
+        let (n, k) = (self.n as f64, x as f64);
+        let ln_choose = Gamma::ln_gamma(n + 1.0).0
+            - Gamma::ln_gamma(k + 1.0).0
+            - Gamma::ln_gamma(n - k + 1.0).0;
+        ln_choose + (k + self.alpha).ln_beta(n - k + self.beta) - self.ln_beta
+    }
+}
+
+impl distribution::Distribution for BetaBinomial {
+    type Value = usize;
+
+    /// Compute the cumulative distribution function.
+    ///
+    /// The probability masses are summed over whichever tail lies farther from
+    /// the expected value, which avoids cancellation.
+    fn distribution(&self, x: f64) -> f64 {
+        use distribution::{Discrete, Mean};
+        if x < 0.0 {
+            return 0.0;
+        }
+        let x = x.floor() as usize;
+        if x >= self.n {
+            return 1.0;
+        }
+        if (x as f64) < self.mean() {
+            (0..=x).map(|i| self.mass(i)).sum::<f64>().min(1.0)
+        } else {
+            (1.0 - ((x + 1)..=self.n).map(|i| self.mass(i)).sum::<f64>()).max(0.0)
+        }
+    }
+}
+
+impl distribution::Kurtosis for BetaBinomial {
+    fn kurtosis(&self) -> f64 {
+        let (n, alpha, beta) = (self.n as f64, self.alpha, self.beta);
+        let sum = alpha + beta;
+        let product = alpha * beta;
+        sum * sum * (1.0 + sum) / (n * product * (sum + 2.0) * (sum + 3.0) * (sum + n))
+            * (sum * (sum - 1.0 + 6.0 * n) + 3.0 * product * (n - 2.0) + 6.0 * n * n
+                - 3.0 * product * n * (6.0 - n) / sum
+                - 18.0 * product * n * n / (sum * sum))
+            - 3.0
+    }
+}
+
+impl distribution::Mean for BetaBinomial {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.n as f64 * self.alpha / (self.alpha + self.beta)
+    }
+}
+
+impl distribution::Mgf for BetaBinomial {
+    fn mgf(&self, t: f64) -> f64 {
+        use distribution::Discrete;
+        (0..=self.n)
+            .map(|x| self.mass(x) * (t * x as f64).exp())
+            .sum()
+    }
+}
+
+impl distribution::Moments for BetaBinomial {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is computed by summing the probability mass function.
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::{moments, Discrete, Mean};
+        moments::discrete(|x| self.mass(x), n, 0, self.n, self.mean())
+    }
+}
+
+impl distribution::Sample for BetaBinomial {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained by drawing a success probability from the beta
+    /// distribution and then a binomial variate with that probability.
+    fn sample<S>(&self, source: &mut S) -> usize
+    where
+        S: Source,
+    {
+        let p = self.mixing.sample(source);
+        if p <= 0.0 {
+            0
+        } else if p >= 1.0 {
+            self.n
+        } else {
+            Binomial::new(self.n, p).sample(source)
+        }
+    }
+}
+
+impl distribution::Skewness for BetaBinomial {
+    fn skewness(&self) -> f64 {
+        let (n, alpha, beta) = (self.n as f64, self.alpha, self.beta);
+        let sum = alpha + beta;
+        (sum + 2.0 * n) * (beta - alpha) / (sum + 2.0)
+            * ((1.0 + sum) / (n * alpha * beta * (n + sum))).sqrt()
+    }
+}
+
+impl distribution::Support for BetaBinomial {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Included;
+        (Included(0.0), Included(self.n as f64))
+    }
+}
+
+impl distribution::Variance for BetaBinomial {
+    #[inline]
+    fn variance(&self) -> f64 {
+        let (n, alpha, beta) = (self.n as f64, self.alpha, self.beta);
+        let sum = alpha + beta;
+        n * alpha * beta * (sum + n) / (sum * sum * (sum + 1.0))
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($n:expr, $alpha:expr, $beta:expr) => (BetaBinomial::new($n, $alpha, $beta));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(10, 2.0, 3.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            -0.10580565416577598,
+            0.28929758202932904,
+            0.016265632524861275,
+        ];
+        let im = vec![
+            -0.13429836994697283,
+            0.6991631876069098,
+            0.02243404217712925,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(10, 2.0, 3.0);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 3.0, 4.0, 5.5, 8.0, 10.0, 11.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            6.5934065934065936e-02,
+            6.5934065934065936e-02,
+            1.7582417582417584e-01,
+            4.5454545454545453e-01,
+            5.9440559440559437e-01,
+            7.2027972027972031e-01,
+            9.5904095904095910e-01,
+            1.0000000000000000e+00,
+            1.0000000000000000e+00,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(
+            new!(10, 2.0, 3.0).kurtosis(),
+            -6.9047619047619047e-01,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn log_mass() {
+        let d = new!(10, 2.0, 3.0);
+        let x = vec![0, 2, 5, 20];
+        let p = vec![
+            -2.7191000372887912,
+            -2.003480000876786,
+            -2.0724728723637384,
+            f64::NEG_INFINITY,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_mass(x)).collect::<Vec<_>>(),
+            &p,
+            1e-13,
+        );
+    }
+
+    #[test]
+    fn mass() {
+        let d = new!(10, 2.0, 3.0);
+        let x = vec![0, 1, 2, 4, 6, 8, 10, 11];
+        let p = vec![
+            6.5934065934065936e-02,
+            1.0989010989010989e-01,
+            1.3486513486513488e-01,
+            1.3986013986013987e-01,
+            1.0489510489510490e-01,
+            5.3946053946053944e-02,
+            1.0989010989010990e-02,
+            0.0000000000000000e+00,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.mass(x)).collect::<Vec<_>>(), &p, 1e-13);
+
+        let d = new!(10, 1.0, 1.0);
+        for x in 0..11 {
+            assert::close(d.mass(x), 1.0 / 11.0, 1e-14);
+        }
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(10, 2.0, 3.0).mean(), 4.0, 1e-15);
+    }
+
+    #[test]
+    fn mgf() {
+        let d = new!(10, 2.0, 3.0);
+        let t = vec![-0.7, -0.1, 0.2, 0.45];
+        let m = vec![
+            0.18611592906472613,
+            0.6901756591379163,
+            2.518957094288805,
+            11.274834376068242,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(10, 2.0, 3.0);
+        let mut source = source::default(42);
+
+        let mean = (0..n).map(|_| d.sample(&mut source) as f64).sum::<f64>() / n as f64;
+        assert!((mean - d.mean()).abs() < 0.05);
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(10, 2.0, 3.0).skewness(), 2.9160592175990213e-01, 1e-15);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::Included;
+        assert_eq!(
+            new!(10, 2.0, 3.0).support(),
+            (Included(0.0), Included(10.0))
+        );
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(10, 2.0, 3.0).variance(), 6.0, 1e-14);
+    }
+}
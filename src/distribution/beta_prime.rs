@@ -0,0 +1,446 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A beta-prime distribution, also known as the inverted beta distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct BetaPrime {
+    alpha: f64,
+    beta: f64,
+    ln_beta: f64,
+}
+
+impl BetaPrime {
+    /// Create a beta-prime distribution with shape parameters `alpha` and
+    /// `beta`.
+    ///
+    /// It should hold that `alpha > 0` and `beta > 0`.
+    #[inline]
+    pub fn new(alpha: f64, beta: f64) -> Self {
+        use special::Beta;
+        should!(alpha > 0.0 && beta > 0.0);
+        BetaPrime {
+            alpha,
+            beta,
+            ln_beta: alpha.ln_beta(beta),
+        }
+    }
+
+    /// Return the first shape parameter.
+    #[inline(always)]
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Return the second shape parameter.
+    #[inline(always)]
+    pub fn beta(&self) -> f64 {
+        self.beta
+    }
+
+    /// Compute `E[X^k]` for `k < beta`.
+    fn moment(&self, k: i32) -> f64 {
+        (1..=k).fold(1.0, |product, i| {
+            product * (self.alpha + (i - 1) as f64) / (self.beta - i as f64)
+        })
+    }
+}
+
+impl distribution::CharacteristicFunction for BetaPrime {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Continuous, Inverse};
+        let (lower, upper) = (self.inverse(0.25), self.inverse(0.75));
+        characteristic::continuous(
+            |x| self.density(x),
+            t,
+            0.0,
+            f64::INFINITY,
+            lower,
+            upper - lower,
+        )
+    }
+}
+
+impl distribution::Continuous for BetaPrime {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        self.log_density(x).exp()
+    }
+
+    fn log_density(&self, x: f64) -> f64 {
+        let &BetaPrime {
+            alpha,
+            beta,
+            ln_beta,
+        } = self;
+        if x < 0.0 {
+            f64::NEG_INFINITY
+        } else if x == 0.0 {
+            if alpha < 1.0 {
+                f64::INFINITY
+            } else if alpha == 1.0 {
+                -ln_beta
+            } else {
+                f64::NEG_INFINITY
+            }
+        } else {
+            (alpha - 1.0) * x.ln() - (alpha + beta) * x.ln_1p() - ln_beta
+        }
+    }
+}
+
+impl distribution::Distribution for BetaPrime {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        use special::Beta;
+        if x <= 0.0 {
+            0.0
+        } else {
+            (x / (1.0 + x)).inc_beta(self.alpha, self.beta, self.ln_beta)
+        }
+    }
+}
+
+impl distribution::Inverse for BetaPrime {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        use special::Beta;
+        should!((0.0..=1.0).contains(&p));
+        let y = p.inv_inc_beta(self.alpha, self.beta, self.ln_beta);
+        y / (1.0 - y)
+    }
+}
+
+impl distribution::Kurtosis for BetaPrime {
+    /// Compute the excess kurtosis.
+    ///
+    /// The excess kurtosis is undefined, which is signified by `NaN`, if
+    /// `beta <= 4`.
+    fn kurtosis(&self) -> f64 {
+        if self.beta <= 4.0 {
+            return f64::NAN;
+        }
+        let (m1, m2, m3, m4) = (
+            self.moment(1),
+            self.moment(2),
+            self.moment(3),
+            self.moment(4),
+        );
+        let m1_2 = m1 * m1;
+        (m4 - 4.0 * m1 * m3 + 6.0 * m1_2 * m2 - 3.0 * m1_2 * m1_2) / (m2 - m1_2).powi(2) - 3.0
+    }
+}
+
+impl distribution::Mean for BetaPrime {
+    /// Compute the expected value.
+    ///
+    /// The expected value is infinite if `beta <= 1`.
+    #[inline]
+    fn mean(&self) -> f64 {
+        if self.beta > 1.0 {
+            self.alpha / (self.beta - 1.0)
+        } else {
+            f64::INFINITY
+        }
+    }
+}
+
+impl distribution::Modes for BetaPrime {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        if self.alpha >= 1.0 {
+            vec![(self.alpha - 1.0) / (self.beta + 1.0)]
+        } else {
+            vec![0.0]
+        }
+    }
+}
+
+impl distribution::Moments for BetaPrime {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is infinite if `n >= beta`.
+    fn raw_moment(&self, n: usize) -> f64 {
+        if n as f64 >= self.beta {
+            f64::INFINITY
+        } else {
+            self.moment(n as i32)
+        }
+    }
+}
+
+impl distribution::Sample for BetaPrime {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained as the ratio of two gamma variates.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::gamma;
+        let x = gamma::sample(self.alpha, source);
+        let y = gamma::sample(self.beta, source);
+        x / y
+    }
+}
+
+impl distribution::Skewness for BetaPrime {
+    /// Compute the skewness.
+    ///
+    /// The skewness is undefined, which is signified by `NaN`, if `beta <= 3`.
+    fn skewness(&self) -> f64 {
+        let &BetaPrime { alpha, beta, .. } = self;
+        if beta <= 3.0 {
+            return f64::NAN;
+        }
+        2.0 * (2.0 * alpha + beta - 1.0) / (beta - 3.0)
+            * ((beta - 2.0) / (alpha * (alpha + beta - 1.0))).sqrt()
+    }
+}
+
+impl distribution::Support for BetaPrime {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Included, Unbounded};
+        (Included(0.0), Unbounded)
+    }
+}
+
+impl distribution::TryMean for BetaPrime {
+    /// Compute the expected value.
+    ///
+    /// The expected value does not exist if `beta <= 1`.
+    #[inline]
+    fn try_mean(&self) -> Option<f64> {
+        use distribution::Mean;
+        if self.beta > 1.0 {
+            Some(self.mean())
+        } else {
+            None
+        }
+    }
+}
+
+impl distribution::TryVariance for BetaPrime {
+    /// Compute the variance.
+    ///
+    /// The variance does not exist if `beta <= 2`.
+    #[inline]
+    fn try_variance(&self) -> Option<f64> {
+        use distribution::Variance;
+        if self.beta > 2.0 {
+            Some(self.variance())
+        } else {
+            None
+        }
+    }
+}
+
+impl distribution::Variance for BetaPrime {
+    /// Compute the variance.
+    ///
+    /// The variance is infinite if `1 < beta <= 2` and undefined, which is
+    /// signified by `NaN`, if `beta <= 1`.
+    fn variance(&self) -> f64 {
+        let &BetaPrime { alpha, beta, .. } = self;
+        if beta > 2.0 {
+            alpha * (alpha + beta - 1.0) / ((beta - 2.0) * (beta - 1.0).powi(2))
+        } else if beta > 1.0 {
+            f64::INFINITY
+        } else {
+            f64::NAN
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($alpha:expr, $beta:expr) => (BetaPrime::new($alpha, $beta));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(3.0, 5.5);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.8459661943127367, 0.9672980191814701, 0.3256068832327738];
+        let im = vec![-0.4031257636709144, 0.19377312028367694, 0.6266871108202325];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(3.0, 5.5);
+        let x = vec![-1.0, 0.0, 0.1, 0.25, 0.5, 1.0, 2.0, 4.0, 8.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            5.9630642674766166e-01,
+            1.2573385193519970e+00,
+            1.0677550166681740e+00,
+            3.7029883926883411e-01,
+            4.7188550808251309e-02,
+            2.4557392956093692e-03,
+            6.6439439138697701e-05,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+
+        assert_eq!(new!(1.0, 2.0).density(0.0), 2.0);
+        assert_eq!(new!(0.5, 2.0).density(0.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(3.0, 5.5);
+        let x = vec![-1.0, 0.0, 0.1, 0.25, 0.5, 1.0, 2.0, 4.0, 8.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            2.4503845474338131e-02,
+            1.7496318601961039e-01,
+            4.8180281009250858e-01,
+            8.1838956694134568e-01,
+            9.7003692018608934e-01,
+            9.9759005537656986e-01,
+            9.9988702972289112e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn inverse() {
+        use core::f64::INFINITY;
+        let d = new!(3.0, 5.5);
+        let p = vec![
+            0.0, 0.01, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.99, 1.0,
+        ];
+        let x = vec![
+            0.0000000000000000e+00,
+            7.0023706527825455e-02,
+            1.8683040294464676e-01,
+            2.6971054112622983e-01,
+            3.4706865512724450e-01,
+            4.2760756835731417e-01,
+            5.1729276410244929e-01,
+            6.2352199818438037e-01,
+            7.5908479430834208e-01,
+            9.5292406365034921e-01,
+            1.3031272154690490e+00,
+            2.7650238715610520e+00,
+            INFINITY,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(3.0, 5.5).kurtosis(), 31.44, 1e-12);
+        assert!(new!(3.0, 4.0).kurtosis().is_nan());
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(3.0, 5.5);
+        let x = vec![-40.0, 0.05, 4.0, 1000000.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -1.5078748334258192,
+            -6.0093274243277355,
+            -84.90252101764119,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(3.0, 5.5).mean(), 6.6666666666666663e-01, 1e-15);
+        assert_eq!(new!(3.0, 1.0).mean(), f64::INFINITY);
+    }
+
+    #[test]
+    fn modes() {
+        assert::close(new!(3.0, 5.5).modes(), vec![3.0769230769230771e-01], 1e-15);
+        assert_eq!(new!(0.5, 5.5).modes(), vec![0.0]);
+    }
+
+    #[test]
+    fn sample() {
+        for x in Independent(&new!(3.0, 5.5), &mut source::default(42)).take(100) {
+            assert!(x > 0.0);
+        }
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(3.0, 5.5).skewness(), 3.3130046785357852e+00, 1e-14);
+        assert!(new!(3.0, 3.0).skewness().is_nan());
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Included, Unbounded};
+        assert_eq!(new!(3.0, 5.5).support(), (Included(0.0), Unbounded));
+    }
+
+    #[test]
+    fn try_mean() {
+        assert_eq!(new!(2.0, 1.0).try_mean(), None);
+        let d = new!(2.0, 3.0);
+        assert_eq!(d.try_mean(), Some(d.mean()));
+    }
+
+    #[test]
+    fn try_variance() {
+        assert_eq!(new!(2.0, 2.0).try_variance(), None);
+        let d = new!(2.0, 3.0);
+        assert_eq!(d.try_variance(), Some(d.variance()));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(3.0, 5.5).variance(), 3.1746031746031744e-01, 1e-15);
+        assert_eq!(new!(3.0, 2.0).variance(), f64::INFINITY);
+        assert!(new!(3.0, 1.0).variance().is_nan());
+    }
+}
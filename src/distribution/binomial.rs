@@ -1,4 +1,5 @@
 use alloc::{vec, vec::Vec};
+use core::ops::Bound;
 #[allow(unused_imports)]
 use special::Primitive;
 
@@ -75,6 +76,27 @@ impl Binomial {
     }
 }
 
+impl distribution::divergence::Bhattacharyya for Binomial {
+    fn bhattacharyya(&self, q: &Self) -> f64 {
+        use distribution::divergence;
+        if self.n != q.n {
+            return divergence::bhattacharyya_discrete(self, q);
+        }
+        -(self.n as f64) * ((self.p * q.p).sqrt() + (self.q * q.q).sqrt()).ln()
+    }
+}
+
+impl distribution::CharacteristicFunction for Binomial {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::complex;
+        let z = (
+            1.0 - 2.0 * self.p * (0.5 * t).sin().powi(2),
+            self.p * t.sin(),
+        );
+        complex::powf(z, self.n as f64)
+    }
+}
+
 impl distribution::Discrete for Binomial {
     /// Compute the probability mass function.
     ///
@@ -111,6 +133,32 @@ impl distribution::Discrete for Binomial {
             ln_c.exp() * (n / (2.0 * PI * x * (n_m_x))).sqrt()
         }
     }
+
+    fn log_mass(&self, x: usize) -> f64 {
+        use core::f64::consts::PI;
+
+        if self.p == 0.0 || self.p == 1.0 {
+            return self.mass(x).ln();
+        }
+
+        let n = self.n as f64;
+        if x > self.n {
+            f64::NEG_INFINITY
+        } else if x == 0 {
+            n * self.q.ln()
+        } else if x == self.n {
+            n * self.p.ln()
+        } else {
+            let x = x as f64;
+            let n_m_x = n - x;
+            let ln_c = stirlerr(n)
+                - stirlerr(x)
+                - stirlerr(n_m_x)
+                - ln_d0(x, self.np)
+                - ln_d0(n_m_x, self.nq);
+            ln_c + 0.5 * (n / (2.0 * PI * x * (n_m_x))).ln()
+        }
+    }
 }
 
 impl distribution::Distribution for Binomial {
@@ -260,6 +308,12 @@ impl distribution::Median for Binomial {
     }
 }
 
+impl distribution::Mgf for Binomial {
+    fn mgf(&self, t: f64) -> f64 {
+        (self.q + self.p * t.exp()).powi(self.n as i32)
+    }
+}
+
 impl distribution::Modes for Binomial {
     fn modes(&self) -> Vec<usize> {
         let r = self.p * (self.n + 1) as f64;
@@ -275,6 +329,30 @@ impl distribution::Modes for Binomial {
     }
 }
 
+impl distribution::Moments for Binomial {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is computed from the factorial moments as `sum S(n, k)
+    /// n_(k) p^k`, where `S(n, k)` are the Stirling numbers of the second kind
+    /// and `n_(k)` is the falling factorial.
+    fn raw_moment(&self, n: usize) -> f64 {
+        let mut stirling = vec![0.0; n + 1];
+        stirling[0] = 1.0;
+        for i in 0..n {
+            for k in (1..i + 2).rev() {
+                stirling[k] = k as f64 * stirling[k] + stirling[k - 1];
+            }
+            stirling[0] = 0.0;
+        }
+        let (mut sum, mut factorial) = (0.0, 1.0);
+        for (k, &s) in stirling.iter().enumerate().take(self.n.min(n) + 1) {
+            sum += s * factorial;
+            factorial *= (self.n - k) as f64 * self.p;
+        }
+        sum
+    }
+}
+
 impl distribution::Sample for Binomial {
     #[inline]
     fn sample<S>(&self, source: &mut S) -> usize
@@ -293,6 +371,34 @@ impl distribution::Skewness for Binomial {
     }
 }
 
+impl distribution::Support for Binomial {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Included;
+        (Included(0.0), Included(self.n as f64))
+    }
+}
+
+impl distribution::Survival for Binomial {
+    /// Compute the survival function.
+    ///
+    /// The implementation is based on the incomplete beta function.
+    fn survival(&self, x: f64) -> f64 {
+        use special::Beta;
+        if x < 0.0 {
+            return 1.0;
+        }
+        let x = x as usize;
+        if x >= self.n {
+            return 0.0;
+        }
+        if x + 1 == self.n {
+            return self.p.powi(self.n as i32);
+        }
+        let (p, q) = ((x + 1) as f64, (self.n - x) as f64);
+        self.p.inc_beta(p, q, p.ln_beta(q))
+    }
+}
+
 impl distribution::Variance for Binomial {
     #[inline]
     fn variance(&self) -> f64 {
@@ -386,7 +492,7 @@ fn stirlerr(n: f64) -> f64 {
         1.041126526197209650e-02,
         9.255462182712732918e-03,
         8.330563433362871256e-03,
-        7.757367548795184079e-03,
+        7.573675487951840795e-03,
         6.942840107209529866e-03,
         6.408994188004207068e-03,
         5.951370112758847736e-03,
@@ -411,6 +517,11 @@ fn stirlerr(n: f64) -> f64 {
 }
 
 #[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
 mod tests {
     use alloc::{vec, vec::Vec};
     use assert;
@@ -422,6 +533,37 @@ mod tests {
         };
     }
 
+    #[test]
+    fn bhattacharyya() {
+        let d = new!(20, 0.3);
+        let q = vec![new!(20, 0.3), new!(20, 0.6), new!(30, 0.4)];
+        assert::close(
+            &q.iter().map(|q| d.bhattacharyya(q)).collect::<Vec<_>>(),
+            &vec![0.0, 0.9541140987375519, 0.7965764562716092],
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn cf() {
+        let d = new!(16, 0.25);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            -0.43430909874875184,
+            0.3220778605622132,
+            0.0015096574927582836,
+        ];
+        let im = vec![
+            -0.19919799783424433,
+            0.812067760558285,
+            -0.0017861856685552748,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
     #[test]
     fn distribution() {
         let d = new!(16, 0.75);
@@ -451,7 +593,7 @@ mod tests {
 
     #[test]
     fn entropy() {
-        assert_eq!(new!(16, 0.25).entropy(), 1.9588018945068573);
+        assert_eq!(new!(16, 0.25).entropy(), 1.95882585314307);
         assert_eq!(new!(10_000_000, 0.5).entropy(), 8.784839178123887);
     }
 
@@ -492,6 +634,23 @@ mod tests {
         assert_eq!(new!(16, 0.25).kurtosis(), -0.041666666666666664);
     }
 
+    #[test]
+    fn log_mass() {
+        let d = new!(16, 0.25);
+        let x = vec![0, 2, 5, 20];
+        let p = vec![
+            -4.602913159228494,
+            -2.0126459937826677,
+            -1.7139140851443018,
+            f64::NEG_INFINITY,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_mass(x)).collect::<Vec<_>>(),
+            &p,
+            1e-13,
+        );
+    }
+
     #[test]
     fn mass() {
         let d = new!(16, 0.25);
@@ -527,6 +686,19 @@ mod tests {
         assert_eq!(new!(39, 0.1).median(), 4.0);
     }
 
+    #[test]
+    fn mgf() {
+        let d = new!(16, 0.25);
+        let t = vec![-0.7, -0.1, 0.2, 0.45];
+        let m = vec![
+            0.11623747973124661,
+            0.6802795869654011,
+            2.3678204755068624,
+            8.377849106630865,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+    }
+
     #[test]
     fn modes() {
         assert_eq!(new!(16, 0.25).modes(), vec![4]);
@@ -535,11 +707,52 @@ mod tests {
         assert_eq!(new!(39, 0.1).modes(), vec![3, 4]);
     }
 
+    #[test]
+    fn moments() {
+        let d = new!(16, 0.25);
+        let m = vec![3759.625, 25370.40625, 181212.671875];
+        assert::close(
+            &(5..8).map(|n| d.raw_moment(n)).collect::<Vec<_>>(),
+            &m,
+            1e-9,
+        );
+        assert_eq!(new!(16, 0.25).raw_moment(0), 1.0);
+        assert_eq!(new!(2, 0.25).raw_moment(5), 2.375);
+    }
+
     #[test]
     fn skewness() {
         assert_eq!(new!(16, 0.25).skewness(), 0.2886751345948129);
     }
 
+    #[test]
+    fn support() {
+        use core::ops::Bound::Included;
+        assert_eq!(new!(16, 0.25).support(), (Included(0.0), Included(16.0)));
+    }
+
+    #[test]
+    fn survival() {
+        let d = new!(100, 0.1);
+        let x = vec![-1.0, 0.0, 5.0, 10.0, 40.0, 80.0, 99.0, 100.0];
+        let p = vec![
+            1.0,
+            0.9999734386011124,
+            0.9424231135129662,
+            0.4168444877335082,
+            4.753173592422117e-16,
+            1.8348987229391154e-62,
+            1e-100,
+            0.0,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.survival(x)).collect::<Vec<_>>(),
+            &p,
+            1e-13,
+        );
+        assert::close(d.survival(80.0) / 1.8348987229391154e-62, 1.0, 1e-13);
+    }
+
     #[test]
     fn variance() {
         assert_eq!(new!(16, 0.25).variance(), 3.0);
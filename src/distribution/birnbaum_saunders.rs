@@ -0,0 +1,357 @@
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A Birnbaum–Saunders distribution, also known as the fatigue-life
+/// distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct BirnbaumSaunders {
+    alpha: f64,
+    beta: f64,
+}
+
+impl BirnbaumSaunders {
+    /// Create a Birnbaum–Saunders distribution with shape `alpha` and scale
+    /// `beta`.
+    ///
+    /// It should hold that `alpha > 0` and `beta > 0`.
+    #[inline]
+    pub fn new(alpha: f64, beta: f64) -> Self {
+        should!(alpha > 0.0 && beta > 0.0);
+        BirnbaumSaunders { alpha, beta }
+    }
+
+    /// Return the shape parameter.
+    #[inline(always)]
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Return the scale parameter, which is also the median.
+    #[inline(always)]
+    pub fn beta(&self) -> f64 {
+        self.beta
+    }
+
+    /// Map a standard Gaussian variate to the distribution.
+    #[inline]
+    fn transform(&self, z: f64) -> f64 {
+        let w = 0.5 * self.alpha * z;
+        let s = (w * w + 1.0).sqrt();
+        let r = if w >= 0.0 { w + s } else { 1.0 / (s - w) };
+        self.beta * r * r
+    }
+}
+
+impl distribution::CharacteristicFunction for BirnbaumSaunders {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Continuous, Mean, Variance};
+        characteristic::continuous(
+            |x| self.density(x),
+            t,
+            0.0,
+            f64::INFINITY,
+            self.mean(),
+            self.deviation(),
+        )
+    }
+}
+
+impl distribution::Continuous for BirnbaumSaunders {
+    fn density(&self, x: f64) -> f64 {
+        use core::f64::consts::PI;
+        let &BirnbaumSaunders { alpha, beta } = self;
+        if x <= 0.0 {
+            0.0
+        } else {
+            let (u, v) = ((x / beta).sqrt(), (beta / x).sqrt());
+            let z = (u - v) / alpha;
+            (u + v) / (2.0 * alpha * x) * (-0.5 * z * z).exp() / (2.0 * PI).sqrt()
+        }
+    }
+
+    fn log_density(&self, x: f64) -> f64 {
+        use core::f64::consts::PI;
+        let &BirnbaumSaunders { alpha, beta } = self;
+        if x <= 0.0 {
+            f64::NEG_INFINITY
+        } else {
+            let (u, v) = ((x / beta).sqrt(), (beta / x).sqrt());
+            let z = (u - v) / alpha;
+            ((u + v) / (2.0 * alpha * x)).ln() - 0.5 * z * z - 0.5 * (2.0 * PI).ln()
+        }
+    }
+}
+
+impl distribution::Distribution for BirnbaumSaunders {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        use core::f64::consts::FRAC_1_SQRT_2;
+        use special::Error;
+        let &BirnbaumSaunders { alpha, beta } = self;
+        if x <= 0.0 {
+            0.0
+        } else {
+            let z = ((x / beta).sqrt() - (beta / x).sqrt()) / alpha;
+            0.5 * (-z * FRAC_1_SQRT_2).compl_error()
+        }
+    }
+}
+
+impl distribution::Inverse for BirnbaumSaunders {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        use distribution::gaussian;
+        should!((0.0..=1.0).contains(&p));
+        self.transform(gaussian::inverse(p))
+    }
+}
+
+impl distribution::Kurtosis for BirnbaumSaunders {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        let alpha2 = self.alpha * self.alpha;
+        6.0 * alpha2 * (93.0 * alpha2 + 40.0) / (5.0 * alpha2 + 4.0).powi(2)
+    }
+}
+
+impl distribution::Mean for BirnbaumSaunders {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.beta * (1.0 + 0.5 * self.alpha * self.alpha)
+    }
+}
+
+impl distribution::Median for BirnbaumSaunders {
+    #[inline]
+    fn median(&self) -> f64 {
+        self.beta
+    }
+}
+
+impl distribution::Moments for BirnbaumSaunders {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is computed by numerical integration.
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::{moments, Continuous, Mean, Variance};
+        moments::continuous(
+            |x| self.density(x),
+            n,
+            0.0,
+            f64::INFINITY,
+            self.mean(),
+            self.deviation(),
+        )
+    }
+}
+
+impl distribution::Sample for BirnbaumSaunders {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::gaussian;
+        self.transform(gaussian::sample(source))
+    }
+}
+
+impl distribution::Skewness for BirnbaumSaunders {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        let alpha = self.alpha;
+        let alpha2 = alpha * alpha;
+        4.0 * alpha * (11.0 * alpha2 + 6.0) / (5.0 * alpha2 + 4.0).powf(1.5)
+    }
+}
+
+impl distribution::Support for BirnbaumSaunders {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Excluded, Unbounded};
+        (Excluded(0.0), Unbounded)
+    }
+}
+
+impl distribution::Variance for BirnbaumSaunders {
+    #[inline]
+    fn variance(&self) -> f64 {
+        let alpha_beta = self.alpha * self.beta;
+        alpha_beta * alpha_beta * (1.0 + 1.25 * self.alpha * self.alpha)
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($alpha:expr, $beta:expr) => (BirnbaumSaunders::new($alpha, $beta));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(0.5, 2.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.07366736468086924, 0.7428583066293013, -0.264066682624247];
+        let im = vec![
+            -0.7558685914026662,
+            0.5832073778363979,
+            -0.06001262772646069,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(0.5, 2.0);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 4.0, 6.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            2.2159242059690037e-02,
+            3.1133062306544601e-01,
+            4.5492919668639598e-01,
+            3.9894228040143270e-01,
+            2.9058160656876514e-01,
+            1.9449944344635814e-01,
+            7.7832655766361503e-02,
+            1.0669389281135484e-02,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(0.5, 2.0);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 4.0, 6.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            1.3498980316300946e-03,
+            7.8649603525142567e-02,
+            2.8185143082538650e-01,
+            5.0000000000000000e-01,
+            6.7263957699071153e-01,
+            7.9289191087873745e-01,
+            9.2135039647485739e-01,
+            9.8953933233110303e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn inverse() {
+        use core::f64::INFINITY;
+        let d = new!(0.5, 2.0);
+        let p = vec![
+            0.0, 0.01, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.99, 1.0,
+        ];
+        let x = vec![
+            0.0000000000000000e+00,
+            6.6179691078526293e-01,
+            1.0648738994578395e+00,
+            1.3170326317811758e+00,
+            1.5398611609840034e+00,
+            1.7621914372083725e+00,
+            2.0000000000000000e+00,
+            2.2699009401252783e+00,
+            2.5976367878802247e+00,
+            3.0371305186192212e+00,
+            3.7563133081170688e+00,
+            6.0441503047419065e+00,
+            INFINITY,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(0.5, 2.0).kurtosis(), 3.4421768707482991e+00, 1e-15);
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(0.5, 2.0);
+        let x = vec![-40.0, 0.05, 1.5, 400.0, 10000.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -72.10407392000336,
+            -0.7876134838181907,
+            -400.2662568555276,
+            -10001.870882329471,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(0.5, 2.0).mean(), 2.25);
+    }
+
+    #[test]
+    fn median() {
+        assert_eq!(new!(0.5, 2.0).median(), 2.0);
+    }
+
+    #[test]
+    fn sample() {
+        for x in Independent(&new!(0.5, 2.0), &mut source::default(42)).take(100) {
+            assert!(x > 0.0);
+        }
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(0.5, 2.0).skewness(), 1.4547859349066159e+00, 1e-15);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Excluded, Unbounded};
+        assert_eq!(new!(0.5, 2.0).support(), (Excluded(0.0), Unbounded));
+    }
+
+    #[test]
+    fn variance() {
+        assert_eq!(new!(0.5, 2.0).variance(), 1.3125);
+    }
+}
@@ -0,0 +1,306 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A Borel distribution.
+///
+/// The distribution describes the total progeny of a branching process with
+/// Poisson offspring, which is also the number of customers served during a
+/// busy period of an M/D/1 queue.
+#[derive(Clone, Copy, Debug)]
+pub struct Borel {
+    mu: f64,
+}
+
+impl Borel {
+    /// Create a Borel distribution with parameter `mu`.
+    ///
+    /// It should hold that `mu > 0` and `mu < 1`.
+    #[inline]
+    pub fn new(mu: f64) -> Self {
+        should!(mu > 0.0 && mu < 1.0);
+        Borel { mu }
+    }
+
+    /// Return the parameter, which is the mean number of offspring.
+    #[inline(always)]
+    pub fn mu(&self) -> f64 {
+        self.mu
+    }
+
+    /// Compute the first four cumulants.
+    ///
+    /// The cumulant generating function satisfies `K(t) = t + mu (exp(K(t)) -
+    /// 1)`, which is differentiated repeatedly at zero.
+    fn cumulants(&self) -> (f64, f64, f64, f64) {
+        let mu = self.mu;
+        let k1 = 1.0 / (1.0 - mu);
+        let factor = mu * k1;
+        let k2 = factor * k1 * k1;
+        let k3 = factor * (k1.powi(3) + 3.0 * k1 * k2);
+        let k4 = factor * (k1.powi(4) + 6.0 * k1 * k1 * k2 + 3.0 * k2 * k2 + 4.0 * k1 * k3);
+        (k1, k2, k3, k4)
+    }
+}
+
+impl distribution::CharacteristicFunction for Borel {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by summing the probability mass function.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Discrete, Mean};
+        characteristic::discrete(|x| self.mass(x), t, 1, usize::MAX, self.mean())
+    }
+}
+
+impl distribution::Discrete for Borel {
+    #[inline]
+    fn mass(&self, x: usize) -> f64 {
+        self.log_mass(x).exp()
+    }
+
+    fn log_mass(&self, x: usize) -> f64 {
+        use special::Gamma;
+        if x < 1 {
+            return f64::NEG_INFINITY;
+        }
+        let n = x as f64;
+        let mu_n = self.mu * n;
+        -mu_n + (n - 1.0) * mu_n.ln() - Gamma::ln_gamma(n + 1.0).0
+    }
+}
+
+impl distribution::Distribution for Borel {
+    type Value = usize;
+
+    /// Compute the cumulative distribution function.
+    ///
+    /// The probability masses are summed until they become negligible relative
+    /// to the sum, beyond which the function is indistinguishable from one.
+    fn distribution(&self, x: f64) -> f64 {
+        use distribution::Discrete;
+        if x < 1.0 {
+            return 0.0;
+        }
+        let x = x as usize;
+        let mut sum = 0.0;
+        for n in 1..=x {
+            let term = self.mass(n);
+            sum += term;
+            if term <= f64::EPSILON * sum {
+                return 1.0;
+            }
+        }
+        sum.min(1.0)
+    }
+}
+
+impl distribution::Kurtosis for Borel {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        let (_, k2, _, k4) = self.cumulants();
+        k4 / (k2 * k2)
+    }
+}
+
+impl distribution::Mean for Borel {
+    #[inline]
+    fn mean(&self) -> f64 {
+        1.0 / (1.0 - self.mu)
+    }
+}
+
+impl distribution::Modes for Borel {
+    #[inline]
+    fn modes(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+impl distribution::Moments for Borel {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is computed by summing the probability mass function.
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::{moments, Discrete, Mean};
+        moments::discrete(|x| self.mass(x), n, 1, usize::MAX, self.mean())
+    }
+}
+
+impl distribution::Sample for Borel {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained by simulating the branching process one
+    /// generation at a time, where the offspring of a generation of size `z`
+    /// is a Poisson variate with rate `mu z`.
+    fn sample<S>(&self, source: &mut S) -> usize
+    where
+        S: Source,
+    {
+        use distribution::poisson;
+        let mut generation = 1;
+        let mut total = 0;
+        while generation > 0 {
+            total += generation;
+            generation = poisson::sample(self.mu * generation as f64, source);
+        }
+        total
+    }
+}
+
+impl distribution::Skewness for Borel {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        let (_, k2, k3, _) = self.cumulants();
+        k3 / k2.powf(1.5)
+    }
+}
+
+impl distribution::Support for Borel {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Included, Unbounded};
+        (Included(1.0), Unbounded)
+    }
+}
+
+impl distribution::Variance for Borel {
+    #[inline]
+    fn variance(&self) -> f64 {
+        self.mu / (1.0 - self.mu).powi(3)
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($mu:expr) => (Borel::new($mu));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(0.5);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            0.39044090939711357,
+            0.7735933173059589,
+            -0.30942532183956195,
+        ];
+        let im = vec![-0.6254169702442718, 0.4460348965016593, 0.41741002724667325];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(0.5);
+        let x = vec![0.0, 1.0, 1.5, 2.0, 3.0, 10.0, 30.0, 100.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            6.0653065971263342e-01,
+            6.0653065971263342e-01,
+            7.9047038029835459e-01,
+            8.7414419035401580e-01,
+            9.9001030156492120e-01,
+            9.9994497333163346e-01,
+            9.9999999998585809e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+        assert_eq!(d.distribution(1e9), 1.0);
+    }
+
+    #[test]
+    fn log_mass() {
+        let d = new!(0.5);
+        let x = vec![0, 1, 20, 1000, 10000];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -0.5,
+            -8.586499693866614,
+            -203.73468816439345,
+            -1945.5131158433942,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_mass(x)).collect::<Vec<_>>(),
+            &p,
+            1e-11,
+        );
+    }
+
+    #[test]
+    fn mass() {
+        let d = new!(0.5);
+        let x = vec![0, 1, 2, 3, 5, 10, 30];
+        let p = vec![
+            0.0000000000000000e+00,
+            6.0653065971263342e-01,
+            1.8393972058572117e-01,
+            8.3673810055661185e-02,
+            2.6720377156217057e-02,
+            3.6265577415643749e-03,
+            1.4742434575962400e-05,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.mass(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(0.5).kurtosis(), 26.0, 1e-13);
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(0.5).mean(), 2.0);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(0.5).modes(), vec![1]);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(0.5);
+        let mut source = source::default(42);
+
+        let mean = (0..n).map(|_| d.sample(&mut source) as f64).sum::<f64>() / n as f64;
+        assert!((mean - d.mean()).abs() < 0.05);
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(0.5).skewness(), 4.0, 1e-14);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Included, Unbounded};
+        assert_eq!(new!(0.5).support(), (Included(1.0), Unbounded));
+    }
+
+    #[test]
+    fn variance() {
+        assert_eq!(new!(0.5).variance(), 4.0);
+    }
+}
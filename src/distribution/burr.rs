@@ -0,0 +1,487 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A Burr distribution of type XII.
+#[derive(Clone, Copy, Debug)]
+pub struct Burr {
+    c: f64,
+    k: f64,
+}
+
+impl Burr {
+    /// Create a Burr distribution with shape parameters `c` and `k`.
+    ///
+    /// It should hold that `c > 0` and `k > 0`.
+    #[inline]
+    pub fn new(c: f64, k: f64) -> Self {
+        should!(c > 0.0 && k > 0.0);
+        Burr { c, k }
+    }
+
+    /// Return the first shape parameter.
+    #[inline(always)]
+    pub fn c(&self) -> f64 {
+        self.c
+    }
+
+    /// Return the second shape parameter.
+    #[inline(always)]
+    pub fn k(&self) -> f64 {
+        self.k
+    }
+
+    #[inline]
+    fn moment(&self, r: f64) -> f64 {
+        use special::Beta;
+        let b = r / self.c;
+        (self.k.ln() + (self.k - b).ln_beta(1.0 + b)).exp()
+    }
+}
+
+impl distribution::CharacteristicFunction for Burr {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Continuous, Inverse};
+        let (lower, upper) = (self.inverse(0.25), self.inverse(0.75));
+        characteristic::continuous(
+            |x| self.density(x),
+            t,
+            0.0,
+            f64::INFINITY,
+            lower,
+            upper - lower,
+        )
+    }
+}
+
+impl distribution::Continuous for Burr {
+    fn density(&self, x: f64) -> f64 {
+        let &Burr { c, k } = self;
+        if x < 0.0 {
+            0.0
+        } else if x == 0.0 {
+            if c < 1.0 {
+                f64::INFINITY
+            } else if c == 1.0 {
+                k
+            } else {
+                0.0
+            }
+        } else {
+            let x_c = x.powf(c);
+            c * k * x_c / x * (-(k + 1.0) * x_c.ln_1p()).exp()
+        }
+    }
+
+    fn log_density(&self, x: f64) -> f64 {
+        let &Burr { c, k } = self;
+        if x < 0.0 {
+            f64::NEG_INFINITY
+        } else if x == 0.0 {
+            if c < 1.0 {
+                f64::INFINITY
+            } else if c == 1.0 {
+                k.ln()
+            } else {
+                f64::NEG_INFINITY
+            }
+        } else {
+            let ln_x_c = c * x.ln();
+            let ln_1p_x_c = if ln_x_c > 0.0 {
+                ln_x_c + (-ln_x_c).exp().ln_1p()
+            } else {
+                ln_x_c.exp().ln_1p()
+            };
+            (c * k).ln() + ln_x_c - x.ln() - (k + 1.0) * ln_1p_x_c
+        }
+    }
+}
+
+impl distribution::Distribution for Burr {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else {
+            -(-self.k * x.powf(self.c).ln_1p()).exp_m1()
+        }
+    }
+}
+
+impl distribution::Entropy for Burr {
+    fn entropy(&self) -> f64 {
+        use special::Gamma;
+        const EULER_MASCHERONI: f64 = 0.5772156649015329;
+        let &Burr { c, k } = self;
+        let harmonic = k.digamma() + EULER_MASCHERONI;
+        -(c * k).ln() + (1.0 - 1.0 / c) * harmonic + 1.0 + 1.0 / k
+    }
+}
+
+impl distribution::Inverse for Burr {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        should!((0.0..=1.0).contains(&p));
+        (-(-p).ln_1p() / self.k).exp_m1().powf(1.0 / self.c)
+    }
+}
+
+impl distribution::Kurtosis for Burr {
+    /// Compute the excess kurtosis.
+    ///
+    /// The excess kurtosis is infinite if `2 < c * k <= 4` and undefined,
+    /// which is signified by `NaN`, if `c * k <= 2`.
+    fn kurtosis(&self) -> f64 {
+        let ck = self.c * self.k;
+        if ck <= 2.0 {
+            return f64::NAN;
+        }
+        if ck <= 4.0 {
+            return f64::INFINITY;
+        }
+        let (m1, m2, m3, m4) = (
+            self.moment(1.0),
+            self.moment(2.0),
+            self.moment(3.0),
+            self.moment(4.0),
+        );
+        let m1_2 = m1 * m1;
+        (m4 - 4.0 * m1 * m3 + 6.0 * m1_2 * m2 - 3.0 * m1_2 * m1_2) / (m2 - m1_2).powi(2) - 3.0
+    }
+}
+
+impl distribution::Mean for Burr {
+    /// Compute the expected value.
+    ///
+    /// The expected value is infinite if `c * k <= 1`.
+    #[inline]
+    fn mean(&self) -> f64 {
+        if self.c * self.k <= 1.0 {
+            f64::INFINITY
+        } else {
+            self.moment(1.0)
+        }
+    }
+}
+
+impl distribution::Median for Burr {
+    #[inline]
+    fn median(&self) -> f64 {
+        use core::f64::consts::LN_2;
+        (LN_2 / self.k).exp_m1().powf(1.0 / self.c)
+    }
+}
+
+impl distribution::Modes for Burr {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        let &Burr { c, k } = self;
+        if c <= 1.0 {
+            vec![0.0]
+        } else {
+            vec![((c - 1.0) / (k * c + 1.0)).powf(1.0 / c)]
+        }
+    }
+}
+
+impl distribution::Moments for Burr {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is infinite if `n >= c * k`.
+    fn raw_moment(&self, n: usize) -> f64 {
+        if n as f64 >= self.c * self.k {
+            f64::INFINITY
+        } else {
+            self.moment(n as f64)
+        }
+    }
+}
+
+impl distribution::Sample for Burr {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::Inverse;
+        self.inverse(source.read::<f64>())
+    }
+}
+
+impl distribution::Skewness for Burr {
+    /// Compute the skewness.
+    ///
+    /// The skewness is infinite if `2 < c * k <= 3` and undefined, which is
+    /// signified by `NaN`, if `c * k <= 2`.
+    fn skewness(&self) -> f64 {
+        let ck = self.c * self.k;
+        if ck <= 2.0 {
+            return f64::NAN;
+        }
+        if ck <= 3.0 {
+            return f64::INFINITY;
+        }
+        let (m1, m2, m3) = (self.moment(1.0), self.moment(2.0), self.moment(3.0));
+        (m3 - 3.0 * m1 * m2 + 2.0 * m1 * m1 * m1) / (m2 - m1 * m1).powf(1.5)
+    }
+}
+
+impl distribution::Support for Burr {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Included, Unbounded};
+        (Included(0.0), Unbounded)
+    }
+}
+
+impl distribution::TryMean for Burr {
+    /// Compute the expected value.
+    ///
+    /// The expected value does not exist if `c * k <= 1`.
+    #[inline]
+    fn try_mean(&self) -> Option<f64> {
+        use distribution::Mean;
+        if self.c * self.k > 1.0 {
+            Some(self.mean())
+        } else {
+            None
+        }
+    }
+}
+
+impl distribution::TryVariance for Burr {
+    /// Compute the variance.
+    ///
+    /// The variance does not exist if `c * k <= 2`.
+    #[inline]
+    fn try_variance(&self) -> Option<f64> {
+        use distribution::Variance;
+        if self.c * self.k > 2.0 {
+            Some(self.variance())
+        } else {
+            None
+        }
+    }
+}
+
+impl distribution::Variance for Burr {
+    /// Compute the variance.
+    ///
+    /// The variance is infinite if `c * k <= 2`.
+    #[inline]
+    fn variance(&self) -> f64 {
+        if self.c * self.k <= 2.0 {
+            f64::INFINITY
+        } else {
+            let m1 = self.moment(1.0);
+            self.moment(2.0) - m1 * m1
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($c:expr, $k:expr) => (Burr::new($c, $k));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(3.0, 2.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.8169110808740566, 0.9642524739775112, 0.030184072895047202];
+        let im = vec![-0.5114114610030722, 0.23741248023649483, 0.7687125866858349];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(3.0, 2.0);
+        let x = vec![-1.0, 0.0, 0.25, 0.5, 0.75, 1.0, 1.5, 2.0, 3.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            3.5795721438324990e-01,
+            1.0534979423868314e+00,
+            1.1740579188954989e+00,
+            7.5000000000000000e-01,
+            1.6121282798833819e-01,
+            3.2921810699588480e-02,
+            2.4599125364431489e-03,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+
+        assert_eq!(new!(1.0, 2.0).density(0.0), 2.0);
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(3.0, 2.0);
+        let x = vec![-1.0, 0.0, 0.25, 0.5, 0.75, 1.0, 1.5, 2.0, 3.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            3.0532544378698224e-02,
+            2.0987654320987653e-01,
+            5.0537374713198890e-01,
+            7.5000000000000000e-01,
+            9.4775510204081637e-01,
+            9.8765432098765427e-01,
+            9.9872448979591832e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(3.0, 2.0).entropy(), 3.7490719743861167e-01, 1e-15);
+    }
+
+    #[test]
+    fn inverse() {
+        use core::f64::INFINITY;
+        let d = new!(3.0, 2.0);
+        let p = vec![
+            0.0, 0.01, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.99, 1.0,
+        ];
+        let x = vec![
+            0.0000000000000000e+00,
+            1.7142760032220200e-01,
+            3.7819213609013502e-01,
+            4.9053390194684271e-01,
+            5.8011552329711846e-01,
+            6.6266632490731570e-01,
+            7.4543212464725617e-01,
+            8.3450055833560244e-01,
+            9.3816976692985732e-01,
+            1.0732002993485570e+00,
+            1.2931150127504951e+00,
+            2.0800838230519036e+00,
+            INFINITY,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(3.0, 2.0).kurtosis(), 7.8094542791956227e+00, 1e-11);
+        assert_eq!(new!(2.0, 2.0).kurtosis(), f64::INFINITY);
+        assert!(new!(1.0, 2.0).kurtosis().is_nan());
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(3.0, 2.0);
+        let x = vec![-40.0, 0.05, 4.0, 1000000.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -4.200080054444381,
+            -7.958813618219075,
+            -94.91681443652186,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(3.0, 2.0).mean(), 8.0613305077076347e-01, 1e-15);
+        assert_eq!(new!(1.0, 1.0).mean(), f64::INFINITY);
+    }
+
+    #[test]
+    fn median() {
+        assert::close(new!(3.0, 2.0).median(), 7.4543212464725617e-01, 1e-15);
+    }
+
+    #[test]
+    fn modes() {
+        assert::close(new!(3.0, 2.0).modes(), vec![6.5863375600834950e-01], 1e-15);
+        assert_eq!(new!(1.0, 2.0).modes(), vec![0.0]);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(3.0, 2.0);
+        let mut source = source::default(42);
+
+        let cross_entropy = -(0..n)
+            .map(|_| d.density(d.sample(&mut source)).ln())
+            .sum::<f64>()
+            / n as f64;
+        assert!((cross_entropy - d.entropy()).abs() < 0.01);
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(3.0, 2.0).skewness(), 1.5891292152782786e+00, 1e-12);
+        assert_eq!(new!(1.5, 2.0).skewness(), f64::INFINITY);
+        assert!(new!(1.0, 2.0).skewness().is_nan());
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Included, Unbounded};
+        assert_eq!(new!(3.0, 2.0).support(), (Included(0.0), Unbounded));
+    }
+
+    #[test]
+    fn try_mean() {
+        assert_eq!(new!(1.0, 1.0).try_mean(), None);
+        let d = new!(1.0, 2.0);
+        assert_eq!(d.try_mean(), Some(d.mean()));
+    }
+
+    #[test]
+    fn try_variance() {
+        assert_eq!(new!(1.0, 2.0).try_variance(), None);
+        let d = new!(2.0, 1.5);
+        assert_eq!(d.try_variance(), Some(d.variance()));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(3.0, 2.0).variance(), 1.5628255522578513e-01, 1e-14);
+        assert_eq!(new!(1.0, 2.0).variance(), f64::INFINITY);
+    }
+}
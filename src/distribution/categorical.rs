@@ -1,4 +1,5 @@
 use alloc::{vec, vec::Vec};
+use core::ops::Bound;
 #[allow(unused_imports)]
 use special::Primitive;
 
@@ -50,12 +51,42 @@ impl Categorical {
     }
 }
 
+impl distribution::divergence::Bhattacharyya for Categorical {
+    fn bhattacharyya(&self, q: &Self) -> f64 {
+        let sum = self
+            .p
+            .iter()
+            .zip(q.p.iter())
+            .fold(0.0, |sum, (&p, &q)| sum + (p * q).sqrt());
+        -sum.ln()
+    }
+}
+
+impl distribution::CharacteristicFunction for Categorical {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use core::f64::consts::FRAC_PI_2;
+        self.p
+            .iter()
+            .enumerate()
+            .fold((0.0, 0.0), |(re, im), (x, &p)| {
+                let phase = t * x as f64;
+                (re + p * (FRAC_PI_2 - phase).sin(), im + p * phase.sin())
+            })
+    }
+}
+
 impl distribution::Discrete for Categorical {
     #[inline]
     fn mass(&self, x: usize) -> f64 {
         should!(x < self.k);
         self.p[x]
     }
+
+    #[inline]
+    fn log_mass(&self, x: usize) -> f64 {
+        should!(x < self.k);
+        self.p[x].ln()
+    }
 }
 
 impl distribution::Distribution for Categorical {
@@ -89,6 +120,19 @@ impl distribution::Inverse for Categorical {
     }
 }
 
+impl distribution::divergence::KullbackLeibler for Categorical {
+    fn kl(&self, q: &Self) -> f64 {
+        self.p
+            .iter()
+            .enumerate()
+            .filter(|&(_, &p)| p > 0.0)
+            .fold(0.0, |sum, (i, &p)| match q.p.get(i) {
+                Some(&q) if q > 0.0 => sum + p * (p / q).ln(),
+                _ => f64::INFINITY,
+            })
+    }
+}
+
 impl distribution::Kurtosis for Categorical {
     fn kurtosis(&self) -> f64 {
         use distribution::{Mean, Variance};
@@ -130,6 +174,16 @@ impl distribution::Median for Categorical {
     }
 }
 
+impl distribution::Mgf for Categorical {
+    fn mgf(&self, t: f64) -> f64 {
+        self.p
+            .iter()
+            .enumerate()
+            .map(|(x, &p)| p * (t * x as f64).exp())
+            .sum()
+    }
+}
+
 impl distribution::Modes for Categorical {
     fn modes(&self) -> Vec<usize> {
         let mut modes = Vec::new();
@@ -147,6 +201,16 @@ impl distribution::Modes for Categorical {
     }
 }
 
+impl distribution::Moments for Categorical {
+    fn raw_moment(&self, n: usize) -> f64 {
+        self.p
+            .iter()
+            .enumerate()
+            .map(|(x, &p)| p * (x as f64).powi(n as i32))
+            .sum()
+    }
+}
+
 impl distribution::Sample for Categorical {
     #[inline]
     fn sample<S>(&self, source: &mut S) -> usize
@@ -171,6 +235,13 @@ impl distribution::Skewness for Categorical {
     }
 }
 
+impl distribution::Support for Categorical {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Included;
+        (Included(0.0), Included((self.k - 1) as f64))
+    }
+}
+
 impl distribution::Variance for Categorical {
     fn variance(&self) -> f64 {
         use distribution::Mean;
@@ -183,6 +254,11 @@ impl distribution::Variance for Categorical {
 }
 
 #[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
 mod tests {
     use alloc::{vec, vec::Vec};
     use prelude::*;
@@ -192,6 +268,43 @@ mod tests {
         ($p:expr) => { Categorical::new(&$p) };
     );
 
+    #[test]
+    fn bhattacharyya() {
+        let d = new!([0.1, 0.2, 0.3, 0.4]);
+        let q = vec![
+            new!([0.1, 0.2, 0.3, 0.4]),
+            new!([0.4, 0.3, 0.2, 0.1]),
+            new!([0.5, 0.5]),
+            new!([0.0, 0.0, 0.0, 1.0]),
+        ];
+        assert::close(
+            &q.iter().map(|q| d.bhattacharyya(q)).collect::<Vec<_>>(),
+            &vec![
+                0.0,
+                0.11664848737353888,
+                0.6164925497574525,
+                0.45814536593707753,
+            ],
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn cf() {
+        let d = new!([0.1, 0.2, 0.3, 0.4]);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.10202013848702712, 0.7873119696062905, 0.20474566109163445];
+        let im = vec![
+            -0.7697622031036258,
+            0.5418275472017718,
+            -0.15694746250681244,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
     #[test]
     fn distribution() {
         let d = new!([0.0, 0.75, 0.25, 0.0]);
@@ -245,12 +358,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn kl() {
+        let d = new!([0.1, 0.2, 0.3, 0.4]);
+        assert_eq!(d.kl(&d), 0.0);
+        assert::close(
+            d.kl(&new!([0.4, 0.3, 0.2, 0.1])),
+            0.45643481914678357,
+            1e-15,
+        );
+        assert_eq!(d.kl(&new!([0.5, 0.5, 0.0, 0.0])), f64::INFINITY);
+        assert_eq!(
+            new!([0.5, 0.5]).kl(&d),
+            0.5 * (0.5f64 / 0.1).ln() + 0.5 * (0.5f64 / 0.2).ln()
+        );
+        assert_eq!(d.kl(&new!([0.5, 0.5])), f64::INFINITY);
+    }
+
     #[test]
     fn kurtosis() {
         assert_eq!(new!(equal 2).kurtosis(), -2.0);
         assert_eq!(new!([0.1, 0.2, 0.3, 0.4]).kurtosis(), -0.7999999999999998);
     }
 
+    #[test]
+    fn log_mass() {
+        let d = new!([0.0, 0.75, 0.25, 0.0]);
+        let x = vec![0, 1, 2, 3];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -0.28768207245178093,
+            -1.3862943611198906,
+            f64::NEG_INFINITY,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_mass(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
     #[test]
     fn mass() {
         let p = [0.0, 0.75, 0.25, 0.0];
@@ -285,6 +432,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn mgf() {
+        let d = new!([0.1, 0.2, 0.3, 0.4]);
+        let t = vec![-0.7, -0.1, 0.2, 0.45];
+        let m = vec![
+            0.32227872124195667,
+            0.8229139978032737,
+            1.5206754810806187,
+            2.694513582723909,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+    }
+
     #[test]
     fn modes() {
         assert_eq!(new!([0.6, 0.2, 0.2]).modes(), vec![0]);
@@ -303,8 +463,8 @@ mod tests {
 
         let sum = Independent(&new!([0.0, 0.5, 0.5]), &mut source)
             .take(100)
-            .fold(0, |a, b| a + b);
-        assert!(100 <= sum && sum <= 200);
+            .sum::<usize>();
+        assert!((100..=200).contains(&sum));
 
         let p = (0..11)
             .map(|i| if i % 2 != 0 { 0.2 } else { 0.0 })
@@ -324,6 +484,15 @@ mod tests {
         assert_eq!(new!([0.1, 0.2, 0.3, 0.4]).skewness(), -0.6);
     }
 
+    #[test]
+    fn support() {
+        use core::ops::Bound::Included;
+        assert_eq!(
+            new!([0.1, 0.2, 0.3, 0.4]).support(),
+            (Included(0.0), Included(3.0))
+        );
+    }
+
     #[test]
     fn variance() {
         assert_eq!(new!(equal 3).variance(), 2.0 / 3.0);
@@ -1,4 +1,5 @@
 use alloc::{vec, vec::Vec};
+use core::ops::Bound;
 #[allow(unused_imports)]
 use special::Primitive;
 
@@ -45,6 +46,13 @@ impl Cauchy {
     }
 }
 
+impl distribution::CharacteristicFunction for Cauchy {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::complex;
+        complex::exp((-self.gamma * t.abs(), self.x_0 * t))
+    }
+}
+
 impl distribution::Continuous for Cauchy {
     #[inline]
     fn density(&self, x: f64) -> f64 {
@@ -52,6 +60,17 @@ impl distribution::Continuous for Cauchy {
         let deviation = x - self.x_0;
         self.gamma / (PI * (self.gamma * self.gamma + deviation * deviation))
     }
+
+    fn log_density(&self, x: f64) -> f64 {
+        use core::f64::consts::PI;
+        let z = (x - self.x_0) / self.gamma;
+        let ln_1p_z2 = if z.abs() > 1.0 {
+            2.0 * z.abs().ln() + z.powi(-2).ln_1p()
+        } else {
+            (z * z).ln_1p()
+        };
+        -(PI * self.gamma).ln() - ln_1p_z2
+    }
 }
 
 impl distribution::Distribution for Cauchy {
@@ -71,17 +90,29 @@ impl distribution::Entropy for Cauchy {
     }
 }
 
+impl distribution::Hazard for Cauchy {
+    fn hazard(&self, x: f64) -> f64 {
+        use distribution::{Continuous, Survival};
+        (self.log_density(x) - self.survival(x).ln()).exp()
+    }
+
+    fn cumulative_hazard(&self, x: f64) -> f64 {
+        use distribution::Survival;
+        -self.survival(x).ln()
+    }
+}
+
 impl distribution::Inverse for Cauchy {
     #[inline]
     fn inverse(&self, p: f64) -> f64 {
-        use core::f64::{consts::PI, INFINITY, NEG_INFINITY};
+        use core::f64::consts::PI;
 
         should!((0.0..=1.0).contains(&p));
 
         if p <= 0.0 {
-            NEG_INFINITY
+            f64::NEG_INFINITY
         } else if 1.0 <= p {
-            INFINITY
+            f64::INFINITY
         } else {
             self.x_0 + self.gamma * (PI * (p - 0.5)).tan()
         }
@@ -95,6 +126,19 @@ impl distribution::Median for Cauchy {
     }
 }
 
+impl distribution::Mgf for Cauchy {
+    /// Compute the moment-generating function.
+    ///
+    /// The function is infinite if `t != 0`.
+    fn mgf(&self, t: f64) -> f64 {
+        if t == 0.0 {
+            1.0
+        } else {
+            f64::INFINITY
+        }
+    }
+}
+
 impl distribution::Modes for Cauchy {
     #[inline]
     fn modes(&self) -> Vec<f64> {
@@ -115,7 +159,48 @@ impl distribution::Sample for Cauchy {
     }
 }
 
+impl distribution::Support for Cauchy {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Unbounded;
+        (Unbounded, Unbounded)
+    }
+}
+
+impl distribution::Survival for Cauchy {
+    #[inline]
+    fn survival(&self, x: f64) -> f64 {
+        use core::f64::consts::FRAC_1_PI;
+        let z = (x - self.x_0) / self.gamma;
+        if z > 0.0 {
+            FRAC_1_PI * z.recip().atan()
+        } else {
+            0.5 - FRAC_1_PI * z.atan()
+        }
+    }
+}
+
+impl distribution::TryMean for Cauchy {
+    #[inline]
+    fn try_mean(&self) -> Option<f64> {
+        None
+    }
+}
+
+impl distribution::TryVariance for Cauchy {
+    #[inline]
+    fn try_variance(&self) -> Option<f64> {
+        None
+    }
+}
+
 #[cfg(test)]
+#[allow(
+    clippy::approx_constant,
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
 mod tests {
     use alloc::{vec, vec::Vec};
     use assert;
@@ -125,6 +210,26 @@ mod tests {
         ($x_0:expr, $gamma:expr) => (Cauchy::new($x_0, $gamma));
     );
 
+    #[test]
+    fn cf() {
+        let d = new!(1.0, 2.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            0.18860776127881243,
+            0.5242997816173469,
+            -0.007621995182886548,
+        ];
+        let im = vec![
+            -0.15886212579029801,
+            0.16218492811665455,
+            0.016654363312194376,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
     #[test]
     fn density() {
         let d = new!(2.0, 8.0);
@@ -186,6 +291,39 @@ mod tests {
         assert::close(new!(3.0, 5.2).entropy(), 4.1796828725566719243, 1e-15);
     }
 
+    #[test]
+    fn hazard() {
+        let d = new!(1.0, 2.0);
+        let x = vec![-10.0, 1.0, 3.0, 1e10];
+        let h = vec![
+            0.00540223131381642,
+            0.3183098861837907,
+            0.3183098861837907,
+            1.0000000001e-10,
+        ];
+        let c = vec![
+            0.05895323809594956,
+            0.6931471805599453,
+            1.3862943611198906,
+            23.477433635129912,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.hazard(x)).collect::<Vec<_>>(),
+            &h,
+            1e-13,
+        );
+        assert::close(
+            &x.iter()
+                .map(|&x| d.cumulative_hazard(x))
+                .collect::<Vec<_>>(),
+            &c,
+            1e-12,
+        );
+
+        assert::close(d.hazard(1e154) * 1e154, 1.0, 1e-12);
+        assert::close(d.hazard(1e200) * 1e200, 1.0, 1e-12);
+    }
+
     #[test]
     fn inverse() {
         use core::f64::{INFINITY, NEG_INFINITY};
@@ -212,11 +350,36 @@ mod tests {
         assert!(d.inverse(1.0) > 1e16);
     }
 
+    #[test]
+    fn log_density() {
+        let d = new!(1.0, 2.0);
+        let x = vec![-40.0, 0.9, 1000000.0, 1e200];
+        let p = vec![
+            -7.881103548075917,
+            -1.8403739466079325,
+            -28.082601821221,
+            -921.48561990290773,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-12,
+        );
+    }
+
     #[test]
     fn median() {
         assert_eq!(new!(2.0, 1.0).median(), 2.0);
     }
 
+    #[test]
+    fn mgf() {
+        let d = new!(1.0, 2.0);
+        assert_eq!(d.mgf(0.0), 1.0);
+        assert_eq!(d.mgf(-0.1), f64::INFINITY);
+        assert_eq!(d.mgf(0.1), f64::INFINITY);
+    }
+
     #[test]
     fn modes() {
         assert_eq!(new!(2.0, 1.0).modes(), vec![2.0]);
@@ -234,4 +397,34 @@ mod tests {
             / n as f64;
         assert!((cross_entropy - d.entropy()).abs() < 0.01);
     }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::Unbounded;
+        assert_eq!(new!(1.0, 2.0).support(), (Unbounded, Unbounded));
+    }
+
+    #[test]
+    fn survival() {
+        let d = new!(1.0, 2.0);
+        let x = vec![-10.0, 1.0, 3.0, 1e10];
+        let p = vec![0.9427508529512998, 0.5, 0.25, 6.366197724312433e-11];
+        assert::close(
+            &x.iter().map(|&x| d.survival(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+        assert::close(d.survival(1e10) / 6.366197724312433e-11, 1.0, 1e-13);
+    }
+
+    #[test]
+    fn try_mean() {
+        assert_eq!(new!(2.0, 8.0).try_mean(), None);
+    }
+
+    #[test]
+    fn try_variance() {
+        assert_eq!(new!(2.0, 8.0).try_variance(), None);
+        assert_eq!(new!(2.0, 8.0).try_deviation(), None);
+    }
 }
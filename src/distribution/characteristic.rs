@@ -0,0 +1,252 @@
+//! Characteristic functions.
+
+use alloc::vec::Vec;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution::{complex, quadrature};
+
+/// The number of half-period panels whose partial sums are averaged in order
+/// to accelerate the convergence of an oscillating tail.
+const TERMS: usize = 48;
+
+/// The value of `n |1 - exp(i t)|` beyond which the tail of a series starting
+/// at `n` is first attempted to be summed by parts.
+const SWITCH: f64 = 1024.0;
+
+/// The maximal number of terms in the summation by parts.
+const ORDER: usize = 8;
+
+/// The relative size of the smallest term at which the summation by parts is
+/// accepted once rounding errors in the differences prevail.
+const TOLERANCE: f64 = 1e-12;
+
+/// Compute the characteristic function of a continuous distribution by
+/// integrating the density over `[a, b]`.
+///
+/// The finite part of the range is integrated adaptively using panels no
+/// wider than half the period of `exp(i t x)`. Infinite endpoints are handled
+/// as for moments until the panels reach half a period; the partial sums over
+/// the subsequent half-period panels are then averaged repeatedly, which
+/// accelerates the convergence for slowly decaying densities.
+pub fn continuous<F>(density: F, t: f64, a: f64, b: f64, center: f64, width: f64) -> (f64, f64)
+where
+    F: Fn(f64) -> f64,
+{
+    use core::f64::consts::PI;
+    if t == 0.0 {
+        return (1.0, 0.0);
+    }
+    let half = PI / t.abs();
+    let lower = if a.is_finite() {
+        a
+    } else {
+        center - quadrature::SPAN * width
+    };
+    let upper = if b.is_finite() {
+        b
+    } else {
+        center + quadrature::SPAN * width
+    };
+    let n = quadrature::PANELS.max(((upper - lower) / half) as usize + 1);
+    let h = (upper - lower) / n as f64;
+    let mass = (0..n)
+        .map(|i| {
+            let (a, b) = (lower + i as f64 * h, lower + (i + 1) as f64 * h);
+            quadrature::legendre(&density, a, b)
+        })
+        .sum::<f64>();
+    let tolerance = 1e-14 * mass;
+    let (re, im) = split(&density, t);
+    let mut sum = (
+        quadrature::panels(&re, lower, upper, n, tolerance),
+        quadrature::panels(&im, lower, upper, n, tolerance),
+    );
+    if !a.is_finite() {
+        let piece = tail(&density, t, lower, -width, mass);
+        sum = (sum.0 + piece.0, sum.1 + piece.1);
+    }
+    if !b.is_finite() {
+        let piece = tail(&density, t, upper, width, mass);
+        sum = (sum.0 + piece.0, sum.1 + piece.1);
+    }
+    sum
+}
+
+/// Compute the characteristic function of a discrete distribution supported
+/// on `{a, …, b}` by summing the probability mass function.
+///
+/// The mass is assumed to decrease beyond `center`, such as the mean or the
+/// mode, and the summation stops early once the terms beyond it become
+/// negligible relative to the sum. For an unbounded support, the tail is
+/// summed by parts, `sum_(x >= n) p(x) z^x = sum_(m >= 0) z^(n + m) Delta^m
+/// p(n) / (1 - z)^(m + 1)` with `z = exp(i t)`, once `n` is sufficiently
+/// large, which accelerates the convergence for slowly decaying masses. The
+/// accuracy of the latter deteriorates as `t` approaches a multiple of `2 pi`.
+pub fn discrete<F>(mass: F, t: f64, a: usize, b: usize, center: f64) -> (f64, f64)
+where
+    F: Fn(usize) -> f64,
+{
+    if t == 0.0 {
+        return (1.0, 0.0);
+    }
+    let z = complex::exp((0.0, t));
+    let distance = complex::modulus((1.0 - z.0, -z.1));
+    let accelerate = b == usize::MAX && distance > 0.0;
+    let (mut sum, mut total, mut switch) = ((0.0, 0.0), 0.0, SWITCH);
+    for x in a..=b {
+        let p = mass(x);
+        let (re, im) = complex::exp((0.0, t * x as f64));
+        sum = (sum.0 + p * re, sum.1 + p * im);
+        total += p;
+        if x as f64 > center && p <= f64::EPSILON * total {
+            break;
+        }
+        if accelerate && x as f64 > center && (x - a) as f64 * distance >= switch {
+            if let Some(piece) = parts(&mass, t, x + 1, total) {
+                return (sum.0 + piece.0, sum.1 + piece.1);
+            }
+            switch *= 2.0;
+        }
+    }
+    sum
+}
+
+fn parts<F>(mass: &F, t: f64, n: usize, total: f64) -> Option<(f64, f64)>
+where
+    F: Fn(usize) -> f64,
+{
+    let z = complex::exp((0.0, t));
+    let mut differences = (0..=ORDER).map(|i| mass(n + i)).collect::<Vec<_>>();
+    let ratio = complex::div(z, (1.0 - z.0, -z.1));
+    let mut factor = complex::div(complex::exp((0.0, t * n as f64)), (1.0 - z.0, -z.1));
+    let (mut sum, mut last) = ((0.0, 0.0), f64::INFINITY);
+    for m in 0..=ORDER {
+        let term = (factor.0 * differences[0], factor.1 * differences[0]);
+        let size = complex::modulus(term);
+        if size > last {
+            return if last <= TOLERANCE * total {
+                Some(sum)
+            } else {
+                None
+            };
+        }
+        sum = (sum.0 + term.0, sum.1 + term.1);
+        if size <= f64::EPSILON * total {
+            return Some(sum);
+        }
+        last = size;
+        for i in 0..ORDER - m {
+            differences[i] = differences[i + 1] - differences[i];
+        }
+        factor = complex::mul(factor, ratio);
+    }
+    if last <= TOLERANCE * total {
+        Some(sum)
+    } else {
+        None
+    }
+}
+
+fn split<F>(density: &F, t: f64) -> (impl Fn(f64) -> f64 + '_, impl Fn(f64) -> f64 + '_)
+where
+    F: Fn(f64) -> f64,
+{
+    use core::f64::consts::FRAC_PI_2;
+    let value = move |x: f64, shift: f64| {
+        let p = density(x);
+        if p == 0.0 || p.is_infinite() {
+            0.0
+        } else {
+            p * (shift - t * x).sin()
+        }
+    };
+    (move |x| value(x, FRAC_PI_2), move |x| -value(x, 0.0))
+}
+
+fn tail<F>(density: &F, t: f64, x: f64, width: f64, mass: f64) -> (f64, f64)
+where
+    F: Fn(f64) -> f64,
+{
+    use core::f64::consts::PI;
+    let half = PI / t.abs();
+    let (re, im) = split(density, t);
+    let (mut a, mut width, mut sum) = (x, width, (0.0, 0.0));
+    while width.abs() < half {
+        let b = a + width;
+        let (lower, upper) = (a.min(b), a.max(b));
+        sum.0 += quadrature::legendre(&re, lower, upper);
+        sum.1 += quadrature::legendre(&im, lower, upper);
+        if quadrature::legendre(density, lower, upper) <= f64::EPSILON * mass {
+            return sum;
+        }
+        a = b;
+        width *= 2.0;
+    }
+    let width = half.copysign(width);
+    let mut sums = Vec::with_capacity(TERMS);
+    for _ in 0..TERMS {
+        let b = a + width;
+        let (lower, upper) = (a.min(b), a.max(b));
+        sum.0 += quadrature::legendre(&re, lower, upper);
+        sum.1 += quadrature::legendre(&im, lower, upper);
+        if quadrature::legendre(density, lower, upper) <= f64::EPSILON * mass {
+            return sum;
+        }
+        sums.push(sum);
+        a = b;
+    }
+    for k in (1..TERMS).rev() {
+        for i in 0..k {
+            sums[i] = (
+                0.5 * (sums[i].0 + sums[i + 1].0),
+                0.5 * (sums[i].1 + sums[i + 1].1),
+            );
+        }
+    }
+    sums[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+
+    #[test]
+    fn continuous() {
+        use core::f64::consts::PI;
+        let (a, b) = (f64::NEG_INFINITY, f64::INFINITY);
+
+        let density = |x: f64| (-0.5 * x * x).exp() / (2.0 * PI).sqrt();
+        assert_eq!(super::continuous(density, 0.0, a, b, 0.0, 1.0), (1.0, 0.0));
+        let (re, im) = super::continuous(density, 1.5, a, b, 0.0, 1.0);
+        assert::close(re, (-1.125f64).exp(), 1e-15);
+        assert::close(im, 0.0, 1e-15);
+
+        let density = |x: f64| 1.0 / (PI * (1.0 + x * x));
+        for &t in &[-3.0, 0.01, 0.7, 20.0] {
+            let (re, im) = super::continuous(density, t, a, b, 0.0, 1.0);
+            assert::close(re, (-f64::abs(t)).exp(), 1e-13);
+            assert::close(im, 0.0, 1e-13);
+        }
+
+        let density = |x: f64| if x < 1.0 { 2.0 * x } else { 0.0 };
+        let (re, im) = super::continuous(density, 2.0, 0.0, 1.0, 0.0, 0.0);
+        assert::close(re, 0.2012240085521105, 1e-15);
+        assert::close(im, 0.8707955499599832, 1e-15);
+    }
+
+    #[test]
+    fn discrete() {
+        let mass = |x: usize| 0.5f64.powi(x as i32 + 1);
+        assert_eq!(super::discrete(mass, 0.0, 0, usize::MAX, 1.0), (1.0, 0.0));
+        let (re, im) = super::discrete(mass, 0.5, 0, usize::MAX, 1.0);
+        assert::close(re, 0.7534672945277237, 1e-15);
+        assert::close(im, 0.3218334384647397, 1e-15);
+
+        let norm = 1.6449340668482264;
+        let mass = |x: usize| 1.0 / ((x * x) as f64 * norm);
+        let (re, im) = super::discrete(mass, 1.0, 1, usize::MAX, 1.0);
+        assert::close(re, 0.19705211691213464, 1e-14);
+        assert::close(im, 0.6164132367345054, 1e-14);
+    }
+}
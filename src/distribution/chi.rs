@@ -0,0 +1,407 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A chi distribution.
+///
+/// The distribution is the distribution of the square root of a chi-squared
+/// variate. It coincides with the half-normal distribution when `k = 1`, with
+/// the Rayleigh distribution when `k = 2`, and with the Maxwell distribution
+/// when `k = 3`, in each case with unit scale.
+#[derive(Clone, Copy, Debug)]
+pub struct Chi {
+    k: f64,
+    ln_norm: f64,
+}
+
+impl Chi {
+    /// Create a chi distribution with `k` degrees of freedom.
+    ///
+    /// It should hold that `k > 0`.
+    #[inline]
+    pub fn new(k: f64) -> Self {
+        use core::f64::consts::LN_2;
+        use special::Gamma;
+        should!(k > 0.0);
+        Chi {
+            k,
+            ln_norm: (0.5 * k - 1.0) * LN_2 + Gamma::ln_gamma(0.5 * k).0,
+        }
+    }
+
+    /// Return the degrees of freedom.
+    #[inline(always)]
+    pub fn k(&self) -> f64 {
+        self.k
+    }
+}
+
+impl distribution::CharacteristicFunction for Chi {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Continuous, Mean, Variance};
+        characteristic::continuous(
+            |x| self.density(x),
+            t,
+            0.0,
+            f64::INFINITY,
+            self.mean(),
+            self.deviation(),
+        )
+    }
+}
+
+impl distribution::Continuous for Chi {
+    fn density(&self, x: f64) -> f64 {
+        use core::f64::consts::FRAC_2_PI;
+        if x < 0.0 {
+            0.0
+        } else if x == 0.0 {
+            if self.k < 1.0 {
+                f64::INFINITY
+            } else if self.k == 1.0 {
+                FRAC_2_PI.sqrt()
+            } else {
+                0.0
+            }
+        } else {
+            ((self.k - 1.0) * x.ln() - 0.5 * x * x - self.ln_norm).exp()
+        }
+    }
+
+    fn log_density(&self, x: f64) -> f64 {
+        use core::f64::consts::FRAC_2_PI;
+        if x < 0.0 {
+            f64::NEG_INFINITY
+        } else if x == 0.0 {
+            if self.k < 1.0 {
+                f64::INFINITY
+            } else if self.k == 1.0 {
+                0.5 * FRAC_2_PI.ln()
+            } else {
+                f64::NEG_INFINITY
+            }
+        } else {
+            (self.k - 1.0) * x.ln() - 0.5 * x * x - self.ln_norm
+        }
+    }
+}
+
+impl distribution::Distribution for Chi {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        use special::Gamma;
+        if x <= 0.0 {
+            0.0
+        } else {
+            (0.5 * x * x).inc_gamma(0.5 * self.k)
+        }
+    }
+}
+
+impl distribution::Entropy for Chi {
+    fn entropy(&self) -> f64 {
+        use core::f64::consts::LN_2;
+        use special::Gamma;
+        let k = self.k;
+        Gamma::ln_gamma(0.5 * k).0 + 0.5 * (k - LN_2 - (k - 1.0) * (0.5 * k).digamma())
+    }
+}
+
+impl distribution::Inverse for Chi {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        use distribution::gamma;
+        should!((0.0..=1.0).contains(&p));
+        (2.0 * gamma::inverse(p, 0.5 * self.k)).sqrt()
+    }
+}
+
+impl distribution::Kurtosis for Chi {
+    fn kurtosis(&self) -> f64 {
+        use distribution::{Mean, Skewness, Variance};
+        let (mean, variance) = (self.mean(), self.variance());
+        2.0 * (1.0 - mean * variance.sqrt() * self.skewness() - variance) / variance
+    }
+}
+
+impl distribution::Mean for Chi {
+    /// Compute the expected value, which is `sqrt(2) Gamma((k + 1) / 2) /
+    /// Gamma(k / 2)`.
+    #[inline]
+    fn mean(&self) -> f64 {
+        use core::f64::consts::SQRT_2;
+        use special::Gamma;
+        let k = 0.5 * self.k;
+        SQRT_2 * (Gamma::ln_gamma(k + 0.5).0 - Gamma::ln_gamma(k).0).exp()
+    }
+}
+
+impl distribution::Median for Chi {
+    #[inline]
+    fn median(&self) -> f64 {
+        use distribution::Inverse;
+        self.inverse(0.5)
+    }
+}
+
+impl distribution::Modes for Chi {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![(self.k - 1.0).max(0.0).sqrt()]
+    }
+}
+
+impl distribution::Moments for Chi {
+    fn raw_moment(&self, n: usize) -> f64 {
+        use core::f64::consts::LN_2;
+        use special::Gamma;
+        let (k, n) = (self.k, n as f64);
+        (0.5 * n * LN_2 + Gamma::ln_gamma(0.5 * (k + n)).0 - Gamma::ln_gamma(0.5 * k).0).exp()
+    }
+}
+
+impl distribution::Sample for Chi {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::gamma;
+        (2.0 * gamma::sample(0.5 * self.k, source)).sqrt()
+    }
+}
+
+impl distribution::Skewness for Chi {
+    fn skewness(&self) -> f64 {
+        use distribution::{Mean, Variance};
+        let (mean, variance) = (self.mean(), self.variance());
+        mean * (1.0 - 2.0 * variance) / variance.powf(1.5)
+    }
+}
+
+impl distribution::Support for Chi {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Included, Unbounded};
+        (Included(0.0), Unbounded)
+    }
+}
+
+impl distribution::Variance for Chi {
+    #[inline]
+    fn variance(&self) -> f64 {
+        use distribution::Mean;
+        let mean = self.mean();
+        self.k - mean * mean
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($k:expr) => (Chi::new($k));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(3.5);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.31333326518929705, 0.8488529017312519, -0.3985946055266565];
+        let im = vec![
+            -0.8367165129940111,
+            0.4887347178929577,
+            -0.06207747156784108,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(3.5);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 1.5, 2.0, 3.0, 4.0, 5.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            1.0093008638925699e-01,
+            3.9240560621625481e-01,
+            5.7880103610834499e-01,
+            4.9530016166016866e-01,
+            1.1203672689568051e-01,
+            6.9450690418110745e-03,
+            1.3478030394703107e-04,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+
+        let x = &x[1..];
+        let (d, other) = (new!(1.0), HalfNormal::new(1.0));
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &x.iter().map(|&x| other.density(x)).collect::<Vec<_>>(),
+            1e-15,
+        );
+        let (d, other) = (new!(2.0), Rayleigh::new(1.0));
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &x.iter().map(|&x| other.density(x)).collect::<Vec<_>>(),
+            1e-15,
+        );
+        let (d, other) = (new!(3.0), Maxwell::new(1.0));
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &x.iter().map(|&x| other.density(x)).collect::<Vec<_>>(),
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(3.5);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 1.5, 2.0, 3.0, 4.0, 5.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            1.5096407917092342e-02,
+            1.3553150361841701e-01,
+            3.8887431735672834e-01,
+            6.6729440430823439e-01,
+            9.5670916053548938e-01,
+            9.9810541227822935e-01,
+            9.9997145615579042e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(3.5).entropy(), 1.0096847217659652e+00, 1e-15);
+    }
+
+    #[test]
+    fn inverse() {
+        use core::f64::INFINITY;
+        let d = new!(3.5);
+        let p = vec![
+            0.0, 0.01, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.99, 1.0,
+        ];
+        let x = vec![
+            0.0000000000000000e+00,
+            4.4232430697514319e-01,
+            9.0209668983621005e-01,
+            1.1485634643625582e+00,
+            1.3430541588316547e+00,
+            1.5191936993274804e+00,
+            1.6913277042213168e+00,
+            1.8700338195271782e+00,
+            2.0678079008336110e+00,
+            2.3069941769021858e+00,
+            2.6507287470284773e+00,
+            3.5113490713306308e+00,
+            INFINITY,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(3.5).kurtosis(), 7.8492445266859778e-02, 1e-13);
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(3.5);
+        let x = vec![-40.0, 0.05, 0.9, 4.0, 400.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -7.92603994828445,
+            -1.1038605535440391,
+            -4.969723361599747,
+            -79985.45679789662,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-9,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(3.5).mean(), 1.7434160334218360e+00, 1e-15);
+    }
+
+    #[test]
+    fn median() {
+        assert::close(new!(3.5).median(), 1.6913277042213168e+00, 1e-12);
+    }
+
+    #[test]
+    fn modes() {
+        assert::close(new!(3.5).modes(), vec![1.5811388300841898e+00], 1e-15);
+        assert_eq!(new!(0.5).modes(), vec![0.0]);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(3.5);
+        let mut source = source::default(42);
+
+        let cross_entropy = -(0..n)
+            .map(|_| d.density(d.sample(&mut source)).ln())
+            .sum::<f64>()
+            / n as f64;
+        assert!((cross_entropy - d.entropy()).abs() < 0.01);
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(3.5).skewness(), 4.4073449564493278e-01, 1e-14);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Included, Unbounded};
+        assert_eq!(new!(3.5).support(), (Included(0.0), Unbounded));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(3.5).variance(), 4.6050053440767136e-01, 1e-14);
+    }
+}
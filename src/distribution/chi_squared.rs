@@ -0,0 +1,404 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A chi-squared distribution.
+///
+/// The distribution is a special case of the gamma distribution with shape
+/// `k / 2` and scale `2`.
+#[derive(Clone, Copy, Debug)]
+pub struct ChiSquared {
+    k: f64,
+    ln_norm: f64,
+}
+
+impl ChiSquared {
+    /// Create a chi-squared distribution with `k` degrees of freedom.
+    ///
+    /// It should hold that `k > 0`.
+    #[inline]
+    pub fn new(k: f64) -> Self {
+        use core::f64::consts::LN_2;
+        use special::Gamma;
+        should!(k > 0.0);
+        ChiSquared {
+            k,
+            ln_norm: 0.5 * k * LN_2 + Gamma::ln_gamma(0.5 * k).0,
+        }
+    }
+
+    /// Return the degrees of freedom.
+    #[inline(always)]
+    pub fn k(&self) -> f64 {
+        self.k
+    }
+}
+
+impl distribution::CharacteristicFunction for ChiSquared {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::complex;
+        complex::powf((1.0, -2.0 * t), -0.5 * self.k)
+    }
+}
+
+impl distribution::Continuous for ChiSquared {
+    fn density(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            0.0
+        } else if x == 0.0 {
+            if self.k < 2.0 {
+                f64::INFINITY
+            } else if self.k == 2.0 {
+                0.5
+            } else {
+                0.0
+            }
+        } else {
+            ((0.5 * self.k - 1.0) * x.ln() - 0.5 * x - self.ln_norm).exp()
+        }
+    }
+
+    fn log_density(&self, x: f64) -> f64 {
+        use core::f64::consts::LN_2;
+        if x < 0.0 {
+            f64::NEG_INFINITY
+        } else if x == 0.0 {
+            if self.k < 2.0 {
+                f64::INFINITY
+            } else if self.k == 2.0 {
+                -LN_2
+            } else {
+                f64::NEG_INFINITY
+            }
+        } else {
+            (0.5 * self.k - 1.0) * x.ln() - 0.5 * x - self.ln_norm
+        }
+    }
+}
+
+impl distribution::Distribution for ChiSquared {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        use special::Gamma;
+        if x <= 0.0 {
+            0.0
+        } else {
+            (0.5 * x).inc_gamma(0.5 * self.k)
+        }
+    }
+}
+
+impl distribution::Entropy for ChiSquared {
+    fn entropy(&self) -> f64 {
+        use core::f64::consts::LN_2;
+        use special::Gamma;
+        let k = 0.5 * self.k;
+        k + LN_2 + Gamma::ln_gamma(k).0 + (1.0 - k) * k.digamma()
+    }
+}
+
+impl distribution::Inverse for ChiSquared {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        use distribution::gamma;
+        should!((0.0..=1.0).contains(&p));
+        2.0 * gamma::inverse(p, 0.5 * self.k)
+    }
+}
+
+impl distribution::Kurtosis for ChiSquared {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        12.0 / self.k
+    }
+}
+
+impl distribution::Mean for ChiSquared {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.k
+    }
+}
+
+impl distribution::Median for ChiSquared {
+    #[inline]
+    fn median(&self) -> f64 {
+        use distribution::Inverse;
+        self.inverse(0.5)
+    }
+}
+
+impl distribution::Mgf for ChiSquared {
+    /// Compute the moment-generating function.
+    ///
+    /// The function is infinite if `t >= 1/2`.
+    fn mgf(&self, t: f64) -> f64 {
+        if t >= 0.5 {
+            f64::INFINITY
+        } else {
+            (-0.5 * self.k * (-2.0 * t).ln_1p()).exp()
+        }
+    }
+}
+
+impl distribution::Modes for ChiSquared {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![(self.k - 2.0).max(0.0)]
+    }
+}
+
+impl distribution::Moments for ChiSquared {
+    fn raw_moment(&self, n: usize) -> f64 {
+        (0..n).fold(1.0, |product, j| product * (self.k + 2.0 * j as f64))
+    }
+}
+
+impl distribution::Sample for ChiSquared {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::gamma;
+        2.0 * gamma::sample(0.5 * self.k, source)
+    }
+}
+
+impl distribution::Skewness for ChiSquared {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        (8.0 / self.k).sqrt()
+    }
+}
+
+impl distribution::Support for ChiSquared {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Included, Unbounded};
+        (Included(0.0), Unbounded)
+    }
+}
+
+impl distribution::Variance for ChiSquared {
+    #[inline]
+    fn variance(&self) -> f64 {
+        2.0 * self.k
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($k:expr) => (ChiSquared::new($k));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(3.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            0.06401844501065077,
+            0.5471316685266159,
+            -0.04847857842595979,
+        ];
+        let im = vec![
+            -0.43848134890009655,
+            0.5754619374864698,
+            0.10916331300635543,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(3.0);
+        let x = vec![
+            -1.0, 0.0, 0.5, 1.0, 1.5, 2.0, 3.0, 4.0, 6.0, 8.0, 10.0, 15.0,
+        ];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            2.1969564473386119e-01,
+            2.4197072451914334e-01,
+            2.3079948420818289e-01,
+            2.0755374871029736e-01,
+            1.5418032980376928e-01,
+            1.0798193302637610e-01,
+            4.8652173329641460e-02,
+            2.0666985354092053e-02,
+            8.5003666025203414e-03,
+            8.5456889492141138e-04,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+
+        assert_eq!(new!(1.0).density(0.0), f64::INFINITY);
+        assert_eq!(new!(2.0).density(0.0), 0.5);
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(3.0);
+        let x = vec![
+            -1.0, 0.0, 0.5, 1.0, 1.5, 2.0, 3.0, 4.0, 6.0, 8.0, 10.0, 15.0,
+        ];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            8.1108588345324140e-02,
+            1.9874804309879920e-01,
+            3.1772966966378741e-01,
+            4.2759329552912018e-01,
+            6.0837482372891105e-01,
+            7.3853587005088939e-01,
+            8.8838977490528748e-01,
+            9.5398829431076859e-01,
+            9.8143386453695680e-01,
+            9.9818335103342770e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        use distribution::Gamma;
+        assert::close(new!(1.0).entropy(), 7.8375711047393370e-01, 1e-14);
+        assert::close(new!(3.0).entropy(), 2.0541199559354117e+00, 1e-14);
+        assert::close(new!(10.0).entropy(), Gamma::new(5.0, 2.0).entropy(), 1e-14);
+    }
+
+    #[test]
+    fn inverse() {
+        use core::f64::INFINITY;
+        let d = new!(3.0);
+        let p = vec![
+            0.0, 0.01, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.99, 1.0,
+        ];
+        let x = vec![
+            0.0000000000000000e+00,
+            1.1483180189911704e-01,
+            5.8437437415518323e-01,
+            1.0051740130523494e+00,
+            1.4236522430352796e+00,
+            1.8691684033887157e+00,
+            2.3659738843753382e+00,
+            2.9461660731019501e+00,
+            3.6648707831703171e+00,
+            4.6416276760874462e+00,
+            6.2513886311703235e+00,
+            1.1344866730144370e+01,
+            INFINITY,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert_eq!(new!(3.0).kurtosis(), 4.0);
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(3.0);
+        let x = vec![-40.0, 0.05, 1.5, 400.0, 10000.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -2.441804669981668,
+            -1.4662059791505904,
+            -197.9232062596507,
+            -4996.313768347217,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(3.0).mean(), 3.0);
+    }
+
+    #[test]
+    fn median() {
+        assert::close(new!(1.0).median(), 4.5493642311957277e-01, 1e-12);
+        assert::close(new!(10.0).median(), 9.3418177655919674e+00, 1e-12);
+    }
+
+    #[test]
+    fn mgf() {
+        let d = new!(3.0);
+        let t = vec![-0.7, -0.1, 0.2, 0.45];
+        let m = vec![
+            0.26895717681995956,
+            0.7607257743127307,
+            2.151657414559676,
+            31.622776601683803,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+        assert_eq!(d.mgf(0.5), f64::INFINITY);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(1.0).modes(), vec![0.0]);
+        assert_eq!(new!(3.0).modes(), vec![1.0]);
+    }
+
+    #[test]
+    fn sample() {
+        for x in Independent(&new!(3.0), &mut source::default(42)).take(100) {
+            assert!(x >= 0.0);
+        }
+    }
+
+    #[test]
+    fn skewness() {
+        assert_eq!(new!(2.0).skewness(), 2.0);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Included, Unbounded};
+        assert_eq!(new!(3.0).support(), (Included(0.0), Unbounded));
+    }
+
+    #[test]
+    fn variance() {
+        assert_eq!(new!(3.0).variance(), 6.0);
+    }
+}
@@ -0,0 +1,129 @@
+//! Cholesky decomposition.
+//!
+//! Matrices are stored in row-major order.
+
+use alloc::{vec, vec::Vec};
+#[allow(unused_imports)]
+use special::Primitive;
+
+/// Compute the lower-triangular Cholesky factor of a symmetric matrix of order
+/// `d`.
+///
+/// If the matrix is not positive definite, the diagonal of the result
+/// contains a nonpositive or `NaN` value.
+pub fn decompose(a: &[f64], d: usize) -> Vec<f64> {
+    let mut l = vec![0.0; d * d];
+    for i in 0..d {
+        for j in 0..=i {
+            let sum = (0..j).map(|k| l[i * d + k] * l[j * d + k]).sum::<f64>();
+            if i == j {
+                l[i * d + i] = (a[i * d + i] - sum).sqrt();
+            } else {
+                l[i * d + j] = (a[i * d + j] - sum) / l[j * d + j];
+            }
+        }
+    }
+    l
+}
+
+/// Check whether the matrix whose Cholesky factor is `l` is positive
+/// definite.
+#[inline]
+pub fn is_positive_definite(l: &[f64], d: usize) -> bool {
+    (0..d).all(|i| l[i * d + i] > 0.0)
+}
+
+/// Compute the natural logarithm of the determinant of the matrix whose
+/// Cholesky factor is `l`.
+#[inline]
+pub fn ln_det(l: &[f64], d: usize) -> f64 {
+    2.0 * (0..d).map(|i| l[i * d + i].ln()).sum::<f64>()
+}
+
+/// Compute `L z`.
+pub fn multiply(l: &[f64], d: usize, z: &[f64]) -> Vec<f64> {
+    (0..d)
+        .map(|i| {
+            let row = &l[(i * d)..(i * d + i + 1)];
+            row.iter().zip(z).map(|(l, z)| l * z).sum::<f64>()
+        })
+        .collect()
+}
+
+/// Solve `L y = b` by forward substitution.
+pub fn solve(l: &[f64], d: usize, b: &[f64]) -> Vec<f64> {
+    let mut y = Vec::with_capacity(d);
+    for (i, b) in b.iter().enumerate() {
+        let row = &l[(i * d)..(i * d + i)];
+        let sum = row.iter().zip(&y).map(|(l, y)| l * y).sum::<f64>();
+        y.push((b - sum) / l[i * d + i]);
+    }
+    y
+}
+
+/// Compute the inverse of the matrix whose Cholesky factor is `l`.
+pub fn invert(l: &[f64], d: usize) -> Vec<f64> {
+    let mut inverse = vec![0.0; d * d];
+    let mut unit = vec![0.0; d];
+    let mut columns = Vec::with_capacity(d);
+    for j in 0..d {
+        unit[j] = 1.0;
+        columns.push(solve(l, d, &unit));
+        unit[j] = 0.0;
+    }
+    for i in 0..d {
+        for j in 0..d {
+            inverse[i * d + j] = (0..d).map(|k| columns[i][k] * columns[j][k]).sum();
+        }
+    }
+    inverse
+}
+
+#[cfg(test)]
+#[allow(clippy::needless_borrows_for_generic_args)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+
+    #[test]
+    fn decompose() {
+        let a = vec![4.0, 2.0, -2.0, 2.0, 10.0, 2.0, -2.0, 2.0, 5.0];
+        let l = super::decompose(&a, 3);
+        assert::close(
+            &l,
+            &vec![2.0, 0.0, 0.0, 1.0, 3.0, 0.0, -1.0, 1.0, 3f64.sqrt()],
+            1e-15,
+        );
+        assert!(super::is_positive_definite(&l, 3));
+        assert::close(super::ln_det(&l, 3), 108f64.ln(), 1e-14);
+
+        let l = super::decompose(&[1.0, 2.0, 2.0, 1.0], 2);
+        assert!(!super::is_positive_definite(&l, 2));
+    }
+
+    #[test]
+    fn invert() {
+        let a = vec![4.0, 2.0, -2.0, 2.0, 10.0, 2.0, -2.0, 2.0, 5.0];
+        let inverse = super::invert(&super::decompose(&a, 3), 3);
+        let product = (0..9)
+            .map(|k| {
+                (0..3)
+                    .map(|m| a[(k / 3) * 3 + m] * inverse[m * 3 + k % 3])
+                    .sum()
+            })
+            .collect::<Vec<f64>>();
+        assert::close(
+            &product,
+            &vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0],
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn solve() {
+        let l = vec![2.0, 0.0, 1.0, 3.0];
+        let y = super::solve(&l, 2, &[4.0, 11.0]);
+        assert_eq!(y, vec![2.0, 3.0]);
+        assert_eq!(super::multiply(&l, 2, &y), vec![4.0, 11.0]);
+    }
+}
@@ -0,0 +1,410 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A Conway–Maxwell–Poisson distribution.
+///
+/// The distribution generalizes the Poisson distribution with a decay
+/// parameter `nu`, which yields under-dispersion if `nu > 1` and
+/// over-dispersion if `nu < 1`.
+#[derive(Clone, Copy, Debug)]
+pub struct ComPoisson {
+    lambda: f64,
+    nu: f64,
+    ln_lambda: f64,
+    mu: f64,
+    ln_norm: f64,
+}
+
+impl ComPoisson {
+    /// Create a Conway–Maxwell–Poisson distribution with rate `lambda` and
+    /// decay `nu`.
+    ///
+    /// It should hold that `lambda > 0` and `nu > 0`.
+    pub fn new(lambda: f64, nu: f64) -> Self {
+        should!(lambda > 0.0 && nu > 0.0);
+        let mut distribution = ComPoisson {
+            lambda,
+            nu,
+            ln_lambda: lambda.ln(),
+            mu: lambda.powf(1.0 / nu),
+            ln_norm: 0.0,
+        };
+        let mode = distribution.mu.floor();
+        distribution.ln_norm = distribution.ln_term(mode) + distribution.sum(|_| 1.0).ln();
+        distribution
+    }
+
+    /// Return the rate parameter.
+    #[inline(always)]
+    pub fn lambda(&self) -> f64 {
+        self.lambda
+    }
+
+    /// Return the decay parameter.
+    #[inline(always)]
+    pub fn nu(&self) -> f64 {
+        self.nu
+    }
+
+    /// Compute the logarithm of the unnormalized mass `lambda^j / (j!)^nu`.
+    #[inline]
+    fn ln_term(&self, j: f64) -> f64 {
+        use special::Gamma;
+        j * self.ln_lambda - self.nu * Gamma::ln_gamma(j + 1.0).0
+    }
+
+    /// Compute `sum_j lambda^j / (j!)^nu f(j)` relative to the largest term.
+    ///
+    /// The terms are summed outward from the mode until they become negligible
+    /// relative to the sum.
+    fn sum<F>(&self, f: F) -> f64
+    where
+        F: Fn(f64) -> f64,
+    {
+        let mode = self.mu.floor();
+        let mut sum = f(mode);
+        let (mut j, mut w) = (mode, 1.0);
+        loop {
+            j += 1.0;
+            w *= (self.ln_lambda - self.nu * j.ln()).exp();
+            let term = w * f(j);
+            sum += term;
+            if term <= f64::EPSILON * sum {
+                break;
+            }
+        }
+        let (mut j, mut w) = (mode, 1.0);
+        while j > 0.0 {
+            w *= (self.nu * j.ln() - self.ln_lambda).exp();
+            j -= 1.0;
+            let term = w * f(j);
+            sum += term;
+            if term <= f64::EPSILON * sum {
+                break;
+            }
+        }
+        sum
+    }
+
+    /// Compute the `k`th raw moment.
+    #[inline]
+    fn moment(&self, k: i32) -> f64 {
+        self.sum(|j| j.powi(k)) / self.sum(|_| 1.0)
+    }
+}
+
+impl distribution::CharacteristicFunction for ComPoisson {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by summing the probability mass function.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Discrete, Mean};
+        characteristic::discrete(|x| self.mass(x), t, 0, usize::MAX, self.mean())
+    }
+}
+
+impl distribution::Discrete for ComPoisson {
+    #[inline]
+    fn mass(&self, x: usize) -> f64 {
+        self.log_mass(x).exp()
+    }
+
+    #[inline]
+    fn log_mass(&self, x: usize) -> f64 {
+        self.ln_term(x as f64) - self.ln_norm
+    }
+}
+
+impl distribution::Distribution for ComPoisson {
+    type Value = usize;
+
+    fn distribution(&self, x: f64) -> f64 {
+        use distribution::Discrete;
+        if x < 0.0 {
+            return 0.0;
+        }
+        let x = x as usize;
+        (0..=x).map(|j| self.mass(j)).sum::<f64>().min(1.0)
+    }
+}
+
+impl distribution::Kurtosis for ComPoisson {
+    fn kurtosis(&self) -> f64 {
+        let (m1, m2, m3, m4) = (
+            self.moment(1),
+            self.moment(2),
+            self.moment(3),
+            self.moment(4),
+        );
+        let m1_2 = m1 * m1;
+        (m4 - 4.0 * m1 * m3 + 6.0 * m1_2 * m2 - 3.0 * m1_2 * m1_2) / (m2 - m1_2).powi(2) - 3.0
+    }
+}
+
+impl distribution::Mean for ComPoisson {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.moment(1)
+    }
+}
+
+impl distribution::Modes for ComPoisson {
+    fn modes(&self) -> Vec<usize> {
+        let mode = self.mu.floor();
+        if mode == self.mu && mode > 0.0 {
+            vec![mode as usize - 1, mode as usize]
+        } else {
+            vec![mode as usize]
+        }
+    }
+}
+
+impl distribution::Moments for ComPoisson {
+    fn raw_moment(&self, n: usize) -> f64 {
+        self.moment(n as i32)
+    }
+}
+
+impl distribution::Sample for ComPoisson {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained by rejection sampling with a Poisson envelope if
+    /// `nu >= 1` and a geometric one otherwise.
+    ///
+    /// ## References
+    ///
+    /// 1. A. Benson and N. Friel, “Bayesian inference, model selection and
+    ///    likelihood estimation using fast rejection sampling: The
+    ///    Conway–Maxwell–Poisson distribution,” Bayesian Analysis, vol. 16,
+    ///    no. 3, pp. 905–931, 2021.
+    fn sample<S>(&self, source: &mut S) -> usize
+    where
+        S: Source,
+    {
+        use distribution::poisson;
+        use special::Gamma;
+        let ln_mu = self.mu.ln();
+        let ln_power = |y: f64| y * ln_mu - Gamma::ln_gamma(y + 1.0).0;
+        if self.nu >= 1.0 {
+            let ln_peak = ln_power(self.mu.floor());
+            loop {
+                let y = poisson::sample(self.mu, source);
+                let ln_ratio = (self.nu - 1.0) * (ln_power(y as f64) - ln_peak);
+                if source.read::<f64>().ln() <= ln_ratio {
+                    return y;
+                }
+            }
+        } else {
+            let p = 2.0 * self.nu / (2.0 * self.mu * self.nu + 1.0 + self.nu);
+            let ln_q = (-p).ln_1p();
+            let ln_ratio = |y: f64| self.nu * ln_power(y) - y * ln_q;
+            let ln_peak = ln_ratio((self.mu / (ln_q / self.nu).exp()).floor());
+            loop {
+                let y = (source.read::<f64>().ln() / ln_q).floor();
+                if source.read::<f64>().ln() <= ln_ratio(y) - ln_peak {
+                    return y as usize;
+                }
+            }
+        }
+    }
+}
+
+impl distribution::Skewness for ComPoisson {
+    fn skewness(&self) -> f64 {
+        let (m1, m2, m3) = (self.moment(1), self.moment(2), self.moment(3));
+        (m3 - 3.0 * m1 * m2 + 2.0 * m1 * m1 * m1) / (m2 - m1 * m1).powf(1.5)
+    }
+}
+
+impl distribution::Support for ComPoisson {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Included, Unbounded};
+        (Included(0.0), Unbounded)
+    }
+}
+
+impl distribution::Variance for ComPoisson {
+    #[inline]
+    fn variance(&self) -> f64 {
+        let m1 = self.moment(1);
+        self.moment(2) - m1 * m1
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($lambda:expr, $nu:expr) => (ComPoisson::new($lambda, $nu));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(3.0, 0.7);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            -0.21242165966625,
+            0.07714269577114727,
+            -0.0007385716458872654,
+        ];
+        let im = vec![
+            -0.032970859549966994,
+            0.7347102070197686,
+            0.0027803442033728274,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn distribution() {
+        let x = vec![-1.0, 0.0, 1.5, 3.0, 5.0, 8.0, 12.0, 20.0];
+
+        let d = new!(3.0, 0.7);
+        let p = vec![
+            0.0000000000000000e+00,
+            1.7526183863548667e-02,
+            7.0104735454194669e-02,
+            3.0220599032951068e-01,
+            6.0491000203306644e-01,
+            8.9944839478476479e-01,
+            9.9283761475194898e-01,
+            9.9999555829890230e-01,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+
+        let d = new!(10.0, 2.0);
+        let p = vec![
+            0.0000000000000000e+00,
+            1.1052660418695619e-02,
+            1.2157926460565180e-01,
+            7.0491412003680942e-01,
+            9.7355517188010565e-01,
+            9.9990692714777596e-01,
+            9.9999999699744380e-01,
+            1.0000000000000000e+00,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(3.0, 0.7).kurtosis(), 3.0273148462554472e-01, 1e-12);
+        assert::close(new!(10.0, 2.0).kurtosis(), 1.5955385767169242e-01, 1e-12);
+    }
+
+    #[test]
+    fn log_mass() {
+        let d = new!(3.0, 0.7);
+        let x = vec![0, 5, 100, 10000];
+        let p = vec![
+            -4.044059295501496,
+            -1.9022420721083786,
+            -148.80039331758496,
+            -46494.17065838444,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_mass(x)).collect::<Vec<_>>(),
+            &p,
+            1e-9,
+        );
+    }
+
+    #[test]
+    fn mass() {
+        let x = vec![0, 1, 2, 3, 5, 8, 12, 20];
+
+        let d = new!(3.0, 0.7);
+        let p = vec![
+            1.7526183863548667e-02,
+            5.2578551590646005e-02,
+            9.7097685078886928e-02,
+            1.3500356979642905e-01,
+            1.4923365124468807e-01,
+            6.8674109217030377e-02,
+            7.8145962614365946e-03,
+            8.2380652228408557e-06,
+        ];
+        assert::close(&x.iter().map(|&x| d.mass(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let d = new!(10.0, 2.0);
+        let p = vec![
+            1.1052660418695619e-02,
+            1.1052660418695619e-01,
+            2.7631651046739047e-01,
+            3.0701834496376718e-01,
+            7.6754586240941794e-02,
+            6.7986984694711763e-04,
+            4.8171802689288909e-08,
+            1.8673150316274879e-19,
+        ];
+        assert::close(&x.iter().map(|&x| d.mass(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let d = new!(3.0, 1.0);
+        assert::close(d.mass(2), 4.5 * (-3.0f64).exp(), 1e-15);
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(3.0, 0.7).mean(), 5.0334475688152827e+00, 1e-13);
+        assert::close(new!(10.0, 2.0).mean(), 2.9002024851051598e+00, 1e-14);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(3.0, 0.7).modes(), vec![4]);
+        assert_eq!(new!(9.0, 2.0).modes(), vec![2, 3]);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let mut source = source::default(42);
+
+        for &d in &[new!(3.0, 0.7), new!(10.0, 2.0), new!(3.0, 0.3)] {
+            let mean = (0..n).map(|_| d.sample(&mut source) as f64).sum::<f64>() / n as f64;
+            assert!((mean - d.mean()).abs() < 0.05 * d.variance().sqrt());
+        }
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(3.0, 0.7).skewness(), 5.5028435333924186e-01, 1e-13);
+        assert::close(new!(10.0, 2.0).skewness(), 3.9155702662775249e-01, 1e-13);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Included, Unbounded};
+        assert_eq!(new!(3.0, 0.7).support(), (Included(0.0), Unbounded));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(3.0, 0.7).variance(), 6.8342553955775260e+00, 1e-13);
+        assert::close(new!(10.0, 2.0).variance(), 1.5888255453898561e+00, 1e-13);
+    }
+}
@@ -0,0 +1,203 @@
+//! Complex arithmetic on pairs of real and imaginary parts.
+
+#[allow(unused_imports)]
+use special::Primitive;
+
+/// The real part beyond which Stirling’s series is used for the logarithm of
+/// the gamma function.
+const STIRLING_THRESHOLD: f64 = 15.0;
+
+// The coefficients `B_(2k) / (2k (2k - 1))` of Stirling’s series.
+const STIRLING: [f64; 8] = [
+    1.0 / 12.0,
+    -1.0 / 360.0,
+    1.0 / 1260.0,
+    -1.0 / 1680.0,
+    1.0 / 1188.0,
+    -691.0 / 360360.0,
+    1.0 / 156.0,
+    -3617.0 / 122400.0,
+];
+
+/// Compute the argument of `z` in `(-pi, pi]`.
+pub fn arg((re, im): (f64, f64)) -> f64 {
+    use core::f64::consts::{FRAC_PI_2, PI};
+    if re == 0.0 && im == 0.0 {
+        0.0
+    } else if im.abs() > re.abs() {
+        FRAC_PI_2.copysign(im) - (re / im).atan()
+    } else if re > 0.0 {
+        (im / re).atan()
+    } else if im < 0.0 {
+        (im / re).atan() - PI
+    } else {
+        (im / re).atan() + PI
+    }
+}
+
+/// Compute `z / w`.
+pub fn div((a, b): (f64, f64), (c, d): (f64, f64)) -> (f64, f64) {
+    if c.abs() >= d.abs() {
+        let r = d / c;
+        let q = c + d * r;
+        ((a + b * r) / q, (b - a * r) / q)
+    } else {
+        let r = c / d;
+        let q = c * r + d;
+        ((a * r + b) / q, (b * r - a) / q)
+    }
+}
+
+/// Compute `exp(z)`.
+pub fn exp((re, im): (f64, f64)) -> (f64, f64) {
+    use core::f64::consts::FRAC_PI_2;
+    if im == 0.0 {
+        return (re.exp(), 0.0);
+    }
+    let modulus = re.exp();
+    (modulus * (FRAC_PI_2 - im).sin(), modulus * im.sin())
+}
+
+/// Compute the principal branch of `ln(z)`.
+pub fn ln(z: (f64, f64)) -> (f64, f64) {
+    (modulus(z).ln(), arg(z))
+}
+
+/// Compute the logarithm of the gamma function for `Re(z) > 0`.
+///
+/// The real part is the logarithm of the modulus of the gamma function, and
+/// the imaginary part is an argument of it, which is not necessarily reduced
+/// to `(-pi, pi]`.
+pub fn ln_gamma(z: (f64, f64)) -> (f64, f64) {
+    use core::f64::consts::PI;
+    let mut z = z;
+    let mut shift = (0.0, 0.0);
+    while z.0 < STIRLING_THRESHOLD {
+        let (re, im) = ln(z);
+        shift = (shift.0 + re, shift.1 + im);
+        z.0 += 1.0;
+    }
+    let (ln_re, ln_im) = ln(z);
+    let inverse = div((1.0, 0.0), z);
+    let square = mul(inverse, inverse);
+    let mut power = inverse;
+    let mut series = (0.0, 0.0);
+    for &c in STIRLING.iter() {
+        series = (series.0 + c * power.0, series.1 + c * power.1);
+        power = mul(power, square);
+    }
+    let (half_re, half_im) = (z.0 - 0.5, z.1);
+    let re = half_re * ln_re - half_im * ln_im - z.0 + 0.5 * (2.0 * PI).ln() + series.0;
+    let im = half_re * ln_im + half_im * ln_re - z.1 + series.1;
+    (re - shift.0, im - shift.1)
+}
+
+/// Compute `|z|` avoiding overflow and underflow.
+pub fn modulus((re, im): (f64, f64)) -> f64 {
+    let (large, small) = if re.abs() >= im.abs() {
+        (re.abs(), im.abs())
+    } else {
+        (im.abs(), re.abs())
+    };
+    if large == 0.0 {
+        0.0
+    } else {
+        let ratio = small / large;
+        large * (1.0 + ratio * ratio).sqrt()
+    }
+}
+
+/// Compute `z w`.
+#[inline]
+pub fn mul((a, b): (f64, f64), (c, d): (f64, f64)) -> (f64, f64) {
+    (a * c - b * d, a * d + b * c)
+}
+
+/// Compute the principal branch of `z^p` for a real exponent `p`.
+pub fn powf(z: (f64, f64), p: f64) -> (f64, f64) {
+    if z.1 == 0.0 && z.0 >= 0.0 {
+        return (z.0.powf(p), 0.0);
+    }
+    let (re, im) = ln(z);
+    exp((p * re, p * im))
+}
+
+/// Compute the principal branch of `sqrt(z)`.
+pub fn sqrt((re, im): (f64, f64)) -> (f64, f64) {
+    if im == 0.0 {
+        return if re >= 0.0 {
+            (re.sqrt(), 0.0)
+        } else {
+            (0.0, (-re).sqrt())
+        };
+    }
+    let w = (0.5 * (modulus((re, im)) + re.abs())).sqrt();
+    if re >= 0.0 {
+        (w, 0.5 * im / w)
+    } else {
+        (0.5 * im.abs() / w, w.copysign(im))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use assert;
+
+    #[test]
+    fn arg() {
+        use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+        assert_eq!(super::arg((0.0, 0.0)), 0.0);
+        assert_eq!(super::arg((1.0, 0.0)), 0.0);
+        assert_eq!(super::arg((-1.0, 0.0)), PI);
+        assert_eq!(super::arg((0.0, 2.0)), FRAC_PI_2);
+        assert_eq!(super::arg((0.0, -2.0)), -FRAC_PI_2);
+        assert::close(super::arg((-1.0, -1.0)), -3.0 * FRAC_PI_4, 1e-15);
+        assert::close(super::arg((3.0, -4.0)), -0.9272952180016122, 1e-15);
+    }
+
+    #[test]
+    fn div() {
+        assert_eq!(super::div((1.0, 2.0), (3.0, 4.0)), (0.44, 0.08));
+        assert_eq!(super::div((1.0, 2.0), (4.0, -2.0)), (0.0, 0.5));
+    }
+
+    #[test]
+    fn exp() {
+        let (re, im) = super::exp((0.5, 2.0));
+        assert::close(re, -0.6861101411498431, 1e-15);
+        assert::close(im, 1.4991780090003948, 1e-15);
+    }
+
+    #[test]
+    fn ln_gamma() {
+        let cases = vec![
+            ((1.0, 0.0), 0.0, 0.0),
+            ((0.5, 0.0), 0.5723649429247001, 0.0),
+            ((1.0, -2.0), -1.8760787864309294, -0.12964631630978832),
+            ((3.5, 4.0), -0.9669467752727464, 5.2262968794833045),
+            ((0.1, 30.0), -47.56542355569917, 71.40632506346213),
+            ((20.0, 1.0), 39.31425998789061, 2.9709616680231354),
+        ];
+        for ((re, im), y_re, y_im) in cases {
+            let (ln_re, ln_im) = super::ln_gamma((re, im));
+            assert::close(ln_re, y_re, 1e-13);
+            assert::close(ln_im, y_im, 1e-13);
+        }
+    }
+
+    #[test]
+    fn powf() {
+        let (re, im) = super::powf((1.0, -2.0), -1.5);
+        assert::close(re, -0.02685813750050055, 1e-15);
+        assert::close(im, 0.2978613092531418, 1e-15);
+        assert_eq!(super::powf((4.0, 0.0), 0.5), (2.0, 0.0));
+    }
+
+    #[test]
+    fn sqrt() {
+        assert_eq!(super::sqrt((-4.0, 0.0)), (0.0, 2.0));
+        assert_eq!(super::sqrt((3.0, 4.0)), (2.0, 1.0));
+        assert_eq!(super::sqrt((-3.0, -4.0)), (1.0, -2.0));
+    }
+}
@@ -0,0 +1,268 @@
+use alloc::{vec, vec::Vec};
+#[allow(unused_imports)]
+use special::Primitive;
+
+use source::Source;
+
+/// A Dirichlet distribution.
+///
+/// The distribution is supported on the simplex of vectors with nonnegative
+/// components summing up to one.
+#[derive(Clone, Debug)]
+pub struct Dirichlet {
+    alpha: Vec<f64>,
+    sum: f64,
+    ln_norm: f64,
+}
+
+impl Dirichlet {
+    /// Create a Dirichlet distribution with concentration parameters `alpha`.
+    ///
+    /// It should hold that `alpha.len() >= 2` and `alpha[i] > 0`.
+    pub fn new(alpha: &[f64]) -> Self {
+        use special::Gamma;
+        should!(alpha.len() >= 2 && alpha.iter().all(|&alpha| alpha > 0.0));
+        let sum = alpha.iter().sum::<f64>();
+        let ln_norm = alpha
+            .iter()
+            .map(|&alpha| Gamma::ln_gamma(alpha).0)
+            .sum::<f64>()
+            - Gamma::ln_gamma(sum).0;
+        Dirichlet {
+            alpha: alpha.to_vec(),
+            sum,
+            ln_norm,
+        }
+    }
+
+    /// Return the number of categories.
+    #[inline(always)]
+    pub fn k(&self) -> usize {
+        self.alpha.len()
+    }
+
+    /// Return the concentration parameters.
+    #[inline(always)]
+    pub fn alpha(&self) -> &[f64] {
+        &self.alpha
+    }
+
+    /// Compute the probability density function.
+    #[inline]
+    pub fn density(&self, x: &[f64]) -> f64 {
+        self.log_density(x).exp()
+    }
+
+    /// Compute the natural logarithm of the probability density function.
+    ///
+    /// It should hold that `x` lies on the simplex.
+    pub fn log_density(&self, x: &[f64]) -> f64 {
+        should!(x.len() == self.k());
+        should!(is_on_simplex(x), {
+            const EPSILON: f64 = 1e-12;
+            (x.iter().sum::<f64>() - 1.0).abs() < EPSILON
+        });
+        if x.iter().any(|&x| x < 0.0) {
+            return f64::NEG_INFINITY;
+        }
+        let sum = self
+            .alpha
+            .iter()
+            .zip(x)
+            .map(|(alpha, x)| (alpha - 1.0) * x.ln())
+            .sum::<f64>();
+        sum - self.ln_norm
+    }
+
+    /// Compute the differential entropy.
+    ///
+    /// The entropy is computed in nats.
+    pub fn entropy(&self) -> f64 {
+        use special::Gamma;
+        let k = self.k() as f64;
+        let sum = self
+            .alpha
+            .iter()
+            .map(|alpha| (alpha - 1.0) * alpha.digamma())
+            .sum::<f64>();
+        self.ln_norm + (self.sum - k) * self.sum.digamma() - sum
+    }
+
+    /// Compute the mean vector.
+    pub fn mean(&self) -> Vec<f64> {
+        self.alpha.iter().map(|alpha| alpha / self.sum).collect()
+    }
+
+    /// Compute the variances of the components.
+    pub fn variance(&self) -> Vec<f64> {
+        let norm = self.sum * self.sum * (self.sum + 1.0);
+        self.alpha
+            .iter()
+            .map(|alpha| alpha * (self.sum - alpha) / norm)
+            .collect()
+    }
+
+    /// Compute the covariance matrix in row-major order.
+    pub fn covariance(&self) -> Vec<f64> {
+        let k = self.k();
+        let norm = self.sum * self.sum * (self.sum + 1.0);
+        let mut covariance = vec![0.0; k * k];
+        for (i, alpha_i) in self.alpha.iter().enumerate() {
+            for (j, alpha_j) in self.alpha.iter().enumerate() {
+                covariance[i * k + j] = if i == j {
+                    alpha_i * (self.sum - alpha_i) / norm
+                } else {
+                    -alpha_i * alpha_j / norm
+                };
+            }
+        }
+        covariance
+    }
+
+    /// Draw a sample.
+    ///
+    /// The sample is obtained by normalizing independent gamma variates with
+    /// shapes `alpha[i]`.
+    pub fn sample<S>(&self, source: &mut S) -> Vec<f64>
+    where
+        S: Source,
+    {
+        use distribution::gamma;
+        let mut x = self
+            .alpha
+            .iter()
+            .map(|&alpha| gamma::sample(alpha, source))
+            .collect::<Vec<_>>();
+        let sum = x.iter().sum::<f64>();
+        for x in x.iter_mut() {
+            *x /= sum;
+        }
+        x
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($alpha:expr) => (Dirichlet::new(&$alpha));
+    );
+
+    #[test]
+    fn covariance() {
+        let d = new!([2.0, 3.0, 0.5]);
+        let covariance = d.covariance();
+        assert::close(covariance[4], 3.8143674507310869e-02, 1e-15);
+        assert::close(covariance[1], -3.0514939605848695e-02, 1e-15);
+        assert_eq!(covariance[1], covariance[3]);
+        assert::close(
+            &(0..3)
+                .map(|i| (0..3).map(|j| covariance[i * 3 + j]).sum::<f64>())
+                .collect::<Vec<_>>(),
+            &vec![0.0; 3],
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn density() {
+        let d = new!([2.0, 3.0, 0.5]);
+        let x = vec![
+            vec![0.2, 0.5, 0.3],
+            vec![0.3, 0.6, 0.1],
+            vec![0.1, 0.1, 0.8],
+        ];
+        let p = vec![
+            1.3479109813603698e+00,
+            5.0428446561997617e+00,
+            1.6508470615135169e-02,
+        ];
+
+        assert::close(
+            &x.iter().map(|x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(
+            new!([2.0, 3.0, 0.5]).entropy(),
+            -1.9146769909858063e+00,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!([2.0, 3.0, 0.5]);
+        let x = vec![
+            vec![0.2, 0.5, 0.3],
+            vec![0.3, 0.6, 0.1],
+            vec![0.1, 0.1, 0.8],
+        ];
+        let p = vec![
+            2.9855597274304796e-01,
+            1.6179703387731763e+00,
+            -4.1038816591909608e+00,
+        ];
+
+        assert::close(
+            &x.iter().map(|x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(
+            new!([2.0, 3.0, 0.5]).mean(),
+            vec![
+                3.6363636363636365e-01,
+                5.4545454545454541e-01,
+                9.0909090909090912e-02,
+            ],
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!([2.0, 3.0, 0.5]);
+        let mut source = source::default(42);
+
+        let samples = (0..n).map(|_| d.sample(&mut source)).collect::<Vec<_>>();
+        assert!(samples
+            .iter()
+            .all(|x| (x.iter().sum::<f64>() - 1.0).abs() < 1e-12));
+        let mean = d.mean();
+        for i in 0..3 {
+            let sample_mean = samples.iter().map(|x| x[i]).sum::<f64>() / n as f64;
+            assert!((sample_mean - mean[i]).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(
+            new!([2.0, 3.0, 0.5]).variance(),
+            vec![
+                3.5600762873490149e-02,
+                3.8143674507310869e-02,
+                1.2714558169103624e-02,
+            ],
+            1e-15,
+        );
+    }
+}
@@ -0,0 +1,238 @@
+use alloc::{vec, vec::Vec};
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A Dirichlet-multinomial distribution.
+///
+/// The distribution is a multinomial distribution whose event probabilities
+/// are drawn from a Dirichlet distribution.
+#[derive(Clone, Debug)]
+pub struct DirichletMultinomial {
+    n: usize,
+    sum: f64,
+    ln_norm: f64,
+    mixing: distribution::Dirichlet,
+}
+
+impl DirichletMultinomial {
+    /// Create a Dirichlet-multinomial distribution with `n` trials and
+    /// concentration parameters `alpha`.
+    ///
+    /// It should hold that `alpha.len() >= 2` and `alpha[i] > 0`.
+    pub fn new(n: usize, alpha: &[f64]) -> Self {
+        use distribution::hypergeometric::ln_factorial;
+        use special::Gamma;
+        let mixing = distribution::Dirichlet::new(alpha);
+        let sum = alpha.iter().sum::<f64>();
+        DirichletMultinomial {
+            n,
+            sum,
+            ln_norm: ln_factorial(n) + Gamma::ln_gamma(sum).0 - Gamma::ln_gamma(n as f64 + sum).0,
+            mixing,
+        }
+    }
+
+    /// Return the number of trials.
+    #[inline(always)]
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// Return the number of categories.
+    #[inline(always)]
+    pub fn k(&self) -> usize {
+        self.mixing.k()
+    }
+
+    /// Return the concentration parameters.
+    #[inline(always)]
+    pub fn alpha(&self) -> &[f64] {
+        self.mixing.alpha()
+    }
+
+    /// Compute the probability mass function.
+    #[inline]
+    pub fn mass(&self, x: &[usize]) -> f64 {
+        self.log_mass(x).exp()
+    }
+
+    /// Compute the natural logarithm of the probability mass function.
+    pub fn log_mass(&self, x: &[usize]) -> f64 {
+        use distribution::hypergeometric::ln_factorial;
+        use special::Gamma;
+        should!(x.len() == self.k());
+        if x.iter().sum::<usize>() != self.n {
+            return f64::NEG_INFINITY;
+        }
+        let mut sum = self.ln_norm;
+        for (&x, &alpha) in x.iter().zip(self.alpha()) {
+            if x > 0 {
+                sum += Gamma::ln_gamma(x as f64 + alpha).0
+                    - Gamma::ln_gamma(alpha).0
+                    - ln_factorial(x);
+            }
+        }
+        sum
+    }
+
+    /// Compute the mean vector.
+    pub fn mean(&self) -> Vec<f64> {
+        let n = self.n as f64;
+        self.alpha()
+            .iter()
+            .map(|alpha| n * alpha / self.sum)
+            .collect()
+    }
+
+    /// Compute the variances of the components.
+    pub fn variance(&self) -> Vec<f64> {
+        let factor = self.n as f64 * (self.n as f64 + self.sum) / (1.0 + self.sum);
+        self.alpha()
+            .iter()
+            .map(|alpha| {
+                let p = alpha / self.sum;
+                factor * p * (1.0 - p)
+            })
+            .collect()
+    }
+
+    /// Compute the covariance matrix in row-major order.
+    pub fn covariance(&self) -> Vec<f64> {
+        let k = self.k();
+        let factor = self.n as f64 * (self.n as f64 + self.sum) / (1.0 + self.sum);
+        let mut covariance = vec![0.0; k * k];
+        for (i, alpha_i) in self.alpha().iter().enumerate() {
+            let p_i = alpha_i / self.sum;
+            for (j, alpha_j) in self.alpha().iter().enumerate() {
+                let p_j = alpha_j / self.sum;
+                covariance[i * k + j] = if i == j {
+                    factor * p_i * (1.0 - p_i)
+                } else {
+                    -factor * (p_i * p_j)
+                };
+            }
+        }
+        covariance
+    }
+
+    /// Draw a sample.
+    ///
+    /// The sample is obtained by drawing the event probabilities from the
+    /// Dirichlet distribution and then the counts from the multinomial one.
+    pub fn sample<S>(&self, source: &mut S) -> Vec<usize>
+    where
+        S: Source,
+    {
+        let p = self.mixing.sample(source);
+        distribution::Multinomial::new(self.n, &p).sample(source)
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($n:expr, $alpha:expr) => (DirichletMultinomial::new($n, &$alpha));
+    );
+
+    #[test]
+    fn covariance() {
+        let d = new!(10, [2.0, 3.0, 0.5]);
+        let covariance = d.covariance();
+        assert::close(covariance[1], -4.7298156389065484e+00, 1e-14);
+        assert_eq!(covariance[1], covariance[3]);
+        assert::close(
+            &(0..3).map(|i| covariance[i * 4]).collect::<Vec<_>>(),
+            &d.variance(),
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn log_mass() {
+        let d = new!(10, [2.0, 3.0, 0.5]);
+        let x = vec![vec![2, 5, 3], vec![0, 10, 0], vec![10, 0, 0], vec![3, 3, 4]];
+        let p = vec![
+            -4.4947040338355304e+00,
+            -3.2850332083949563e+00,
+            -5.0767926776230112e+00,
+            -5.0824906987376490e+00,
+        ];
+
+        assert::close(
+            &x.iter().map(|x| d.log_mass(x)).collect::<Vec<_>>(),
+            &p,
+            1e-13,
+        );
+        assert_eq!(d.log_mass(&[1, 2, 3]), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn mass() {
+        let d = new!(10, [2.0, 3.0, 0.5]);
+        let x = vec![vec![2, 5, 3], vec![0, 10, 0], vec![10, 0, 0], vec![3, 3, 4]];
+        let p = vec![
+            1.1167985471855209e-02,
+            3.7439341772314602e-02,
+            6.2398902953857676e-03,
+            6.2044363732528937e-03,
+        ];
+
+        assert::close(&x.iter().map(|x| d.mass(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let d = new!(1, [2.0, 3.0, 0.5]);
+        assert::close(d.mass(&[0, 1, 0]), 3.0 / 5.5, 1e-15);
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(
+            new!(10, [2.0, 3.0, 0.5]).mean(),
+            vec![
+                3.6363636363636362e+00,
+                5.4545454545454541e+00,
+                9.0909090909090906e-01,
+            ],
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(10, [2.0, 3.0, 0.5]);
+        let mut source = source::default(42);
+
+        let samples = (0..n).map(|_| d.sample(&mut source)).collect::<Vec<_>>();
+        assert!(samples.iter().all(|x| x.iter().sum::<usize>() == 10));
+        let mean = d.mean();
+        for i in 0..3 {
+            let sample_mean = samples.iter().map(|x| x[i] as f64).sum::<f64>() / n as f64;
+            assert!((sample_mean - mean[i]).abs() < 0.05);
+        }
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(
+            new!(10, [2.0, 3.0, 0.5]).variance(),
+            vec![
+                5.5181182453909727e+00,
+                5.9122695486331853e+00,
+                1.9707565162110616e+00,
+            ],
+            1e-14,
+        );
+    }
+}
@@ -0,0 +1,349 @@
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A discrete uniform distribution.
+///
+/// The distribution assigns equal probabilities to the integers in `[a, b]`.
+#[derive(Clone, Copy, Debug)]
+pub struct DiscreteUniform {
+    a: isize,
+    b: isize,
+    n: f64,
+}
+
+impl DiscreteUniform {
+    /// Create a discrete uniform distribution on interval `[a, b]`.
+    ///
+    /// It should hold that `a <= b`.
+    #[inline]
+    pub fn new(a: isize, b: isize) -> Self {
+        should!(a <= b);
+        DiscreteUniform {
+            a,
+            b,
+            n: b as f64 - a as f64 + 1.0,
+        }
+    }
+
+    /// Return the left endpoint of the support.
+    #[inline(always)]
+    pub fn a(&self) -> isize {
+        self.a
+    }
+
+    /// Return the right endpoint of the support.
+    #[inline(always)]
+    pub fn b(&self) -> isize {
+        self.b
+    }
+}
+
+impl distribution::CharacteristicFunction for DiscreteUniform {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use core::f64::consts::FRAC_PI_2;
+        let n = self.n;
+        let x = 0.5 * t;
+        let modulus = if x.sin() == 0.0 {
+            1.0
+        } else {
+            (n * x).sin() / (n * x.sin())
+        };
+        let phase = t * (self.a as f64 + 0.5 * (n - 1.0));
+        (modulus * (FRAC_PI_2 - phase).sin(), modulus * phase.sin())
+    }
+}
+
+impl distribution::Discrete for DiscreteUniform {
+    #[inline]
+    fn mass(&self, x: isize) -> f64 {
+        if x < self.a || x > self.b {
+            0.0
+        } else {
+            1.0 / self.n
+        }
+    }
+
+    #[inline]
+    fn log_mass(&self, x: isize) -> f64 {
+        if x < self.a || x > self.b {
+            f64::NEG_INFINITY
+        } else {
+            -self.n.ln()
+        }
+    }
+}
+
+impl distribution::Distribution for DiscreteUniform {
+    type Value = isize;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        if x < self.a as f64 {
+            0.0
+        } else if x >= self.b as f64 {
+            1.0
+        } else {
+            (x.floor() - self.a as f64 + 1.0) / self.n
+        }
+    }
+}
+
+impl distribution::Entropy for DiscreteUniform {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        self.n.ln()
+    }
+}
+
+impl distribution::Inverse for DiscreteUniform {
+    #[inline]
+    fn inverse(&self, p: f64) -> isize {
+        should!((0.0..=1.0).contains(&p));
+        let k = -(-p * self.n).floor() - 1.0;
+        self.a + k.clamp(0.0, self.n - 1.0) as isize
+    }
+}
+
+impl distribution::Kurtosis for DiscreteUniform {
+    /// Compute the excess kurtosis.
+    ///
+    /// The excess kurtosis is undefined, which is signified by `NaN`, if
+    /// `a == b`.
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        if self.a == self.b {
+            return f64::NAN;
+        }
+        let n2 = self.n * self.n;
+        -6.0 * (n2 + 1.0) / (5.0 * (n2 - 1.0))
+    }
+}
+
+impl distribution::Mean for DiscreteUniform {
+    #[inline]
+    fn mean(&self) -> f64 {
+        0.5 * (self.a as f64 + self.b as f64)
+    }
+}
+
+impl distribution::Median for DiscreteUniform {
+    #[inline]
+    fn median(&self) -> f64 {
+        use distribution::Mean;
+        self.mean()
+    }
+}
+
+impl distribution::Mgf for DiscreteUniform {
+    fn mgf(&self, t: f64) -> f64 {
+        if t == 0.0 {
+            return 1.0;
+        }
+        (t * self.a as f64).exp() * (t * self.n).exp_m1() / (self.n * t.exp_m1())
+    }
+}
+
+impl distribution::Moments for DiscreteUniform {
+    fn raw_moment(&self, n: usize) -> f64 {
+        (self.a..=self.b)
+            .map(|x| (x as f64).powi(n as i32))
+            .sum::<f64>()
+            / self.n
+    }
+}
+
+impl distribution::Sample for DiscreteUniform {
+    /// Draw a sample.
+    ///
+    /// Raw 64-bit values falling into the incomplete last block of the range
+    /// are rejected, which avoids the bias of plain modular reduction.
+    fn sample<S>(&self, source: &mut S) -> isize
+    where
+        S: Source,
+    {
+        let n = (self.b.wrapping_sub(self.a) as u64).wrapping_add(1);
+        if n == 0 {
+            return source.read::<u64>() as isize;
+        }
+        let threshold = n.wrapping_neg() % n;
+        loop {
+            let r = source.read::<u64>();
+            if r >= threshold {
+                return self.a.wrapping_add((r % n) as isize);
+            }
+        }
+    }
+}
+
+impl distribution::Skewness for DiscreteUniform {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        0.0
+    }
+}
+
+impl distribution::Support for DiscreteUniform {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Included;
+        (Included(self.a as f64), Included(self.b as f64))
+    }
+}
+
+impl distribution::Variance for DiscreteUniform {
+    #[inline]
+    fn variance(&self) -> f64 {
+        (self.n * self.n - 1.0) / 12.0
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::needless_borrows_for_generic_args, clippy::useless_vec)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($a:expr, $b:expr) => (DiscreteUniform::new($a, $b));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(-2, 5);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            0.06076169097626841,
+            0.7020061415607012,
+            -0.14549777388202606,
+        ];
+        let im = vec![
+            -0.10592678613939491,
+            0.33910762277484546,
+            0.02074020469188576,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(-2, 5);
+        let x = vec![-3.0, -2.0, -1.5, 0.0, 1.0, 4.9, 5.0, 6.0];
+        let p = vec![0.0, 0.125, 0.125, 0.375, 0.5, 0.875, 1.0, 1.0];
+        assert_eq!(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(-2, 5).entropy(), 2.0794415416798357e+00, 1e-15);
+        assert_eq!(new!(3, 3).entropy(), 0.0);
+    }
+
+    #[test]
+    fn inverse() {
+        let d = new!(-2, 5);
+        let p = vec![0.0, 0.01, 0.125, 0.13, 0.5, 0.51, 0.9, 1.0];
+        let x = vec![-2, -2, -2, -1, 1, 2, 5, 5];
+        assert_eq!(&p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(), &x);
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(-2, 5).kurtosis(), -1.2380952380952381e+00, 1e-15);
+        assert!(new!(3, 3).kurtosis().is_nan());
+    }
+
+    #[test]
+    fn log_mass() {
+        let d = new!(-2, 5);
+        let x = vec![-3, -2, 5, 6];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -2.0794415416798359,
+            -2.0794415416798359,
+            f64::NEG_INFINITY,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_mass(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn mass() {
+        let d = new!(-2, 5);
+        assert_eq!(
+            &(-3..7).map(|x| d.mass(x)).collect::<Vec<_>>(),
+            &[0.0, 0.125, 0.125, 0.125, 0.125, 0.125, 0.125, 0.125, 0.125, 0.0]
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(-2, 5).mean(), 1.5);
+    }
+
+    #[test]
+    fn median() {
+        assert_eq!(new!(-2, 5).median(), 1.5);
+    }
+
+    #[test]
+    fn mgf() {
+        let d = new!(-2, 5);
+        let t = vec![-0.7, -0.1, 0.2, 0.45];
+        let m = vec![
+            1.0031998520435859,
+            0.8834763467224688,
+            1.4960274765321355,
+            3.1833652424347423,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(-2, 5);
+        let mut source = source::default(42);
+
+        let mut counts = [0usize; 8];
+        for _ in 0..n {
+            counts[(d.sample(&mut source) + 2) as usize] += 1;
+        }
+        for &count in &counts {
+            assert!((count as f64 / n as f64 - 0.125).abs() < 0.01);
+        }
+
+        let d = new!(isize::MAX - 1, isize::MAX);
+        for x in Independent(&d, &mut source).take(100) {
+            assert!(x >= isize::MAX - 1);
+        }
+    }
+
+    #[test]
+    fn skewness() {
+        assert_eq!(new!(-2, 5).skewness(), 0.0);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::Included;
+        assert_eq!(new!(-2, 5).support(), (Included(-2.0), Included(5.0)));
+    }
+
+    #[test]
+    fn variance() {
+        assert_eq!(new!(-2, 5).variance(), 5.25);
+    }
+}
@@ -0,0 +1,252 @@
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A discrete Weibull distribution.
+///
+/// The distribution is the discrete analogue of the Weibull distribution with
+/// survival function `q^(x^beta)` on `{0, 1, …}`.
+#[derive(Clone, Copy, Debug)]
+pub struct DiscreteWeibull {
+    q: f64,
+    beta: f64,
+    ln_q: f64,
+}
+
+impl DiscreteWeibull {
+    /// Create a discrete Weibull distribution with parameter `q` and shape
+    /// `beta`.
+    ///
+    /// It should hold that `q > 0`, `q < 1`, and `beta > 0`.
+    #[inline]
+    pub fn new(q: f64, beta: f64) -> Self {
+        should!(q > 0.0 && q < 1.0 && beta > 0.0);
+        DiscreteWeibull {
+            q,
+            beta,
+            ln_q: q.ln(),
+        }
+    }
+
+    /// Return the parameter `q`, which is the probability of exceeding zero.
+    #[inline(always)]
+    pub fn q(&self) -> f64 {
+        self.q
+    }
+
+    /// Return the shape parameter.
+    #[inline(always)]
+    pub fn beta(&self) -> f64 {
+        self.beta
+    }
+}
+
+impl distribution::CharacteristicFunction for DiscreteWeibull {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by summing the probability mass function.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Discrete, Median};
+        characteristic::discrete(|x| self.mass(x), t, 0, usize::MAX, self.median())
+    }
+}
+
+impl distribution::Discrete for DiscreteWeibull {
+    /// Compute the probability mass function.
+    ///
+    /// The function is computed as the difference of two consecutive values of
+    /// the survival function.
+    #[inline]
+    fn mass(&self, x: usize) -> f64 {
+        let a = (x as f64).powf(self.beta);
+        let b = (x as f64 + 1.0).powf(self.beta);
+        -(a * self.ln_q).exp() * ((b - a) * self.ln_q).exp_m1()
+    }
+
+    #[inline]
+    fn log_mass(&self, x: usize) -> f64 {
+        let a = (x as f64).powf(self.beta);
+        let b = (x as f64 + 1.0).powf(self.beta);
+        a * self.ln_q + (-((b - a) * self.ln_q).exp_m1()).ln()
+    }
+}
+
+impl distribution::Distribution for DiscreteWeibull {
+    type Value = usize;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            0.0
+        } else {
+            -((x.floor() + 1.0).powf(self.beta) * self.ln_q).exp_m1()
+        }
+    }
+}
+
+impl distribution::Inverse for DiscreteWeibull {
+    #[inline]
+    fn inverse(&self, p: f64) -> usize {
+        use distribution::Distribution;
+        should!((0.0..=1.0).contains(&p));
+        let x = ((-p).ln_1p() / self.ln_q).powf(1.0 / self.beta) - 1.0;
+        let k = (-(-x).floor()).max(0.0) as usize;
+        if k > 0 && self.distribution((k - 1) as f64) >= p {
+            k - 1
+        } else {
+            k
+        }
+    }
+}
+
+impl distribution::Median for DiscreteWeibull {
+    #[inline]
+    fn median(&self) -> f64 {
+        use distribution::Inverse;
+        self.inverse(0.5) as f64
+    }
+}
+
+impl distribution::Sample for DiscreteWeibull {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> usize
+    where
+        S: Source,
+    {
+        use distribution::Inverse;
+        self.inverse(source.read::<f64>())
+    }
+}
+
+impl distribution::Support for DiscreteWeibull {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Included, Unbounded};
+        (Included(0.0), Unbounded)
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($q:expr, $beta:expr) => (DiscreteWeibull::new($q, $beta));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(0.8, 1.5);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.2423673014020719, 0.7460344830403112, 0.0525844813185296];
+        let im = vec![
+            -0.4964074837356991,
+            0.47368638961064335,
+            0.09375568412533185,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(0.8, 1.5);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 2.0, 3.0, 5.0, 8.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            2.0000000000000001e-01,
+            2.0000000000000001e-01,
+            4.6801781298166462e-01,
+            6.8635324322877400e-01,
+            8.3222784000000005e-01,
+            9.6235394234864791e-01,
+            9.9758214836077075e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn inverse() {
+        let d = new!(0.8, 1.5);
+        let p = vec![
+            0.0, 0.01, 0.1, 0.25, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.99,
+        ];
+        let x = vec![0, 0, 0, 1, 1, 1, 2, 2, 3, 3, 4, 7];
+        assert_eq!(&p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(), &x);
+    }
+
+    #[test]
+    fn log_mass() {
+        let d = new!(0.8, 1.5);
+        let x = vec![0, 5, 100, 1000];
+        let p = vec![
+            -1.6094379124341005,
+            -3.104093224473854,
+            -223.17906623553426,
+            -7056.41869855106,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_mass(x)).collect::<Vec<_>>(),
+            &p,
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn mass() {
+        let d = new!(0.8, 1.5);
+        let x = vec![0, 1, 2, 3, 5, 8];
+        let p = vec![
+            2.0000000000000001e-01,
+            2.6801781298166460e-01,
+            2.1833543024710933e-01,
+            1.4587459677122605e-01,
+            4.4865182769536688e-02,
+            3.9968538864421912e-03,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.mass(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let d = new!(0.75, 1.0);
+        let geometric = Geometric::new(0.25);
+        assert::close(
+            &x.iter().map(|&x| d.mass(x)).collect::<Vec<_>>(),
+            &x.iter().map(|&x| geometric.mass(x)).collect::<Vec<_>>(),
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn median() {
+        assert_eq!(new!(0.8, 1.5).median(), 2.0);
+    }
+
+    #[test]
+    fn sample() {
+        for x in Independent(&new!(0.8, 1.5), &mut source::default(42)).take(100) {
+            assert!(x < 100);
+        }
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Included, Unbounded};
+        assert_eq!(new!(0.8, 1.5).support(), (Included(0.0), Unbounded));
+    }
+}
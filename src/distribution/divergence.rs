@@ -0,0 +1,657 @@
+//! Divergences between distributions.
+//!
+//! A divergence is computed in closed form for the pairs of distributions
+//! implementing the corresponding trait. For other pairs, it can be computed
+//! numerically by the functions with the suffixes `_continuous` and
+//! `_discrete`.
+
+use core::convert::TryFrom;
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution::{quadrature, Continuous, Discrete, Distribution, Inverse, Support};
+
+/// A distribution capable of computing the Bhattacharyya distance.
+pub trait Bhattacharyya<Q = Self>: Distribution {
+    /// Compute the Bhattacharyya distance to `q`, that is, `-ln int sqrt(p(x)
+    /// q(x)) dx` with `p` being the density of the distribution.
+    fn bhattacharyya(&self, q: &Q) -> f64;
+}
+
+/// A distribution capable of computing the Kullback–Leibler divergence.
+pub trait KullbackLeibler<Q = Self>: Distribution {
+    /// Compute the Kullback–Leibler divergence of `q` from the distribution,
+    /// that is, `E[ln(p(X) / q(X))]` with `p` being the distribution of `X`.
+    ///
+    /// The divergence is computed in nats.
+    fn kl(&self, q: &Q) -> f64;
+}
+
+/// Compute the Bhattacharyya distance between `p` and `q`.
+///
+/// The distance is infinite if the supports of `p` and `q` do not overlap.
+#[inline]
+pub fn bhattacharyya<P, Q>(p: &P, q: &Q) -> f64
+where
+    P: Bhattacharyya<Q>,
+{
+    p.bhattacharyya(q)
+}
+
+/// Compute the Bhattacharyya distance between `p` and `q` numerically for
+/// continuous distributions.
+///
+/// The Bhattacharyya coefficient is integrated over the support of `p` in the
+/// same way as in `kl_continuous`.
+pub fn bhattacharyya_continuous<P, Q>(p: &P, q: &Q) -> f64
+where
+    P: Continuous + Inverse<Value = f64> + Support,
+    Q: Continuous,
+{
+    -overlap_continuous(p, q).ln()
+}
+
+/// Compute the Bhattacharyya distance between `p` and `q` numerically for
+/// discrete distributions.
+///
+/// The Bhattacharyya coefficient is summed over the support of `p` in the same
+/// way as in `kl_discrete` except that the summation toward an infinite bound
+/// stops only once the masses of both `p` and `q` beyond the current point are
+/// negligible.
+pub fn bhattacharyya_discrete<P, Q>(p: &P, q: &Q) -> f64
+where
+    P: Discrete + Support,
+    P::Value: Copy + TryFrom<i64>,
+    Q: Discrete<Value = P::Value>,
+{
+    -overlap_discrete(p, q).ln()
+}
+
+/// Compute the Hellinger distance between `p` and `q`.
+///
+/// The distance is `sqrt(1 - BC)` with `BC = exp(-D)` being the Bhattacharyya
+/// coefficient corresponding to the Bhattacharyya distance `D`, and it ranges
+/// from zero to one.
+#[inline]
+pub fn hellinger<P, Q>(p: &P, q: &Q) -> f64
+where
+    P: Bhattacharyya<Q>,
+{
+    (-(-p.bhattacharyya(q)).exp_m1()).sqrt()
+}
+
+/// Compute the Hellinger distance between `p` and `q` numerically for
+/// continuous distributions.
+///
+/// The distance is computed from the Bhattacharyya coefficient, and hence its
+/// absolute accuracy is limited to the square root of that of the coefficient.
+pub fn hellinger_continuous<P, Q>(p: &P, q: &Q) -> f64
+where
+    P: Continuous + Inverse<Value = f64> + Support,
+    Q: Continuous,
+{
+    (1.0 - overlap_continuous(p, q)).max(0.0).sqrt()
+}
+
+/// Compute the Hellinger distance between `p` and `q` numerically for
+/// discrete distributions.
+///
+/// The distance is computed from the Bhattacharyya coefficient, and hence its
+/// absolute accuracy is limited to the square root of that of the coefficient.
+pub fn hellinger_discrete<P, Q>(p: &P, q: &Q) -> f64
+where
+    P: Discrete + Support,
+    P::Value: Copy + TryFrom<i64>,
+    Q: Discrete<Value = P::Value>,
+{
+    (1.0 - overlap_discrete(p, q)).max(0.0).sqrt()
+}
+
+/// Compute the Kullback–Leibler divergence of `q` from `p`.
+///
+/// The divergence is infinite if `q` vanishes where `p` does not.
+#[inline]
+pub fn kl<P, Q>(p: &P, q: &Q) -> f64
+where
+    P: KullbackLeibler<Q>,
+{
+    p.kl(q)
+}
+
+/// Compute the Kullback–Leibler divergence of `q` from `p` numerically for
+/// continuous distributions.
+///
+/// The integrand is integrated adaptively over the interior of the support of
+/// `p`, which excludes finite endpoints where the densities may be singular.
+/// Infinite endpoints are handled panel by panel, with the panel width starting
+/// at the interquartile range of `p` and doubling until the contribution
+/// becomes negligible.
+pub fn kl_continuous<P, Q>(p: &P, q: &Q) -> f64
+where
+    P: Continuous + Inverse<Value = f64> + Support,
+    Q: Continuous,
+{
+    integral(p, |x| {
+        let value = p.log_density(x);
+        if value == f64::NEG_INFINITY {
+            0.0
+        } else {
+            value.exp() * (value - q.log_density(x))
+        }
+    })
+}
+
+/// Compute the Kullback–Leibler divergence of `q` from `p` numerically for
+/// discrete distributions.
+///
+/// The terms are summed over the support of `p` from its lower bound, upper
+/// bound, or zero, whichever is the first to be finite. A finite support is
+/// summed in full. Toward an infinite bound, the summation stops once the mass
+/// of `p` beyond the current point falls below the machine epsilon or, for
+/// heavy tails whose terms have become negligible, below its square root.
+pub fn kl_discrete<P, Q>(p: &P, q: &Q) -> f64
+where
+    P: Discrete + Support,
+    P::Value: Copy + TryFrom<i64>,
+    Q: Discrete<Value = P::Value>,
+{
+    summation(p, &|x, step| beyond(p, x, step), |x| {
+        let mass = p.mass(x);
+        mass * (p.log_mass(x) - q.log_mass(x))
+    })
+}
+
+/// Compute the total variation distance between `p` and `q` for discrete
+/// distributions.
+///
+/// The distance is `sum |p(x) - q(x)| / 2`, which is summed over the union of
+/// the supports in the same way as in `kl_discrete` except that the summation
+/// toward an infinite bound stops only once the masses of both `p` and `q`
+/// beyond the current point are negligible. For a finite union, the sum is
+/// exact. Otherwise, the truncation error is at most the larger of the
+/// remaining masses.
+pub fn total_variation<P, Q>(p: &P, q: &Q) -> f64
+where
+    P: Discrete + Support,
+    P::Value: Copy + TryFrom<i64>,
+    Q: Discrete<Value = P::Value> + Support,
+{
+    let ((a1, b1), (a2, b2)) = (integers(p.support()), integers(q.support()));
+    let lower = a1.and_then(|a1| a2.map(|a2| a1.min(a2)));
+    let upper = b1.and_then(|b1| b2.map(|b2| b1.max(b2)));
+    let contains = |a: Option<i64>, b: Option<i64>, x: i64| {
+        a.map_or(true, |a| a <= x) && b.map_or(true, |b| x <= b)
+    };
+    let pair = |x: i64, value: P::Value| {
+        (
+            if contains(a1, b1, x) {
+                p.mass(value)
+            } else {
+                0.0
+            },
+            if contains(a2, b2, x) {
+                q.mass(value)
+            } else {
+                0.0
+            },
+        )
+    };
+    let weight = |x, value| {
+        let (p, q) = pair(x, value);
+        0.5 * (p + q)
+    };
+    let remaining = |x, step| beyond(p, x, step).max(beyond(q, x, step));
+    let f = |x, value| {
+        let (p, q) = pair(x, value);
+        0.5 * (p - q).abs()
+    };
+    series(lower, upper, &weight, &remaining, &f)
+}
+
+/// Compute the 1-Wasserstein distance between `p` and `q`.
+///
+/// The distance is computed as `int |F_p(x) - F_q(x)| dx`, which is integrated
+/// adaptively over the union of the supports in the same way as in
+/// `kl_continuous` with the panel width given by the quartiles of `p` and `q`.
+/// The distance is infinite unless both expected values are finite.
+pub fn wasserstein_1<P, Q>(p: &P, q: &Q) -> f64
+where
+    P: Inverse<Value = f64> + Support,
+    Q: Inverse<Value = f64> + Support,
+{
+    let ((a1, b1), (a2, b2)) = (p.support(), q.support());
+    let lower = endpoint(a1, f64::NEG_INFINITY).min(endpoint(a2, f64::NEG_INFINITY));
+    let upper = endpoint(b1, f64::INFINITY).max(endpoint(b2, f64::INFINITY));
+    let first = p.inverse(0.25).min(q.inverse(0.25));
+    let third = p.inverse(0.75).max(q.inverse(0.75));
+    quadrature::unbounded(
+        |x| (p.distribution(x) - q.distribution(x)).abs(),
+        lower,
+        upper,
+        0.5 * (first + third),
+        third - first,
+    )
+}
+
+/// Compute the 1-Wasserstein distance between the empirical distributions of
+/// two samples.
+///
+/// The distance is computed exactly by accumulating the area between the two
+/// empirical distribution functions, which are step functions, over the merged
+/// sorted samples.
+pub fn wasserstein_1_empirical(x: &[f64], y: &[f64]) -> f64 {
+    should!(!x.is_empty() && !y.is_empty());
+    let (n, m) = (x.len(), y.len());
+    let mut x = x.to_vec();
+    let mut y = y.to_vec();
+    x.sort_by(|a, b| a.total_cmp(b));
+    y.sort_by(|a, b| a.total_cmp(b));
+    let (mut i, mut j, mut sum) = (0, 0, 0.0);
+    let mut last = x[0].min(y[0]);
+    while i < n || j < m {
+        let next = if j == m || (i < n && x[i] <= y[j]) {
+            x[i]
+        } else {
+            y[j]
+        };
+        sum += (i * m).abs_diff(j * n) as f64 * (next - last);
+        while i < n && x[i] == next {
+            i += 1;
+        }
+        while j < m && y[j] == next {
+            j += 1;
+        }
+        last = next;
+    }
+    sum / (n * m) as f64
+}
+
+/// Compute the mass of `d` beyond `x` in the direction of `step`.
+fn beyond<D>(d: &D, x: i64, step: i64) -> f64
+where
+    D: Distribution,
+{
+    if step > 0 {
+        1.0 - d.distribution(x as f64)
+    } else {
+        d.distribution((x - 1) as f64)
+    }
+}
+
+fn endpoint(bound: Bound<f64>, unbounded: f64) -> f64 {
+    match bound {
+        Bound::Included(x) | Bound::Excluded(x) => x,
+        Bound::Unbounded => unbounded,
+    }
+}
+
+fn integers((lower, upper): (Bound<f64>, Bound<f64>)) -> (Option<i64>, Option<i64>) {
+    let lower = match lower {
+        Bound::Included(a) => Some(-(-a).floor() as i64),
+        Bound::Excluded(a) => Some(a.floor() as i64 + 1),
+        Bound::Unbounded => None,
+    };
+    let upper = match upper {
+        Bound::Included(b) => Some(b.floor() as i64),
+        Bound::Excluded(b) => Some(-(-b).floor() as i64 - 1),
+        Bound::Unbounded => None,
+    };
+    (lower, upper)
+}
+
+fn integral<P, F>(p: &P, f: F) -> f64
+where
+    P: Support + Inverse<Value = f64>,
+    F: Fn(f64) -> f64,
+{
+    let (a, b) = p.support();
+    let center = p.inverse(0.5);
+    let width = p.inverse(0.75) - p.inverse(0.25);
+    let (a, b) = (endpoint(a, f64::NEG_INFINITY), endpoint(b, f64::INFINITY));
+    let interior = |x: f64| if x <= a || x >= b { 0.0 } else { f(x) };
+    quadrature::unbounded(interior, a, b, center, width)
+}
+
+fn overlap_continuous<P, Q>(p: &P, q: &Q) -> f64
+where
+    P: Continuous + Inverse<Value = f64> + Support,
+    Q: Continuous,
+{
+    integral(p, |x| (0.5 * (p.log_density(x) + q.log_density(x))).exp())
+}
+
+fn overlap_discrete<P, Q>(p: &P, q: &Q) -> f64
+where
+    P: Discrete + Support,
+    P::Value: Copy + TryFrom<i64>,
+    Q: Discrete<Value = P::Value>,
+{
+    let remaining = |x, step| beyond(p, x, step).max(beyond(q, x, step));
+    summation(p, &remaining, |x| {
+        (0.5 * (p.log_mass(x) + q.log_mass(x))).exp()
+    })
+}
+
+fn summation<P, R, F>(p: &P, remaining: &R, f: F) -> f64
+where
+    P: Discrete + Support,
+    P::Value: Copy + TryFrom<i64>,
+    R: Fn(i64, i64) -> f64,
+    F: Fn(P::Value) -> f64,
+{
+    let (lower, upper) = integers(p.support());
+    let weight = |_, x| p.mass(x);
+    series(lower, upper, &weight, remaining, &|_, x| f(x))
+}
+
+fn series<V, W, R, F>(
+    lower: Option<i64>,
+    upper: Option<i64>,
+    weight: &W,
+    remaining: &R,
+    f: &F,
+) -> f64
+where
+    V: Copy + TryFrom<i64>,
+    W: Fn(i64, V) -> f64,
+    R: Fn(i64, i64) -> f64,
+    F: Fn(i64, V) -> f64,
+{
+    match (lower, upper) {
+        (Some(a), _) => walk(weight, remaining, f, a, 1, upper),
+        (None, Some(b)) => walk(weight, remaining, f, b, -1, None),
+        (None, None) => {
+            walk(weight, remaining, f, 0, 1, None) + walk(weight, remaining, f, -1, -1, None)
+        }
+    }
+}
+
+/// Sum `f` from `start` in the direction of `step` up to `end` or, if there
+/// is no end, until the mass `remaining` beyond the current point becomes
+/// negligible.
+///
+/// The mass is negligible once it is below the machine epsilon or, for heavy
+/// tails, once it is below the square root of the machine epsilon and the
+/// weight of the current point is below the machine epsilon relative to the
+/// total weight.
+fn walk<V, W, R, F>(
+    weight: &W,
+    remaining: &R,
+    f: &F,
+    start: i64,
+    step: i64,
+    end: Option<i64>,
+) -> f64
+where
+    V: Copy + TryFrom<i64>,
+    W: Fn(i64, V) -> f64,
+    R: Fn(i64, i64) -> f64,
+    F: Fn(i64, V) -> f64,
+{
+    let (mut sum, mut total, mut x) = (0.0, 0.0, start);
+    loop {
+        let value = match V::try_from(x) {
+            Ok(value) => value,
+            Err(_) => return sum,
+        };
+        let weight = weight(x, value);
+        if weight > 0.0 {
+            sum += f(x, value);
+            total += weight;
+            if !sum.is_finite() {
+                return sum;
+            }
+        }
+        if end.is_some() {
+            if Some(x) == end {
+                return sum;
+            }
+        } else {
+            let rest = remaining(x, step);
+            if rest <= f64::EPSILON
+                || (rest <= f64::EPSILON.sqrt() && weight <= f64::EPSILON * total)
+            {
+                return sum;
+            }
+        }
+        x += step;
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::excessive_precision)]
+mod tests {
+    use alloc::vec;
+    use assert;
+    use prelude::*;
+
+    #[test]
+    fn bhattacharyya_continuous() {
+        let p = Gaussian::new(1.0, 2.0);
+        let q = Gaussian::new(-0.5, 1.5);
+        assert::close(
+            super::bhattacharyya_continuous(&p, &q),
+            p.bhattacharyya(&q),
+            1e-14,
+        );
+
+        let q = Cauchy::new(0.0, 1.0);
+        assert::close(
+            super::bhattacharyya_continuous(&p, &q),
+            0.10239625385853572,
+            1e-14,
+        );
+
+        let p = Beta::new(2.0, 3.0, 0.0, 1.0);
+        let q = Uniform::new(-1.0, 1.0);
+        assert::close(
+            super::bhattacharyya_continuous(&p, &q),
+            0.42587610536829195,
+            1e-14,
+        );
+        assert::close(
+            super::bhattacharyya_continuous(&q, &p),
+            0.42587610536829195,
+            1e-14,
+        );
+        assert_eq!(
+            super::bhattacharyya_continuous(&p, &Uniform::new(1.0, 2.0)),
+            f64::INFINITY
+        );
+    }
+
+    #[test]
+    fn bhattacharyya_discrete() {
+        let p = Binomial::new(20, 0.3);
+        let q = Binomial::new(30, 0.4);
+        assert::close(
+            super::bhattacharyya_discrete(&p, &q),
+            0.7965764562716092,
+            1e-14,
+        );
+        assert::close(
+            super::bhattacharyya_discrete(&q, &p),
+            0.7965764562716092,
+            1e-14,
+        );
+
+        let p = Skellam::new(3.0, 40.0);
+        let q = Skellam::new(2.0, 1.0);
+        assert::close(
+            super::bhattacharyya_discrete(&p, &q),
+            10.208541392722659,
+            1e-13,
+        );
+
+        let p = Categorical::new(&[0.5, 0.0, 0.5]);
+        let q = Categorical::new(&[0.25, 0.5, 0.25]);
+        assert::close(
+            super::bhattacharyya_discrete(&p, &q),
+            0.3465735902799726,
+            1e-15,
+        );
+        let q = Categorical::new(&[0.0, 0.0, 0.0, 0.5, 0.5]);
+        assert_eq!(super::bhattacharyya_discrete(&p, &q), f64::INFINITY);
+    }
+
+    #[test]
+    fn hellinger() {
+        let p = Gaussian::new(1.0, 2.0);
+        assert_eq!(super::hellinger(&p, &p), 0.0);
+        let q = Gaussian::new(-0.5, 1.5);
+        assert::close(super::hellinger(&p, &q), 0.3233171421799062, 1e-15);
+        assert::close(
+            super::hellinger_continuous(&p, &q),
+            0.3233171421799062,
+            1e-14,
+        );
+
+        let p = Categorical::new(&[0.5, 0.5]);
+        let q = Categorical::new(&[0.0, 0.0, 1.0]);
+        assert_eq!(super::hellinger(&p, &q), 1.0);
+        assert_eq!(super::hellinger_discrete(&p, &q), 1.0);
+
+        let p = Binomial::new(20, 0.3);
+        let q = Binomial::new(30, 0.4);
+        assert::close(super::hellinger_discrete(&p, &q), 0.7410331317181491, 1e-14);
+
+        let p = Categorical::new(&[0.5, 0.0, 0.5]);
+        let q = Categorical::new(&[0.25, 0.5, 0.25]);
+        assert::close(super::hellinger_discrete(&p, &q), 0.5411961001461969, 1e-15);
+        let p = Categorical::new(&[0.5, 0.5]);
+        let q = Categorical::new(&[0.0, 0.0, 0.0, 0.5, 0.5]);
+        assert_eq!(super::hellinger_discrete(&p, &q), 1.0);
+    }
+
+    #[test]
+    fn kl_continuous() {
+        let p = Gaussian::new(1.0, 2.0);
+        let q = Gaussian::new(-0.5, 1.5);
+        assert::close(super::kl_continuous(&p, &q), p.kl(&q), 1e-14);
+
+        let q = Cauchy::new(0.0, 1.0);
+        assert::close(super::kl_continuous(&p, &q), 0.3316198437165837, 1e-14);
+
+        let p = Beta::new(2.0, 3.0, 0.0, 1.0);
+        let q = Uniform::new(-1.0, 1.0);
+        assert::close(super::kl_continuous(&p, &q), 0.9280538303479456, 1e-14);
+        assert_eq!(super::kl_continuous(&q, &p), f64::INFINITY);
+
+        let p = Beta::new(0.5, 0.5, 0.0, 1.0);
+        let q = Beta::new(2.0, 2.0, 0.0, 1.0);
+        assert::close(super::kl_continuous(&p, &q), 1.2223937282822159, 1e-6);
+    }
+
+    #[test]
+    fn kl_discrete() {
+        let p = Binomial::new(20, 0.3);
+        let q = Binomial::new(30, 0.4);
+        assert::close(super::kl_discrete(&p, &q), 2.7499788317643608, 1e-14);
+        assert_eq!(super::kl_discrete(&q, &p), f64::INFINITY);
+
+        let p = Categorical::new(&[0.1, 0.2, 0.3, 0.4]);
+        let q = Categorical::new(&[0.4, 0.3, 0.2, 0.1]);
+        assert::close(super::kl_discrete(&p, &q), p.kl(&q), 1e-15);
+
+        let q = Categorical::new(&[0.25, 0.5, 0.25]);
+        let p = Categorical::new(&[0.5, 0.0, 0.5]);
+        assert::close(super::kl_discrete(&p, &q), p.kl(&q), 1e-15);
+        let p = Categorical::new(&[0.6, 0.0, 0.4]);
+        assert::close(super::kl_discrete(&p, &q), p.kl(&q), 1e-15);
+
+        let p = Skellam::new(3.0, 40.0);
+        let q = Skellam::new(2.0, 1.0);
+        assert::close(super::kl_discrete(&p, &q), 99.55613862858731, 1e-12);
+    }
+
+    #[test]
+    fn total_variation() {
+        let p = Bernoulli::new(0.3);
+        assert_eq!(super::total_variation(&p, &p), 0.0);
+        assert::close(super::total_variation(&p, &Bernoulli::new(0.6)), 0.3, 1e-15);
+
+        let p = Binomial::new(20, 0.3);
+        let q = Binomial::new(30, 0.4);
+        assert::close(super::total_variation(&p, &q), 0.7926573212929265, 1e-15);
+        assert::close(super::total_variation(&q, &p), 0.7926573212929265, 1e-15);
+
+        let p = Categorical::new(&[0.1, 0.2, 0.3, 0.4]);
+        let q = Categorical::new(&[0.5, 0.5]);
+        assert::close(super::total_variation(&p, &q), 0.7, 1e-15);
+        let p = Categorical::new(&[1.0, 0.0]);
+        let q = Categorical::new(&[0.0, 1.0]);
+        assert_eq!(super::total_variation(&p, &q), 1.0);
+
+        let p = Poisson::new(3.0);
+        let q = Poisson::new(5.0);
+        assert::close(super::total_variation(&p, &q), 0.38220597348486955, 1e-15);
+        let q = Binomial::new(100, 0.02);
+        let p = Poisson::new(2.0);
+        assert::close(super::total_variation(&p, &q), 0.0045722418275816547, 1e-15);
+        assert::close(super::total_variation(&q, &p), 0.0045722418275816547, 1e-15);
+
+        let p = Categorical::new(&[0.5, 0.5]);
+        let q = Categorical::new(&[0.0, 0.0, 0.0, 0.5, 0.5]);
+        assert_eq!(super::total_variation(&p, &q), 1.0);
+        let p = DiscreteUniform::new(-2, 2);
+        let q = DiscreteUniform::new(40, 60);
+        assert::close(super::total_variation(&p, &q), 1.0, 1e-15);
+        let p = Binomial::new(3, 0.5);
+        let q = Binomial::new(10, 0.9);
+        assert::close(super::total_variation(&p, &q), 0.9999908784, 1e-15);
+        let p = ZeroTruncatedPoisson::new(1.0);
+        let q = ZeroTruncatedPoisson::new(200.0);
+        assert::close(super::total_variation(&p, &q), 1.0, 1e-12);
+        assert::close(super::total_variation(&q, &p), 1.0, 1e-12);
+        let p = Poisson::new(1.0);
+        let q = Poisson::new(200.0);
+        assert::close(super::total_variation(&p, &q), 1.0, 1e-12);
+        assert::close(super::total_variation(&q, &p), 1.0, 1e-12);
+
+        let p = ZeroTruncatedPoisson::new(3.0);
+        let q = ZeroTruncatedPoisson::new(5.0);
+        assert::close(super::total_variation(&p, &q), 0.3688860489597254, 1e-15);
+
+        let p = Skellam::new(3.0, 40.0);
+        let q = Skellam::new(2.0, 1.0);
+        assert::close(super::total_variation(&p, &q), 0.9999991284249409, 1e-15);
+    }
+
+    #[test]
+    fn wasserstein_1() {
+        let p = Gaussian::new(1.0, 2.0);
+        assert_eq!(super::wasserstein_1(&p, &p), 0.0);
+        assert::close(
+            super::wasserstein_1(&p, &Gaussian::new(-0.5, 2.0)),
+            1.5,
+            1e-14,
+        );
+        assert::close(
+            super::wasserstein_1(&p, &Gaussian::new(-0.5, 1.5)),
+            1.5003821543170477,
+            1e-14,
+        );
+
+        let p = Exponential::new(1.0);
+        assert::close(super::wasserstein_1(&p, &Exponential::new(2.0)), 0.5, 1e-14);
+
+        let p = Beta::new(2.0, 3.0, 0.0, 1.0);
+        let q = Uniform::new(-1.0, 1.0);
+        assert::close(super::wasserstein_1(&p, &q), 0.4417152749342134, 1e-14);
+        assert::close(super::wasserstein_1(&q, &p), 0.4417152749342134, 1e-14);
+    }
+
+    #[test]
+    fn wasserstein_1_empirical() {
+        let x = vec![3.0, 1.0, 2.0];
+        assert_eq!(super::wasserstein_1_empirical(&x, &x), 0.0);
+        assert_eq!(super::wasserstein_1_empirical(&x, &[6.0, 4.0, 5.0]), 3.0);
+        assert_eq!(super::wasserstein_1_empirical(&[0.0, 1.0], &[0.5]), 0.5);
+        assert::close(
+            super::wasserstein_1_empirical(&[0.0, 0.0, 1.0, 4.0], &[-1.0, 2.0, 2.0]),
+            17.0 / 12.0,
+            1e-15,
+        );
+    }
+}
@@ -0,0 +1,400 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// An Erlang distribution.
+///
+/// The distribution is a special case of the gamma distribution with an
+/// integer shape.
+#[derive(Clone, Copy, Debug)]
+pub struct Erlang {
+    k: usize,
+    lambda: f64,
+    ln_norm: f64,
+}
+
+impl Erlang {
+    /// Create an Erlang distribution with shape parameter `k` and rate
+    /// parameter `lambda`.
+    ///
+    /// It should hold that `k > 0` and `lambda > 0`.
+    #[inline]
+    pub fn new(k: usize, lambda: f64) -> Self {
+        should!(k > 0 && lambda > 0.0);
+        let ln_factorial = (2..k).fold(0.0, |sum, i| sum + (i as f64).ln());
+        Erlang {
+            k,
+            lambda,
+            ln_norm: k as f64 * lambda.ln() - ln_factorial,
+        }
+    }
+
+    /// Return the shape parameter.
+    #[inline(always)]
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
+    /// Return the rate parameter.
+    #[inline(always)]
+    pub fn lambda(&self) -> f64 {
+        self.lambda
+    }
+}
+
+impl distribution::CharacteristicFunction for Erlang {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::complex;
+        complex::powf((1.0, -t / self.lambda), -(self.k as f64))
+    }
+}
+
+impl distribution::Continuous for Erlang {
+    fn density(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            0.0
+        } else if x == 0.0 {
+            if self.k == 1 {
+                self.lambda
+            } else {
+                0.0
+            }
+        } else {
+            ((self.k - 1) as f64 * x.ln() - self.lambda * x + self.ln_norm).exp()
+        }
+    }
+
+    fn log_density(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            f64::NEG_INFINITY
+        } else if x == 0.0 {
+            if self.k == 1 {
+                self.lambda.ln()
+            } else {
+                f64::NEG_INFINITY
+            }
+        } else {
+            (self.k - 1) as f64 * x.ln() - self.lambda * x + self.ln_norm
+        }
+    }
+}
+
+impl distribution::Distribution for Erlang {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        use special::Gamma;
+        if x <= 0.0 {
+            0.0
+        } else {
+            (self.lambda * x).inc_gamma(self.k as f64)
+        }
+    }
+}
+
+impl distribution::Entropy for Erlang {
+    fn entropy(&self) -> f64 {
+        use special::Gamma;
+        let k = self.k as f64;
+        (1.0 - k) * k.digamma() + Gamma::ln_gamma(k).0 - self.lambda.ln() + k
+    }
+}
+
+impl distribution::Inverse for Erlang {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        use distribution::gamma;
+        should!((0.0..=1.0).contains(&p));
+        gamma::inverse(p, self.k as f64) / self.lambda
+    }
+}
+
+impl distribution::Kurtosis for Erlang {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        6.0 / self.k as f64
+    }
+}
+
+impl distribution::Mean for Erlang {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.k as f64 / self.lambda
+    }
+}
+
+impl distribution::Median for Erlang {
+    #[inline]
+    fn median(&self) -> f64 {
+        use distribution::Inverse;
+        self.inverse(0.5)
+    }
+}
+
+impl distribution::Mgf for Erlang {
+    /// Compute the moment-generating function.
+    ///
+    /// The function is infinite if `t >= lambda`.
+    fn mgf(&self, t: f64) -> f64 {
+        if t >= self.lambda {
+            f64::INFINITY
+        } else {
+            (-(self.k as f64) * (-t / self.lambda).ln_1p()).exp()
+        }
+    }
+}
+
+impl distribution::Modes for Erlang {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![(self.k - 1) as f64 / self.lambda]
+    }
+}
+
+impl distribution::Moments for Erlang {
+    fn raw_moment(&self, n: usize) -> f64 {
+        (0..n).fold(1.0, |product, j| {
+            product * (self.k + j) as f64 / self.lambda
+        })
+    }
+}
+
+impl distribution::Sample for Erlang {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained as a sum of `k` exponential variates.
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        -(0..self.k).map(|_| source.read::<f64>().ln()).sum::<f64>() / self.lambda
+    }
+}
+
+impl distribution::Skewness for Erlang {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        2.0 / (self.k as f64).sqrt()
+    }
+}
+
+impl distribution::Support for Erlang {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Included, Unbounded};
+        (Included(0.0), Unbounded)
+    }
+}
+
+impl distribution::Variance for Erlang {
+    #[inline]
+    fn variance(&self) -> f64 {
+        self.k as f64 / (self.lambda * self.lambda)
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($k:expr, $lambda:expr) => (Erlang::new($k, $lambda));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(3, 2.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.4471996766110008, 0.8722859763849327, -0.24999999999999994];
+        let im = vec![-0.7120726866511525, 0.4177852270272607, 0.25];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(3, 2.0);
+        let x = vec![-1.0, 0.0, 0.25, 0.5, 1.0, 1.5, 2.0, 3.0, 4.0, 6.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            1.5163266492815836e-01,
+            3.6787944117144233e-01,
+            5.4134113294645081e-01,
+            4.4808361531077551e-01,
+            2.9305022221974686e-01,
+            8.9235078359988909e-02,
+            2.1469608185760759e-02,
+            8.8476657887926217e-04,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+
+        assert_eq!(new!(1, 2.0).density(0.0), 2.0);
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(3, 2.0);
+        let x = vec![-1.0, 0.0, 0.25, 0.5, 1.0, 1.5, 2.0, 3.0, 4.0, 6.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            1.4387677966970687e-02,
+            8.0301397071394193e-02,
+            3.2332358381693654e-01,
+            5.7680991887315647e-01,
+            7.6189669444645569e-01,
+            9.3803119558334103e-01,
+            9.8624603225599705e-01,
+            9.9947774194996708e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        use distribution::Gamma;
+        assert::close(new!(3, 2.0).entropy(), 1.1544313298030657e+00, 1e-14);
+        assert::close(
+            new!(5, 0.5).entropy(),
+            Gamma::new(5.0, 2.0).entropy(),
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn inverse() {
+        use core::f64::INFINITY;
+        let d = new!(3, 2.0);
+        let p = vec![
+            0.0, 0.01, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.99, 1.0,
+        ];
+        let x = vec![
+            0.0000000000000000e+00,
+            2.1802258253914658e-01,
+            5.5103266412466057e-01,
+            7.6752210132232179e-01,
+            9.5688789706353128e-01,
+            1.1425384520016904e+00,
+            1.3370301568617802e+00,
+            1.5526892986316749e+00,
+            1.8077838329329949e+00,
+            2.1395149300626670e+00,
+            2.6611601689171049e+00,
+            4.2029734574427318e+00,
+            INFINITY,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert_eq!(new!(3, 2.0).kurtosis(), 2.0);
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(3, 2.0);
+        let x = vec![-40.0, 0.05, 0.9, 40.0, 10000.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -4.7051701859880914,
+            -0.6244266701957621,
+            -71.23594673065223,
+            -19980.19302489493,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(3, 2.0).mean(), 1.5);
+    }
+
+    #[test]
+    fn median() {
+        assert::close(new!(3, 2.0).median(), 1.3370301568617802e+00, 1e-12);
+    }
+
+    #[test]
+    fn mgf() {
+        let d = new!(3, 2.0);
+        let t = vec![-0.7, -0.1, 0.2, 0.45];
+        let m = vec![
+            0.40644210740232695,
+            0.863837598531476,
+            1.3717421124828533,
+            2.148299822093921,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+        assert_eq!(d.mgf(2.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(1, 2.0).modes(), vec![0.0]);
+        assert_eq!(new!(3, 2.0).modes(), vec![1.0]);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(3, 2.0);
+        let mut source = source::default(42);
+
+        let cross_entropy = -(0..n)
+            .map(|_| d.density(d.sample(&mut source)).ln())
+            .sum::<f64>()
+            / n as f64;
+        assert!((cross_entropy - d.entropy()).abs() < 0.01);
+    }
+
+    #[test]
+    fn skewness() {
+        assert_eq!(new!(4, 2.0).skewness(), 1.0);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Included, Unbounded};
+        assert_eq!(new!(3, 2.0).support(), (Included(0.0), Unbounded));
+    }
+
+    #[test]
+    fn variance() {
+        assert_eq!(new!(3, 2.0).variance(), 0.75);
+    }
+}
@@ -0,0 +1,330 @@
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// An exponentially modified Gaussian distribution.
+///
+/// The distribution is the distribution of the sum of a Gaussian and an
+/// independent exponential variate.
+#[derive(Clone, Copy, Debug)]
+pub struct ExpModifiedGaussian {
+    mu: f64,
+    sigma: f64,
+    lambda: f64,
+}
+
+impl ExpModifiedGaussian {
+    /// Create an exponentially modified Gaussian distribution with mean `mu`
+    /// and standard deviation `sigma` of the Gaussian component and rate
+    /// `lambda` of the exponential component.
+    ///
+    /// It should hold that `sigma > 0` and `lambda > 0`.
+    #[inline]
+    pub fn new(mu: f64, sigma: f64, lambda: f64) -> Self {
+        should!(sigma > 0.0 && lambda > 0.0);
+        ExpModifiedGaussian { mu, sigma, lambda }
+    }
+
+    /// Return the mean of the Gaussian component.
+    #[inline(always)]
+    pub fn mu(&self) -> f64 {
+        self.mu
+    }
+
+    /// Return the standard deviation of the Gaussian component.
+    #[inline(always)]
+    pub fn sigma(&self) -> f64 {
+        self.sigma
+    }
+
+    /// Return the rate of the exponential component.
+    #[inline(always)]
+    pub fn lambda(&self) -> f64 {
+        self.lambda
+    }
+}
+
+impl distribution::CharacteristicFunction for ExpModifiedGaussian {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::complex;
+        let &ExpModifiedGaussian { mu, sigma, lambda } = self;
+        let gaussian = complex::exp((-0.5 * sigma * sigma * t * t, mu * t));
+        complex::div(gaussian, (1.0, -t / lambda))
+    }
+}
+
+impl distribution::Continuous for ExpModifiedGaussian {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        use core::f64::consts::FRAC_1_SQRT_2;
+        use special::Error;
+        let &ExpModifiedGaussian { mu, sigma, lambda } = self;
+        let lambda_sigma2 = lambda * sigma * sigma;
+        let exp = (0.5 * lambda * (2.0 * (mu - x) + lambda_sigma2)).exp();
+        0.5 * lambda * exp * ((mu + lambda_sigma2 - x) * FRAC_1_SQRT_2 / sigma).compl_error()
+    }
+
+    fn log_density(&self, x: f64) -> f64 {
+        use distribution::gaussian;
+        let &ExpModifiedGaussian { mu, sigma, lambda } = self;
+        let lambda_sigma2 = lambda * sigma * sigma;
+        lambda.ln()
+            + 0.5 * lambda * (2.0 * (mu - x) + lambda_sigma2)
+            + gaussian::ln_survival((mu + lambda_sigma2 - x) / sigma)
+    }
+}
+
+impl distribution::Distribution for ExpModifiedGaussian {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        use core::f64::consts::FRAC_1_SQRT_2;
+        use special::Error;
+        let &ExpModifiedGaussian { mu, sigma, lambda } = self;
+        let z = (x - mu) / sigma;
+        let lambda_sigma = lambda * sigma;
+        let exp = (lambda_sigma * (0.5 * lambda_sigma - z)).exp();
+        let p = 0.5 * (-z * FRAC_1_SQRT_2).compl_error();
+        let q = 0.5 * ((lambda_sigma - z) * FRAC_1_SQRT_2).compl_error();
+        (p - exp * q).clamp(0.0, 1.0)
+    }
+}
+
+impl distribution::Kurtosis for ExpModifiedGaussian {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        let tau2 = (self.lambda * self.lambda).recip();
+        6.0 * tau2 * tau2 / (self.sigma * self.sigma + tau2).powi(2)
+    }
+}
+
+impl distribution::Mean for ExpModifiedGaussian {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.mu + 1.0 / self.lambda
+    }
+}
+
+impl distribution::Mgf for ExpModifiedGaussian {
+    /// Compute the moment-generating function.
+    ///
+    /// The function is infinite if `t >= lambda`.
+    fn mgf(&self, t: f64) -> f64 {
+        let &ExpModifiedGaussian { mu, sigma, lambda } = self;
+        if t >= lambda {
+            f64::INFINITY
+        } else {
+            (mu * t + 0.5 * sigma * sigma * t * t).exp() * lambda / (lambda - t)
+        }
+    }
+}
+
+impl distribution::Moments for ExpModifiedGaussian {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The variate is the sum of a Gaussian and an exponential variate, whose
+    /// moments are combined by the binomial theorem.
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::Gaussian;
+        let gaussian = Gaussian::new(self.mu, self.sigma);
+        let (mut sum, mut binomial, mut exponential) = (0.0, 1.0, 1.0);
+        for k in 0..=n {
+            sum += binomial * exponential * gaussian.raw_moment(n - k);
+            binomial *= (n - k) as f64 / (k + 1) as f64;
+            exponential *= (k + 1) as f64 / self.lambda;
+        }
+        sum
+    }
+}
+
+impl distribution::Sample for ExpModifiedGaussian {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::gaussian;
+        let gaussian = self.mu + self.sigma * gaussian::sample(source);
+        gaussian - source.read::<f64>().ln() / self.lambda
+    }
+}
+
+impl distribution::Skewness for ExpModifiedGaussian {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        let tau = self.lambda.recip();
+        2.0 * tau * tau * tau / (self.sigma * self.sigma + tau * tau).powf(1.5)
+    }
+}
+
+impl distribution::Support for ExpModifiedGaussian {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Unbounded;
+        (Unbounded, Unbounded)
+    }
+}
+
+impl distribution::Variance for ExpModifiedGaussian {
+    #[inline]
+    fn variance(&self) -> f64 {
+        self.sigma * self.sigma + (self.lambda * self.lambda).recip()
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::approx_constant,
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($mu:expr, $sigma:expr, $lambda:expr) => (ExpModifiedGaussian::new($mu, $sigma, $lambda));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(1.0, 2.0, 0.5);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            -0.017378751884361415,
+            0.4778386587825408,
+            -7.998487816312718e-05,
+        ];
+        let im = vec![
+            -0.2174517954585791,
+            0.5335424207638443,
+            -1.4904208304573575e-05,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(1.0, 2.0, 0.5);
+        let x = vec![-5.0, -2.0, 0.0, 1.0, 2.0, 3.0, 5.0, 8.0, 12.0];
+        let p = vec![
+            5.2440374369840451e-04,
+            2.2941782723872168e-02,
+            9.0800400609671469e-02,
+            1.3078914593256169e-01,
+            1.5426876936299344e-01,
+            1.5163266492815836e-01,
+            9.3864693965157009e-02,
+            2.4738953667873986e-02,
+            3.3689620528720163e-03,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(1.0, 2.0, 0.5);
+        let x = vec![-5.0, -2.0, 0.0, 1.0, 2.0, 3.0, 5.0, 8.0, 12.0];
+        let p = vec![
+            3.0109054423328550e-04,
+            2.0923635821113732e-02,
+            1.2693673750664394e-01,
+            2.3842170813487662e-01,
+            3.8292492254802618e-01,
+            5.3807941621222621e-01,
+            7.8952048012150677e-01,
+            9.5028946358521649e-01,
+            9.9326205690469349e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(1.0, 2.0, 0.5).kurtosis(), 1.5, 1e-15);
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(1.0, 2.0, 0.5);
+        let x = vec![-40.0, 0.5, 400.0, 10000.0];
+        let p = vec![
+            -214.80729040339298,
+            -2.1907728577742636,
+            -199.69314718055995,
+            -4999.69314718056,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(1.0, 2.0, 0.5).mean(), 3.0);
+    }
+
+    #[test]
+    fn mgf() {
+        let d = new!(1.0, 2.0, 0.5);
+        let t = vec![-0.7, -0.1, 0.2, 0.45];
+        let m = vec![
+            0.5513040884739321,
+            0.7692636219888631,
+            2.2052163538957283,
+            23.513743805749026,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+        assert_eq!(d.mgf(0.5), f64::INFINITY);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(1.0, 2.0, 0.5);
+        let mut source = source::default(42);
+
+        let mean = (0..n).map(|_| d.sample(&mut source)).sum::<f64>() / n as f64;
+        assert!((mean - d.mean()).abs() < 0.05);
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(
+            new!(1.0, 2.0, 0.5).skewness(),
+            7.0710678118654757e-01,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::Unbounded;
+        assert_eq!(new!(1.0, 2.0, 0.5).support(), (Unbounded, Unbounded));
+    }
+
+    #[test]
+    fn variance() {
+        assert_eq!(new!(1.0, 2.0, 0.5).variance(), 8.0);
+    }
+}
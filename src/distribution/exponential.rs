@@ -1,4 +1,5 @@
 use alloc::{vec, vec::Vec};
+use core::ops::Bound;
 #[allow(unused_imports)]
 use special::Primitive;
 
@@ -28,6 +29,20 @@ impl Exponential {
     }
 }
 
+impl distribution::divergence::Bhattacharyya for Exponential {
+    fn bhattacharyya(&self, q: &Self) -> f64 {
+        let (l1, l2) = (self.lambda, q.lambda);
+        (0.5 * (l1 + l2) / (l1 * l2).sqrt()).ln()
+    }
+}
+
+impl distribution::CharacteristicFunction for Exponential {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::complex;
+        complex::div((1.0, 0.0), (1.0, -t / self.lambda))
+    }
+}
+
 impl distribution::Continuous for Exponential {
     #[inline]
     fn density(&self, x: f64) -> f64 {
@@ -37,6 +52,15 @@ impl distribution::Continuous for Exponential {
             self.lambda * (-self.lambda * x).exp()
         }
     }
+
+    #[inline]
+    fn log_density(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            f64::NEG_INFINITY
+        } else {
+            self.lambda.ln() - self.lambda * x
+        }
+    }
 }
 
 impl distribution::Distribution for Exponential {
@@ -59,6 +83,26 @@ impl distribution::Entropy for Exponential {
     }
 }
 
+impl distribution::Hazard for Exponential {
+    #[inline]
+    fn hazard(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            0.0
+        } else {
+            self.lambda
+        }
+    }
+
+    #[inline]
+    fn cumulative_hazard(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else {
+            self.lambda * x
+        }
+    }
+}
+
 impl distribution::Inverse for Exponential {
     #[inline]
     fn inverse(&self, p: f64) -> f64 {
@@ -89,6 +133,19 @@ impl distribution::Median for Exponential {
     }
 }
 
+impl distribution::Mgf for Exponential {
+    /// Compute the moment-generating function.
+    ///
+    /// The function is infinite if `t >= lambda`.
+    fn mgf(&self, t: f64) -> f64 {
+        if t >= self.lambda {
+            f64::INFINITY
+        } else {
+            self.lambda / (self.lambda - t)
+        }
+    }
+}
+
 impl distribution::Modes for Exponential {
     #[inline]
     fn modes(&self) -> Vec<f64> {
@@ -96,6 +153,12 @@ impl distribution::Modes for Exponential {
     }
 }
 
+impl distribution::Moments for Exponential {
+    fn raw_moment(&self, n: usize) -> f64 {
+        (1..=n).fold(1.0, |product, j| product * j as f64 / self.lambda)
+    }
+}
+
 impl distribution::Sample for Exponential {
     #[inline]
     fn sample<S>(&self, source: &mut S) -> f64
@@ -113,6 +176,24 @@ impl distribution::Skewness for Exponential {
     }
 }
 
+impl distribution::Support for Exponential {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Included, Unbounded};
+        (Included(0.0), Unbounded)
+    }
+}
+
+impl distribution::Survival for Exponential {
+    #[inline]
+    fn survival(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            1.0
+        } else {
+            (-self.lambda * x).exp()
+        }
+    }
+}
+
 impl distribution::Variance for Exponential {
     #[inline]
     fn variance(&self) -> f64 {
@@ -126,6 +207,12 @@ impl distribution::Variance for Exponential {
 }
 
 #[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
 mod tests {
     use alloc::{vec, vec::Vec};
     use assert;
@@ -135,6 +222,29 @@ mod tests {
         ($lambda:expr) => (Exponential::new($lambda));
     );
 
+    #[test]
+    fn bhattacharyya() {
+        let d = new!(2.0);
+        let q = vec![new!(2.0), new!(0.5), new!(10.0)];
+        assert::close(
+            &q.iter().map(|q| d.bhattacharyya(q)).collect::<Vec<_>>(),
+            &vec![0.0, 0.22314355131420976, 0.2938933324510595],
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn cf() {
+        let d = new!(2.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.8908685968819599, 0.9779951100244499, 0.5];
+        let im = vec![-0.3118040089086859, 0.1466992665036675, 0.5];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
     #[test]
     fn density() {
         let d = new!(2.0);
@@ -195,6 +305,22 @@ mod tests {
         assert_eq!(new!(E).entropy(), 0.0);
     }
 
+    #[test]
+    fn hazard() {
+        let d = new!(2.0);
+        let x = vec![-1.0, 0.0, 0.5, 300.0];
+        assert_eq!(
+            &x.iter().map(|&x| d.hazard(x)).collect::<Vec<_>>(),
+            &vec![0.0, 2.0, 2.0, 2.0],
+        );
+        assert_eq!(
+            &x.iter()
+                .map(|&x| d.cumulative_hazard(x))
+                .collect::<Vec<_>>(),
+            &vec![0.0, 0.0, 1.0, 600.0],
+        );
+    }
+
     #[test]
     fn inverse() {
         use core::f64::INFINITY;
@@ -231,6 +357,24 @@ mod tests {
         assert_eq!(new!(2.0).kurtosis(), 6.0);
     }
 
+    #[test]
+    fn log_density() {
+        let d = new!(2.0);
+        let x = vec![-40.0, 0.05, 0.9, 40.0, 10000.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            0.5931471805599453,
+            -1.1068528194400549,
+            -79.30685281944005,
+            -19999.30685281944,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-10,
+        );
+    }
+
     #[test]
     fn mean() {
         assert_eq!(new!(2.0).mean(), 0.5);
@@ -242,16 +386,61 @@ mod tests {
         assert_eq!(new!(LN_2).median(), 1.0);
     }
 
+    #[test]
+    fn mgf() {
+        let d = new!(2.0);
+        let t = vec![-0.7, -0.1, 0.2, 0.45];
+        let m = vec![
+            0.7407407407407407,
+            0.9523809523809523,
+            1.1111111111111112,
+            1.2903225806451613,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+        assert_eq!(d.mgf(2.0), f64::INFINITY);
+    }
+
     #[test]
     fn modes() {
         assert_eq!(new!(2.0).modes(), vec![0.0]);
     }
 
+    #[test]
+    fn moments() {
+        let d = new!(2.0);
+        let m = vec![3.75, 11.25, 39.375];
+        assert::close(
+            &(5..8).map(|n| d.raw_moment(n)).collect::<Vec<_>>(),
+            &m,
+            1e-14,
+        );
+    }
+
     #[test]
     fn skewness() {
         assert_eq!(new!(2.0).skewness(), 2.0);
     }
 
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Included, Unbounded};
+        assert_eq!(new!(2.0).support(), (Included(0.0), Unbounded));
+    }
+
+    #[test]
+    fn survival() {
+        let d = new!(2.0);
+        let x = vec![-1.0, 0.0, 0.5, 10.0, 300.0];
+        let p = vec![
+            1.0,
+            1.0,
+            (-1.0f64).exp(),
+            (-20.0f64).exp(),
+            (-600.0f64).exp(),
+        ];
+        assert_eq!(&x.iter().map(|&x| d.survival(x)).collect::<Vec<_>>(), &p);
+    }
+
     #[test]
     fn variance() {
         assert_eq!(new!(2.0).variance(), 0.25);
@@ -0,0 +1,467 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A Fisher–Snedecor distribution, also known as the F-distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct FisherSnedecor {
+    d1: f64,
+    d2: f64,
+    ln_beta: f64,
+}
+
+impl FisherSnedecor {
+    /// Create a Fisher–Snedecor distribution with `d1` and `d2` degrees of
+    /// freedom.
+    ///
+    /// It should hold that `d1 > 0` and `d2 > 0`.
+    #[inline]
+    pub fn new(d1: f64, d2: f64) -> Self {
+        use special::Beta;
+        should!(d1 > 0.0 && d2 > 0.0);
+        FisherSnedecor {
+            d1,
+            d2,
+            ln_beta: (0.5 * d1).ln_beta(0.5 * d2),
+        }
+    }
+
+    /// Return the degrees of freedom of the numerator.
+    #[inline(always)]
+    pub fn d1(&self) -> f64 {
+        self.d1
+    }
+
+    /// Return the degrees of freedom of the denominator.
+    #[inline(always)]
+    pub fn d2(&self) -> f64 {
+        self.d2
+    }
+}
+
+impl distribution::CharacteristicFunction for FisherSnedecor {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Continuous, Inverse};
+        let (lower, upper) = (self.inverse(0.25), self.inverse(0.75));
+        characteristic::continuous(
+            |x| self.density(x),
+            t,
+            0.0,
+            f64::INFINITY,
+            lower,
+            upper - lower,
+        )
+    }
+}
+
+impl distribution::Continuous for FisherSnedecor {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        self.log_density(x).exp()
+    }
+
+    fn log_density(&self, x: f64) -> f64 {
+        let &FisherSnedecor { d1, d2, ln_beta } = self;
+        if x < 0.0 {
+            f64::NEG_INFINITY
+        } else if x == 0.0 {
+            if d1 < 2.0 {
+                f64::INFINITY
+            } else if d1 == 2.0 {
+                0.0
+            } else {
+                f64::NEG_INFINITY
+            }
+        } else {
+            let d1_x = d1 * x;
+            0.5 * (d1 * d1_x.ln() + d2 * d2.ln() - (d1 + d2) * (d1_x + d2).ln()) - x.ln() - ln_beta
+        }
+    }
+}
+
+impl distribution::Distribution for FisherSnedecor {
+    type Value = f64;
+
+    fn distribution(&self, x: f64) -> f64 {
+        use special::Beta;
+        if x <= 0.0 {
+            0.0
+        } else {
+            let d1_x = self.d1 * x;
+            (d1_x / (d1_x + self.d2)).inc_beta(0.5 * self.d1, 0.5 * self.d2, self.ln_beta)
+        }
+    }
+}
+
+impl distribution::Entropy for FisherSnedecor {
+    fn entropy(&self) -> f64 {
+        use special::Gamma;
+        let (a, b) = (0.5 * self.d1, 0.5 * self.d2);
+        (self.d2 / self.d1).ln() + self.ln_beta + (1.0 - a) * a.digamma() - (1.0 + b) * b.digamma()
+            + (a + b) * (a + b).digamma()
+    }
+}
+
+impl distribution::Inverse for FisherSnedecor {
+    fn inverse(&self, p: f64) -> f64 {
+        use special::Beta;
+        should!((0.0..=1.0).contains(&p));
+        if p >= 1.0 {
+            return f64::INFINITY;
+        }
+        let z = p.inv_inc_beta(0.5 * self.d1, 0.5 * self.d2, self.ln_beta);
+        self.d2 * z / (self.d1 * (1.0 - z))
+    }
+}
+
+impl distribution::Kurtosis for FisherSnedecor {
+    /// Compute the excess kurtosis.
+    ///
+    /// The excess kurtosis is undefined, which is signified by `NaN`, if
+    /// `d2 <= 8`.
+    fn kurtosis(&self) -> f64 {
+        let &FisherSnedecor { d1, d2, .. } = self;
+        if d2 <= 8.0 {
+            return f64::NAN;
+        }
+        12.0 * (d1 * (5.0 * d2 - 22.0) * (d1 + d2 - 2.0) + (d2 - 4.0) * (d2 - 2.0).powi(2))
+            / (d1 * (d2 - 6.0) * (d2 - 8.0) * (d1 + d2 - 2.0))
+    }
+}
+
+impl distribution::Mean for FisherSnedecor {
+    /// Compute the expected value.
+    ///
+    /// The expected value is infinite if `d2 <= 2`.
+    #[inline]
+    fn mean(&self) -> f64 {
+        if self.d2 > 2.0 {
+            self.d2 / (self.d2 - 2.0)
+        } else {
+            f64::INFINITY
+        }
+    }
+}
+
+impl distribution::Median for FisherSnedecor {
+    #[inline]
+    fn median(&self) -> f64 {
+        use distribution::Inverse;
+        self.inverse(0.5)
+    }
+}
+
+impl distribution::Modes for FisherSnedecor {
+    fn modes(&self) -> Vec<f64> {
+        let &FisherSnedecor { d1, d2, .. } = self;
+        if d1 > 2.0 {
+            vec![(d1 - 2.0) / d1 * d2 / (d2 + 2.0)]
+        } else {
+            vec![0.0]
+        }
+    }
+}
+
+impl distribution::Moments for FisherSnedecor {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is infinite if `2 n >= d2`.
+    fn raw_moment(&self, n: usize) -> f64 {
+        let &FisherSnedecor { d1, d2, .. } = self;
+        if 2.0 * n as f64 >= d2 {
+            return f64::INFINITY;
+        }
+        (0..n).fold(1.0, |product, j| {
+            let j = j as f64;
+            product * d2 * (0.5 * d1 + j) / (d1 * (0.5 * d2 - j - 1.0))
+        })
+    }
+}
+
+impl distribution::Sample for FisherSnedecor {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::gamma;
+        let x = gamma::sample(0.5 * self.d1, source);
+        let y = gamma::sample(0.5 * self.d2, source);
+        (x * self.d2) / (y * self.d1)
+    }
+}
+
+impl distribution::Skewness for FisherSnedecor {
+    /// Compute the skewness.
+    ///
+    /// The skewness is undefined, which is signified by `NaN`, if `d2 <= 6`.
+    fn skewness(&self) -> f64 {
+        let &FisherSnedecor { d1, d2, .. } = self;
+        if d2 <= 6.0 {
+            return f64::NAN;
+        }
+        (2.0 * d1 + d2 - 2.0) * (8.0 * (d2 - 4.0)).sqrt()
+            / ((d2 - 6.0) * (d1 * (d1 + d2 - 2.0)).sqrt())
+    }
+}
+
+impl distribution::Support for FisherSnedecor {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Included, Unbounded};
+        (Included(0.0), Unbounded)
+    }
+}
+
+impl distribution::TryMean for FisherSnedecor {
+    /// Compute the expected value.
+    ///
+    /// The expected value does not exist if `d2 <= 2`.
+    #[inline]
+    fn try_mean(&self) -> Option<f64> {
+        use distribution::Mean;
+        if self.d2 > 2.0 {
+            Some(self.mean())
+        } else {
+            None
+        }
+    }
+}
+
+impl distribution::TryVariance for FisherSnedecor {
+    /// Compute the variance.
+    ///
+    /// The variance does not exist if `d2 <= 4`.
+    #[inline]
+    fn try_variance(&self) -> Option<f64> {
+        use distribution::Variance;
+        if self.d2 > 4.0 {
+            Some(self.variance())
+        } else {
+            None
+        }
+    }
+}
+
+impl distribution::Variance for FisherSnedecor {
+    /// Compute the variance.
+    ///
+    /// The variance is infinite if `2 < d2 <= 4` and undefined, which is
+    /// signified by `NaN`, if `d2 <= 2`.
+    fn variance(&self) -> f64 {
+        let &FisherSnedecor { d1, d2, .. } = self;
+        if d2 > 4.0 {
+            2.0 * d2 * d2 * (d1 + d2 - 2.0) / (d1 * (d2 - 2.0).powi(2) * (d2 - 4.0))
+        } else if d2 > 2.0 {
+            f64::INFINITY
+        } else {
+            f64::NAN
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($d1:expr, $d2:expr) => (FisherSnedecor::new($d1, $d2));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(4.0, 6.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.5487108920989395, 0.8444312954600338, 0.05724477021550996];
+        let im = vec![-0.5082704087117839, 0.3433979449181409, 0.39775057409515163];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(4.0, 6.0);
+        let x = vec![-1.0, 0.0, 0.25, 0.5, 1.0, 1.5, 2.0, 3.0, 4.0, 6.0, 10.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            6.1688582138394721e-01,
+            6.3281250000000000e-01,
+            4.1471999999999998e-01,
+            2.5000000000000000e-01,
+            1.5422145534598680e-01,
+            6.5843621399176960e-02,
+            3.2188561387386606e-02,
+            1.0240000000000001e-02,
+            2.0135657779580735e-03,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+
+        assert_eq!(new!(1.0, 6.0).density(0.0), f64::INFINITY);
+        assert_eq!(new!(2.0, 6.0).density(0.0), 1.0);
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(4.0, 6.0);
+        let x = vec![-1.0, 0.0, 0.25, 0.5, 1.0, 1.5, 2.0, 3.0, 4.0, 6.0, 10.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            1.0037484381507705e-01,
+            2.6171875000000000e-01,
+            5.2480000000000004e-01,
+            6.8750000000000000e-01,
+            7.8633902540608080e-01,
+            8.8888888888888884e-01,
+            9.3545522846800078e-01,
+            9.7280000000000000e-01,
+            9.9199188110391257e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(4.0, 6.0).entropy(), 1.3372251249868308e+00, 1e-14);
+        assert::close(new!(5.0, 12.0).entropy(), 1.0815894027424704e+00, 1e-14);
+    }
+
+    #[test]
+    fn inverse() {
+        use core::f64::INFINITY;
+        let d = new!(4.0, 6.0);
+        let p = vec![
+            0.0, 0.01, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.99, 1.0,
+        ];
+        let x = vec![
+            0.0000000000000000e+00,
+            6.5759774228958401e-02,
+            2.4939214948905103e-01,
+            4.0431968733897777e-01,
+            5.6152679515881665e-01,
+            7.3602430044743161e-01,
+            9.4191326548622289e-01,
+            1.2002700371318356e+00,
+            1.5512907007699350e+00,
+            2.0924148992323617e+00,
+            3.1807628650583206e+00,
+            9.1483010302278505e+00,
+            INFINITY,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert!(new!(4.0, 6.0).kurtosis().is_nan());
+        assert::close(new!(5.0, 12.0).kurtosis(), 2.4333333333333332e+01, 1e-12);
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(4.0, 6.0);
+        let x = vec![-40.0, 0.05, 4.0, 1000000.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -1.485704954097275,
+            -3.436144125959744,
+            -51.56074775773897,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(4.0, 2.0).mean(), f64::INFINITY);
+        assert_eq!(new!(4.0, 6.0).mean(), 1.5);
+    }
+
+    #[test]
+    fn median() {
+        assert::close(new!(4.0, 6.0).median(), 9.4191326548622289e-01, 1e-12);
+        assert::close(new!(5.0, 12.0).median(), 9.2124150981748532e-01, 1e-12);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(2.0, 6.0).modes(), vec![0.0]);
+        assert::close(new!(4.0, 6.0).modes(), vec![0.375], 1e-15);
+    }
+
+    #[test]
+    fn sample() {
+        for x in Independent(&new!(4.0, 6.0), &mut source::default(42)).take(100) {
+            assert!(x >= 0.0);
+        }
+    }
+
+    #[test]
+    fn skewness() {
+        assert!(new!(4.0, 6.0).skewness().is_nan());
+        assert::close(new!(5.0, 12.0).skewness(), 3.0792014356780042e+00, 1e-14);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Included, Unbounded};
+        assert_eq!(new!(4.0, 6.0).support(), (Included(0.0), Unbounded));
+    }
+
+    #[test]
+    fn try_mean() {
+        assert_eq!(new!(3.0, 2.0).try_mean(), None);
+        let d = new!(3.0, 6.0);
+        assert_eq!(d.try_mean(), Some(d.mean()));
+    }
+
+    #[test]
+    fn try_variance() {
+        assert_eq!(new!(3.0, 4.0).try_variance(), None);
+        let d = new!(3.0, 6.0);
+        assert_eq!(d.try_variance(), Some(d.variance()));
+    }
+
+    #[test]
+    fn variance() {
+        assert!(new!(4.0, 2.0).variance().is_nan());
+        assert_eq!(new!(4.0, 4.0).variance(), f64::INFINITY);
+        assert::close(new!(4.0, 6.0).variance(), 4.5, 1e-14);
+        assert::close(new!(5.0, 12.0).variance(), 1.08, 1e-14);
+    }
+}
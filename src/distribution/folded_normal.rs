@@ -0,0 +1,359 @@
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A folded normal distribution.
+///
+/// The distribution is that of the absolute value of a Gaussian random
+/// variable.
+#[derive(Clone, Copy, Debug)]
+pub struct FoldedNormal {
+    mu: f64,
+    sigma: f64,
+}
+
+impl FoldedNormal {
+    /// Create a folded normal distribution with location `mu` and scale
+    /// `sigma`, which are the mean and standard deviation of the underlying
+    /// Gaussian distribution.
+    ///
+    /// It should hold that `sigma > 0`.
+    #[inline]
+    pub fn new(mu: f64, sigma: f64) -> Self {
+        should!(sigma > 0.0);
+        FoldedNormal { mu, sigma }
+    }
+
+    /// Return the location parameter.
+    #[inline(always)]
+    pub fn mu(&self) -> f64 {
+        self.mu
+    }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn sigma(&self) -> f64 {
+        self.sigma
+    }
+
+    fn moment(&self, k: usize) -> f64 {
+        use core::f64::consts::{FRAC_1_SQRT_2, FRAC_2_SQRT_PI};
+        use special::Error;
+        let &FoldedNormal { mu, sigma } = self;
+        let (mu2, sigma2) = (mu * mu, sigma * sigma);
+        let a = || sigma * FRAC_2_SQRT_PI * FRAC_1_SQRT_2 * (-0.5 * mu2 / sigma2).exp();
+        let e = || (mu * FRAC_1_SQRT_2 / sigma).error();
+        match k {
+            1 => a() + mu * e(),
+            2 => mu2 + sigma2,
+            3 => (mu2 + 2.0 * sigma2) * a() + mu * (mu2 + 3.0 * sigma2) * e(),
+            4 => mu2 * mu2 + 6.0 * mu2 * sigma2 + 3.0 * sigma2 * sigma2,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl distribution::CharacteristicFunction for FoldedNormal {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Continuous, Mean, Variance};
+        characteristic::continuous(
+            |x| self.density(x),
+            t,
+            0.0,
+            f64::INFINITY,
+            self.mean(),
+            self.deviation(),
+        )
+    }
+}
+
+impl distribution::Continuous for FoldedNormal {
+    fn density(&self, x: f64) -> f64 {
+        use core::f64::consts::PI;
+        if x < 0.0 {
+            0.0
+        } else {
+            let &FoldedNormal { mu, sigma } = self;
+            let (z1, z2) = ((x - mu) / sigma, (x + mu) / sigma);
+            ((-0.5 * z1 * z1).exp() + (-0.5 * z2 * z2).exp()) / ((2.0 * PI).sqrt() * sigma)
+        }
+    }
+
+    fn log_density(&self, x: f64) -> f64 {
+        use core::f64::consts::PI;
+        if x < 0.0 {
+            f64::NEG_INFINITY
+        } else {
+            let &FoldedNormal { mu, sigma } = self;
+            let (z1, z2) = ((x - mu) / sigma, (x + mu) / sigma);
+            let (a, b) = (0.5 * z1 * z1, 0.5 * z2 * z2);
+            -a.min(b) + (-(a - b).abs()).exp().ln_1p() - ((2.0 * PI).sqrt() * sigma).ln()
+        }
+    }
+}
+
+impl distribution::Distribution for FoldedNormal {
+    type Value = f64;
+
+    fn distribution(&self, x: f64) -> f64 {
+        use core::f64::consts::FRAC_1_SQRT_2;
+        use special::Error;
+        if x <= 0.0 {
+            0.0
+        } else {
+            let &FoldedNormal { mu, sigma } = self;
+            let scale = FRAC_1_SQRT_2 / sigma;
+            0.5 * (((x + mu) * scale).error() + ((x - mu) * scale).error())
+        }
+    }
+}
+
+impl distribution::Inverse for FoldedNormal {
+    fn inverse(&self, p: f64) -> f64 {
+        distribution::numeric::inverse(self, p)
+    }
+}
+
+impl distribution::Kurtosis for FoldedNormal {
+    fn kurtosis(&self) -> f64 {
+        let (m1, m2, m3, m4) = (
+            self.moment(1),
+            self.moment(2),
+            self.moment(3),
+            self.moment(4),
+        );
+        let m1_2 = m1 * m1;
+        (m4 - 4.0 * m1 * m3 + 6.0 * m1_2 * m2 - 3.0 * m1_2 * m1_2) / (m2 - m1_2).powi(2) - 3.0
+    }
+}
+
+impl distribution::Mean for FoldedNormal {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.moment(1)
+    }
+}
+
+impl distribution::Moments for FoldedNormal {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moments of order higher than four are computed by numerical
+    /// integration.
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::{moments, Continuous, Mean, Variance};
+        match n {
+            0 => 1.0,
+            1..=4 => self.moment(n),
+            _ => moments::continuous(
+                |x| self.density(x),
+                n,
+                0.0,
+                f64::INFINITY,
+                self.mean(),
+                self.deviation(),
+            ),
+        }
+    }
+}
+
+impl distribution::Sample for FoldedNormal {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::gaussian;
+        (self.mu + self.sigma * gaussian::sample(source)).abs()
+    }
+}
+
+impl distribution::Skewness for FoldedNormal {
+    fn skewness(&self) -> f64 {
+        let (m1, m2, m3) = (self.moment(1), self.moment(2), self.moment(3));
+        (m3 - 3.0 * m1 * m2 + 2.0 * m1 * m1 * m1) / (m2 - m1 * m1).powf(1.5)
+    }
+}
+
+impl distribution::Support for FoldedNormal {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Included, Unbounded};
+        (Included(0.0), Unbounded)
+    }
+}
+
+impl distribution::Variance for FoldedNormal {
+    #[inline]
+    fn variance(&self) -> f64 {
+        let m1 = self.moment(1);
+        self.moment(2) - m1 * m1
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($mu:expr, $sigma:expr) => (FoldedNormal::new($mu, $sigma));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(1.0, 2.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            0.28705376175764935,
+            0.7979641112408474,
+            -0.00013960171138143048,
+        ];
+        let im = vec![-0.596229056967051, 0.46440456449691914, 0.18560917549615236];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(1.0, 2.0);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 1.5, 2.0, 3.0, 4.0, 6.0, 10.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            3.5206532676429947e-01,
+            3.4390277447882678e-01,
+            3.2045650246028801e-01,
+            2.8465860109593555e-01,
+            2.4079146121509559e-01,
+            1.4798084551616569e-01,
+            7.3522948079730130e-02,
+            9.2004915943071485e-03,
+            8.0457193536654529e-06,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(1.0, 2.0);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 1.5, 2.0, 3.0, 4.0, 6.0, 10.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            1.7466632194020806e-01,
+            3.4134474606854293e-01,
+            4.9305655201606846e-01,
+            6.2465526000515503e-01,
+            8.1859461412036372e-01,
+            9.2698313340536576e-01,
+            9.9355770559518830e-01,
+            9.9999658333731278e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn inverse() {
+        use core::f64::INFINITY;
+
+        let d = new!(1.0, 2.0);
+        let p = vec![0.0, 0.01, 0.1, 0.25, 0.5, 0.75, 0.9, 0.99, 1.0];
+        let x = vec![
+            0.0,
+            0.02840453566808914,
+            0.284758244462161,
+            0.7216836611316717,
+            1.524477976323128,
+            2.5880231883309945,
+            3.6775023781187697,
+            5.684459249637256,
+            INFINITY,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(1.0, 2.0).kurtosis(), 7.0564481912248378e-01, 1e-13);
+        assert::close(new!(3.0, 1.0).kurtosis(), -5.9389974422997684e-02, 1e-12);
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(1.0, 2.0);
+        let x = vec![-40.0, 0.05, 0.9, 40.0, 400.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -1.044172910239093,
+            -1.1200867677671629,
+            -191.7370857117035,
+            -19901.737085713765,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        use distribution::HalfNormal;
+        assert::close(new!(1.0, 2.0).mean(), 1.7911862296052241e+00, 1e-15);
+        assert::close(new!(3.0, 1.0).mean(), 3.0007643086340954e+00, 1e-15);
+        assert::close(new!(0.0, 2.0).mean(), HalfNormal::new(2.0).mean(), 1e-15);
+    }
+
+    #[test]
+    fn sample() {
+        for x in Independent(&new!(-1.0, 2.0), &mut source::default(42)).take(100) {
+            assert!(x >= 0.0);
+        }
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(1.0, 2.0).skewness(), 9.4992335290632302e-01, 1e-13);
+        assert::close(new!(3.0, 1.0).skewness(), 1.8791057545847117e-02, 1e-12);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Included, Unbounded};
+        assert_eq!(new!(1.0, 2.0).support(), (Included(0.0), Unbounded));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(1.0, 2.0).variance(), 1.7916518908726213e+00, 1e-14);
+        assert::close(new!(3.0, 1.0).variance(), 9.9541356402773917e-01, 1e-14);
+    }
+}
@@ -0,0 +1,494 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A Fréchet distribution, also known as the type-II extreme value
+/// distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct Frechet {
+    alpha: f64,
+    s: f64,
+    m: f64,
+}
+
+impl Frechet {
+    /// Create a Fréchet distribution with shape `alpha`, scale `s`, and
+    /// location `m`.
+    ///
+    /// It should hold that `alpha > 0` and `s > 0`.
+    #[inline]
+    pub fn new(alpha: f64, s: f64, m: f64) -> Self {
+        should!(alpha > 0.0 && s > 0.0);
+        Frechet { alpha, s, m }
+    }
+
+    /// Return the shape parameter.
+    #[inline(always)]
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn s(&self) -> f64 {
+        self.s
+    }
+
+    /// Return the location parameter, which is also the left endpoint of the
+    /// support.
+    #[inline(always)]
+    pub fn m(&self) -> f64 {
+        self.m
+    }
+
+    #[inline]
+    fn moment(&self, i: f64) -> f64 {
+        use special::Gamma;
+        Gamma::gamma(1.0 - i / self.alpha)
+    }
+}
+
+impl distribution::CharacteristicFunction for Frechet {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Continuous, Inverse};
+        let (lower, upper) = (self.inverse(0.25), self.inverse(0.75));
+        characteristic::continuous(
+            |x| self.density(x),
+            t,
+            self.m,
+            f64::INFINITY,
+            lower,
+            upper - lower,
+        )
+    }
+}
+
+impl distribution::Continuous for Frechet {
+    fn density(&self, x: f64) -> f64 {
+        let &Frechet { alpha, s, m } = self;
+        if x <= m {
+            0.0
+        } else {
+            let z = (x - m) / s;
+            let z_alpha = z.powf(-alpha);
+            alpha / s * z_alpha / z * (-z_alpha).exp()
+        }
+    }
+
+    fn log_density(&self, x: f64) -> f64 {
+        let &Frechet { alpha, s, m } = self;
+        if x <= m {
+            f64::NEG_INFINITY
+        } else {
+            let z = (x - m) / s;
+            (alpha / s).ln() - (alpha + 1.0) * z.ln() - z.powf(-alpha)
+        }
+    }
+}
+
+impl distribution::Distribution for Frechet {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        if x <= self.m {
+            0.0
+        } else {
+            (-((x - self.m) / self.s).powf(-self.alpha)).exp()
+        }
+    }
+}
+
+impl distribution::Entropy for Frechet {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        const EULER_MASCHERONI: f64 = 0.5772156649015329;
+        1.0 + EULER_MASCHERONI / self.alpha + EULER_MASCHERONI + (self.s / self.alpha).ln()
+    }
+}
+
+impl distribution::Inverse for Frechet {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        should!((0.0..=1.0).contains(&p));
+        self.m + self.s * (-p.ln()).powf(-1.0 / self.alpha)
+    }
+}
+
+impl distribution::Kurtosis for Frechet {
+    /// Compute the excess kurtosis.
+    ///
+    /// The excess kurtosis is infinite if `2 < alpha <= 4` and undefined,
+    /// which is signified by `NaN`, if `alpha <= 2`.
+    fn kurtosis(&self) -> f64 {
+        if self.alpha <= 2.0 {
+            return f64::NAN;
+        }
+        if self.alpha <= 4.0 {
+            return f64::INFINITY;
+        }
+        let (g1, g2, g3, g4) = (
+            self.moment(1.0),
+            self.moment(2.0),
+            self.moment(3.0),
+            self.moment(4.0),
+        );
+        let g1_2 = g1 * g1;
+        (-6.0 * g1_2 * g1_2 + 12.0 * g1_2 * g2 - 3.0 * g2 * g2 - 4.0 * g1 * g3 + g4)
+            / (g2 - g1_2).powi(2)
+    }
+}
+
+impl distribution::Mean for Frechet {
+    /// Compute the expected value.
+    ///
+    /// The expected value is infinite if `alpha <= 1`.
+    #[inline]
+    fn mean(&self) -> f64 {
+        if self.alpha <= 1.0 {
+            f64::INFINITY
+        } else {
+            self.m + self.s * self.moment(1.0)
+        }
+    }
+}
+
+impl distribution::Median for Frechet {
+    #[inline]
+    fn median(&self) -> f64 {
+        use core::f64::consts::LN_2;
+        self.m + self.s * LN_2.powf(-1.0 / self.alpha)
+    }
+}
+
+impl distribution::Modes for Frechet {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        let alpha = self.alpha;
+        vec![self.m + self.s * (alpha / (1.0 + alpha)).powf(1.0 / alpha)]
+    }
+}
+
+impl distribution::Moments for Frechet {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is infinite if `n >= alpha`.
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::moments;
+        if n as f64 >= self.alpha {
+            return f64::INFINITY;
+        }
+        moments::shift(|k| self.moment(k as f64), self.m, self.s, n)
+    }
+}
+
+impl distribution::Sample for Frechet {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::Inverse;
+        self.inverse(source.read::<f64>())
+    }
+}
+
+impl distribution::Skewness for Frechet {
+    /// Compute the skewness.
+    ///
+    /// The skewness is infinite if `2 < alpha <= 3` and undefined, which is
+    /// signified by `NaN`, if `alpha <= 2`.
+    fn skewness(&self) -> f64 {
+        if self.alpha <= 2.0 {
+            return f64::NAN;
+        }
+        if self.alpha <= 3.0 {
+            return f64::INFINITY;
+        }
+        let (g1, g2, g3) = (self.moment(1.0), self.moment(2.0), self.moment(3.0));
+        (g3 - 3.0 * g2 * g1 + 2.0 * g1 * g1 * g1) / (g2 - g1 * g1).powf(1.5)
+    }
+}
+
+impl distribution::Support for Frechet {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Excluded, Unbounded};
+        (Excluded(self.m), Unbounded)
+    }
+}
+
+impl distribution::TryMean for Frechet {
+    /// Compute the expected value.
+    ///
+    /// The expected value does not exist if `alpha <= 1`.
+    #[inline]
+    fn try_mean(&self) -> Option<f64> {
+        use distribution::Mean;
+        if self.alpha > 1.0 {
+            Some(self.mean())
+        } else {
+            None
+        }
+    }
+}
+
+impl distribution::TryVariance for Frechet {
+    /// Compute the variance.
+    ///
+    /// The variance does not exist if `alpha <= 2`.
+    #[inline]
+    fn try_variance(&self) -> Option<f64> {
+        use distribution::Variance;
+        if self.alpha > 2.0 {
+            Some(self.variance())
+        } else {
+            None
+        }
+    }
+}
+
+impl distribution::Variance for Frechet {
+    /// Compute the variance.
+    ///
+    /// The variance is infinite if `alpha <= 2`.
+    #[inline]
+    fn variance(&self) -> f64 {
+        if self.alpha <= 2.0 {
+            f64::INFINITY
+        } else {
+            let g1 = self.moment(1.0);
+            self.s * self.s * (self.moment(2.0) - g1 * g1)
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($alpha:expr, $s:expr, $m:expr) => (Frechet::new($alpha, $s, $m));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(5.0, 2.0, 1.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![-0.5953871640649621, 0.5335824394773917, 0.6014290400642963];
+        let im = vec![
+            -0.6805331563293165,
+            0.8198136244692352,
+            -0.005332688164234155,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(5.0, 2.0, 1.0);
+        let x = vec![0.0, 1.0, 2.0, 2.5, 3.0, 3.5, 4.0, 5.0, 7.0, 10.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            2.0262664878550681e-12,
+            2.0771078928529962e-01,
+            9.1969860292860584e-01,
+            4.7224820384276683e-01,
+            1.9239838022533964e-01,
+            3.7860673221732193e-02,
+            3.4152717064578502e-03,
+            3.0090511616643034e-04,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(5.0, 2.0, 1.0);
+        let x = vec![0.0, 1.0, 2.0, 2.5, 3.0, 3.5, 4.0, 5.0, 7.0, 10.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            1.2664165549094176e-14,
+            1.4787223182517913e-02,
+            3.6787944117144233e-01,
+            7.2059357275812808e-01,
+            8.7661511990170371e-01,
+            9.6923323447634413e-01,
+            9.9589322960310911e-01,
+            9.9945822400377438e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(5.0, 2.0, 1.0).entropy(), 7.7636806600768438e-01, 1e-15);
+    }
+
+    #[test]
+    fn inverse() {
+        use core::f64::INFINITY;
+        let d = new!(5.0, 2.0, 1.0);
+        let p = vec![
+            0.0, 0.01, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.99, 1.0,
+        ];
+        let x = vec![
+            1.0000000000000000e+00,
+            2.4736042275868360e+00,
+            2.6927267521456750e+00,
+            2.8184239811051839e+00,
+            2.9271106888164695e+00,
+            3.0352761194388700e+00,
+            3.1521121702780102e+00,
+            3.2875756278549266e+00,
+            3.4579637596327713e+00,
+            3.6996852115859755e+00,
+            4.1368548130050673e+00,
+            6.0187305634343122e+00,
+            INFINITY,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert!(new!(2.0, 2.0, 1.0).kurtosis().is_nan());
+        assert_eq!(new!(3.0, 2.0, 1.0).kurtosis(), f64::INFINITY);
+        assert::close(
+            new!(5.0, 2.0, 1.0).kurtosis(),
+            4.5091512125815761e+01,
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(5.0, 2.0, 1.0);
+        let x = vec![-40.0, 1.5, 4.0, 400.0, 1000000.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -1014.7659431014065,
+            -1.6481871595731852,
+            -30.85859468610852,
+            -77.8178835325488,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-11,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(1.0, 2.0, 1.0).mean(), f64::INFINITY);
+        assert::close(new!(5.0, 2.0, 1.0).mean(), 3.3284594274506070e+00, 1e-14);
+    }
+
+    #[test]
+    fn median() {
+        assert::close(new!(5.0, 2.0, 1.0).median(), 3.1521121702780102e+00, 1e-15);
+    }
+
+    #[test]
+    fn modes() {
+        assert::close(
+            new!(5.0, 2.0, 1.0).modes(),
+            vec![2.9283850080052543e+00],
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn moments() {
+        let d = new!(9.0, 2.0, 1.0);
+        let m = vec![359.54295316330209, 1240.2605385305794, 4456.6464680971519];
+        assert::close(
+            &(5..8).map(|n| d.raw_moment(n)).collect::<Vec<_>>(),
+            &m,
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn sample() {
+        for x in Independent(&new!(5.0, 2.0, 1.0), &mut source::default(42)).take(100) {
+            assert!(x >= 1.0);
+        }
+    }
+
+    #[test]
+    fn skewness() {
+        assert!(new!(2.0, 2.0, 1.0).skewness().is_nan());
+        assert_eq!(new!(3.0, 2.0, 1.0).skewness(), f64::INFINITY);
+        assert::close(
+            new!(5.0, 2.0, 1.0).skewness(),
+            3.5350716046213946e+00,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Excluded, Unbounded};
+        assert_eq!(new!(5.0, 2.0, 1.0).support(), (Excluded(1.0), Unbounded));
+    }
+
+    #[test]
+    fn try_mean() {
+        assert_eq!(new!(1.0, 2.0, 1.0).try_mean(), None);
+        let d = new!(3.0, 2.0, 1.0);
+        assert_eq!(d.try_mean(), Some(d.mean()));
+    }
+
+    #[test]
+    fn try_variance() {
+        assert_eq!(new!(2.0, 2.0, 1.0).try_variance(), None);
+        let d = new!(3.0, 2.0, 1.0);
+        assert_eq!(d.try_variance(), Some(d.variance()));
+    }
+
+    #[test]
+    fn variance() {
+        assert_eq!(new!(2.0, 2.0, 1.0).variance(), f64::INFINITY);
+        assert::close(
+            new!(5.0, 2.0, 1.0).variance(),
+            5.3504568996766100e-01,
+            1e-13,
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use alloc::{vec, vec::Vec};
+use core::ops::Bound;
 #[allow(unused_imports)]
 use special::Primitive;
 
@@ -25,7 +26,7 @@ impl Gamma {
         Gamma {
             k,
             theta,
-            norm: k.gamma() * theta.powf(k),
+            norm: SpecialGamma::gamma(k) * theta.powf(k),
         }
     }
 
@@ -42,6 +43,23 @@ impl Gamma {
     }
 }
 
+impl distribution::divergence::Bhattacharyya for Gamma {
+    fn bhattacharyya(&self, q: &Self) -> f64 {
+        use special::Gamma;
+        let normalizer = |k: f64, theta: f64| Gamma::ln_gamma(k).0 + k * theta.ln();
+        let k = 0.5 * (self.k + q.k);
+        let theta = 2.0 / (1.0 / self.theta + 1.0 / q.theta);
+        0.5 * (normalizer(self.k, self.theta) + normalizer(q.k, q.theta)) - normalizer(k, theta)
+    }
+}
+
+impl distribution::CharacteristicFunction for Gamma {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::complex;
+        complex::powf((1.0, -self.theta * t), -self.k)
+    }
+}
+
 impl distribution::Continuous for Gamma {
     fn density(&self, x: f64) -> f64 {
         if x <= 0.0 {
@@ -50,6 +68,14 @@ impl distribution::Continuous for Gamma {
             x.powf(self.k - 1.0) * (-x / self.theta).exp() / self.norm
         }
     }
+
+    fn log_density(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            f64::NEG_INFINITY
+        } else {
+            (self.k - 1.0) * x.ln() - x / self.theta - self.norm.ln()
+        }
+    }
 }
 
 impl distribution::Distribution for Gamma {
@@ -68,7 +94,48 @@ impl distribution::Distribution for Gamma {
 impl distribution::Entropy for Gamma {
     fn entropy(&self) -> f64 {
         use special::Gamma;
-        self.k + self.theta.ln() + self.k.ln_gamma().0 + (1.0 - self.k) * self.k.digamma()
+        self.k + self.theta.ln() + Gamma::ln_gamma(self.k).0 + (1.0 - self.k) * self.k.digamma()
+    }
+}
+
+impl distribution::Hazard for Gamma {
+    fn hazard(&self, x: f64) -> f64 {
+        use distribution::{Continuous, Survival};
+        let y = x / self.theta;
+        if y < self.k + 1.0 {
+            self.density(x) / self.survival(x)
+        } else {
+            1.0 / (x * fraction(y, self.k))
+        }
+    }
+
+    fn cumulative_hazard(&self, x: f64) -> f64 {
+        use special::Gamma;
+        let (k, y) = (self.k, x / self.theta);
+        if y <= 0.0 {
+            0.0
+        } else if y < k + 1.0 {
+            -(-y.inc_gamma(k)).ln_1p()
+        } else {
+            y - k * y.ln() + Gamma::ln_gamma(k).0 - fraction(y, k).ln()
+        }
+    }
+}
+
+impl distribution::Inverse for Gamma {
+    fn inverse(&self, p: f64) -> f64 {
+        distribution::numeric::inverse(self, p)
+    }
+}
+
+impl distribution::divergence::KullbackLeibler for Gamma {
+    fn kl(&self, q: &Self) -> f64 {
+        use special::Gamma;
+        let (k, theta) = (self.k, self.theta);
+        (k - q.k) * k.digamma() - Gamma::ln_gamma(k).0
+            + Gamma::ln_gamma(q.k).0
+            + q.k * (q.theta / theta).ln()
+            + k * (theta - q.theta) / q.theta
     }
 }
 
@@ -86,6 +153,19 @@ impl distribution::Mean for Gamma {
     }
 }
 
+impl distribution::Mgf for Gamma {
+    /// Compute the moment-generating function.
+    ///
+    /// The function is infinite if `t >= 1 / theta`.
+    fn mgf(&self, t: f64) -> f64 {
+        if t * self.theta >= 1.0 {
+            f64::INFINITY
+        } else {
+            (-self.k * (-t * self.theta).ln_1p()).exp()
+        }
+    }
+}
+
 impl distribution::Modes for Gamma {
     fn modes(&self) -> Vec<f64> {
         if self.k >= 1.0 {
@@ -96,6 +176,12 @@ impl distribution::Modes for Gamma {
     }
 }
 
+impl distribution::Moments for Gamma {
+    fn raw_moment(&self, n: usize) -> f64 {
+        (0..n).fold(1.0, |product, j| product * (self.k + j as f64) * self.theta)
+    }
+}
+
 impl distribution::Sample for Gamma {
     /// Draw a sample.
     ///
@@ -120,6 +206,23 @@ impl distribution::Skewness for Gamma {
     }
 }
 
+impl distribution::Support for Gamma {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Excluded, Unbounded};
+        (Excluded(0.0), Unbounded)
+    }
+}
+
+impl distribution::Survival for Gamma {
+    fn survival(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            1.0
+        } else {
+            survival(x / self.theta, self.k)
+        }
+    }
+}
+
 impl distribution::Variance for Gamma {
     #[inline]
     fn variance(&self) -> f64 {
@@ -127,6 +230,80 @@ impl distribution::Variance for Gamma {
     }
 }
 
+/// Compute the inverse of the regularized lower incomplete gamma function
+/// with respect to `x` for shape `k`.
+///
+/// The result is obtained via Halley’s method starting from the
+/// Wilson–Hilferty approximation.
+///
+/// ## References
+///
+/// 1. W. H. Press, S. A. Teukolsky, W. T. Vetterling, and B. P. Flannery,
+///    Numerical Recipes: The Art of Scientific Computing, 3rd ed. Cambridge
+///    University Press, 2007, pp. 263–264.
+pub fn inverse(p: f64, k: f64) -> f64 {
+    use special::Gamma;
+
+    should!((0.0..=1.0).contains(&p) && k > 0.0);
+
+    if p <= 0.0 {
+        return 0.0;
+    }
+    if p >= 1.0 {
+        return f64::INFINITY;
+    }
+
+    let ln_gamma = Gamma::ln_gamma(k).0;
+    let k_m1 = k - 1.0;
+    let (ln_k_m1, factor) = if k > 1.0 {
+        let ln_k_m1 = k_m1.ln();
+        (ln_k_m1, (k_m1 * (ln_k_m1 - 1.0) - ln_gamma).exp())
+    } else {
+        (0.0, 0.0)
+    };
+
+    let mut x = if k > 1.0 {
+        let q = if p < 0.5 { p } else { 1.0 - p };
+        let t = (-2.0 * q.ln()).sqrt();
+        let mut z = (2.30753 + t * 0.27061) / (1.0 + t * (0.99229 + t * 0.04481)) - t;
+        if p < 0.5 {
+            z = -z;
+        }
+        let w = 1.0 - 1.0 / (9.0 * k) - z / (3.0 * k.sqrt());
+        (k * w * w * w).max(1e-3)
+    } else {
+        let t = 1.0 - k * (0.253 + k * 0.12);
+        if p < t {
+            (p / t).powf(1.0 / k)
+        } else {
+            1.0 - (-(p - t) / (1.0 - t)).ln_1p()
+        }
+    };
+
+    for _ in 0..100 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        let error = x.inc_gamma(k) - p;
+        let t = if k > 1.0 {
+            factor * (-(x - k_m1) + k_m1 * (x.ln() - ln_k_m1)).exp()
+        } else {
+            (-x + k_m1 * x.ln() - ln_gamma).exp()
+        };
+        let u = error / t;
+        let delta = u / (1.0 - 0.5 * (u * (k_m1 / x - 1.0)).min(1.0));
+        x -= delta;
+        if x <= 0.0 {
+            x = 0.5 * (x + delta);
+        }
+        if delta.abs() < 1e-10 * x {
+            break;
+        }
+    }
+
+    x
+}
+
 /// Draw a sample from the standard Gamma distribution.
 pub fn sample<S: Source>(k: f64, source: &mut S) -> f64 {
     use distribution::gaussian;
@@ -166,7 +343,63 @@ pub fn sample<S: Source>(k: f64, source: &mut S) -> f64 {
     }
 }
 
+/// Compute the survival function of the standard Gamma distribution, that is,
+/// the regularized upper incomplete gamma function.
+///
+/// Beyond `k + 1`, the function is evaluated via a continued fraction, which
+/// avoids the cancellation in `1 - inc_gamma(x, k)`.
+pub fn survival(x: f64, k: f64) -> f64 {
+    use special::Gamma;
+
+    should!(k > 0.0);
+
+    if x <= 0.0 {
+        return 1.0;
+    }
+    if x < k + 1.0 {
+        return 1.0 - x.inc_gamma(k);
+    }
+    (k * x.ln() - x - Gamma::ln_gamma(k).0).exp() * fraction(x, k)
+}
+
+/// Compute `P(X > x) / (x^k exp(-x) / Gamma(k))` for the standard Gamma
+/// distribution by Lentz’s method applied to the continued fraction, which
+/// converges rapidly for `x >= k + 1`.
+fn fraction(x: f64, k: f64) -> f64 {
+    const TINY: f64 = 1e-300;
+
+    let mut b = x + 1.0 - k;
+    let mut c = 1.0 / TINY;
+    let mut d = 1.0 / b;
+    let mut value = d;
+    for i in 1..1000 {
+        let a = -(i as f64) * (i as f64 - k);
+        b += 2.0;
+        d = a * d + b;
+        if d.abs() < TINY {
+            d = TINY;
+        }
+        c = b + a / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        let delta = c * d;
+        value *= delta;
+        if (delta - 1.0).abs() <= f64::EPSILON {
+            break;
+        }
+    }
+    value
+}
+
 #[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
 mod tests {
     use alloc::{vec, vec::Vec};
     use assert;
@@ -176,6 +409,37 @@ mod tests {
         ($k:expr, $theta:expr) => (Gamma::new($k, $theta));
     );
 
+    #[test]
+    fn bhattacharyya() {
+        let d = new!(9.0, 0.5);
+        let q = vec![new!(9.0, 0.5), new!(3.0, 1.5), new!(10.0, 0.4)];
+        assert::close(
+            &q.iter().map(|q| d.bhattacharyya(q)).collect::<Vec<_>>(),
+            &vec![0.0, 0.07651108322373002, 0.01710285278068546],
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn cf() {
+        let d = new!(9.0, 0.5);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            -0.5908200809103712,
+            0.2069492694244429,
+            0.031249999999999993,
+        ];
+        let im = vec![
+            -0.06616233423080825,
+            0.8807346989920017,
+            0.03124999999999998,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
     #[test]
     fn density() {
         let d = new!(9.0, 0.5);
@@ -260,26 +524,167 @@ mod tests {
         );
     }
 
+    #[test]
+    fn hazard() {
+        let d = new!(3.0, 2.0);
+        let x = vec![0.0, 0.5, 7.9, 8.1, 20.0, 1000.0];
+        let h = vec![
+            0.0,
+            0.012195121951219512,
+            0.30590138221742967,
+            0.30945193849636826,
+            0.4098360655737705,
+            0.49800399996812774,
+        ];
+        let c = vec![
+            0.0,
+            0.0021638360954187432,
+            1.4043706939854085,
+            1.4659081123948295,
+            5.889126135826689,
+            488.2599309943503,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.hazard(x)).collect::<Vec<_>>(),
+            &h,
+            1e-13,
+        );
+        assert::close(
+            &x.iter()
+                .map(|&x| d.cumulative_hazard(x))
+                .collect::<Vec<_>>(),
+            &c,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn inverse() {
+        use core::f64::INFINITY;
+
+        let d = new!(9.0, 0.5);
+        let p = vec![0.0, 0.01, 0.1, 0.25, 0.5, 0.75, 0.9, 0.99, 1.0];
+        let x = vec![
+            0.0,
+            1.7537277252931451,
+            2.7162340291272153,
+            3.418822587599573,
+            4.3344755921851865,
+            5.4012224489320415,
+            6.497355770659303,
+            8.701326433676268,
+            INFINITY,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn kl() {
+        let d = new!(9.0, 0.5);
+        let q = vec![new!(9.0, 0.5), new!(3.0, 1.5), new!(10.0, 0.4)];
+        assert::close(
+            &q.iter().map(|q| d.kl(q)).collect::<Vec<_>>(),
+            &vec![0.0, 0.22823001155268413, 0.07514758623851176],
+            1e-14,
+        );
+    }
+
     #[test]
     fn kurtosis() {
         assert_eq!(new!(3.0, 1.5).kurtosis(), 2.0);
     }
 
+    #[test]
+    fn log_density() {
+        let d = new!(9.0, 0.5);
+        let x = vec![-40.0, 0.05, 0.9, 40.0, 10000.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -28.43213646613767,
+            -7.009162402968353,
+            -54.85524264479426,
+            -19930.683555301897,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-10,
+        );
+    }
+
     #[test]
     fn mean() {
         assert_eq!(new!(9.0, 0.5).mean(), 4.5);
     }
 
+    #[test]
+    fn mgf() {
+        let d = new!(9.0, 0.5);
+        let t = vec![-0.7, -0.1, 0.2, 0.45];
+        let m = vec![
+            0.06714227980273117,
+            0.6446089162177974,
+            2.5811747917131975,
+            9.914816422374592,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+        assert_eq!(d.mgf(2.0), f64::INFINITY);
+    }
+
     #[test]
     fn modes() {
         assert_eq!(new!(5.5, 1.5).modes(), vec![6.75]);
     }
 
+    #[test]
+    fn moments() {
+        let d = new!(9.0, 0.5);
+        let m = vec![4826.25, 33783.75, 253378.125];
+        assert::close(
+            &(5..8).map(|n| d.raw_moment(n)).collect::<Vec<_>>(),
+            &m,
+            1e-8,
+        );
+    }
+
     #[test]
     fn skewness() {
         assert_eq!(new!(4.0, 1.5).skewness(), 1.0);
     }
 
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Excluded, Unbounded};
+        assert_eq!(new!(9.0, 0.5).support(), (Excluded(0.0), Unbounded));
+    }
+
+    #[test]
+    fn survival() {
+        let d = new!(3.0, 2.0);
+        let x = vec![0.0, 2.0, 6.0, 7.9, 8.1, 20.0, 100.0, 500.0];
+        let p = vec![
+            1.0,
+            0.9196986029286058,
+            0.4231900811268435,
+            0.2455215160134025,
+            0.2308682419415884,
+            0.002769395715511576,
+            2.509303552201057e-19,
+            8.408216097976575e-105,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.survival(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+        assert::close(d.survival(500.0) / 8.408216097976575e-105, 1.0, 1e-13);
+    }
+
     #[test]
     fn variance() {
         assert_eq!(new!(9.0, 0.5).variance(), 2.25);
@@ -1,10 +1,15 @@
 use alloc::{vec, vec::Vec};
+use core::ops::Bound;
 #[allow(unused_imports)]
 use special::Primitive;
 
 use distribution;
 use source::Source;
 
+/// The standardized value beyond which the tail is computed via Mills’ ratio
+/// instead of the survival function, which underflows.
+const MILLS_THRESHOLD: f64 = 8.0;
+
 /// A Gaussian distribution.
 #[derive(Clone, Copy, Debug)]
 pub struct Gaussian {
@@ -49,10 +54,29 @@ impl Default for Gaussian {
     }
 }
 
+impl distribution::divergence::Bhattacharyya for Gaussian {
+    fn bhattacharyya(&self, q: &Self) -> f64 {
+        let (s1, s2) = (self.sigma * self.sigma, q.sigma * q.sigma);
+        let (sum, delta) = (s1 + s2, self.mu - q.mu);
+        0.25 * delta * delta / sum + 0.5 * (0.5 * sum / (self.sigma * q.sigma)).ln()
+    }
+}
+
+impl distribution::CharacteristicFunction for Gaussian {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::complex;
+        complex::exp((-0.5 * self.sigma * self.sigma * t * t, self.mu * t))
+    }
+}
+
 impl distribution::Continuous for Gaussian {
     fn density(&self, x: f64) -> f64 {
         (-(x - self.mu).powi(2) / (2.0 * self.sigma * self.sigma)).exp() / self.norm
     }
+
+    fn log_density(&self, x: f64) -> f64 {
+        -(x - self.mu).powi(2) / (2.0 * self.sigma * self.sigma) - self.norm.ln()
+    }
 }
 
 impl distribution::Distribution for Gaussian {
@@ -73,6 +97,23 @@ impl distribution::Entropy for Gaussian {
     }
 }
 
+impl distribution::Hazard for Gaussian {
+    fn hazard(&self, x: f64) -> f64 {
+        use distribution::{Continuous, Survival};
+        let z = (x - self.mu) / self.sigma;
+        if z < MILLS_THRESHOLD {
+            self.density(x) / self.survival(x)
+        } else {
+            1.0 / (self.sigma * mills(z))
+        }
+    }
+
+    #[inline]
+    fn cumulative_hazard(&self, x: f64) -> f64 {
+        -ln_survival((x - self.mu) / self.sigma)
+    }
+}
+
 impl distribution::Inverse for Gaussian {
     /// Compute the inverse of the cumulative distribution function.
     ///
@@ -89,6 +130,14 @@ impl distribution::Inverse for Gaussian {
     }
 }
 
+impl distribution::divergence::KullbackLeibler for Gaussian {
+    fn kl(&self, q: &Self) -> f64 {
+        let ratio = self.sigma / q.sigma;
+        let (d, z) = (ratio * ratio - 1.0, (self.mu - q.mu) / q.sigma);
+        0.5 * (d - d.ln_1p() + z * z)
+    }
+}
+
 impl distribution::Kurtosis for Gaussian {
     #[inline]
     fn kurtosis(&self) -> f64 {
@@ -110,6 +159,12 @@ impl distribution::Median for Gaussian {
     }
 }
 
+impl distribution::Mgf for Gaussian {
+    fn mgf(&self, t: f64) -> f64 {
+        (self.mu * t + 0.5 * self.sigma * self.sigma * t * t).exp()
+    }
+}
+
 impl distribution::Modes for Gaussian {
     #[inline]
     fn modes(&self) -> Vec<f64> {
@@ -117,6 +172,20 @@ impl distribution::Modes for Gaussian {
     }
 }
 
+impl distribution::Moments for Gaussian {
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::moments;
+        let moment = |k: usize| {
+            if k % 2 == 1 {
+                0.0
+            } else {
+                (1..k).step_by(2).fold(1.0, |product, j| product * j as f64)
+            }
+        };
+        moments::shift(moment, self.mu, self.sigma, n)
+    }
+}
+
 impl distribution::Sample for Gaussian {
     /// Draw a sample.
     ///
@@ -143,6 +212,21 @@ impl distribution::Skewness for Gaussian {
     }
 }
 
+impl distribution::Support for Gaussian {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Unbounded;
+        (Unbounded, Unbounded)
+    }
+}
+
+impl distribution::Survival for Gaussian {
+    fn survival(&self, x: f64) -> f64 {
+        use core::f64::consts::SQRT_2;
+        use special::Error;
+        ((x - self.mu) / (self.sigma * SQRT_2)).compl_error() / 2.0
+    }
+}
+
 impl distribution::Variance for Gaussian {
     #[inline]
     fn variance(&self) -> f64 {
@@ -159,8 +243,6 @@ impl distribution::Variance for Gaussian {
 /// Gaussian distribution.
 #[allow(clippy::excessive_precision)]
 pub fn inverse(p: f64) -> f64 {
-    use core::f64::{INFINITY, NEG_INFINITY};
-
     should!((0.0..=1.0).contains(&p));
 
     const CONST1: f64 = 0.180625;
@@ -236,10 +318,10 @@ pub fn inverse(p: f64) -> f64 {
     }
 
     if p <= 0.0 {
-        return NEG_INFINITY;
+        return f64::NEG_INFINITY;
     }
     if 1.0 <= p {
-        return INFINITY;
+        return f64::INFINITY;
     }
 
     let q = p - 0.5;
@@ -268,6 +350,41 @@ pub fn inverse(p: f64) -> f64 {
     }
 }
 
+/// Compute the natural logarithm of the survival function of the standard
+/// Gaussian distribution.
+pub fn ln_survival(z: f64) -> f64 {
+    use core::f64::consts::{PI, SQRT_2};
+    use special::Error;
+    if z < 0.0 {
+        (-0.5 * (-z / SQRT_2).compl_error()).ln_1p()
+    } else if z < MILLS_THRESHOLD {
+        (0.5 * (z / SQRT_2).compl_error()).ln()
+    } else {
+        -0.5 * z * z - 0.5 * (2.0 * PI).ln() + mills(z).ln()
+    }
+}
+
+/// Compute Mills’ ratio of the standard Gaussian distribution, that is,
+/// `P(X > z) / f(z)`, for large `z`.
+///
+/// The ratio is evaluated by Lentz’s method applied to the continued fraction
+/// `1 / (z + 1 / (z + 2 / (z + 3 / (z + …))))`.
+fn mills(z: f64) -> f64 {
+    let mut value = z;
+    let (mut c, mut d) = (z, 0.0);
+    for i in 1..1000 {
+        let a = i as f64;
+        d = 1.0 / (z + a * d);
+        c = z + a / c;
+        let delta = c * d;
+        value *= delta;
+        if (delta - 1.0).abs() <= f64::EPSILON {
+            break;
+        }
+    }
+    1.0 / value
+}
+
 /// Draw a sample from the standard Gaussian distribution.
 pub fn sample<S: Source>(source: &mut S) -> f64 {
     loop {
@@ -409,6 +526,12 @@ const W: [f64; 128] = [
 ];
 
 #[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
 mod tests {
     use alloc::{vec, vec::Vec};
     use assert;
@@ -418,6 +541,37 @@ mod tests {
         ($mu:expr, $sigma:expr) => (Gaussian::new($mu, $sigma));
     );
 
+    #[test]
+    fn bhattacharyya() {
+        let d = new!(1.0, 2.0);
+        let q = vec![new!(1.0, 2.0), new!(-0.5, 1.5), new!(3.0, 0.5)];
+        assert::close(
+            &q.iter().map(|q| d.bhattacharyya(q)).collect::<Vec<_>>(),
+            &vec![0.0, 0.11041099726012756, 0.6121800188352489],
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn cf() {
+        let d = new!(1.0, 2.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            0.2870537617576493,
+            0.7979641112408473,
+            -0.00013960171138142148,
+        ];
+        let im = vec![
+            -0.24178204809668508,
+            0.24683922549431989,
+            0.00030503530434793514,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
     #[test]
     fn density() {
         let d = new!(1.0, 2.0);
@@ -492,6 +646,36 @@ mod tests {
         assert_eq!(new!(0.0, 1.0).entropy(), ((2.0 * PI).ln() + 1.0) / 2.0);
     }
 
+    #[test]
+    fn hazard() {
+        let d = new!(1.0, 2.0);
+        let x = vec![-10.0, 0.0, 3.0, 40.0];
+        let h = vec![
+            5.384880123528156e-8,
+            0.25458021691851674,
+            0.7625676380804906,
+            9.77550790129031,
+        ];
+        let c = vec![
+            1.8989562646189463e-8,
+            0.3689464152886564,
+            1.8410216450092635,
+            194.0169657774975,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.hazard(x)).collect::<Vec<_>>(),
+            &h,
+            1e-13,
+        );
+        assert::close(
+            &x.iter()
+                .map(|&x| d.cumulative_hazard(x))
+                .collect::<Vec<_>>(),
+            &c,
+            1e-12,
+        );
+    }
+
     #[test]
     fn inverse() {
         use core::f64::{INFINITY, NEG_INFINITY};
@@ -532,11 +716,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn kl() {
+        let d = new!(1.0, 2.0);
+        let q = vec![
+            new!(1.0, 2.0),
+            new!(-0.5, 1.5),
+            new!(3.0, 0.5),
+            new!(1.0, 20.0),
+        ];
+        assert::close(
+            &q.iter().map(|q| d.kl(q)).collect::<Vec<_>>(),
+            &vec![
+                0.0,
+                0.601206816437108,
+                14.11370563888011,
+                1.8075850929940457,
+            ],
+            1e-14,
+        );
+    }
+
     #[test]
     fn kurtosis() {
         assert_eq!(new!(0.0, 2.0).kurtosis(), 0.0);
     }
 
+    #[test]
+    fn log_density() {
+        let d = new!(1.0, 2.0);
+        let x = vec![-40.0, 0.5, 40.0, 400.0];
+        let p = vec![
+            -211.73708571376463,
+            -1.6433357137646178,
+            -191.73708571376463,
+            -19901.737085713765,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-10,
+        );
+    }
+
     #[test]
     fn mean() {
         assert_eq!(new!(0.0, 1.0).mean(), 0.0);
@@ -547,16 +769,67 @@ mod tests {
         assert_eq!(new!(0.0, 2.0).median(), 0.0);
     }
 
+    #[test]
+    fn mgf() {
+        let d = new!(1.0, 2.0);
+        let t = vec![-0.7, -0.1, 0.2, 0.45];
+        let m = vec![
+            1.3231298123374369,
+            0.9231163463866358,
+            1.3231298123374369,
+            2.351374380574902,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+    }
+
     #[test]
     fn modes() {
         assert_eq!(new!(2.0, 5.0).modes(), vec![2.0]);
     }
 
+    #[test]
+    fn moments() {
+        let d = new!(1.0, 2.0);
+        let m = vec![281.0, 1741.0, 8485.0];
+        assert::close(
+            &(5..8).map(|n| d.raw_moment(n)).collect::<Vec<_>>(),
+            &m,
+            1e-10,
+        );
+        assert_eq!(new!(0.0, 2.0).raw_moment(6), 960.0);
+    }
+
     #[test]
     fn skewness() {
         assert_eq!(new!(0.0, 2.0).skewness(), 0.0);
     }
 
+    #[test]
+    fn support() {
+        use core::ops::Bound::Unbounded;
+        assert_eq!(new!(1.0, 2.0).support(), (Unbounded, Unbounded));
+    }
+
+    #[test]
+    fn survival() {
+        let d = new!(1.0, 2.0);
+        let x = vec![-5.0, 0.0, 1.0, 3.0, 20.0, 40.0];
+        let p = vec![
+            0.9986501019683699,
+            0.691462461274013,
+            0.5,
+            0.15865525393145705,
+            1.0494515075362607e-21,
+            5.48911547566041e-85,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.survival(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+        assert::close(d.survival(40.0) / 5.48911547566041e-85, 1.0, 1e-13);
+    }
+
     #[test]
     fn variance() {
         assert_eq!(new!(0.0, 2.0).variance(), 4.0);
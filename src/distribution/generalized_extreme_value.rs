@@ -0,0 +1,782 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+const EULER_MASCHERONI: f64 = 0.5772156649015329;
+
+// The moments are computed via the Taylor expansions below when the shape
+// parameter is close to zero since the closed-form expressions suffer from
+// catastrophic cancellation there.
+const SMALL_XI: f64 = 5e-3;
+
+// The number of scale parameters beyond which the bound of the support is
+// treated as infinite in the characteristic function.
+const FAR: f64 = 256.0;
+const MEAN_SERIES: [f64; 5] = [
+    EULER_MASCHERONI,
+    0.9890559953279725,
+    0.9074790760808863,
+    0.9817280868344002,
+    0.9819950689031453,
+];
+const VARIANCE_SERIES: [f64; 5] = [
+    1.6449340668482264,
+    4.303077228549151,
+    11.718339177218882,
+    26.53141916464012,
+    57.676112859609745,
+];
+const SKEWNESS_SERIES: [f64; 5] = [
+    1.1395470994046486,
+    5.966612412667091,
+    12.27978665898371,
+    36.22426785110785,
+    106.07709592394814,
+];
+const KURTOSIS_SERIES: [f64; 5] = [
+    2.4,
+    28.917688340799625,
+    152.61603300554222,
+    666.448329815961,
+    2877.87676765926,
+];
+
+/// A generalized extreme value distribution.
+///
+/// The distribution unifies the Gumbel (`xi = 0`), Fréchet (`xi > 0`), and
+/// reversed Weibull (`xi < 0`) families.
+#[derive(Clone, Copy, Debug)]
+pub struct GeneralizedExtremeValue {
+    mu: f64,
+    sigma: f64,
+    xi: f64,
+}
+
+impl GeneralizedExtremeValue {
+    /// Create a generalized extreme value distribution with location `mu`,
+    /// scale `sigma`, and shape `xi`.
+    ///
+    /// It should hold that `sigma > 0`.
+    #[inline]
+    pub fn new(mu: f64, sigma: f64, xi: f64) -> Self {
+        should!(sigma > 0.0);
+        GeneralizedExtremeValue { mu, sigma, xi }
+    }
+
+    /// Return the location parameter.
+    #[inline(always)]
+    pub fn mu(&self) -> f64 {
+        self.mu
+    }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn sigma(&self) -> f64 {
+        self.sigma
+    }
+
+    /// Return the shape parameter.
+    #[inline(always)]
+    pub fn xi(&self) -> f64 {
+        self.xi
+    }
+
+    // Compute the logarithm of the transformed variable `t(x)`, which is such
+    // that the distribution function is `exp(-t(x))`. `None` is returned
+    // outside the support.
+    fn ln_t(&self, x: f64) -> Option<f64> {
+        let &GeneralizedExtremeValue { mu, sigma, xi } = self;
+        let z = (x - mu) / sigma;
+        if xi == 0.0 {
+            Some(-z)
+        } else if xi * z <= -1.0 {
+            None
+        } else {
+            Some(-(xi * z).ln_1p() / xi)
+        }
+    }
+
+    #[inline]
+    fn moments(&self) -> [f64; 5] {
+        use special::Gamma;
+        let xi = self.xi;
+        [
+            1.0,
+            Gamma::gamma(1.0 - xi),
+            Gamma::gamma(1.0 - 2.0 * xi),
+            Gamma::gamma(1.0 - 3.0 * xi),
+            Gamma::gamma(1.0 - 4.0 * xi),
+        ]
+    }
+}
+
+impl distribution::CharacteristicFunction for GeneralizedExtremeValue {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration, with the bound of
+    /// the support treated as infinite if it lies more than `FAR` scale
+    /// parameters away from `mu`. If `xi = 0`, the function is computed as
+    /// `Gamma(1 - i sigma t) exp(i mu t)`.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, complex, Continuous, Inverse};
+        let &GeneralizedExtremeValue { mu, sigma, xi } = self;
+        if xi == 0.0 {
+            let (re, im) = complex::ln_gamma((1.0, -sigma * t));
+            return complex::exp((re, im + mu * t));
+        }
+        let bound = mu - sigma / xi;
+        let (a, b) = if xi.abs() * FAR < 1.0 {
+            (f64::NEG_INFINITY, f64::INFINITY)
+        } else if xi > 0.0 {
+            (bound, f64::INFINITY)
+        } else {
+            (f64::NEG_INFINITY, bound)
+        };
+        let (lower, upper) = (self.inverse(0.25), self.inverse(0.75));
+        characteristic::continuous(|x| self.density(x), t, a, b, lower, upper - lower)
+    }
+}
+
+impl distribution::Continuous for GeneralizedExtremeValue {
+    fn density(&self, x: f64) -> f64 {
+        match self.ln_t(x) {
+            Some(ln_t) => ((self.xi + 1.0) * ln_t - ln_t.exp()).exp() / self.sigma,
+            None => 0.0,
+        }
+    }
+
+    fn log_density(&self, x: f64) -> f64 {
+        match self.ln_t(x) {
+            Some(ln_t) => (self.xi + 1.0) * ln_t - ln_t.exp() - self.sigma.ln(),
+            None => f64::NEG_INFINITY,
+        }
+    }
+}
+
+impl distribution::Distribution for GeneralizedExtremeValue {
+    type Value = f64;
+
+    fn distribution(&self, x: f64) -> f64 {
+        match self.ln_t(x) {
+            Some(ln_t) => (-ln_t.exp()).exp(),
+            None if self.xi > 0.0 => 0.0,
+            None => 1.0,
+        }
+    }
+}
+
+impl distribution::Entropy for GeneralizedExtremeValue {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        self.sigma.ln() + EULER_MASCHERONI * (self.xi + 1.0) + 1.0
+    }
+}
+
+impl distribution::Inverse for GeneralizedExtremeValue {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        let &GeneralizedExtremeValue { mu, sigma, xi } = self;
+        should!((0.0..=1.0).contains(&p));
+        let ln_t = (-p.ln()).ln();
+        if xi == 0.0 {
+            mu - sigma * ln_t
+        } else {
+            mu + sigma * (-xi * ln_t).exp_m1() / xi
+        }
+    }
+}
+
+impl distribution::Kurtosis for GeneralizedExtremeValue {
+    /// Compute the excess kurtosis.
+    ///
+    /// The excess kurtosis is infinite if `1/4 <= xi < 1/2` and undefined,
+    /// which is signified by `NaN`, if `xi >= 1/2`.
+    fn kurtosis(&self) -> f64 {
+        let xi = self.xi;
+        if xi >= 0.5 {
+            return f64::NAN;
+        }
+        if xi >= 0.25 {
+            return f64::INFINITY;
+        }
+        if xi.abs() < SMALL_XI {
+            return evaluate(&KURTOSIS_SERIES, xi);
+        }
+        let [_, g1, g2, g3, g4] = self.moments();
+        let g1_2 = g1 * g1;
+        (g4 - 4.0 * g1 * g3 + 6.0 * g1_2 * g2 - 3.0 * g1_2 * g1_2) / (g2 - g1_2).powi(2) - 3.0
+    }
+}
+
+impl distribution::Mean for GeneralizedExtremeValue {
+    /// Compute the expected value.
+    ///
+    /// The expected value is infinite if `xi >= 1`.
+    fn mean(&self) -> f64 {
+        let &GeneralizedExtremeValue { mu, sigma, xi } = self;
+        if xi >= 1.0 {
+            f64::INFINITY
+        } else if xi.abs() < SMALL_XI {
+            mu + sigma * evaluate(&MEAN_SERIES, xi)
+        } else {
+            mu + sigma * (self.moments()[1] - 1.0) / xi
+        }
+    }
+}
+
+impl distribution::Median for GeneralizedExtremeValue {
+    #[inline]
+    fn median(&self) -> f64 {
+        use distribution::Inverse;
+        self.inverse(0.5)
+    }
+}
+
+impl distribution::Modes for GeneralizedExtremeValue {
+    fn modes(&self) -> Vec<f64> {
+        let &GeneralizedExtremeValue { mu, sigma, xi } = self;
+        if xi == 0.0 {
+            vec![mu]
+        } else if xi <= -1.0 {
+            vec![mu - sigma / xi]
+        } else {
+            vec![mu + sigma * (-xi * xi.ln_1p()).exp_m1() / xi]
+        }
+    }
+}
+
+impl distribution::Moments for GeneralizedExtremeValue {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is infinite if `n xi >= 1`. When `xi` is close to zero, the
+    /// moment is computed by numerical integration.
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::{moments, Continuous, Mean, Variance};
+        use special::Gamma;
+        let &GeneralizedExtremeValue { mu, sigma, xi } = self;
+        if n as f64 * xi >= 1.0 {
+            f64::INFINITY
+        } else if xi.abs() < SMALL_XI {
+            let (a, b) = (f64::NEG_INFINITY, f64::INFINITY);
+            moments::continuous(|x| self.density(x), n, a, b, self.mean(), self.deviation())
+        } else {
+            let moment = |k: usize| Gamma::gamma(1.0 - k as f64 * xi);
+            moments::shift(moment, mu - sigma / xi, sigma / xi, n)
+        }
+    }
+}
+
+impl distribution::Sample for GeneralizedExtremeValue {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::Inverse;
+        self.inverse(source.read::<f64>())
+    }
+}
+
+impl distribution::Skewness for GeneralizedExtremeValue {
+    /// Compute the skewness.
+    ///
+    /// The skewness is infinite if `1/3 <= xi < 1/2` and undefined, which is
+    /// signified by `NaN`, if `xi >= 1/2`.
+    fn skewness(&self) -> f64 {
+        let xi = self.xi;
+        if xi >= 0.5 {
+            return f64::NAN;
+        }
+        if xi >= 1.0 / 3.0 {
+            return f64::INFINITY;
+        }
+        if xi.abs() < SMALL_XI {
+            return evaluate(&SKEWNESS_SERIES, xi);
+        }
+        let [_, g1, g2, g3, _] = self.moments();
+        let value = (g3 - 3.0 * g1 * g2 + 2.0 * g1 * g1 * g1) / (g2 - g1 * g1).powf(1.5);
+        if xi > 0.0 {
+            value
+        } else {
+            -value
+        }
+    }
+}
+
+impl distribution::Support for GeneralizedExtremeValue {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Excluded, Unbounded};
+        let &GeneralizedExtremeValue { mu, sigma, xi } = self;
+        if xi > 0.0 {
+            (Excluded(mu - sigma / xi), Unbounded)
+        } else if xi < 0.0 {
+            (Unbounded, Excluded(mu - sigma / xi))
+        } else {
+            (Unbounded, Unbounded)
+        }
+    }
+}
+
+impl distribution::TryMean for GeneralizedExtremeValue {
+    /// Compute the expected value.
+    ///
+    /// The expected value does not exist if `xi >= 1`.
+    #[inline]
+    fn try_mean(&self) -> Option<f64> {
+        use distribution::Mean;
+        if self.xi < 1.0 {
+            Some(self.mean())
+        } else {
+            None
+        }
+    }
+}
+
+impl distribution::TryVariance for GeneralizedExtremeValue {
+    /// Compute the variance.
+    ///
+    /// The variance does not exist if `xi >= 1/2`.
+    #[inline]
+    fn try_variance(&self) -> Option<f64> {
+        use distribution::Variance;
+        if self.xi < 0.5 {
+            Some(self.variance())
+        } else {
+            None
+        }
+    }
+}
+
+impl distribution::Variance for GeneralizedExtremeValue {
+    /// Compute the variance.
+    ///
+    /// The variance is infinite if `xi >= 1/2`.
+    fn variance(&self) -> f64 {
+        let &GeneralizedExtremeValue { sigma, xi, .. } = self;
+        if xi >= 0.5 {
+            f64::INFINITY
+        } else if xi.abs() < SMALL_XI {
+            sigma * sigma * evaluate(&VARIANCE_SERIES, xi)
+        } else {
+            let [_, g1, g2, _, _] = self.moments();
+            sigma * sigma * (g2 - g1 * g1) / (xi * xi)
+        }
+    }
+}
+
+#[inline]
+fn evaluate(coefficients: &[f64], x: f64) -> f64 {
+    coefficients.iter().rev().fold(0.0, |sum, &c| sum * x + c)
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($mu:expr, $sigma:expr, $xi:expr) => (GeneralizedExtremeValue::new($mu, $sigma, $xi));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(1.0, 2.0, 0.2);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.2429006263433892, 0.5987546590298739, 0.029005693261476135];
+        let im = vec![
+            -0.24623267036027047,
+            0.389057937808124,
+            -0.006566847667673252,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+
+        let d = new!(1.0, 2.0, 0.0);
+        let (re, im) = d.cf(-0.7);
+        assert::close(re, 0.20336545980958132, 1e-14);
+        assert::close(im, -0.25854851581566585, 1e-14);
+
+        let t = vec![-0.7, 2.0];
+        for &(xi, ref re, ref im) in &[
+            (
+                1e-6,
+                vec![0.2033656948817262, 0.008916646413802944],
+                vec![-0.25854841388275923, -0.002853281217385681],
+            ),
+            (
+                -1e-9,
+                vec![0.20336545957450952, 0.00891657970216006],
+                vec![-0.2585485159175997, -0.0028532574553818526],
+            ),
+            (
+                -1e-3,
+                vec![0.20313017169033204, 0.008850099489573948],
+                vec![-0.25865073030246016, -0.0028295376471485644],
+            ),
+        ] {
+            let d = new!(1.0, 2.0, xi);
+            let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+            assert::close(&x, re, 1e-13);
+            assert::close(&y, im, 1e-13);
+        }
+    }
+
+    #[test]
+    fn density() {
+        let x = vec![-2.0, -1.0, 0.0, 0.5, 1.0, 2.0, 3.0, 5.0, 10.0];
+
+        let d = new!(1.0, 2.0, 0.2);
+        let p = vec![
+            1.1075726759846099e-02,
+            9.0171335995274632e-02,
+            1.7299495141956980e-01,
+            1.8679512534069323e-01,
+            1.8393972058572117e-01,
+            1.5168798738034539e-01,
+            1.1203386432543155e-01,
+            5.5138061367779406e-02,
+            1.0207254306753941e-02,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+
+        let d = new!(1.0, 2.0, -0.3);
+        let p = vec![
+            3.7750543683582730e-02,
+            8.3847629579349575e-02,
+            1.4079633986620560e-01,
+            1.6579426122492896e-01,
+            1.8393972058572117e-01,
+            1.9126428385965427e-01,
+            1.6042322672492859e-01,
+            5.6229458239443440e-02,
+            0.0000000000000000e+00,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+
+        let d1 = new!(1.0, 2.0, 0.0);
+        let d2 = new!(1.0, 2.0, 1e-12);
+        let d3 = Gumbel::new(1.0, 2.0);
+        for &x in &x {
+            assert::close(d1.density(x), d3.density(x), 1e-15);
+            assert::close(d2.density(x), d3.density(x), 1e-11);
+        }
+    }
+
+    #[test]
+    fn distribution() {
+        let x = vec![-2.0, -1.0, 0.0, 0.5, 1.0, 2.0, 3.0, 5.0, 10.0];
+
+        let d = new!(1.0, 2.0, 0.2);
+        let p = vec![
+            2.6060963551382669e-03,
+            4.7275749406290542e-02,
+            1.8387321995473518e-01,
+            2.7462316369244810e-01,
+            3.6787944117144233e-01,
+            5.3744904522302417e-01,
+            6.6906265266781884e-01,
+            8.3032803607780858e-01,
+            9.6041854290456674e-01,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+        assert_eq!(d.distribution(-10.0), 0.0);
+
+        let d = new!(1.0, 2.0, -0.3);
+        let p = vec![
+            3.1726916663147996e-02,
+            9.0918646748839579e-02,
+            2.0323245649650049e-01,
+            2.8009997686582794e-01,
+            3.6787944117144233e-01,
+            5.5892437222921654e-01,
+            7.3745436356275462e-01,
+            9.5393895010458063e-01,
+            1.0000000000000000e+00,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+
+        let d1 = new!(1.0, 2.0, 0.0);
+        let d2 = new!(1.0, 2.0, -1e-12);
+        let d3 = Gumbel::new(1.0, 2.0);
+        for &x in &x {
+            assert::close(d1.distribution(x), d3.distribution(x), 1e-15);
+            assert::close(d2.distribution(x), d3.distribution(x), 1e-11);
+        }
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(1.0, 2.0, 0.2).entropy(), 2.3858059784417849e+00, 1e-15);
+        assert::close(
+            new!(1.0, 2.0, 0.0).entropy(),
+            Gumbel::new(1.0, 2.0).entropy(),
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn inverse() {
+        use core::f64::{INFINITY, NEG_INFINITY};
+        let p = vec![
+            0.0, 0.01, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.99, 1.0,
+        ];
+
+        let d = new!(1.0, 2.0, 0.2);
+        let x = vec![
+            -9.0000000000000000e+00,
+            -1.6319788620658195e+00,
+            -5.3636623927162486e-01,
+            9.2119905525918824e-02,
+            6.3555344408234815e-01,
+            1.1763805971943504e+00,
+            1.7605608513900513e+00,
+            2.4378781392746336e+00,
+            3.2898187981638563e+00,
+            4.4984260579298772e+00,
+            6.6842740650253383e+00,
+            1.6093652817171563e+01,
+            INFINITY,
+        ];
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-14,
+        );
+
+        let d = new!(1.0, 2.0, -0.3);
+        let x = vec![
+            NEG_INFINITY,
+            -2.8743486953256849e+00,
+            -8.9529832113951247e-01,
+            -2.3061880866593901e-02,
+            6.1821470685340363e-01,
+            1.1725702971476271e+00,
+            1.6941636296881406e+00,
+            2.2167414478757621e+00,
+            2.7734942214331300e+00,
+            3.4157357895693594e+00,
+            4.2726645627929107e+00,
+            5.9895513957673483e+00,
+            7.6666666666666670e+00,
+        ];
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(
+            new!(1.0, 2.0, 0.1).kurtosis(),
+            7.9785662393540031e+00,
+            1e-11,
+        );
+        assert::close(
+            new!(1.0, 2.0, -0.2).kurtosis(),
+            -1.1970993621779816e-01,
+            1e-12,
+        );
+        assert::close(
+            new!(1.0, 2.0, 0.001).kurtosis(),
+            2.4290709737121832e+00,
+            1e-10,
+        );
+        assert::close(
+            new!(1.0, 2.0, -0.004).kurtosis(),
+            2.2867291749993064e+00,
+            1e-7,
+        );
+        assert::close(
+            new!(1.0, 2.0, 0.006).kurtosis(),
+            2.5791480864594902e+00,
+            1e-6,
+        );
+        assert_eq!(new!(1.0, 2.0, 0.0).kurtosis(), 2.4);
+        assert_eq!(new!(1.0, 2.0, 0.3).kurtosis(), f64::INFINITY);
+        assert!(new!(1.0, 2.0, 0.5).kurtosis().is_nan());
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(1.0, 2.0, 0.2);
+        let x = vec![-40.0, -3.0, 1.5, 1000000.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -10.488275742490751,
+            -1.7694143320449962,
+            -69.77075397013832,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(1.0, 2.0, 0.1).mean(), 2.3725740423863870e+00, 1e-13);
+        assert::close(new!(1.0, 2.0, -0.2).mean(), 1.8183125760023939e+00, 1e-14);
+        assert::close(new!(1.0, 2.0, 0.001).mean(), 2.1564112587172959e+00, 1e-14);
+        assert::close(new!(1.0, 2.0, -0.004).mean(), 2.1465477960104371e+00, 1e-11);
+        assert::close(new!(1.0, 2.0, 0.006).mean(), 2.1663657669078829e+00, 1e-13);
+        assert::close(
+            new!(1.0, 2.0, 0.0).mean(),
+            Gumbel::new(1.0, 2.0).mean(),
+            1e-15,
+        );
+        assert_eq!(new!(1.0, 2.0, 1.0).mean(), f64::INFINITY);
+    }
+
+    #[test]
+    fn median() {
+        assert::close(new!(1.0, 2.0, 0.2).median(), 1.7605608513900513e+00, 1e-15);
+    }
+
+    #[test]
+    fn modes() {
+        assert::close(
+            new!(1.0, 2.0, 0.2).modes(),
+            vec![6.4192504002627204e-01],
+            1e-15,
+        );
+        assert_eq!(new!(1.0, 2.0, 0.0).modes(), vec![1.0]);
+        assert_eq!(new!(1.0, 2.0, -2.0).modes(), vec![2.0]);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(1.0, 2.0, 0.2);
+        let mut source = source::default(42);
+
+        let cross_entropy = -(0..n)
+            .map(|_| d.density(d.sample(&mut source)).ln())
+            .sum::<f64>()
+            / n as f64;
+        assert!((cross_entropy - d.entropy()).abs() < 0.01);
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(
+            new!(1.0, 2.0, 0.1).skewness(),
+            1.9103391341685199e+00,
+            1e-12,
+        );
+        assert::close(
+            new!(1.0, 2.0, -0.2).skewness(),
+            2.5410960370674751e-01,
+            1e-13,
+        );
+        assert::close(
+            new!(1.0, 2.0, 0.001).skewness(),
+            1.1455260279346362e+00,
+            1e-12,
+        );
+        assert::close(
+            new!(1.0, 2.0, -0.004).skewness(),
+            1.1158748348238112e+00,
+            1e-9,
+        );
+        assert::close(
+            new!(1.0, 2.0, 0.006).skewness(),
+            1.1757968106166348e+00,
+            1e-8,
+        );
+        assert_eq!(new!(1.0, 2.0, 0.4).skewness(), f64::INFINITY);
+        assert!(new!(1.0, 2.0, 0.5).skewness().is_nan());
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Excluded, Unbounded};
+        assert_eq!(new!(1.0, 2.0, 0.5).support(), (Excluded(-3.0), Unbounded));
+        assert_eq!(new!(1.0, 2.0, -0.5).support(), (Unbounded, Excluded(5.0)));
+        assert_eq!(new!(1.0, 2.0, 0.0).support(), (Unbounded, Unbounded));
+    }
+
+    #[test]
+    fn try_mean() {
+        assert_eq!(new!(1.0, 2.0, 1.0).try_mean(), None);
+        let d = new!(1.0, 2.0, 0.7);
+        assert_eq!(d.try_mean(), Some(d.mean()));
+        let d = new!(1.0, 2.0, -0.3);
+        assert_eq!(d.try_mean(), Some(d.mean()));
+    }
+
+    #[test]
+    fn try_variance() {
+        assert_eq!(new!(1.0, 2.0, 0.5).try_variance(), None);
+        let d = new!(1.0, 2.0, 0.2);
+        assert_eq!(d.try_variance(), Some(d.variance()));
+        let d = new!(1.0, 2.0, -0.3);
+        assert_eq!(d.try_variance(), Some(d.variance()));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(
+            new!(1.0, 2.0, 0.1).variance(),
+            8.9049642928329575e+00,
+            1e-12,
+        );
+        assert::close(
+            new!(1.0, 2.0, -0.2).variance(),
+            4.4229977983117328e+00,
+            1e-13,
+        );
+        assert::close(
+            new!(1.0, 2.0, 0.001).variance(),
+            6.5969955560206754e+00,
+            1e-12,
+        );
+        assert::close(
+            new!(1.0, 2.0, -0.004).variance(),
+            6.5116302719704384e+00,
+            1e-9,
+        );
+        assert::close(
+            new!(1.0, 2.0, 0.006).variance(),
+            6.6847207876574535e+00,
+            1e-10,
+        );
+        assert::close(
+            new!(1.0, 2.0, 0.0).variance(),
+            Gumbel::new(1.0, 2.0).variance(),
+            1e-14,
+        );
+        assert_eq!(new!(1.0, 2.0, 0.5).variance(), f64::INFINITY);
+    }
+}
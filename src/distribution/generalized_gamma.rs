@@ -0,0 +1,418 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A generalized gamma distribution.
+///
+/// The distribution coincides with the gamma distribution when `p = 1`, with
+/// the Weibull distribution when `d = p`, and with the half-normal
+/// distribution when `d = p = 2`.
+#[derive(Clone, Copy, Debug)]
+pub struct GeneralizedGamma {
+    a: f64,
+    d: f64,
+    p: f64,
+    norm: f64,
+}
+
+impl GeneralizedGamma {
+    /// Create a generalized gamma distribution with scale `a` and shapes `d`
+    /// and `p`.
+    ///
+    /// It should hold that `a > 0`, `d > 0`, and `p > 0`.
+    #[inline]
+    pub fn new(a: f64, d: f64, p: f64) -> Self {
+        use special::Gamma;
+        should!(a > 0.0 && d > 0.0 && p > 0.0);
+        GeneralizedGamma {
+            a,
+            d,
+            p,
+            norm: p / a * (-Gamma::ln_gamma(d / p).0).exp(),
+        }
+    }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+
+    /// Return the first shape parameter.
+    #[inline(always)]
+    pub fn d(&self) -> f64 {
+        self.d
+    }
+
+    /// Return the second shape parameter.
+    #[inline(always)]
+    pub fn p(&self) -> f64 {
+        self.p
+    }
+
+    /// Compute `E[X^k]`.
+    fn moment(&self, k: i32) -> f64 {
+        use special::Gamma;
+        let &GeneralizedGamma { a, d, p, .. } = self;
+        let ln_ratio = Gamma::ln_gamma((d + k as f64) / p).0 - Gamma::ln_gamma(d / p).0;
+        a.powi(k) * ln_ratio.exp()
+    }
+}
+
+impl distribution::CharacteristicFunction for GeneralizedGamma {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Continuous, Mean, Variance};
+        characteristic::continuous(
+            |x| self.density(x),
+            t,
+            0.0,
+            f64::INFINITY,
+            self.mean(),
+            self.deviation(),
+        )
+    }
+}
+
+impl distribution::Continuous for GeneralizedGamma {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            0.0
+        } else {
+            let y = x / self.a;
+            self.norm * y.powf(self.d - 1.0) * (-y.powf(self.p)).exp()
+        }
+    }
+
+    fn log_density(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            f64::NEG_INFINITY
+        } else if x == 0.0 {
+            self.density(x).ln()
+        } else {
+            let y = x / self.a;
+            self.norm.ln() + (self.d - 1.0) * y.ln() - y.powf(self.p)
+        }
+    }
+}
+
+impl distribution::Distribution for GeneralizedGamma {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        use special::Gamma;
+        if x <= 0.0 {
+            0.0
+        } else {
+            (x / self.a).powf(self.p).inc_gamma(self.d / self.p)
+        }
+    }
+}
+
+impl distribution::Entropy for GeneralizedGamma {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        use special::Gamma;
+        let k = self.d / self.p;
+        k + (1.0 / self.p - k) * k.digamma() - self.norm.ln()
+    }
+}
+
+impl distribution::Inverse for GeneralizedGamma {
+    fn inverse(&self, p: f64) -> f64 {
+        distribution::numeric::inverse(self, p)
+    }
+}
+
+impl distribution::Kurtosis for GeneralizedGamma {
+    fn kurtosis(&self) -> f64 {
+        let (m1, m2, m3, m4) = (
+            self.moment(1),
+            self.moment(2),
+            self.moment(3),
+            self.moment(4),
+        );
+        let m1_2 = m1 * m1;
+        (m4 - 4.0 * m1 * m3 + 6.0 * m1_2 * m2 - 3.0 * m1_2 * m1_2) / (m2 - m1_2).powi(2) - 3.0
+    }
+}
+
+impl distribution::Mean for GeneralizedGamma {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.moment(1)
+    }
+}
+
+impl distribution::Modes for GeneralizedGamma {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        if self.d > 1.0 {
+            vec![self.a * ((self.d - 1.0) / self.p).powf(1.0 / self.p)]
+        } else {
+            vec![0.0]
+        }
+    }
+}
+
+impl distribution::Moments for GeneralizedGamma {
+    fn raw_moment(&self, n: usize) -> f64 {
+        self.moment(n as i32)
+    }
+}
+
+impl distribution::Sample for GeneralizedGamma {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained by raising a gamma variate with shape `d / p`
+    /// to the power `1 / p`.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::gamma;
+        self.a * gamma::sample(self.d / self.p, source).powf(1.0 / self.p)
+    }
+}
+
+impl distribution::Skewness for GeneralizedGamma {
+    fn skewness(&self) -> f64 {
+        let (m1, m2, m3) = (self.moment(1), self.moment(2), self.moment(3));
+        (m3 - 3.0 * m1 * m2 + 2.0 * m1 * m1 * m1) / (m2 - m1 * m1).powf(1.5)
+    }
+}
+
+impl distribution::Support for GeneralizedGamma {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Included, Unbounded};
+        (Included(0.0), Unbounded)
+    }
+}
+
+impl distribution::Variance for GeneralizedGamma {
+    #[inline]
+    fn variance(&self) -> f64 {
+        let m1 = self.moment(1);
+        self.moment(2) - m1 * m1
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($a:expr, $d:expr, $p:expr) => (GeneralizedGamma::new($a, $d, $p));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(2.0, 3.0, 1.5);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            -0.25050994491135814,
+            0.5721301556335718,
+            -0.04701055563802804,
+        ];
+        let im = vec![
+            -0.5674657699684744,
+            0.7107208201020696,
+            -0.051559116606683456,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(2.0, 3.0, 1.5);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 2.0, 3.0, 4.0, 6.0, 8.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            4.1367042308652908e-02,
+            1.3166034399872992e-01,
+            2.7590958087858175e-01,
+            2.6877809557691107e-01,
+            1.7731723968586871e-01,
+            3.7380357322081698e-02,
+            4.0255515348301425e-03,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+
+        let d = new!(2.0, 3.0, 1.0);
+        let gamma = Gamma::new(3.0, 2.0);
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &x.iter().map(|&x| gamma.density(x)).collect::<Vec<_>>(),
+            1e-15,
+        );
+
+        let d = new!(2.0, 1.5, 1.5);
+        let weibull = Weibull::new(1.5, 2.0);
+        assert::close(
+            &x[1..].iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &x[1..]
+                .iter()
+                .map(|&x| weibull.density(x))
+                .collect::<Vec<_>>(),
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(2.0, 3.0, 1.5);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 2.0, 3.0, 4.0, 6.0, 8.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            7.1909845923301720e-03,
+            4.9550373193825749e-02,
+            2.6424111765711533e-01,
+            5.4811556342089407e-01,
+            7.7371795663383292e-01,
+            9.6568675680253979e-01,
+            9.9698083634887735e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(2.0, 3.0, 1.5).entropy(), 1.7239696256538248e+00, 1e-14);
+    }
+
+    #[test]
+    fn inverse() {
+        use core::f64::INFINITY;
+
+        let d = new!(2.0, 3.0, 1.5);
+        let p = vec![0.0, 0.01, 0.1, 0.25, 0.5, 0.75, 0.9, 0.99, 1.0];
+        let x = vec![
+            0.0,
+            0.5609889908943091,
+            1.3128099840791216,
+            1.9480326318909058,
+            2.8245623443420884,
+            3.8709265976360485,
+            4.946624015072513,
+            7.064317067890976,
+            INFINITY,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(
+            new!(2.0, 3.0, 1.5).kurtosis(),
+            6.3628944660762887e-01,
+            1e-13,
+        );
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(2.0, 3.0, 1.5);
+        let x = vec![-40.0, 0.05, 0.9, 40.0, 400.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -7.669393827754864,
+            -2.186566641849796,
+            -83.73893662533538,
+            -2818.118172085546,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(2.0, 3.0, 1.5).mean(), 3.0091509765031121e+00, 1e-14);
+    }
+
+    #[test]
+    fn modes() {
+        assert::close(
+            new!(2.0, 3.0, 1.5).modes(),
+            vec![2.4228274571095194e+00],
+            1e-15,
+        );
+        assert_eq!(new!(2.0, 0.5, 1.5).modes(), vec![0.0]);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(2.0, 3.0, 1.5);
+        let mut source = source::default(42);
+
+        let cross_entropy = -(0..n)
+            .map(|_| d.density(d.sample(&mut source)).ln())
+            .sum::<f64>()
+            / n as f64;
+        assert!((cross_entropy - d.entropy()).abs() < 0.01);
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(
+            new!(2.0, 3.0, 1.5).skewness(),
+            7.3752953528298493e-01,
+            1e-13,
+        );
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Included, Unbounded};
+        assert_eq!(new!(2.0, 3.0, 1.5).support(), (Included(0.0), Unbounded));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(
+            new!(2.0, 3.0, 1.5).variance(),
+            2.0576443223610239e+00,
+            1e-14,
+        );
+    }
+}
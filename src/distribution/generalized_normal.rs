@@ -0,0 +1,386 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A generalized normal distribution, also known as the exponential power
+/// distribution.
+///
+/// The distribution coincides with the Laplace distribution when `beta = 1`
+/// and with the Gaussian distribution when `beta = 2`, and it approaches the
+/// uniform distribution as `beta` goes to infinity.
+#[derive(Clone, Copy, Debug)]
+pub struct GeneralizedNormal {
+    mu: f64,
+    alpha: f64,
+    beta: f64,
+    norm: f64,
+}
+
+impl GeneralizedNormal {
+    /// Create a generalized normal distribution with location `mu`, scale
+    /// `alpha`, and shape `beta`.
+    ///
+    /// It should hold that `alpha > 0` and `beta > 0`.
+    #[inline]
+    pub fn new(mu: f64, alpha: f64, beta: f64) -> Self {
+        use special::Gamma;
+        should!(alpha > 0.0 && beta > 0.0);
+        let norm = beta / (2.0 * alpha) * (-Gamma::ln_gamma(1.0 / beta).0).exp();
+        GeneralizedNormal {
+            mu,
+            alpha,
+            beta,
+            norm,
+        }
+    }
+
+    /// Return the location parameter.
+    #[inline(always)]
+    pub fn mu(&self) -> f64 {
+        self.mu
+    }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Return the shape parameter.
+    #[inline(always)]
+    pub fn beta(&self) -> f64 {
+        self.beta
+    }
+}
+
+impl distribution::CharacteristicFunction for GeneralizedNormal {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Continuous, Variance};
+        characteristic::continuous(
+            |x| self.density(x),
+            t,
+            f64::NEG_INFINITY,
+            f64::INFINITY,
+            self.mu,
+            self.deviation(),
+        )
+    }
+}
+
+impl distribution::Continuous for GeneralizedNormal {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        self.norm * (-((x - self.mu).abs() / self.alpha).powf(self.beta)).exp()
+    }
+
+    #[inline]
+    fn log_density(&self, x: f64) -> f64 {
+        self.norm.ln() - ((x - self.mu).abs() / self.alpha).powf(self.beta)
+    }
+}
+
+impl distribution::Distribution for GeneralizedNormal {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        use special::Gamma;
+        let y = x - self.mu;
+        let p = 0.5
+            * (y.abs() / self.alpha)
+                .powf(self.beta)
+                .inc_gamma(1.0 / self.beta);
+        if y < 0.0 {
+            0.5 - p
+        } else {
+            0.5 + p
+        }
+    }
+}
+
+impl distribution::Entropy for GeneralizedNormal {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        1.0 / self.beta - self.norm.ln()
+    }
+}
+
+impl distribution::Kurtosis for GeneralizedNormal {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        use special::Gamma;
+        let beta = self.beta;
+        (Gamma::ln_gamma(5.0 / beta).0 + Gamma::ln_gamma(1.0 / beta).0
+            - 2.0 * Gamma::ln_gamma(3.0 / beta).0)
+            .exp()
+            - 3.0
+    }
+}
+
+impl distribution::Mean for GeneralizedNormal {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.mu
+    }
+}
+
+impl distribution::Median for GeneralizedNormal {
+    #[inline]
+    fn median(&self) -> f64 {
+        self.mu
+    }
+}
+
+impl distribution::Modes for GeneralizedNormal {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![self.mu]
+    }
+}
+
+impl distribution::Moments for GeneralizedNormal {
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::moments;
+        use special::Gamma;
+        let beta = self.beta;
+        let moment = |k: usize| {
+            if k % 2 == 1 {
+                0.0
+            } else {
+                (Gamma::ln_gamma((k + 1) as f64 / beta).0 - Gamma::ln_gamma(1.0 / beta).0).exp()
+            }
+        };
+        moments::shift(moment, self.mu, self.alpha, n)
+    }
+}
+
+impl distribution::Sample for GeneralizedNormal {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained by raising a gamma variate with shape
+    /// `1 / beta` to the power `1 / beta` and attaching a random sign.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::gamma;
+        let k = 1.0 / self.beta;
+        let r = self.alpha * gamma::sample(k, source).powf(k);
+        if source.read::<f64>() < 0.5 {
+            self.mu - r
+        } else {
+            self.mu + r
+        }
+    }
+}
+
+impl distribution::Skewness for GeneralizedNormal {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        0.0
+    }
+}
+
+impl distribution::Support for GeneralizedNormal {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Unbounded;
+        (Unbounded, Unbounded)
+    }
+}
+
+impl distribution::Variance for GeneralizedNormal {
+    #[inline]
+    fn variance(&self) -> f64 {
+        use special::Gamma;
+        let beta = self.beta;
+        self.alpha
+            * self.alpha
+            * (Gamma::ln_gamma(3.0 / beta).0 - Gamma::ln_gamma(1.0 / beta).0).exp()
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($mu:expr, $alpha:expr, $beta:expr) => (GeneralizedNormal::new($mu, $alpha, $beta));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(1.0, 2.0, 1.5);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            0.39540200473733506,
+            0.8382908522821788,
+            -0.01980127715568595,
+        ];
+        let im = vec![
+            -0.3330425142020649,
+            0.2593137483270239,
+            0.043266579928658316,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(1.0, 2.0, 1.5);
+        let x = vec![-5.0, -2.0, 0.0, 0.5, 1.0, 1.5, 3.0, 5.0, 8.0];
+        let p = vec![
+            1.5336083050292537e-03,
+            4.4108761832856877e-02,
+            1.9445919763015737e-01,
+            2.4439255166311935e-01,
+            2.7693304185811812e-01,
+            2.4439255166311935e-01,
+            1.0187797268067214e-01,
+            1.6368334186697548e-02,
+            3.9687866448431195e-04,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+
+        let d = new!(1.0, 2.0, 2.0);
+        let gaussian = Gaussian::new(1.0, 2f64.sqrt());
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &x.iter().map(|&x| gaussian.density(x)).collect::<Vec<_>>(),
+            1e-15,
+        );
+
+        let d = new!(1.0, 2.0, 1.0);
+        let laplace = Laplace::new(1.0, 2.0);
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &x.iter().map(|&x| laplace.density(x)).collect::<Vec<_>>(),
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(1.0, 2.0, 1.5);
+        let x = vec![-5.0, -2.0, 0.0, 0.5, 1.0, 1.5, 3.0, 5.0, 8.0];
+        let p = vec![
+            1.1187338539480607e-03,
+            4.2432043134412914e-02,
+            2.5825067076132052e-01,
+            3.6819436076719131e-01,
+            5.0000000000000000e-01,
+            6.3180563923280864e-01,
+            8.8759123599192769e-01,
+            9.8588038782075404e-01,
+            9.9972932692597460e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(1.0, 2.0, 1.5).entropy(), 1.9506461948259164e+00, 1e-15);
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(
+            new!(1.0, 2.0, 1.5).kurtosis(),
+            7.6195423693022957e-01,
+            1e-14,
+        );
+        assert::close(new!(1.0, 2.0, 2.0).kurtosis(), 0.0, 1e-14);
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(1.0, 2.0, 1.5);
+        let x = vec![-40.0, 0.5, 40.0, 400.0];
+        let p = vec![
+            -94.10167719406778,
+            -1.4089795281592499,
+            -87.39364797485575,
+            -2819.1111344473534,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(1.0, 2.0, 1.5).mean(), 1.0);
+    }
+
+    #[test]
+    fn median() {
+        assert_eq!(new!(1.0, 2.0, 1.5).median(), 1.0);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(1.0, 2.0, 1.5).modes(), vec![1.0]);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(1.0, 2.0, 1.5);
+        let mut source = source::default(42);
+
+        let cross_entropy = -(0..n)
+            .map(|_| d.density(d.sample(&mut source)).ln())
+            .sum::<f64>()
+            / n as f64;
+        assert!((cross_entropy - d.entropy()).abs() < 0.01);
+    }
+
+    #[test]
+    fn skewness() {
+        assert_eq!(new!(1.0, 2.0, 1.5).skewness(), 0.0);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::Unbounded;
+        assert_eq!(new!(1.0, 2.0, 1.5).support(), (Unbounded, Unbounded));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(
+            new!(1.0, 2.0, 1.5).variance(),
+            2.9539524464865932e+00,
+            1e-14,
+        );
+    }
+}
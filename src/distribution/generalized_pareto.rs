@@ -0,0 +1,580 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A generalized Pareto distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct GeneralizedPareto {
+    mu: f64,
+    sigma: f64,
+    xi: f64,
+}
+
+impl GeneralizedPareto {
+    /// Create a generalized Pareto distribution with location `mu`, scale
+    /// `sigma`, and shape `xi`.
+    ///
+    /// It should hold that `sigma > 0`. The support is `[mu, inf)` if
+    /// `xi >= 0` and `[mu, mu - sigma / xi]` otherwise.
+    #[inline]
+    pub fn new(mu: f64, sigma: f64, xi: f64) -> Self {
+        should!(sigma > 0.0);
+        GeneralizedPareto { mu, sigma, xi }
+    }
+
+    /// Return the location parameter.
+    #[inline(always)]
+    pub fn mu(&self) -> f64 {
+        self.mu
+    }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn sigma(&self) -> f64 {
+        self.sigma
+    }
+
+    /// Return the shape parameter.
+    #[inline(always)]
+    pub fn xi(&self) -> f64 {
+        self.xi
+    }
+}
+
+impl distribution::CharacteristicFunction for GeneralizedPareto {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Continuous, Inverse};
+        let &GeneralizedPareto { mu, sigma, xi } = self;
+        let b = if xi < 0.0 {
+            mu - sigma / xi
+        } else {
+            f64::INFINITY
+        };
+        let (lower, upper) = (self.inverse(0.25), self.inverse(0.75));
+        characteristic::continuous(|x| self.density(x), t, mu, b, lower, upper - lower)
+    }
+}
+
+impl distribution::Continuous for GeneralizedPareto {
+    fn density(&self, x: f64) -> f64 {
+        let &GeneralizedPareto { mu, sigma, xi } = self;
+        let z = (x - mu) / sigma;
+        if z < 0.0 || (xi < 0.0 && z > -1.0 / xi) {
+            return 0.0;
+        }
+        if xi == 0.0 {
+            return (-z).exp() / sigma;
+        }
+        let power = 1.0 + 1.0 / xi;
+        if power == 0.0 {
+            1.0 / sigma
+        } else {
+            (-power * (xi * z).ln_1p()).exp() / sigma
+        }
+    }
+
+    fn log_density(&self, x: f64) -> f64 {
+        let &GeneralizedPareto { mu, sigma, xi } = self;
+        let z = (x - mu) / sigma;
+        if z < 0.0 || (xi < 0.0 && z > -1.0 / xi) {
+            return f64::NEG_INFINITY;
+        }
+        if xi == 0.0 {
+            return -z - sigma.ln();
+        }
+        let power = 1.0 + 1.0 / xi;
+        if power == 0.0 {
+            -sigma.ln()
+        } else {
+            -power * (xi * z).ln_1p() - sigma.ln()
+        }
+    }
+}
+
+impl distribution::Distribution for GeneralizedPareto {
+    type Value = f64;
+
+    fn distribution(&self, x: f64) -> f64 {
+        let &GeneralizedPareto { mu, sigma, xi } = self;
+        let z = (x - mu) / sigma;
+        if z <= 0.0 {
+            0.0
+        } else if xi == 0.0 {
+            -(-z).exp_m1()
+        } else if xi < 0.0 && z >= -1.0 / xi {
+            1.0
+        } else {
+            -(-(xi * z).ln_1p() / xi).exp_m1()
+        }
+    }
+}
+
+impl distribution::Entropy for GeneralizedPareto {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        self.sigma.ln() + self.xi + 1.0
+    }
+}
+
+impl distribution::Inverse for GeneralizedPareto {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        let &GeneralizedPareto { mu, sigma, xi } = self;
+        should!((0.0..=1.0).contains(&p));
+        if xi == 0.0 {
+            mu - sigma * (-p).ln_1p()
+        } else {
+            mu + sigma * (-xi * (-p).ln_1p()).exp_m1() / xi
+        }
+    }
+}
+
+impl distribution::Kurtosis for GeneralizedPareto {
+    /// Compute the excess kurtosis.
+    ///
+    /// The excess kurtosis is infinite if `1/4 <= xi < 1/2` and undefined,
+    /// which is signified by `NaN`, if `xi >= 1/2`.
+    fn kurtosis(&self) -> f64 {
+        let xi = self.xi;
+        if xi >= 0.5 {
+            return f64::NAN;
+        }
+        if xi >= 0.25 {
+            return f64::INFINITY;
+        }
+        3.0 * (1.0 - 2.0 * xi) * (2.0 * xi * xi + xi + 3.0) / ((1.0 - 3.0 * xi) * (1.0 - 4.0 * xi))
+            - 3.0
+    }
+}
+
+impl distribution::Mean for GeneralizedPareto {
+    /// Compute the expected value.
+    ///
+    /// The expected value is infinite if `xi >= 1`.
+    #[inline]
+    fn mean(&self) -> f64 {
+        if self.xi >= 1.0 {
+            f64::INFINITY
+        } else {
+            self.mu + self.sigma / (1.0 - self.xi)
+        }
+    }
+}
+
+impl distribution::Median for GeneralizedPareto {
+    #[inline]
+    fn median(&self) -> f64 {
+        use distribution::Inverse;
+        self.inverse(0.5)
+    }
+}
+
+impl distribution::Modes for GeneralizedPareto {
+    fn modes(&self) -> Vec<f64> {
+        let &GeneralizedPareto { mu, sigma, xi } = self;
+        if xi > -1.0 {
+            vec![mu]
+        } else if xi < -1.0 {
+            vec![mu - sigma / xi]
+        } else {
+            vec![]
+        }
+    }
+}
+
+impl distribution::Moments for GeneralizedPareto {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is infinite if `n xi >= 1`.
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::moments;
+        let xi = self.xi;
+        if n as f64 * xi >= 1.0 {
+            return f64::INFINITY;
+        }
+        let moment =
+            |k: usize| (1..=k).fold(1.0, |product, j| product * j as f64 / (1.0 - j as f64 * xi));
+        moments::shift(moment, self.mu, self.sigma, n)
+    }
+}
+
+impl distribution::Sample for GeneralizedPareto {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::Inverse;
+        self.inverse(source.read::<f64>())
+    }
+}
+
+impl distribution::Skewness for GeneralizedPareto {
+    /// Compute the skewness.
+    ///
+    /// The skewness is infinite if `1/3 <= xi < 1/2` and undefined, which is
+    /// signified by `NaN`, if `xi >= 1/2`.
+    fn skewness(&self) -> f64 {
+        let xi = self.xi;
+        if xi >= 0.5 {
+            return f64::NAN;
+        }
+        if xi >= 1.0 / 3.0 {
+            return f64::INFINITY;
+        }
+        2.0 * (1.0 + xi) * (1.0 - 2.0 * xi).sqrt() / (1.0 - 3.0 * xi)
+    }
+}
+
+impl distribution::Support for GeneralizedPareto {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Included, Unbounded};
+        let &GeneralizedPareto { mu, sigma, xi } = self;
+        if xi < 0.0 {
+            (Included(mu), Included(mu - sigma / xi))
+        } else {
+            (Included(mu), Unbounded)
+        }
+    }
+}
+
+impl distribution::TryMean for GeneralizedPareto {
+    /// Compute the expected value.
+    ///
+    /// The expected value does not exist if `xi >= 1`.
+    #[inline]
+    fn try_mean(&self) -> Option<f64> {
+        use distribution::Mean;
+        if self.xi < 1.0 {
+            Some(self.mean())
+        } else {
+            None
+        }
+    }
+}
+
+impl distribution::TryVariance for GeneralizedPareto {
+    /// Compute the variance.
+    ///
+    /// The variance does not exist if `xi >= 1/2`.
+    #[inline]
+    fn try_variance(&self) -> Option<f64> {
+        use distribution::Variance;
+        if self.xi < 0.5 {
+            Some(self.variance())
+        } else {
+            None
+        }
+    }
+}
+
+impl distribution::Variance for GeneralizedPareto {
+    /// Compute the variance.
+    ///
+    /// The variance is infinite if `xi >= 1/2`.
+    #[inline]
+    fn variance(&self) -> f64 {
+        let &GeneralizedPareto { sigma, xi, .. } = self;
+        if xi >= 0.5 {
+            f64::INFINITY
+        } else {
+            sigma * sigma / ((1.0 - xi).powi(2) * (1.0 - 2.0 * xi))
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($mu:expr, $sigma:expr, $xi:expr) => (GeneralizedPareto::new($mu, $sigma, $xi));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(1.0, 2.0, 0.2);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            -0.02785871401620783,
+            0.5128295276294095,
+            -0.23447720964075455,
+        ];
+        let im = vec![
+            -0.5366147385790955,
+            0.6057231105093733,
+            -0.03438348951373525,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let x = vec![0.0, 1.0, 1.5, 2.0, 3.0, 4.0, 5.0, 7.0, 10.0, 20.0];
+
+        let d = new!(1.0, 2.0, 0.2);
+        let p = vec![
+            0.0000000000000000e+00,
+            5.0000000000000000e-01,
+            3.7310769831831381e-01,
+            2.8223696502688872e-01,
+            1.6744898834019203e-01,
+            1.0358810551650169e-01,
+            6.6405154314953807e-02,
+            2.9802322387695312e-02,
+            1.0627922984373489e-02,
+            8.4058573755886753e-04,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+
+        let d = new!(1.0, 2.0, -0.5);
+        let p = vec![0.0, 0.5, 0.4375, 0.375, 0.25, 0.125, 0.0, 0.0, 0.0, 0.0];
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+
+        let d1 = new!(1.0, 2.0, 0.0);
+        let d2 = new!(1.0, 2.0, 1e-12);
+        let d3 = Exponential::new(0.5);
+        for &x in &[1.5, 2.0, 5.0] {
+            assert::close(d1.density(x), d3.density(x - 1.0), 1e-15);
+            assert::close(d2.density(x), d3.density(x - 1.0), 1e-11);
+        }
+
+        assert_eq!(new!(1.0, 2.0, -1.0).density(3.0), 0.5);
+    }
+
+    #[test]
+    fn distribution() {
+        let x = vec![0.0, 1.0, 1.5, 2.0, 3.0, 4.0, 5.0, 7.0, 10.0, 20.0];
+
+        let d = new!(1.0, 2.0, 0.2);
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            2.1647383353154095e-01,
+            3.7907867694084485e-01,
+            5.9812242798353910e-01,
+            7.3067092565709557e-01,
+            8.1406556791812934e-01,
+            9.0463256835937500e-01,
+            9.5961389265938080e-01,
+            9.9512460272215852e-01,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+
+        let d = new!(1.0, 2.0, -0.5);
+        let p = vec![0.0, 0.0, 0.234375, 0.4375, 0.75, 0.9375, 1.0, 1.0, 1.0, 1.0];
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+
+        let d1 = new!(1.0, 2.0, 0.0);
+        let d2 = new!(1.0, 2.0, 1e-12);
+        let d3 = Exponential::new(0.5);
+        for &x in &[1.5, 2.0, 5.0] {
+            assert::close(d1.distribution(x), d3.distribution(x - 1.0), 1e-15);
+            assert::close(d2.distribution(x), d3.distribution(x - 1.0), 1e-11);
+        }
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(1.0, 2.0, 0.2).entropy(), 1.8931471805599454e+00, 1e-15);
+    }
+
+    #[test]
+    fn inverse() {
+        use core::f64::INFINITY;
+        let p = vec![
+            0.0, 0.01, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.99, 1.0,
+        ];
+
+        let d = new!(1.0, 2.0, 0.2);
+        let x = vec![
+            1.0000000000000000e+00,
+            1.0201208870996530e+00,
+            1.2129568760013507e+00,
+            1.4563955259127324e+00,
+            1.7394092378577934e+00,
+            2.0756634324828998e+00,
+            2.4869835499703501e+00,
+            3.0112443398143123e+00,
+            3.7225963653939207e+00,
+            4.7972966146121490e+00,
+            6.8489319246111355e+00,
+            1.6118864315095799e+01,
+            INFINITY,
+        ];
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-14,
+        );
+
+        let d = new!(1.0, 2.0, -0.5);
+        let x = vec![
+            1.0000000000000000e+00,
+            1.0200502515735201e+00,
+            1.2052668077979447e+00,
+            1.4222912360003366e+00,
+            1.6533598938636977e+00,
+            1.9016133230340666e+00,
+            2.1715728752538097e+00,
+            2.4701778718652965e+00,
+            2.8091097699793353e+00,
+            3.2111456180001685e+00,
+            3.7350889359326485e+00,
+            4.5999999999999996e+00,
+            5.0000000000000000e+00,
+        ];
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-14,
+        );
+
+        let d = new!(1.0, 2.0, 0.0);
+        assert::close(d.inverse(0.5), 1.0 + 2.0 * core::f64::consts::LN_2, 1e-15);
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(
+            new!(1.0, 2.0, 0.2).kurtosis(),
+            7.0800000000000026e+01,
+            1e-12,
+        );
+        assert_eq!(new!(1.0, 2.0, 0.0).kurtosis(), 6.0);
+        assert_eq!(new!(1.0, 2.0, 0.3).kurtosis(), f64::INFINITY);
+        assert!(new!(1.0, 2.0, 0.5).kurtosis().is_nan());
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(1.0, 2.0, 0.2);
+        let x = vec![-40.0, 1.5, 400.0, 1000000.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -0.9858881655765374,
+            -22.95992755885248,
+            -69.77075397013832,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(1.0, 2.0, 0.2).mean(), 3.5, 1e-15);
+        assert_eq!(new!(1.0, 2.0, 1.0).mean(), f64::INFINITY);
+    }
+
+    #[test]
+    fn median() {
+        assert::close(new!(1.0, 2.0, 0.2).median(), 2.4869835499703501e+00, 1e-15);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(1.0, 2.0, 0.2).modes(), vec![1.0]);
+        assert_eq!(new!(1.0, 2.0, -2.0).modes(), vec![2.0]);
+        assert!(new!(1.0, 2.0, -1.0).modes().is_empty());
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(1.0, 2.0, 0.2);
+        let mut source = source::default(42);
+
+        let cross_entropy = -(0..n)
+            .map(|_| d.density(d.sample(&mut source)).ln())
+            .sum::<f64>()
+            / n as f64;
+        assert!((cross_entropy - d.entropy()).abs() < 0.01);
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(
+            new!(1.0, 2.0, 0.2).skewness(),
+            4.6475800154489004e+00,
+            1e-14,
+        );
+        assert_eq!(new!(1.0, 2.0, 0.0).skewness(), 2.0);
+        assert_eq!(new!(1.0, 2.0, 0.4).skewness(), f64::INFINITY);
+        assert!(new!(1.0, 2.0, 0.5).skewness().is_nan());
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Included, Unbounded};
+        assert_eq!(new!(1.0, 2.0, 0.5).support(), (Included(1.0), Unbounded));
+        assert_eq!(
+            new!(1.0, 2.0, -0.5).support(),
+            (Included(1.0), Included(5.0))
+        );
+    }
+
+    #[test]
+    fn try_mean() {
+        assert_eq!(new!(1.0, 2.0, 1.0).try_mean(), None);
+        let d = new!(1.0, 2.0, 0.7);
+        assert_eq!(d.try_mean(), Some(d.mean()));
+        let d = new!(1.0, 2.0, -0.3);
+        assert_eq!(d.try_mean(), Some(d.mean()));
+    }
+
+    #[test]
+    fn try_variance() {
+        assert_eq!(new!(1.0, 2.0, 0.5).try_variance(), None);
+        let d = new!(1.0, 2.0, 0.2);
+        assert_eq!(d.try_variance(), Some(d.variance()));
+        let d = new!(1.0, 2.0, -0.3);
+        assert_eq!(d.try_variance(), Some(d.variance()));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(
+            new!(1.0, 2.0, 0.2).variance(),
+            1.0416666666666668e+01,
+            1e-14,
+        );
+        assert_eq!(new!(1.0, 2.0, 0.5).variance(), f64::INFINITY);
+    }
+}
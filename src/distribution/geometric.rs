@@ -0,0 +1,354 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A geometric distribution.
+///
+/// The distribution describes the number of failures before the first success
+/// in a sequence of Bernoulli trials.
+#[derive(Clone, Copy, Debug)]
+pub struct Geometric {
+    p: f64,
+    q: f64,
+    ln_q: f64,
+}
+
+impl Geometric {
+    /// Create a geometric distribution with success probability `p`.
+    ///
+    /// It should hold that `p > 0` and `p < 1`.
+    #[inline]
+    pub fn new(p: f64) -> Self {
+        should!(p > 0.0 && p < 1.0);
+        Geometric {
+            p,
+            q: 1.0 - p,
+            ln_q: (-p).ln_1p(),
+        }
+    }
+
+    /// Return the success probability.
+    #[inline(always)]
+    pub fn p(&self) -> f64 {
+        self.p
+    }
+
+    /// Return the failure probability.
+    #[inline(always)]
+    pub fn q(&self) -> f64 {
+        self.q
+    }
+}
+
+impl distribution::CharacteristicFunction for Geometric {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use core::f64::consts::FRAC_PI_2;
+        use distribution::complex;
+        let q = self.q;
+        complex::div(
+            (self.p, 0.0),
+            (1.0 - q * (FRAC_PI_2 - t).sin(), -q * t.sin()),
+        )
+    }
+}
+
+impl distribution::Discrete for Geometric {
+    #[inline]
+    fn mass(&self, x: usize) -> f64 {
+        self.p * (x as f64 * self.ln_q).exp()
+    }
+
+    #[inline]
+    fn log_mass(&self, x: usize) -> f64 {
+        self.p.ln() + x as f64 * self.ln_q
+    }
+}
+
+impl distribution::Distribution for Geometric {
+    type Value = usize;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            0.0
+        } else {
+            -((x.floor() + 1.0) * self.ln_q).exp_m1()
+        }
+    }
+}
+
+impl distribution::Entropy for Geometric {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        -(self.q * self.ln_q + self.p * self.p.ln()) / self.p
+    }
+}
+
+impl distribution::Inverse for Geometric {
+    #[inline]
+    fn inverse(&self, p: f64) -> usize {
+        use distribution::Distribution;
+        should!((0.0..=1.0).contains(&p));
+        let x = (-p).ln_1p() / self.ln_q - 1.0;
+        let k = (-(-x).floor()).max(0.0) as usize;
+        if k > 0 && self.distribution((k - 1) as f64) >= p {
+            k - 1
+        } else {
+            k
+        }
+    }
+}
+
+impl distribution::Kurtosis for Geometric {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        6.0 + self.p * self.p / self.q
+    }
+}
+
+impl distribution::Mean for Geometric {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.q / self.p
+    }
+}
+
+impl distribution::Median for Geometric {
+    #[inline]
+    fn median(&self) -> f64 {
+        use distribution::Inverse;
+        self.inverse(0.5) as f64
+    }
+}
+
+impl distribution::Mgf for Geometric {
+    /// Compute the moment-generating function.
+    ///
+    /// The function is infinite if `t >= -ln(1 - p)`.
+    fn mgf(&self, t: f64) -> f64 {
+        if t >= -self.ln_q {
+            f64::INFINITY
+        } else {
+            self.p / (1.0 - self.q * t.exp())
+        }
+    }
+}
+
+impl distribution::Modes for Geometric {
+    #[inline]
+    fn modes(&self) -> Vec<usize> {
+        vec![0]
+    }
+}
+
+impl distribution::Moments for Geometric {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is computed by summing the probability mass function.
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::{moments, Discrete, Mean};
+        moments::discrete(|x| self.mass(x), n, 0, usize::MAX, self.mean())
+    }
+}
+
+impl distribution::Sample for Geometric {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained in constant time by inverting the cumulative
+    /// distribution function.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> usize
+    where
+        S: Source,
+    {
+        (source.read::<f64>().ln() / self.ln_q).floor() as usize
+    }
+}
+
+impl distribution::Skewness for Geometric {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        (2.0 - self.p) / self.q.sqrt()
+    }
+}
+
+impl distribution::Support for Geometric {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Included, Unbounded};
+        (Included(0.0), Unbounded)
+    }
+}
+
+impl distribution::Variance for Geometric {
+    #[inline]
+    fn variance(&self) -> f64 {
+        self.q / (self.p * self.p)
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($p:expr) => (Geometric::new($p));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(0.6);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.759748096210928, 0.9367965153588729, 0.4687970066615079];
+        let im = vec![
+            -0.28207414785993856,
+            0.17922498840007142,
+            0.14617779410779677,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(0.25);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 2.0, 3.5, 5.0, 10.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            2.5000000000000000e-01,
+            2.5000000000000000e-01,
+            4.3750000000000000e-01,
+            5.7812500000000000e-01,
+            6.8359375000000000e-01,
+            8.2202148437500000e-01,
+            9.5776486396789551e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(0.25).entropy(), 2.2493405784752336e+00, 1e-15);
+    }
+
+    #[test]
+    fn inverse() {
+        let d = new!(0.25);
+        let p = vec![
+            0.0, 0.01, 0.1, 0.2, 0.25, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.99,
+        ];
+        let x = vec![0, 0, 0, 0, 0, 1, 1, 2, 3, 4, 5, 8, 16];
+        assert_eq!(&p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(), &x);
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(0.25).kurtosis(), 6.0833333333333330e+00, 1e-15);
+    }
+
+    #[test]
+    fn log_mass() {
+        let d = new!(0.6);
+        let x = vec![0, 5, 100, 10000];
+        let p = vec![
+            -0.5108256237659907,
+            -5.092279283136765,
+            -92.1398988111815,
+            -9163.418144365316,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_mass(x)).collect::<Vec<_>>(),
+            &p,
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn mass() {
+        let d = new!(0.25);
+        let p = vec![
+            2.5000000000000000e-01,
+            1.8750000000000000e-01,
+            1.4062500000000000e-01,
+            1.0546875000000000e-01,
+            7.9101562500000000e-02,
+            5.9326171875000000e-02,
+            4.4494628906250000e-02,
+            3.3370971679687500e-02,
+        ];
+
+        assert::close(&(0..8).map(|x| d.mass(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(0.25).mean(), 3.0, 1e-15);
+    }
+
+    #[test]
+    fn median() {
+        assert_eq!(new!(0.25).median(), 2.0);
+    }
+
+    #[test]
+    fn mgf() {
+        let d = new!(0.6);
+        let t = vec![-0.7, -0.1, 0.2, 0.45];
+        let m = vec![
+            0.7487216714735648,
+            0.9403430201786256,
+            1.1731606724269041,
+            1.60998134422222,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+        assert_eq!(d.mgf(1.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(0.25).modes(), vec![0]);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(0.25);
+        let mut source = source::default(42);
+
+        let mean = (0..n).map(|_| d.sample(&mut source) as f64).sum::<f64>() / n as f64;
+        assert!((mean - d.mean()).abs() < 0.05);
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(0.25).skewness(), 2.0207259421636903e+00, 1e-15);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Included, Unbounded};
+        assert_eq!(new!(0.6).support(), (Included(0.0), Unbounded));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(0.25).variance(), 12.0, 1e-15);
+    }
+}
@@ -0,0 +1,336 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A Gompertz distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct Gompertz {
+    eta: f64,
+    b: f64,
+}
+
+impl Gompertz {
+    /// Create a Gompertz distribution with shape `eta` and scale `b`.
+    ///
+    /// It should hold that `eta > 0` and `b > 0`.
+    #[inline]
+    pub fn new(eta: f64, b: f64) -> Self {
+        should!(eta > 0.0 && b > 0.0);
+        Gompertz { eta, b }
+    }
+
+    /// Return the shape parameter.
+    #[inline(always)]
+    pub fn eta(&self) -> f64 {
+        self.eta
+    }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn b(&self) -> f64 {
+        self.b
+    }
+}
+
+impl distribution::CharacteristicFunction for Gompertz {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Continuous, Inverse};
+        let (lower, upper) = (self.inverse(0.25), self.inverse(0.75));
+        characteristic::continuous(
+            |x| self.density(x),
+            t,
+            0.0,
+            f64::INFINITY,
+            lower,
+            upper - lower,
+        )
+    }
+}
+
+impl distribution::Continuous for Gompertz {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        let &Gompertz { eta, b } = self;
+        if x < 0.0 {
+            0.0
+        } else {
+            b * eta * (eta + b * x - eta * (b * x).exp()).exp()
+        }
+    }
+
+    #[inline]
+    fn log_density(&self, x: f64) -> f64 {
+        let &Gompertz { eta, b } = self;
+        if x < 0.0 {
+            f64::NEG_INFINITY
+        } else {
+            (b * eta).ln() + eta + b * x - eta * (b * x).exp()
+        }
+    }
+}
+
+impl distribution::Distribution for Gompertz {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else {
+            -(-self.eta * (self.b * x).exp_m1()).exp_m1()
+        }
+    }
+}
+
+impl distribution::Hazard for Gompertz {
+    #[inline]
+    fn hazard(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            0.0
+        } else {
+            self.eta * self.b * (self.b * x).exp()
+        }
+    }
+
+    #[inline]
+    fn cumulative_hazard(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else {
+            self.eta * (self.b * x).exp_m1()
+        }
+    }
+}
+
+impl distribution::Inverse for Gompertz {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        should!((0.0..=1.0).contains(&p));
+        (-(-p).ln_1p() / self.eta).ln_1p() / self.b
+    }
+}
+
+impl distribution::Median for Gompertz {
+    #[inline]
+    fn median(&self) -> f64 {
+        use core::f64::consts::LN_2;
+        (LN_2 / self.eta).ln_1p() / self.b
+    }
+}
+
+impl distribution::Modes for Gompertz {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        if self.eta < 1.0 {
+            vec![-self.eta.ln() / self.b]
+        } else {
+            vec![0.0]
+        }
+    }
+}
+
+impl distribution::Sample for Gompertz {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::Inverse;
+        self.inverse(source.read::<f64>())
+    }
+}
+
+impl distribution::Support for Gompertz {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Included, Unbounded};
+        (Included(0.0), Unbounded)
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::approx_constant,
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($eta:expr, $b:expr) => (Gompertz::new($eta, $b));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(0.5, 2.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.9294802319165861, 0.9867721561821103, 0.5202873527595022];
+        let im = vec![-0.31052656155490926, 0.13743823576926945, 0.667242960098079];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(0.5, 2.0);
+        let x = vec![-1.0, 0.0, 0.1, 0.25, 0.5, 0.75, 1.0, 1.5, 2.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            1.0000000000000000e+00,
+            1.0934071218197507e+00,
+            1.1920081008676060e+00,
+            1.1512624072988991e+00,
+            7.8596380662107779e-01,
+            3.0284684919220323e-01,
+            1.4404950185375705e-03,
+            1.2545487088450366e-10,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(0.5, 2.0);
+        let x = vec![-1.0, 0.0, 0.1, 0.25, 0.5, 0.75, 1.0, 1.5, 2.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            1.0479396373168687e-01,
+            2.7701054019796761e-01,
+            5.7647422896119160e-01,
+            8.2462776995776943e-01,
+            9.5901413588725748e-01,
+            9.9992828197602845e-01,
+            9.9999999999770217e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn hazard() {
+        let d = new!(0.5, 2.0);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 2.0];
+        let h = vec![
+            0.0000000000000000e+00,
+            1.0000000000000000e+00,
+            2.7182818284590451e+00,
+            7.3890560989306504e+00,
+            5.4598150033144236e+01,
+        ];
+        let c = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            8.5914091422952255e-01,
+            3.1945280494653250e+00,
+            2.6799075016572118e+01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.hazard(x)).collect::<Vec<_>>(),
+            &h,
+            1e-14,
+        );
+        assert::close(
+            &x.iter()
+                .map(|&x| d.cumulative_hazard(x))
+                .collect::<Vec<_>>(),
+            &c,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn inverse() {
+        use core::f64::INFINITY;
+        let d = new!(0.5, 2.0);
+        let p = vec![
+            0.0, 0.01, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.99, 1.0,
+        ];
+        let x = vec![
+            0.0000000000000000e+00,
+            9.9506600889203295e-03,
+            9.5608037891914535e-02,
+            1.8449982678189122e-01,
+            2.6922522646878805e-01,
+            3.5195731337834119e-01,
+            4.3487084309597196e-01,
+            5.2059423693827667e-01,
+            6.1305482446854442e-01,
+            7.1978435017394293e-01,
+            8.6184470965407278e-01,
+            1.1617004843695211e+00,
+            INFINITY,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(0.5, 2.0);
+        let x = vec![-40.0, 0.05, 0.5, 1.5, 4.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            0.04741454096217612,
+            0.14085908577047745,
+            -6.542768461593834,
+            -1481.9789935208642,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-11,
+        );
+    }
+
+    #[test]
+    fn median() {
+        assert::close(new!(0.5, 2.0).median(), 4.3487084309597196e-01, 1e-15);
+    }
+
+    #[test]
+    fn modes() {
+        assert::close(new!(0.5, 2.0).modes(), vec![3.4657359027997264e-01], 1e-15);
+        assert_eq!(new!(2.0, 2.0).modes(), vec![0.0]);
+    }
+
+    #[test]
+    fn sample() {
+        for x in Independent(&new!(0.5, 2.0), &mut source::default(42)).take(100) {
+            assert!(x >= 0.0);
+        }
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Included, Unbounded};
+        assert_eq!(new!(0.5, 2.0).support(), (Included(0.0), Unbounded));
+    }
+}
@@ -0,0 +1,394 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A Gumbel distribution, also known as the type-I extreme value
+/// distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct Gumbel {
+    mu: f64,
+    beta: f64,
+}
+
+impl Gumbel {
+    /// Create a Gumbel distribution with location `mu` and scale `beta`.
+    ///
+    /// It should hold that `beta > 0`.
+    #[inline]
+    pub fn new(mu: f64, beta: f64) -> Self {
+        should!(beta > 0.0);
+        Gumbel { mu, beta }
+    }
+
+    /// Return the location parameter.
+    #[inline(always)]
+    pub fn mu(&self) -> f64 {
+        self.mu
+    }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn beta(&self) -> f64 {
+        self.beta
+    }
+}
+
+impl Default for Gumbel {
+    #[inline]
+    fn default() -> Self {
+        Gumbel::new(0.0, 1.0)
+    }
+}
+
+const EULER_MASCHERONI: f64 = 0.5772156649015329;
+
+impl distribution::CharacteristicFunction for Gumbel {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::complex;
+        let (re, im) = complex::ln_gamma((1.0, -self.beta * t));
+        complex::exp((re, im + self.mu * t))
+    }
+}
+
+impl distribution::Continuous for Gumbel {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        let z = (x - self.mu) / self.beta;
+        (-(z + (-z).exp())).exp() / self.beta
+    }
+
+    #[inline]
+    fn log_density(&self, x: f64) -> f64 {
+        let z = (x - self.mu) / self.beta;
+        -(z + (-z).exp()) - self.beta.ln()
+    }
+}
+
+impl distribution::Distribution for Gumbel {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        (-(-(x - self.mu) / self.beta).exp()).exp()
+    }
+}
+
+impl distribution::Entropy for Gumbel {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        self.beta.ln() + EULER_MASCHERONI + 1.0
+    }
+}
+
+impl distribution::Inverse for Gumbel {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        should!((0.0..=1.0).contains(&p));
+        self.mu - self.beta * (-p.ln()).ln()
+    }
+}
+
+impl distribution::Kurtosis for Gumbel {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        2.4
+    }
+}
+
+impl distribution::Mean for Gumbel {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.mu + self.beta * EULER_MASCHERONI
+    }
+}
+
+impl distribution::Median for Gumbel {
+    #[inline]
+    fn median(&self) -> f64 {
+        use core::f64::consts::LN_2;
+        self.mu - self.beta * LN_2.ln()
+    }
+}
+
+impl distribution::Mgf for Gumbel {
+    /// Compute the moment-generating function.
+    ///
+    /// The function is infinite if `t >= 1 / beta`.
+    fn mgf(&self, t: f64) -> f64 {
+        use special::Gamma;
+        if t * self.beta >= 1.0 {
+            f64::INFINITY
+        } else {
+            (self.mu * t).exp() * Gamma::gamma(1.0 - self.beta * t)
+        }
+    }
+}
+
+impl distribution::Modes for Gumbel {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![self.mu]
+    }
+}
+
+impl distribution::Moments for Gumbel {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is computed from the cumulants, which are `mu + beta gamma`
+    /// and `beta^k (k - 1)! zeta(k)` for `k > 1` where `gamma` is the
+    /// Euler–Mascheroni constant.
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::{harmonic, moments, Mean};
+        let beta = self.beta;
+        let cumulant = |k: usize| {
+            if k == 1 {
+                self.mean()
+            } else {
+                (1..k).fold(beta * harmonic::zeta(k as f64), |product, j| {
+                    product * j as f64 * beta
+                })
+            }
+        };
+        moments::cumulants(cumulant, n)
+    }
+}
+
+impl distribution::Sample for Gumbel {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::Inverse;
+        self.inverse(source.read::<f64>())
+    }
+}
+
+impl distribution::Skewness for Gumbel {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        1.1395470994046486
+    }
+}
+
+impl distribution::Support for Gumbel {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Unbounded;
+        (Unbounded, Unbounded)
+    }
+}
+
+impl distribution::Variance for Gumbel {
+    #[inline]
+    fn variance(&self) -> f64 {
+        use core::f64::consts::PI;
+        (PI * self.beta).powi(2) / 6.0
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($mu:expr, $beta:expr) => (Gumbel::new($mu, $beta));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(1.0, 2.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            0.20336545980958132,
+            0.6432981606580176,
+            0.008916579768804908,
+        ];
+        let im = vec![
+            -0.25854851581566585,
+            0.41482854225394256,
+            -0.0028532574791200974,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(1.0, 2.0);
+        let x = vec![-4.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0, 5.0, 8.0, 12.0];
+        let p = vec![
+            3.1182885938309958e-05,
+            2.5353556804990358e-02,
+            8.9687039367008586e-02,
+            1.5852096053897108e-01,
+            1.8393972058572117e-01,
+            1.6535214944520904e-01,
+            1.2732319002179124e-01,
+            5.9102475796571574e-02,
+            1.4649566066640760e-02,
+            2.0350519096235145e-03,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+
+        assert_eq!(d.density(-1e4), 0.0);
+        assert_eq!(d.density(1e4), 0.0);
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(1.0, 2.0);
+        let x = vec![-4.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0, 5.0, 8.0, 12.0];
+        let p = vec![
+            5.1192942986707321e-06,
+            1.1314286380459624e-02,
+            6.5988035845312543e-02,
+            1.9229564554796494e-01,
+            3.6787944117144233e-01,
+            5.4523921189260505e-01,
+            6.9220062755534639e-01,
+            8.7342301849311665e-01,
+            9.7025400259106243e-01,
+            9.9592156804753873e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(1.0, 2.0).entropy(), 2.2703628454614782e+00, 1e-15);
+    }
+
+    #[test]
+    fn inverse() {
+        use core::f64::{INFINITY, NEG_INFINITY};
+        let d = new!(1.0, 2.0);
+        let p = vec![
+            0.0, 0.01, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.99, 1.0,
+        ];
+        let x = vec![
+            NEG_INFINITY,
+            -2.0543592516158022e+00,
+            -6.6806489049591156e-01,
+            4.8230009345778826e-02,
+            6.2874648227526864e-01,
+            1.1748431435815103e+00,
+            1.7330258411633286e+00,
+            2.3434539841842441e+00,
+            3.0618608663174460e+00,
+            3.9998799735190316e+00,
+            5.5007346546248908e+00,
+            1.0200298453553158e+01,
+            INFINITY,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert_eq!(new!(1.0, 2.0).kurtosis(), 2.4);
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(1.0, 2.0);
+        let x = vec![-3.0, 0.9, 400.0, 10000.0];
+        let p = vec![
+            -6.082203279490596,
+            -1.6944182769359695,
+            -200.19314718055995,
+            -5000.19314718056,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(1.0, 2.0).mean(), 2.1544313298030655e+00, 1e-15);
+    }
+
+    #[test]
+    fn median() {
+        assert::close(new!(1.0, 2.0).median(), 1.7330258411633286e+00, 1e-15);
+    }
+
+    #[test]
+    fn mgf() {
+        let d = new!(1.0, 2.0);
+        let t = vec![-0.7, -0.1, 0.2, 0.45];
+        let m = vec![
+            0.6168430413010464,
+            0.8307934341943234,
+            1.8189035201307207,
+            14.920150050576707,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+        assert_eq!(d.mgf(0.5), f64::INFINITY);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(1.0, 2.0).modes(), vec![1.0]);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(1.0, 2.0);
+        let mut source = source::default(42);
+
+        let cross_entropy = -(0..n)
+            .map(|_| d.density(d.sample(&mut source)).ln())
+            .sum::<f64>()
+            / n as f64;
+        assert!((cross_entropy - d.entropy()).abs() < 0.01);
+    }
+
+    #[test]
+    fn skewness() {
+        assert_eq!(new!(1.0, 2.0).skewness(), 1.1395470994046486e+00);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::Unbounded;
+        assert_eq!(new!(1.0, 2.0).support(), (Unbounded, Unbounded));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(1.0, 2.0).variance(), 6.5797362673929056e+00, 1e-15);
+    }
+}
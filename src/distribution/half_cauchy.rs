@@ -0,0 +1,337 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A half-Cauchy distribution.
+///
+/// The distribution is the distribution of the absolute value of a Cauchy
+/// variate with location zero. It is long tailed and has no mean or variance.
+#[derive(Clone, Copy, Debug)]
+pub struct HalfCauchy {
+    gamma: f64,
+}
+
+impl HalfCauchy {
+    /// Create a half-Cauchy distribution with scale `gamma`.
+    ///
+    /// It should hold that `gamma > 0`.
+    #[inline]
+    pub fn new(gamma: f64) -> Self {
+        should!(gamma > 0.0);
+        HalfCauchy { gamma }
+    }
+
+    /// Return the scale parameter, which is also the median.
+    #[inline(always)]
+    pub fn gamma(&self) -> f64 {
+        self.gamma
+    }
+}
+
+impl distribution::CharacteristicFunction for HalfCauchy {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Continuous};
+        characteristic::continuous(|x| self.density(x), t, 0.0, f64::INFINITY, 0.0, self.gamma)
+    }
+}
+
+impl distribution::Continuous for HalfCauchy {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        use core::f64::consts::FRAC_2_PI;
+        if x < 0.0 {
+            0.0
+        } else {
+            FRAC_2_PI * self.gamma / (self.gamma * self.gamma + x * x)
+        }
+    }
+
+    #[inline]
+    fn log_density(&self, x: f64) -> f64 {
+        use core::f64::consts::FRAC_2_PI;
+        if x < 0.0 {
+            f64::NEG_INFINITY
+        } else {
+            let gamma = self.gamma;
+            let ln_sum = if x > gamma {
+                2.0 * x.ln() + (gamma / x).powi(2).ln_1p()
+            } else {
+                2.0 * gamma.ln() + (x / gamma).powi(2).ln_1p()
+            };
+            (FRAC_2_PI * gamma).ln() - ln_sum
+        }
+    }
+}
+
+impl distribution::Distribution for HalfCauchy {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        use core::f64::consts::FRAC_2_PI;
+        if x <= 0.0 {
+            0.0
+        } else {
+            FRAC_2_PI * (x / self.gamma).atan()
+        }
+    }
+}
+
+impl distribution::Entropy for HalfCauchy {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        (core::f64::consts::PI * 2.0 * self.gamma).ln()
+    }
+}
+
+impl distribution::Inverse for HalfCauchy {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        use core::f64::consts::FRAC_PI_2;
+
+        should!((0.0..=1.0).contains(&p));
+
+        if 1.0 <= p {
+            f64::INFINITY
+        } else {
+            self.gamma * (FRAC_PI_2 * p).tan()
+        }
+    }
+}
+
+impl distribution::Median for HalfCauchy {
+    #[inline]
+    fn median(&self) -> f64 {
+        self.gamma
+    }
+}
+
+impl distribution::Modes for HalfCauchy {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![0.0]
+    }
+}
+
+impl distribution::Sample for HalfCauchy {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::Inverse;
+        self.inverse(source.read::<f64>())
+    }
+}
+
+impl distribution::Support for HalfCauchy {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Included, Unbounded};
+        (Included(0.0), Unbounded)
+    }
+}
+
+impl distribution::TryMean for HalfCauchy {
+    /// Compute the expected value.
+    ///
+    /// The expected value does not exist.
+    #[inline]
+    fn try_mean(&self) -> Option<f64> {
+        None
+    }
+}
+
+impl distribution::TryVariance for HalfCauchy {
+    /// Compute the variance.
+    ///
+    /// The variance does not exist.
+    #[inline]
+    fn try_variance(&self) -> Option<f64> {
+        None
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::approx_constant,
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($gamma:expr) => (HalfCauchy::new($gamma));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(2.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.2465969639416066, 0.5488116360940267, 0.018315638888734092];
+        let im = vec![
+            -0.3860655778215781,
+            0.39803163028579136,
+            0.18013081903474798,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(2.0);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 2.0, 3.0, 5.0, 10.0, 20.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            3.1830988618379069e-01,
+            2.9958577523180296e-01,
+            2.5464790894703254e-01,
+            1.5915494309189535e-01,
+            9.7941503441166367e-02,
+            4.3904811887419404e-02,
+            1.2242687930145796e-02,
+            3.1515830315226798e-03,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+
+        let cauchy = Cauchy::new(0.0, 2.0);
+        assert::close(
+            &x[1..].iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &x[1..]
+                .iter()
+                .map(|&x| 2.0 * cauchy.density(x))
+                .collect::<Vec<_>>(),
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(2.0);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 2.0, 3.0, 5.0, 10.0, 20.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            1.5595826075473865e-01,
+            2.9516723530086653e-01,
+            5.0000000000000000e-01,
+            6.2566591637800240e-01,
+            7.5776211681831318e-01,
+            8.7433408362199760e-01,
+            9.3654896513889285e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(2.0).entropy(), 2.5310242469692907e+00, 1e-15);
+    }
+
+    #[test]
+    fn inverse() {
+        use core::f64::INFINITY;
+        let d = new!(2.0);
+        let p = vec![
+            0.0, 0.01, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.99, 1.0,
+        ];
+        let x = vec![
+            0.0000000000000000e+00,
+            3.1418510647329832e-02,
+            3.1676888064907260e-01,
+            6.4983939246581268e-01,
+            1.0190508989888576e+00,
+            1.4530850560107218e+00,
+            2.0000000000000000e+00,
+            2.7527638409423467e+00,
+            3.9252210110103003e+00,
+            6.1553670743505080e+00,
+            1.2627503029350089e+01,
+            1.2731348232574305e+02,
+            INFINITY,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(2.0);
+        let x = vec![-40.0, 0.05, 4.0, 1000000.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -1.1453546906182424,
+            -2.7541677982835004,
+            -27.389456640662058,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-12,
+        );
+        assert::close(new!(1.0).log_density(1e200), -921.4856199029077, 1e-12);
+    }
+
+    #[test]
+    fn median() {
+        assert_eq!(new!(2.0).median(), 2.0);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(2.0).modes(), vec![0.0]);
+    }
+
+    #[test]
+    fn sample() {
+        for x in Independent(&new!(2.0), &mut source::default(42)).take(100) {
+            assert!(x >= 0.0);
+        }
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Included, Unbounded};
+        assert_eq!(new!(2.0).support(), (Included(0.0), Unbounded));
+    }
+
+    #[test]
+    fn try_mean() {
+        assert_eq!(new!(1.0).try_mean(), None);
+        assert_eq!(new!(2.5).try_mean(), None);
+    }
+
+    #[test]
+    fn try_variance() {
+        assert_eq!(new!(1.0).try_variance(), None);
+        assert_eq!(new!(2.5).try_variance(), None);
+    }
+}
@@ -0,0 +1,375 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A half-normal distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct HalfNormal {
+    sigma: f64,
+}
+
+impl HalfNormal {
+    /// Create a half-normal distribution with scale `sigma`.
+    ///
+    /// It should hold that `sigma > 0`.
+    #[inline]
+    pub fn new(sigma: f64) -> Self {
+        should!(sigma > 0.0);
+        HalfNormal { sigma }
+    }
+
+    /// Return the scale parameter, which is the standard deviation of the
+    /// underlying Gaussian distribution.
+    #[inline(always)]
+    pub fn sigma(&self) -> f64 {
+        self.sigma
+    }
+}
+
+impl Default for HalfNormal {
+    #[inline]
+    fn default() -> Self {
+        HalfNormal::new(1.0)
+    }
+}
+
+impl distribution::CharacteristicFunction for HalfNormal {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Continuous, Mean, Variance};
+        characteristic::continuous(
+            |x| self.density(x),
+            t,
+            0.0,
+            f64::INFINITY,
+            self.mean(),
+            self.deviation(),
+        )
+    }
+}
+
+impl distribution::Continuous for HalfNormal {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        use core::f64::consts::{FRAC_1_SQRT_2, FRAC_2_SQRT_PI};
+        if x < 0.0 {
+            0.0
+        } else {
+            let z = x / self.sigma;
+            FRAC_2_SQRT_PI * FRAC_1_SQRT_2 / self.sigma * (-0.5 * z * z).exp()
+        }
+    }
+
+    #[inline]
+    fn log_density(&self, x: f64) -> f64 {
+        use core::f64::consts::FRAC_2_PI;
+        if x < 0.0 {
+            f64::NEG_INFINITY
+        } else {
+            let z = x / self.sigma;
+            0.5 * FRAC_2_PI.ln() - self.sigma.ln() - 0.5 * z * z
+        }
+    }
+}
+
+impl distribution::Distribution for HalfNormal {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        use core::f64::consts::SQRT_2;
+        use special::Error;
+        if x <= 0.0 {
+            0.0
+        } else {
+            (x / (self.sigma * SQRT_2)).error()
+        }
+    }
+}
+
+impl distribution::Entropy for HalfNormal {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        use core::f64::consts::PI;
+        0.5 * (0.5 * PI * self.sigma * self.sigma).ln() + 0.5
+    }
+}
+
+impl distribution::Inverse for HalfNormal {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        use core::f64::consts::SQRT_2;
+        use special::Error;
+        should!((0.0..=1.0).contains(&p));
+        if p >= 1.0 {
+            return f64::INFINITY;
+        }
+        self.sigma * SQRT_2 * p.inv_error()
+    }
+}
+
+impl distribution::Kurtosis for HalfNormal {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        use core::f64::consts::PI;
+        8.0 * (PI - 3.0) / (PI - 2.0).powi(2)
+    }
+}
+
+impl distribution::Mean for HalfNormal {
+    #[inline]
+    fn mean(&self) -> f64 {
+        use core::f64::consts::{FRAC_1_SQRT_2, FRAC_2_SQRT_PI};
+        self.sigma * FRAC_2_SQRT_PI * FRAC_1_SQRT_2
+    }
+}
+
+impl distribution::Median for HalfNormal {
+    #[inline]
+    fn median(&self) -> f64 {
+        use distribution::Inverse;
+        self.inverse(0.5)
+    }
+}
+
+impl distribution::Modes for HalfNormal {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![0.0]
+    }
+}
+
+impl distribution::Moments for HalfNormal {
+    fn raw_moment(&self, n: usize) -> f64 {
+        use core::f64::consts::{LN_2, PI};
+        use special::Gamma;
+        let n = n as f64;
+        (n * (self.sigma.ln() + 0.5 * LN_2) + Gamma::ln_gamma(0.5 * (n + 1.0)).0 - 0.5 * PI.ln())
+            .exp()
+    }
+}
+
+impl distribution::Sample for HalfNormal {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::gaussian;
+        self.sigma * gaussian::sample(source).abs()
+    }
+}
+
+impl distribution::Skewness for HalfNormal {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        use core::f64::consts::{PI, SQRT_2};
+        SQRT_2 * (4.0 - PI) / (PI - 2.0).powf(1.5)
+    }
+}
+
+impl distribution::Support for HalfNormal {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Included, Unbounded};
+        (Included(0.0), Unbounded)
+    }
+}
+
+impl distribution::Variance for HalfNormal {
+    #[inline]
+    fn variance(&self) -> f64 {
+        use core::f64::consts::FRAC_2_PI;
+        self.sigma * self.sigma * (1.0 - FRAC_2_PI)
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($sigma:expr) => (HalfNormal::new($sigma));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(2.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            0.3753110988513996,
+            0.8352702114112719,
+            0.00033546262790245987,
+        ];
+        let im = vec![
+            -0.6079683483141366,
+            0.42521479896687625,
+            0.21574502972779797,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(2.0);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 1.5, 2.0, 3.0, 4.0, 6.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            3.9894228040143270e-01,
+            3.8666811680284918e-01,
+            3.5206532676429947e-01,
+            3.0113743215480443e-01,
+            2.4197072451914334e-01,
+            1.2951759566589172e-01,
+            5.3990966513188049e-02,
+            4.4318484119380075e-03,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(2.0);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 1.5, 2.0, 3.0, 4.0, 6.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            1.9741265136584746e-01,
+            3.8292492254802618e-01,
+            5.4674529524626359e-01,
+            6.8268949213708585e-01,
+            8.6638559746228383e-01,
+            9.5449973610364158e-01,
+            9.9730020393673979e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(2.0).entropy(), 1.4189385332046727e+00, 1e-15);
+    }
+
+    #[test]
+    fn inverse() {
+        use core::f64::INFINITY;
+        let d = new!(2.0);
+        let p = vec![
+            0.0, 0.01, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.99, 1.0,
+        ];
+        let x = vec![
+            0.0000000000000000e+00,
+            2.5066939016138528e-02,
+            2.5132269371014809e-01,
+            5.0669420627159967e-01,
+            7.7064093281513524e-01,
+            1.0488010254160816e+00,
+            1.3489795003921634e+00,
+            1.6832424671458284e+00,
+            2.0728667789875788e+00,
+            2.5631031310892012e+00,
+            3.2897072539029457e+00,
+            5.1516586070978008e+00,
+            INFINITY,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(2.0).kurtosis(), 8.6917730360597412e-01, 1e-15);
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(2.0);
+        let x = vec![-40.0, 0.05, 0.9, 40.0, 400.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -0.9192510332046727,
+            -1.0201885332046727,
+            -200.91893853320468,
+            -20000.918938533203,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(2.0).mean(), 1.5957691216057308e+00, 1e-15);
+    }
+
+    #[test]
+    fn median() {
+        assert::close(new!(2.0).median(), 1.3489795003921634e+00, 1e-14);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(2.0).modes(), vec![0.0]);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(2.0);
+        let mut source = source::default(42);
+
+        let cross_entropy = -(0..n)
+            .map(|_| d.density(d.sample(&mut source)).ln())
+            .sum::<f64>()
+            / n as f64;
+        assert!((cross_entropy - d.entropy()).abs() < 0.01);
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(2.0).skewness(), 9.9527174643115601e-01, 1e-15);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Included, Unbounded};
+        assert_eq!(new!(2.0).support(), (Included(0.0), Unbounded));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(2.0).variance(), 1.4535209105296747e+00, 1e-15);
+    }
+}
@@ -0,0 +1,90 @@
+//! Generalized harmonic numbers and the Riemann zeta function.
+
+#[allow(unused_imports)]
+use special::Primitive;
+
+/// The number of leading terms that are summed directly.
+const DIRECT: usize = 1000;
+
+/// Compute the generalized harmonic number `sum_{k = 1}^n k^(-s)`.
+///
+/// The leading terms are summed directly, and the remainder is approximated by
+/// the Euler–Maclaurin formula, which makes the cost independent of `n`.
+pub fn harmonic(n: usize, s: f64) -> f64 {
+    if n <= DIRECT {
+        return direct(1, n, s);
+    }
+    direct(1, DIRECT - 1, s) + euler_maclaurin(DIRECT as f64, n as f64, s)
+}
+
+/// Compute the tail `sum_{k = n + 1}^infinity k^(-s)` for `s > 1`.
+pub fn tail(n: usize, s: f64) -> f64 {
+    if n + 1 >= DIRECT {
+        return euler_maclaurin((n + 1) as f64, f64::INFINITY, s);
+    }
+    direct(n + 1, DIRECT - 1, s) + euler_maclaurin(DIRECT as f64, f64::INFINITY, s)
+}
+
+/// Compute the Riemann zeta function for `s > 1`.
+#[inline]
+pub fn zeta(s: f64) -> f64 {
+    tail(0, s)
+}
+
+/// Compute `sum_{k = a}^b k^(-s)` term by term, starting from the smallest.
+#[inline]
+fn direct(a: usize, b: usize, s: f64) -> f64 {
+    (a..=b).rev().map(|k| (k as f64).powf(-s)).sum()
+}
+
+/// Approximate `sum_{k = a}^b k^(-s)` by the Euler–Maclaurin formula, where `b`
+/// may be infinite.
+fn euler_maclaurin(a: f64, b: f64, s: f64) -> f64 {
+    let f = |x: f64| x.powf(-s);
+    let integral = if b.is_infinite() {
+        a.powf(1.0 - s) / (s - 1.0)
+    } else {
+        let l = (b / a).ln();
+        let t = (1.0 - s) * l;
+        let ratio = if t == 0.0 { 1.0 } else { t.exp_m1() / t };
+        a.powf(1.0 - s) * l * ratio
+    };
+    let c1 = -s;
+    let c3 = c1 * (s + 1.0) * (s + 2.0);
+    let c5 = c3 * (s + 3.0) * (s + 4.0);
+    let derivative = |c: f64, k: f64| c * (b.powf(-s - k) - a.powf(-s - k));
+    integral + 0.5 * (f(a) + f(b)) + derivative(c1, 1.0) / 12.0 - derivative(c3, 3.0) / 720.0
+        + derivative(c5, 5.0) / 30240.0
+}
+
+#[cfg(test)]
+#[allow(clippy::excessive_precision)]
+mod tests {
+    use assert;
+
+    #[test]
+    fn harmonic() {
+        assert::close(super::harmonic(1, 1.5), 1.0, 1e-15);
+        assert::close(
+            super::harmonic(1_000_000, 1.5),
+            1.0 / 3.8308667001166274e-01,
+            1e-14,
+        );
+        assert::close(
+            super::harmonic(1_000_000, 0.8),
+            1.0 / 1.3367709890864481e-02,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn tail() {
+        assert::close(super::tail(2, 3.5), 3.8345519668738198e-02, 1e-15);
+    }
+
+    #[test]
+    fn zeta() {
+        assert::close(super::zeta(1.5), 2.6123753486854882e+00, 1e-15);
+        assert::close(super::zeta(1.01), 1.0057794333849687e+02, 1e-12);
+    }
+}
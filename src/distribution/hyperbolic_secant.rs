@@ -0,0 +1,397 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A hyperbolic secant distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct HyperbolicSecant {
+    mu: f64,
+    sigma: f64,
+}
+
+impl HyperbolicSecant {
+    /// Create a hyperbolic secant distribution with location `mu` and scale
+    /// `sigma`.
+    ///
+    /// It should hold that `sigma > 0`.
+    #[inline]
+    pub fn new(mu: f64, sigma: f64) -> Self {
+        should!(sigma > 0.0);
+        HyperbolicSecant { mu, sigma }
+    }
+
+    /// Return the location parameter.
+    #[inline(always)]
+    pub fn mu(&self) -> f64 {
+        self.mu
+    }
+
+    /// Return the scale parameter, which is also the standard deviation.
+    #[inline(always)]
+    pub fn sigma(&self) -> f64 {
+        self.sigma
+    }
+}
+
+impl Default for HyperbolicSecant {
+    #[inline]
+    fn default() -> Self {
+        HyperbolicSecant::new(0.0, 1.0)
+    }
+}
+
+impl distribution::CharacteristicFunction for HyperbolicSecant {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use core::f64::consts::FRAC_PI_2;
+        let exp = (-(self.sigma * t).abs()).exp();
+        let modulus = 2.0 * exp / (1.0 + exp * exp);
+        let phase = self.mu * t;
+        (modulus * (FRAC_PI_2 - phase).sin(), modulus * phase.sin())
+    }
+}
+
+impl distribution::Continuous for HyperbolicSecant {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        use core::f64::consts::FRAC_PI_2;
+        let exp = (-FRAC_PI_2 * (x - self.mu).abs() / self.sigma).exp();
+        exp / (self.sigma * (1.0 + exp * exp))
+    }
+
+    #[inline]
+    fn log_density(&self, x: f64) -> f64 {
+        use core::f64::consts::FRAC_PI_2;
+        let z = FRAC_PI_2 * (x - self.mu).abs() / self.sigma;
+        -z - self.sigma.ln() - (-2.0 * z).exp().ln_1p()
+    }
+}
+
+impl distribution::Distribution for HyperbolicSecant {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        use core::f64::consts::{FRAC_2_PI, FRAC_PI_2};
+        FRAC_2_PI * (FRAC_PI_2 * (x - self.mu) / self.sigma).exp().atan()
+    }
+}
+
+impl distribution::Entropy for HyperbolicSecant {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        (4.0 * self.sigma).ln()
+    }
+}
+
+impl distribution::Inverse for HyperbolicSecant {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        use core::f64::consts::{FRAC_2_PI, FRAC_PI_2};
+        should!((0.0..=1.0).contains(&p));
+        let ln_tan = (FRAC_PI_2 * p).sin().ln() - (FRAC_PI_2 * (1.0 - p)).sin().ln();
+        self.mu + self.sigma * FRAC_2_PI * ln_tan
+    }
+}
+
+impl distribution::Kurtosis for HyperbolicSecant {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        2.0
+    }
+}
+
+impl distribution::Mean for HyperbolicSecant {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.mu
+    }
+}
+
+impl distribution::Median for HyperbolicSecant {
+    #[inline]
+    fn median(&self) -> f64 {
+        self.mu
+    }
+}
+
+impl distribution::Mgf for HyperbolicSecant {
+    /// Compute the moment-generating function.
+    ///
+    /// The function is infinite if `|t| >= pi / (2 sigma)`.
+    fn mgf(&self, t: f64) -> f64 {
+        use core::f64::consts::FRAC_PI_2;
+        let x = self.sigma * t;
+        if x.abs() >= FRAC_PI_2 {
+            f64::INFINITY
+        } else {
+            (self.mu * t).exp() / (FRAC_PI_2 - x).sin()
+        }
+    }
+}
+
+impl distribution::Modes for HyperbolicSecant {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![self.mu]
+    }
+}
+
+impl distribution::Moments for HyperbolicSecant {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is computed from the cumulants, the even ones of which are
+    /// expressed in terms of the zeta function.
+    fn raw_moment(&self, n: usize) -> f64 {
+        use core::f64::consts::PI;
+        use distribution::{harmonic, moments};
+        let (mu, sigma) = (self.mu, self.sigma);
+        let cumulant = |k: usize| {
+            if k == 1 {
+                mu
+            } else if k % 2 == 1 {
+                0.0
+            } else {
+                let z = sigma / PI;
+                (1..k).fold(
+                    2.0 * (2f64.powi(k as i32) - 1.0) * harmonic::zeta(k as f64) * z,
+                    |product, j| product * j as f64 * z,
+                )
+            }
+        };
+        moments::cumulants(cumulant, n)
+    }
+}
+
+impl distribution::Sample for HyperbolicSecant {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::Inverse;
+        self.inverse(source.read::<f64>())
+    }
+}
+
+impl distribution::Skewness for HyperbolicSecant {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        0.0
+    }
+}
+
+impl distribution::Support for HyperbolicSecant {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Unbounded;
+        (Unbounded, Unbounded)
+    }
+}
+
+impl distribution::Variance for HyperbolicSecant {
+    #[inline]
+    fn variance(&self) -> f64 {
+        self.sigma * self.sigma
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($mu:expr, $sigma:expr) => (HyperbolicSecant::new($mu, $sigma));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(1.0, 2.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            0.35559195359074786,
+            0.8058747523121075,
+            -0.015238878291615104,
+        ];
+        let im = vec![
+            -0.2995109706956535,
+            0.24928627353531138,
+            0.03329755653856959,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(1.0, 2.0);
+        let x = vec![-5.0, -2.0, 0.0, 0.5, 1.0, 2.0, 3.0, 5.0, 8.0];
+        let p = vec![
+            4.4912830661880008e-03,
+            4.6968183559429250e-02,
+            1.8873492717853282e-01,
+            2.3188894286732459e-01,
+            2.5000000000000000e-01,
+            1.8873492717853282e-01,
+            9.9634203834596674e-02,
+            2.1566684583513605e-02,
+            2.0478780899199933e-03,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(1.0, 2.0);
+        let x = vec![-5.0, -2.0, 0.0, 0.5, 1.0, 2.0, 3.0, 5.0, 8.0];
+        let p = vec![
+            5.7187868538467806e-03,
+            6.0159251995447534e-02,
+            2.7233389961322152e-01,
+            3.7809419148089901e-01,
+            5.0000000000000000e-01,
+            7.2766610038677848e-01,
+            8.6951811357284370e-01,
+            9.7250627099892550e-01,
+            9.9739253147199292e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        assert_eq!(new!(1.0, 2.0).entropy(), 8f64.ln());
+    }
+
+    #[test]
+    fn inverse() {
+        use core::f64::{INFINITY, NEG_INFINITY};
+        let d = new!(1.0, 2.0);
+        let p = vec![
+            0.0, 0.01, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.99, 1.0,
+        ];
+        let x = vec![
+            NEG_INFINITY,
+            -4.2884071071578669e+00,
+            -1.3462367504526556e+00,
+            -4.3134688631687546e-01,
+            1.4148579784034762e-01,
+            5.9325311113667689e-01,
+            1.0000000000000000e+00,
+            1.4067468888633232e+00,
+            1.8585142021596521e+00,
+            2.4313468863168759e+00,
+            3.3462367504526558e+00,
+            6.2884071071578660e+00,
+            INFINITY,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert_eq!(new!(1.0, 2.0).kurtosis(), 2.0);
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(1.0, 2.0);
+        let x = vec![-40.0, 0.5, 400.0, 10000.0];
+        let p = vec![
+            -32.894471879855324,
+            -1.4614967164640285,
+            -314.0670143761418,
+            -7853.889382991645,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(1.0, 2.0).mean(), 1.0);
+    }
+
+    #[test]
+    fn median() {
+        assert_eq!(new!(1.0, 2.0).median(), 1.0);
+    }
+
+    #[test]
+    fn mgf() {
+        let d = new!(1.0, 2.0);
+        let t = vec![-0.7, -0.1, 0.2, 0.45];
+        let m = vec![
+            2.9216547111277302,
+            0.923240765978382,
+            1.3260823833739983,
+            2.5229842916664538,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+        assert_eq!(d.mgf(1.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(1.0, 2.0).modes(), vec![1.0]);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(1.0, 2.0);
+        let mut source = source::default(42);
+
+        let cross_entropy = -(0..n)
+            .map(|_| d.density(d.sample(&mut source)).ln())
+            .sum::<f64>()
+            / n as f64;
+        assert!((cross_entropy - d.entropy()).abs() < 0.01);
+    }
+
+    #[test]
+    fn skewness() {
+        assert_eq!(new!(1.0, 2.0).skewness(), 0.0);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::Unbounded;
+        assert_eq!(new!(1.0, 2.0).support(), (Unbounded, Unbounded));
+    }
+
+    #[test]
+    fn variance() {
+        assert_eq!(new!(1.0, 2.0).variance(), 4.0);
+    }
+}
@@ -0,0 +1,484 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A hypergeometric distribution.
+///
+/// The distribution describes the number of successes in `draws` draws
+/// without replacement from a population of size `population` that contains
+/// `successes` successes.
+#[derive(Clone, Copy, Debug)]
+pub struct Hypergeometric {
+    population: usize,
+    successes: usize,
+    draws: usize,
+    lower: usize,
+    upper: usize,
+    ln_norm: f64,
+}
+
+impl Hypergeometric {
+    /// Create a hypergeometric distribution with population size
+    /// `population`, `successes` successes in the population, and `draws`
+    /// draws.
+    ///
+    /// It should hold that `successes <= population` and `draws <= population`.
+    #[inline]
+    pub fn new(population: usize, successes: usize, draws: usize) -> Self {
+        should!(successes <= population && draws <= population);
+        Hypergeometric {
+            population,
+            successes,
+            draws,
+            lower: (draws + successes).saturating_sub(population),
+            upper: draws.min(successes),
+            ln_norm: ln_choose(population, draws),
+        }
+    }
+
+    /// Return the population size.
+    #[inline(always)]
+    pub fn population(&self) -> usize {
+        self.population
+    }
+
+    /// Return the number of successes in the population.
+    #[inline(always)]
+    pub fn successes(&self) -> usize {
+        self.successes
+    }
+
+    /// Return the number of draws.
+    #[inline(always)]
+    pub fn draws(&self) -> usize {
+        self.draws
+    }
+
+    /// Return the parameters as floating-point numbers.
+    #[inline]
+    fn parameters(&self) -> (f64, f64, f64) {
+        (
+            self.population as f64,
+            self.successes as f64,
+            self.draws as f64,
+        )
+    }
+
+    /// Draw a sample by the ratio-of-uniforms method.
+    fn sample_ratio<S: Source>(&self, source: &mut S) -> usize {
+        const D1: f64 = 1.715_527_769_921_413_5;
+        const D2: f64 = 0.898_916_162_058_898_8;
+
+        let n = self.population;
+        let draws = self.draws.min(n - self.draws);
+        let few = self.successes.min(n - self.successes);
+        let many = n - few;
+
+        let (n_, draws_, few_) = (n as f64, draws as f64, few as f64);
+        let p = few_ / n_;
+        let q = many as f64 / n_;
+        let a = draws_ * p + 0.5;
+        let c = ((n_ - draws_) * draws_ * p * q / (n_ - 1.0) + 0.5).sqrt();
+        let h = D1 * c + D2;
+        let mode = (((draws + 1) * (few + 1)) as f64 / (n + 2) as f64).floor() as usize;
+        let ln_weight = |k: usize| {
+            ln_factorial(k)
+                + ln_factorial(few - k)
+                + ln_factorial(draws - k)
+                + ln_factorial(many - draws + k)
+        };
+        let g = ln_weight(mode);
+        let b = ((draws.min(few) + 1) as f64).min((a + 16.0 * c).floor());
+
+        let k = loop {
+            let u = source.read::<f64>();
+            let v = source.read::<f64>();
+            let x = a + h * (v - 0.5) / u;
+            if !(0.0..b).contains(&x) {
+                continue;
+            }
+            let k = x.floor() as usize;
+            let t = g - ln_weight(k);
+            if u * (4.0 - u) - 3.0 <= t {
+                break k;
+            }
+            if u * (u - t) >= 1.0 {
+                continue;
+            }
+            if 2.0 * u.ln() <= t {
+                break k;
+            }
+        };
+
+        let k = if self.successes > n - self.successes {
+            draws - k
+        } else {
+            k
+        };
+        if draws < self.draws {
+            self.successes - k
+        } else {
+            k
+        }
+    }
+}
+
+impl distribution::CharacteristicFunction for Hypergeometric {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Discrete};
+        characteristic::discrete(|x| self.mass(x), t, self.lower, self.upper, f64::INFINITY)
+    }
+}
+
+impl distribution::Discrete for Hypergeometric {
+    #[inline]
+    fn mass(&self, x: usize) -> f64 {
+        self.log_mass(x).exp()
+    }
+
+    /// Compute the natural logarithm of the probability mass function.
+    ///
+    /// The function is evaluated via logarithms of binomial coefficients.
+    fn log_mass(&self, x: usize) -> f64 {
+        if x < self.lower || x > self.upper {
+            return f64::NEG_INFINITY;
+        }
+        let &Hypergeometric {
+            population: n,
+            successes: k,
+            draws: m,
+            ..
+        } = self;
+        ln_choose(k, x) + ln_choose(n - k, m - x) - self.ln_norm
+    }
+}
+
+impl distribution::Distribution for Hypergeometric {
+    type Value = usize;
+
+    /// Compute the cumulative distribution function.
+    ///
+    /// The probability masses are summed over whichever tail lies farther from
+    /// the expected value, which avoids cancellation.
+    fn distribution(&self, x: f64) -> f64 {
+        use distribution::{Discrete, Mean};
+        if x < self.lower as f64 {
+            return 0.0;
+        }
+        let x = x.floor() as usize;
+        if x >= self.upper {
+            return 1.0;
+        }
+        if (x as f64) < self.mean() {
+            (self.lower..=x).map(|i| self.mass(i)).sum::<f64>().min(1.0)
+        } else {
+            (1.0 - ((x + 1)..=self.upper).map(|i| self.mass(i)).sum::<f64>()).max(0.0)
+        }
+    }
+}
+
+impl distribution::Inverse for Hypergeometric {
+    /// Compute the inverse of the cumulative distribution function.
+    ///
+    /// The probability masses are summed starting from the smallest outcome.
+    fn inverse(&self, p: f64) -> usize {
+        use distribution::Discrete;
+        should!((0.0..=1.0).contains(&p));
+        let mut k = self.lower;
+        let mut sum = self.mass(k);
+        while sum < p && k < self.upper {
+            k += 1;
+            sum += self.mass(k);
+        }
+        k
+    }
+}
+
+impl distribution::Kurtosis for Hypergeometric {
+    fn kurtosis(&self) -> f64 {
+        let (n, k, m) = self.parameters();
+        let product = m * k * (n - k) * (n - m);
+        ((n - 1.0) * n * n * (n * (n + 1.0) - 6.0 * k * (n - k) - 6.0 * m * (n - m))
+            + 6.0 * product * (5.0 * n - 6.0))
+            / (product * (n - 2.0) * (n - 3.0))
+    }
+}
+
+impl distribution::Mean for Hypergeometric {
+    #[inline]
+    fn mean(&self) -> f64 {
+        let (n, k, m) = self.parameters();
+        m * k / n
+    }
+}
+
+impl distribution::Mgf for Hypergeometric {
+    fn mgf(&self, t: f64) -> f64 {
+        use distribution::Discrete;
+        (self.lower..=self.upper)
+            .map(|x| self.mass(x) * (t * x as f64).exp())
+            .sum()
+    }
+}
+
+impl distribution::Modes for Hypergeometric {
+    fn modes(&self) -> Vec<usize> {
+        let numerator = (self.draws + 1) * (self.successes + 1);
+        let denominator = self.population + 2;
+        let mode = numerator / denominator;
+        if numerator % denominator == 0 && mode > self.lower {
+            vec![mode - 1, mode]
+        } else {
+            vec![mode]
+        }
+    }
+}
+
+impl distribution::Moments for Hypergeometric {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is computed by summing the probability mass function.
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::{moments, Discrete, Mean};
+        moments::discrete(|x| self.mass(x), n, self.lower, self.upper, self.mean())
+    }
+}
+
+impl distribution::Sample for Hypergeometric {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained by inverting the cumulative distribution function
+    /// if the number of draws or non-draws is small and by the ratio-of-uniforms
+    /// method of Stadlober otherwise.
+    ///
+    /// ## References
+    ///
+    /// 1. E. Stadlober, “The ratio of uniforms approach for generating discrete
+    ///    random variates,” Journal of Computational and Applied Mathematics,
+    ///    vol. 31, no. 1, pp. 181–189, 1990.
+    fn sample<S>(&self, source: &mut S) -> usize
+    where
+        S: Source,
+    {
+        use distribution::Inverse;
+        if self.draws.min(self.population - self.draws) < SAMPLE_THRESHOLD {
+            self.inverse(source.read::<f64>())
+        } else {
+            self.sample_ratio(source)
+        }
+    }
+}
+
+impl distribution::Skewness for Hypergeometric {
+    fn skewness(&self) -> f64 {
+        let (n, k, m) = self.parameters();
+        (n - 2.0 * k) * (n - 1.0).sqrt() * (n - 2.0 * m)
+            / ((m * k * (n - k) * (n - m)).sqrt() * (n - 2.0))
+    }
+}
+
+impl distribution::Support for Hypergeometric {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Included;
+        (Included(self.lower as f64), Included(self.upper as f64))
+    }
+}
+
+impl distribution::Variance for Hypergeometric {
+    #[inline]
+    fn variance(&self) -> f64 {
+        let (n, k, m) = self.parameters();
+        m * k * (n - k) * (n - m) / (n * n * (n - 1.0))
+    }
+}
+
+const SAMPLE_THRESHOLD: usize = 10;
+
+/// Compute the logarithm of the binomial coefficient.
+#[inline]
+pub fn ln_choose(n: usize, k: usize) -> f64 {
+    ln_factorial(n) - ln_factorial(k) - ln_factorial(n - k)
+}
+
+/// Compute the logarithm of the factorial.
+#[inline]
+pub fn ln_factorial(n: usize) -> f64 {
+    use special::Gamma;
+    Gamma::ln_gamma(n as f64 + 1.0).0
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($population:expr, $successes:expr, $draws:expr) => (
+            Hypergeometric::new($population, $successes, $draws)
+        );
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(50, 20, 10);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            -0.577064746398124,
+            0.33266483123772655,
+            0.002953207472542517,
+        ];
+        let im = vec![
+            -0.21444562784144067,
+            0.8528726540827974,
+            0.013316980093732935,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(50, 20, 10);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 3.0, 4.0, 5.5, 8.0, 10.0, 11.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            2.9248638425452608e-03,
+            2.9248638425452608e-03,
+            3.0780709962023936e-02,
+            3.6496828677683679e-01,
+            6.4502688988220813e-01,
+            8.6011189706713331e-01,
+            9.9949149001676618e-01,
+            1.0000000000000000e+00,
+            1.0000000000000000e+00,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn inverse() {
+        let d = new!(50, 20, 10);
+        let p = vec![
+            0.0, 0.01, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.99, 1.0,
+        ];
+        let x = vec![0, 1, 2, 3, 3, 4, 4, 4, 5, 5, 6, 7, 10];
+        assert_eq!(&p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(), &x);
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(50, 20, 10).kurtosis(), -1.3162123226950354e-01, 1e-14);
+    }
+
+    #[test]
+    fn log_mass() {
+        let d = new!(50, 20, 10);
+        let x = vec![0, 2, 5, 20];
+        let p = vec![
+            -5.834507348938942,
+            -2.223238497529934,
+            -1.536721946741963,
+            f64::NEG_INFINITY,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_mass(x)).collect::<Vec<_>>(),
+            &p,
+            1e-13,
+        );
+    }
+
+    #[test]
+    fn mass() {
+        let d = new!(50, 20, 10);
+        let x = vec![0, 1, 2, 4, 6, 8, 10, 11];
+        let p = vec![
+            2.9248638425452608e-03,
+            2.7855846119478674e-02,
+            1.0825794741888303e-01,
+            2.8005860310537134e-01,
+            1.0340625345429094e-01,
+            5.3344495829594539e-03,
+            1.7985883651357545e-05,
+            0.0000000000000000e+00,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.mass(x)).collect::<Vec<_>>(), &p, 1e-13);
+
+        let d = new!(10, 8, 5);
+        assert_eq!(d.mass(2), 0.0);
+        assert::close(d.mass(3), 2.2222222222222221e-01, 1e-14);
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(50, 20, 10).mean(), 4.0, 1e-15);
+    }
+
+    #[test]
+    fn mgf() {
+        let d = new!(50, 20, 10);
+        let t = vec![-0.7, -0.1, 0.2, 0.45];
+        let m = vec![
+            0.09644510455910636,
+            0.6768896687701719,
+            2.3151539019755076,
+            7.3979691141046695,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(50, 20, 10).modes(), vec![4]);
+        assert_eq!(new!(10, 3, 2).modes(), vec![0, 1]);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let mut source = source::default(42);
+
+        for &d in &[new!(50, 20, 5), new!(50, 20, 10), new!(500, 400, 300)] {
+            let mean = (0..n).map(|_| d.sample(&mut source) as f64).sum::<f64>() / n as f64;
+            assert!((mean - d.mean()).abs() < 0.05);
+        }
+
+        let d = new!(10, 8, 5);
+        for x in Independent(&d, &mut source).take(100) {
+            assert!((3..=5).contains(&x));
+        }
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(50, 20, 10).skewness(), 8.9304313538970032e-02, 1e-14);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::Included;
+        assert_eq!(new!(50, 20, 10).support(), (Included(0.0), Included(10.0)));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(50, 20, 10).variance(), 1.9591836734693877e+00, 1e-15);
+    }
+}
@@ -0,0 +1,453 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// An inverse-gamma distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct InverseGamma {
+    alpha: f64,
+    beta: f64,
+    ln_norm: f64,
+}
+
+impl InverseGamma {
+    /// Create an inverse-gamma distribution with shape parameter `alpha` and
+    /// scale parameter `beta`.
+    ///
+    /// It should hold that `alpha > 0` and `beta > 0`.
+    #[inline]
+    pub fn new(alpha: f64, beta: f64) -> Self {
+        use special::Gamma;
+        should!(alpha > 0.0 && beta > 0.0);
+        InverseGamma {
+            alpha,
+            beta,
+            ln_norm: alpha * beta.ln() - Gamma::ln_gamma(alpha).0,
+        }
+    }
+
+    /// Return the shape parameter.
+    #[inline(always)]
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn beta(&self) -> f64 {
+        self.beta
+    }
+}
+
+impl distribution::CharacteristicFunction for InverseGamma {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Continuous, Inverse};
+        let (lower, upper) = (self.inverse(0.25), self.inverse(0.75));
+        characteristic::continuous(
+            |x| self.density(x),
+            t,
+            0.0,
+            f64::INFINITY,
+            lower,
+            upper - lower,
+        )
+    }
+}
+
+impl distribution::Continuous for InverseGamma {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        self.log_density(x).exp()
+    }
+
+    fn log_density(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            f64::NEG_INFINITY
+        } else {
+            self.ln_norm - (self.alpha + 1.0) * x.ln() - self.beta / x
+        }
+    }
+}
+
+impl distribution::Distribution for InverseGamma {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        use special::Gamma;
+        if x <= 0.0 {
+            0.0
+        } else {
+            1.0 - (self.beta / x).inc_gamma(self.alpha)
+        }
+    }
+}
+
+impl distribution::Entropy for InverseGamma {
+    fn entropy(&self) -> f64 {
+        use special::Gamma;
+        let alpha = self.alpha;
+        alpha + self.beta.ln() + Gamma::ln_gamma(alpha).0 - (1.0 + alpha) * alpha.digamma()
+    }
+}
+
+impl distribution::Inverse for InverseGamma {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        use distribution::gamma;
+        should!((0.0..=1.0).contains(&p));
+        self.beta / gamma::inverse(1.0 - p, self.alpha)
+    }
+}
+
+impl distribution::Kurtosis for InverseGamma {
+    /// Compute the excess kurtosis.
+    ///
+    /// The excess kurtosis is infinite if `2 < alpha <= 4` and undefined,
+    /// which is signified by `NaN`, if `alpha <= 2`.
+    fn kurtosis(&self) -> f64 {
+        let alpha = self.alpha;
+        if alpha > 4.0 {
+            (30.0 * alpha - 66.0) / ((alpha - 3.0) * (alpha - 4.0))
+        } else if alpha > 2.0 {
+            f64::INFINITY
+        } else {
+            f64::NAN
+        }
+    }
+}
+
+impl distribution::Mean for InverseGamma {
+    /// Compute the expected value.
+    ///
+    /// The expected value is infinite if `alpha <= 1`.
+    #[inline]
+    fn mean(&self) -> f64 {
+        if self.alpha > 1.0 {
+            self.beta / (self.alpha - 1.0)
+        } else {
+            f64::INFINITY
+        }
+    }
+}
+
+impl distribution::Median for InverseGamma {
+    #[inline]
+    fn median(&self) -> f64 {
+        use distribution::Inverse;
+        self.inverse(0.5)
+    }
+}
+
+impl distribution::Modes for InverseGamma {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![self.beta / (self.alpha + 1.0)]
+    }
+}
+
+impl distribution::Moments for InverseGamma {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is infinite if `n >= alpha`.
+    fn raw_moment(&self, n: usize) -> f64 {
+        if n as f64 >= self.alpha {
+            return f64::INFINITY;
+        }
+        (1..=n).fold(1.0, |product, j| {
+            product * self.beta / (self.alpha - j as f64)
+        })
+    }
+}
+
+impl distribution::Sample for InverseGamma {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::gamma;
+        self.beta / gamma::sample(self.alpha, source)
+    }
+}
+
+impl distribution::Skewness for InverseGamma {
+    /// Compute the skewness.
+    ///
+    /// The skewness is infinite if `2 < alpha <= 3` and undefined, which is
+    /// signified by `NaN`, if `alpha <= 2`.
+    fn skewness(&self) -> f64 {
+        let alpha = self.alpha;
+        if alpha > 3.0 {
+            4.0 * (alpha - 2.0).sqrt() / (alpha - 3.0)
+        } else if alpha > 2.0 {
+            f64::INFINITY
+        } else {
+            f64::NAN
+        }
+    }
+}
+
+impl distribution::Support for InverseGamma {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Excluded, Unbounded};
+        (Excluded(0.0), Unbounded)
+    }
+}
+
+impl distribution::TryMean for InverseGamma {
+    /// Compute the expected value.
+    ///
+    /// The expected value does not exist if `alpha <= 1`.
+    #[inline]
+    fn try_mean(&self) -> Option<f64> {
+        use distribution::Mean;
+        if self.alpha > 1.0 {
+            Some(self.mean())
+        } else {
+            None
+        }
+    }
+}
+
+impl distribution::TryVariance for InverseGamma {
+    /// Compute the variance.
+    ///
+    /// The variance does not exist if `alpha <= 2`.
+    #[inline]
+    fn try_variance(&self) -> Option<f64> {
+        use distribution::Variance;
+        if self.alpha > 2.0 {
+            Some(self.variance())
+        } else {
+            None
+        }
+    }
+}
+
+impl distribution::Variance for InverseGamma {
+    /// Compute the variance.
+    ///
+    /// The variance is infinite if `1 < alpha <= 2` and undefined, which is
+    /// signified by `NaN`, if `alpha <= 1`.
+    fn variance(&self) -> f64 {
+        let alpha = self.alpha;
+        if alpha > 2.0 {
+            self.beta * self.beta / ((alpha - 1.0).powi(2) * (alpha - 2.0))
+        } else if alpha > 1.0 {
+            f64::INFINITY
+        } else {
+            f64::NAN
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($alpha:expr, $beta:expr) => (InverseGamma::new($alpha, $beta));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(3.0, 2.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.7266376116634531, 0.9314524289834217, 0.04778375668542437];
+        let im = vec![-0.5228954409093821, 0.27486040153763813, 0.6369011569400643];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(3.0, 2.0);
+        let x = vec![
+            -1.0, 0.0, 0.1, 0.25, 0.5, 0.75, 1.0, 1.5, 2.0, 3.0, 5.0, 10.0,
+        ];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            8.2446144897542386e-05,
+            3.4351373097217214e-01,
+            1.1722008888789874e+00,
+            8.7840807471788607e-01,
+            5.4134113294645081e-01,
+            2.0827428196798164e-01,
+            9.1969860292860584e-02,
+            2.5353931804078619e-02,
+            4.2900482946280913e-03,
+            3.2749230123119274e-04,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(3.0, 2.0);
+        let x = vec![
+            -1.0, 0.0, 0.1, 0.25, 0.5, 0.75, 1.0, 1.5, 2.0, 3.0, 5.0, 10.0,
+        ];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            4.5551495055892172e-07,
+            1.3753967744002985e-02,
+            2.3810330555354434e-01,
+            5.0182492549801105e-01,
+            6.7667641618306351e-01,
+            8.4936855615067519e-01,
+            9.1969860292860584e-01,
+            9.6978789150600719e-01,
+            9.9207366813274611e-01,
+            9.9885151875513789e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(3.0, 2.0).entropy(), 6.9515702072602203e-01, 1e-14);
+        assert::close(new!(6.0, 2.0).entropy(), -4.6218475568061201e-01, 1e-14);
+    }
+
+    #[test]
+    fn inverse() {
+        use core::f64::INFINITY;
+        let d = new!(3.0, 2.0);
+        let p = vec![
+            0.0, 0.01, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.99, 1.0,
+        ];
+        let x = vec![
+            0.0000000000000000e+00,
+            2.3792679400084588e-01,
+            3.7577595354094223e-01,
+            4.6739566335753957e-01,
+            5.5316348215017175e-01,
+            6.4404385402878828e-01,
+            7.4792628638022429e-01,
+            8.7524406574503677e-01,
+            1.0450544970510858e+00,
+            1.3028940772873576e+00,
+            1.8147744500565022e+00,
+            4.5866808307366362e+00,
+            INFINITY,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert!(new!(2.0, 2.0).kurtosis().is_nan());
+        assert_eq!(new!(3.0, 2.0).kurtosis(), f64::INFINITY);
+        assert_eq!(new!(6.0, 2.0).kurtosis(), 19.0);
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(3.0, 2.0);
+        let x = vec![-40.0, 0.05, 4.0, 1000000.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -26.630776544664144,
+            -4.658883083359672,
+            -53.87574987073721,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(1.0, 2.0).mean(), f64::INFINITY);
+        assert_eq!(new!(3.0, 2.0).mean(), 1.0);
+    }
+
+    #[test]
+    fn median() {
+        assert::close(new!(3.0, 2.0).median(), 7.4792628638022429e-01, 1e-12);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(3.0, 2.0).modes(), vec![0.5]);
+    }
+
+    #[test]
+    fn sample() {
+        for x in Independent(&new!(3.0, 2.0), &mut source::default(42)).take(100) {
+            assert!(x > 0.0);
+        }
+    }
+
+    #[test]
+    fn skewness() {
+        assert!(new!(2.0, 2.0).skewness().is_nan());
+        assert_eq!(new!(3.0, 2.0).skewness(), f64::INFINITY);
+        assert::close(new!(6.0, 2.0).skewness(), 2.6666666666666665e+00, 1e-15);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Excluded, Unbounded};
+        assert_eq!(new!(3.0, 2.0).support(), (Excluded(0.0), Unbounded));
+    }
+
+    #[test]
+    fn try_mean() {
+        assert_eq!(new!(1.0, 2.0).try_mean(), None);
+        let d = new!(3.0, 2.0);
+        assert_eq!(d.try_mean(), Some(d.mean()));
+    }
+
+    #[test]
+    fn try_variance() {
+        assert_eq!(new!(2.0, 2.0).try_variance(), None);
+        let d = new!(3.0, 2.0);
+        assert_eq!(d.try_variance(), Some(d.variance()));
+    }
+
+    #[test]
+    fn variance() {
+        assert!(new!(1.0, 2.0).variance().is_nan());
+        assert_eq!(new!(2.0, 2.0).variance(), f64::INFINITY);
+        assert_eq!(new!(3.0, 2.0).variance(), 1.0);
+        assert::close(new!(6.0, 2.0).variance(), 0.04, 1e-15);
+    }
+}
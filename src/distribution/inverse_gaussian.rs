@@ -0,0 +1,368 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// An inverse Gaussian distribution, also known as the Wald distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct InverseGaussian {
+    mu: f64,
+    lambda: f64,
+}
+
+impl InverseGaussian {
+    /// Create an inverse Gaussian distribution with mean `mu` and shape
+    /// `lambda`.
+    ///
+    /// It should hold that `mu > 0` and `lambda > 0`.
+    #[inline]
+    pub fn new(mu: f64, lambda: f64) -> Self {
+        should!(mu > 0.0 && lambda > 0.0);
+        InverseGaussian { mu, lambda }
+    }
+
+    /// Return the mean parameter.
+    #[inline(always)]
+    pub fn mu(&self) -> f64 {
+        self.mu
+    }
+
+    /// Return the shape parameter.
+    #[inline(always)]
+    pub fn lambda(&self) -> f64 {
+        self.lambda
+    }
+}
+
+impl distribution::CharacteristicFunction for InverseGaussian {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::complex;
+        let &InverseGaussian { mu, lambda } = self;
+        let y = 2.0 * mu * mu * t / lambda;
+        let root = complex::sqrt((1.0, -y));
+        let (re, im) = complex::div((0.0, y), (1.0 + root.0, root.1));
+        complex::exp((lambda / mu * re, lambda / mu * im))
+    }
+}
+
+impl distribution::Continuous for InverseGaussian {
+    fn density(&self, x: f64) -> f64 {
+        use core::f64::consts::PI;
+        let &InverseGaussian { mu, lambda } = self;
+        if x <= 0.0 {
+            0.0
+        } else {
+            let z = x - mu;
+            (lambda / (2.0 * PI * x * x * x)).sqrt() * (-lambda * z * z / (2.0 * mu * mu * x)).exp()
+        }
+    }
+
+    fn log_density(&self, x: f64) -> f64 {
+        use core::f64::consts::PI;
+        let &InverseGaussian { mu, lambda } = self;
+        if x <= 0.0 {
+            f64::NEG_INFINITY
+        } else {
+            let z = x - mu;
+            0.5 * (lambda / (2.0 * PI * x * x * x)).ln() - lambda * z * z / (2.0 * mu * mu * x)
+        }
+    }
+}
+
+impl distribution::Distribution for InverseGaussian {
+    type Value = f64;
+
+    fn distribution(&self, x: f64) -> f64 {
+        use core::f64::consts::FRAC_1_SQRT_2;
+        use special::Error;
+        let &InverseGaussian { mu, lambda } = self;
+        if x <= 0.0 {
+            0.0
+        } else {
+            let scale = (lambda / x).sqrt() * FRAC_1_SQRT_2;
+            let a = 0.5 * (-scale * (x / mu - 1.0)).compl_error();
+            let b = 0.5 * (scale * (x / mu + 1.0)).compl_error();
+            a + (2.0 * lambda / mu).exp() * b
+        }
+    }
+}
+
+impl distribution::Inverse for InverseGaussian {
+    fn inverse(&self, p: f64) -> f64 {
+        distribution::numeric::inverse(self, p)
+    }
+}
+
+impl distribution::Kurtosis for InverseGaussian {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        15.0 * self.mu / self.lambda
+    }
+}
+
+impl distribution::Mean for InverseGaussian {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.mu
+    }
+}
+
+impl distribution::Mgf for InverseGaussian {
+    /// Compute the moment-generating function.
+    ///
+    /// The function is infinite if `t > lambda / (2 mu^2)`.
+    fn mgf(&self, t: f64) -> f64 {
+        let &InverseGaussian { mu, lambda } = self;
+        let x = 2.0 * mu * mu * t / lambda;
+        if x > 1.0 {
+            f64::INFINITY
+        } else {
+            (lambda / mu * (1.0 - (1.0 - x).sqrt())).exp()
+        }
+    }
+}
+
+impl distribution::Modes for InverseGaussian {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        let ratio = 1.5 * self.mu / self.lambda;
+        vec![self.mu * ((1.0 + ratio * ratio).sqrt() - ratio)]
+    }
+}
+
+impl distribution::Moments for InverseGaussian {
+    fn raw_moment(&self, n: usize) -> f64 {
+        let &InverseGaussian { mu, lambda } = self;
+        if n == 0 {
+            return 1.0;
+        }
+        let ratio = 0.5 * mu / lambda;
+        let (mut sum, mut term) = (0.0, 1.0);
+        for k in 0..n {
+            sum += term;
+            term *= ((n + k) * (n - 1 - k)) as f64 / (k + 1) as f64 * ratio;
+        }
+        mu.powi(n as i32) * sum
+    }
+}
+
+impl distribution::Sample for InverseGaussian {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained using the algorithm of Michael, Schucany, and
+    /// Haas.
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::gaussian;
+        let &InverseGaussian { mu, lambda } = self;
+        let z = gaussian::sample(source);
+        let y = mu * z * z;
+        let x = mu + 0.5 * mu / lambda * (y - (4.0 * lambda * y + y * y).sqrt());
+        if source.read::<f64>() * (mu + x) <= mu {
+            x
+        } else {
+            mu * mu / x
+        }
+    }
+}
+
+impl distribution::Skewness for InverseGaussian {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        3.0 * (self.mu / self.lambda).sqrt()
+    }
+}
+
+impl distribution::Support for InverseGaussian {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Excluded, Unbounded};
+        (Excluded(0.0), Unbounded)
+    }
+}
+
+impl distribution::Variance for InverseGaussian {
+    #[inline]
+    fn variance(&self) -> f64 {
+        self.mu * self.mu * self.mu / self.lambda
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($mu:expr, $lambda:expr) => (InverseGaussian::new($mu, $lambda));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(1.0, 2.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.707133424830696, 0.935580628613228, -0.0008743295509764966];
+        let im = vec![-0.5537125396810614, 0.2860820192319295, 0.5803984470140696];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(1.0, 2.0);
+        let x = vec![-1.0, 0.0, 0.1, 0.25, 0.5, 1.0, 1.5, 2.0, 3.0, 5.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            5.4155149644273216e-03,
+            4.7572115689451749e-01,
+            9.6788289807657335e-01,
+            5.6418958354775628e-01,
+            2.5995954096365487e-01,
+            1.2098536225957167e-01,
+            2.8620938625281114e-02,
+            2.0569688505407070e-03,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(1.0, 2.0);
+        let x = vec![-1.0, 0.0, 0.1, 0.25, 0.5, 1.0, 1.5, 2.0, 3.0, 5.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            5.2201467605742617e-05,
+            2.8056840414719935e-02,
+            2.3235718919184303e-01,
+            6.2769783815525282e-01,
+            8.2440795620513707e-01,
+            9.1504668132892897e-01,
+            9.7854357387388546e-01,
+            9.9832884816444212e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn inverse() {
+        use core::f64::INFINITY;
+
+        let d = new!(1.0, 2.0);
+        let p = vec![0.0, 0.01, 0.1, 0.25, 0.5, 0.75, 0.9, 0.99, 1.0];
+        let x = vec![
+            0.0,
+            0.2009995047711905,
+            0.35698511685306883,
+            0.5182380336431315,
+            0.8043390412960016,
+            1.2633532241599172,
+            1.8859953361265274,
+            3.5809303313709195,
+            INFINITY,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert_eq!(new!(1.0, 2.0).kurtosis(), 7.5);
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(1.0, 2.0);
+        let x = vec![-40.0, 0.05, 1.5, 400.0, 10000.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -14.12876653259371,
+            -1.3472292717536134,
+            -407.56206176358666,
+            -10012.387975500887,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(1.0, 2.0).mean(), 1.0);
+    }
+
+    #[test]
+    fn mgf() {
+        let d = new!(1.0, 2.0);
+        let t = vec![-0.7, -0.1, 0.2, 0.45];
+        let m = vec![
+            0.5446123825265765,
+            0.9069955852124588,
+            1.2350921936224695,
+            1.6765872065165255,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+        assert_eq!(d.mgf(1.5), f64::INFINITY);
+    }
+
+    #[test]
+    fn modes() {
+        assert::close(new!(1.0, 2.0).modes(), vec![0.5], 1e-15);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(1.0, 2.0);
+        let mut source = source::default(42);
+
+        let mean = (0..n).map(|_| d.sample(&mut source)).sum::<f64>() / n as f64;
+        assert!((mean - d.mean()).abs() < 0.01);
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(1.0, 2.0).skewness(), 2.1213203435596424e+00, 1e-15);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Excluded, Unbounded};
+        assert_eq!(new!(1.0, 2.0).support(), (Excluded(0.0), Unbounded));
+    }
+
+    #[test]
+    fn variance() {
+        assert_eq!(new!(1.0, 2.0).variance(), 0.5);
+    }
+}
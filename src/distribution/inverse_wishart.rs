@@ -0,0 +1,208 @@
+use alloc::vec::Vec;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// An inverse-Wishart distribution.
+///
+/// The distribution is supported on symmetric positive-definite matrices,
+/// which are stored in row-major order.
+#[derive(Clone, Debug)]
+pub struct InverseWishart {
+    nu: f64,
+    psi: Vec<f64>,
+    ln_norm: f64,
+    wishart: distribution::Wishart,
+}
+
+impl InverseWishart {
+    /// Create an inverse-Wishart distribution with `nu` degrees of freedom and
+    /// scale matrix `psi` of order `d` given in row-major order.
+    ///
+    /// It should hold that `psi` is a symmetric positive-definite matrix and
+    /// `nu > d - 1`.
+    pub fn new(nu: f64, psi: Vec<f64>) -> Self {
+        use core::f64::consts::LN_2;
+        use distribution::{cholesky, wishart};
+        let d = (psi.len() as f64).sqrt() as usize;
+        should!(d > 0 && psi.len() == d * d && nu > (d - 1) as f64);
+        let cholesky = cholesky::decompose(&psi, d);
+        should!(cholesky::is_positive_definite(&cholesky, d));
+        let ln_norm = 0.5 * nu * (d as f64 * LN_2 - cholesky::ln_det(&cholesky, d))
+            + wishart::ln_multivariate_gamma(0.5 * nu, d);
+        InverseWishart {
+            nu,
+            wishart: distribution::Wishart::new(nu, cholesky::invert(&cholesky, d)),
+            psi,
+            ln_norm,
+        }
+    }
+
+    /// Return the number of degrees of freedom.
+    #[inline(always)]
+    pub fn nu(&self) -> f64 {
+        self.nu
+    }
+
+    /// Return the scale matrix in row-major order.
+    #[inline(always)]
+    pub fn psi(&self) -> &[f64] {
+        &self.psi
+    }
+
+    /// Return the order of the matrices.
+    #[inline(always)]
+    pub fn dimension(&self) -> usize {
+        self.wishart.dimension()
+    }
+
+    /// Compute the probability density function.
+    #[inline]
+    pub fn density(&self, x: &[f64]) -> f64 {
+        self.log_density(x).exp()
+    }
+
+    /// Compute the natural logarithm of the probability density function.
+    ///
+    /// The logarithm is negative infinity if `x` is not positive definite.
+    pub fn log_density(&self, x: &[f64]) -> f64 {
+        use distribution::cholesky;
+        let d = self.dimension();
+        should!(x.len() == d * d);
+        let l = cholesky::decompose(x, d);
+        if !cholesky::is_positive_definite(&l, d) {
+            return f64::NEG_INFINITY;
+        }
+        let inverse = cholesky::invert(&l, d);
+        let trace = self
+            .psi
+            .iter()
+            .zip(&inverse)
+            .map(|(a, b)| a * b)
+            .sum::<f64>();
+        -0.5 * ((self.nu + d as f64 + 1.0) * cholesky::ln_det(&l, d) + trace) - self.ln_norm
+    }
+
+    /// Compute the mean matrix in row-major order.
+    ///
+    /// The diagonal elements are infinite and the off-diagonal ones are
+    /// undefined, which is signified by `NaN`, if `nu <= d + 1`.
+    pub fn mean(&self) -> Vec<f64> {
+        let d = self.dimension();
+        let excess = self.nu - d as f64 - 1.0;
+        self.psi
+            .iter()
+            .enumerate()
+            .map(|(k, psi)| {
+                if excess > 0.0 {
+                    psi / excess
+                } else if k / d == k % d {
+                    f64::INFINITY
+                } else {
+                    f64::NAN
+                }
+            })
+            .collect()
+    }
+
+    /// Draw a sample.
+    ///
+    /// The sample is obtained by inverting a draw from the Wishart
+    /// distribution with the inverse scale matrix.
+    pub fn sample<S>(&self, source: &mut S) -> Vec<f64>
+    where
+        S: Source,
+    {
+        use distribution::cholesky;
+        let d = self.dimension();
+        let x = self.wishart.sample(source);
+        cholesky::invert(&cholesky::decompose(&x, d), d)
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($nu:expr) => (InverseWishart::new($nu, vec![2.0, 0.5, 0.5, 1.0]));
+    );
+
+    #[test]
+    fn density() {
+        let d = new!(5.0);
+        let x = vec![
+            vec![8.0, 1.0, 1.0, 4.0],
+            vec![3.0, -1.0, -1.0, 2.0],
+            vec![10.0, 5.0, 5.0, 5.0],
+        ];
+        let p = vec![
+            4.5678936738580001e-08,
+            3.8629439296993475e-05,
+            1.0190268519067608e-07,
+        ];
+
+        assert::close(
+            &x.iter().map(|x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(5.0);
+        let x = vec![
+            vec![8.0, 1.0, 1.0, 4.0],
+            vec![3.0, -1.0, -1.0, 2.0],
+            vec![10.0, 5.0, 5.0, 5.0],
+        ];
+        let p = vec![
+            -1.6901628548170343e+01,
+            -1.0161495896095191e+01,
+            -1.6099247545831592e+01,
+        ];
+
+        assert::close(
+            &x.iter().map(|x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-13,
+        );
+        assert_eq!(d.log_density(&[1.0, 2.0, 2.0, 1.0]), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(5.0).mean(), vec![1.0, 0.25, 0.25, 0.5], 1e-15);
+
+        let mean = new!(3.0).mean();
+        assert_eq!(mean[0], f64::INFINITY);
+        assert!(mean[1].is_nan());
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(10.0);
+        let mut source = source::default(42);
+
+        let mut mean = vec![0.0; 4];
+        for _ in 0..n {
+            let x = d.sample(&mut source);
+            assert!(x[0] > 0.0 && x[3] > 0.0 && x[0] * x[3] > x[1] * x[2]);
+            for (mean, x) in mean.iter_mut().zip(&x) {
+                *mean += x / n as f64;
+            }
+        }
+        assert::close(&mean, &d.mean(), 0.01);
+    }
+}
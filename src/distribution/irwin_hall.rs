@@ -0,0 +1,372 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// An Irwin–Hall distribution.
+///
+/// The distribution is that of the sum of `n` independent random variables
+/// uniformly distributed on `[0, 1]`.
+#[derive(Clone, Copy, Debug)]
+pub struct IrwinHall {
+    n: usize,
+}
+
+impl IrwinHall {
+    /// Create an Irwin–Hall distribution with `n` summands.
+    ///
+    /// It should hold that `n > 0`.
+    #[inline]
+    pub fn new(n: usize) -> Self {
+        should!(n > 0);
+        IrwinHall { n }
+    }
+
+    /// Return the number of summands.
+    #[inline(always)]
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// Compute `sum_k (-1)^k C(n, k) (x - k)^m / m!` for `k <= x`.
+    fn series(&self, x: f64, m: usize) -> f64 {
+        let n = self.n;
+        let mut coefficient = 1.0;
+        let mut sum = 0.0;
+        for k in 0..(x.floor() as usize + 1).min(n + 1) {
+            sum += coefficient * (x - k as f64).powi(m as i32);
+            coefficient *= -((n - k) as f64) / (k + 1) as f64;
+        }
+        (2..(m + 1)).fold(sum, |sum, i| sum / i as f64)
+    }
+}
+
+impl distribution::CharacteristicFunction for IrwinHall {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use core::f64::consts::FRAC_PI_2;
+        let n = self.n;
+        let x = 0.5 * t;
+        let modulus = if x == 0.0 {
+            1.0
+        } else {
+            (x.sin() / x).powi(n as i32)
+        };
+        let phase = n as f64 * x;
+        (modulus * (FRAC_PI_2 - phase).sin(), modulus * phase.sin())
+    }
+}
+
+impl distribution::Continuous for IrwinHall {
+    fn density(&self, x: f64) -> f64 {
+        let n = self.n as f64;
+        if x < 0.0 || x > n {
+            0.0
+        } else {
+            self.series(x.min(n - x), self.n - 1)
+        }
+    }
+
+    /// Compute the natural logarithm of the probability density function.
+    ///
+    /// The series is scaled by its leading term `x^(n - 1) / (n - 1)!` so
+    /// that the result does not underflow near the ends of the support.
+    fn log_density(&self, x: f64) -> f64 {
+        use special::Gamma;
+        let n = self.n as f64;
+        if x < 0.0 || x > n {
+            return f64::NEG_INFINITY;
+        }
+        let m = self.n - 1;
+        if m == 0 {
+            return 0.0;
+        }
+        let x = x.min(n - x);
+        if x == 0.0 {
+            return f64::NEG_INFINITY;
+        }
+        let mut coefficient = 1.0;
+        let mut sum = 0.0;
+        for k in 0..(x.floor() as usize + 1).min(self.n + 1) {
+            sum += coefficient * (1.0 - k as f64 / x).powi(m as i32);
+            coefficient *= -((self.n - k) as f64) / (k + 1) as f64;
+        }
+        m as f64 * x.ln() - Gamma::ln_gamma(n).0 + sum.ln()
+    }
+}
+
+impl distribution::Distribution for IrwinHall {
+    type Value = f64;
+
+    fn distribution(&self, x: f64) -> f64 {
+        let n = self.n as f64;
+        if x <= 0.0 {
+            0.0
+        } else if x >= n {
+            1.0
+        } else if 2.0 * x <= n {
+            self.series(x, self.n)
+        } else {
+            1.0 - self.series(n - x, self.n)
+        }
+    }
+}
+
+impl distribution::Kurtosis for IrwinHall {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        -1.2 / self.n as f64
+    }
+}
+
+impl distribution::Mean for IrwinHall {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.n as f64 / 2.0
+    }
+}
+
+impl distribution::Median for IrwinHall {
+    #[inline]
+    fn median(&self) -> f64 {
+        self.n as f64 / 2.0
+    }
+}
+
+impl distribution::Mgf for IrwinHall {
+    fn mgf(&self, t: f64) -> f64 {
+        if t == 0.0 {
+            1.0
+        } else {
+            (t.exp_m1() / t).powi(self.n as i32)
+        }
+    }
+}
+
+impl distribution::Modes for IrwinHall {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        if self.n == 1 {
+            vec![]
+        } else {
+            vec![self.n as f64 / 2.0]
+        }
+    }
+}
+
+impl distribution::Moments for IrwinHall {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is computed by numerical integration.
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::{moments, Continuous, Mean, Variance};
+        moments::continuous(
+            |x| self.density(x),
+            n,
+            0.0,
+            self.n as f64,
+            self.mean(),
+            self.deviation(),
+        )
+    }
+}
+
+impl distribution::Sample for IrwinHall {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        (0..self.n).map(|_| source.read::<f64>()).sum()
+    }
+}
+
+impl distribution::Skewness for IrwinHall {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        0.0
+    }
+}
+
+impl distribution::Support for IrwinHall {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Included;
+        (Included(0.0), Included(self.n as f64))
+    }
+}
+
+impl distribution::Variance for IrwinHall {
+    #[inline]
+    fn variance(&self) -> f64 {
+        self.n as f64 / 12.0
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($n:expr) => (IrwinHall::new($n));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(4);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.15658553100484643, 0.8130388086001799, -0.3277159724626987];
+        let im = vec![
+            -0.9078647002932394,
+            0.5562297755736592,
+            -0.37943652748320067,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(4);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 3.5, 4.0, 5.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            2.0833333333333332e-02,
+            1.6666666666666666e-01,
+            4.7916666666666669e-01,
+            6.6666666666666663e-01,
+            4.7916666666666669e-01,
+            1.6666666666666666e-01,
+            2.0833333333333332e-02,
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+
+        assert_eq!(new!(1).density(0.5), 1.0);
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(4);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 3.5, 4.0, 5.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            2.6041666666666665e-03,
+            4.1666666666666664e-02,
+            2.0052083333333334e-01,
+            5.0000000000000000e-01,
+            7.9947916666666663e-01,
+            9.5833333333333337e-01,
+            9.9739583333333337e-01,
+            1.0000000000000000e+00,
+            1.0000000000000000e+00,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(4).kurtosis(), -0.3, 1e-15);
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(4);
+        let x = vec![-1.0, 1.5, 2.0, 3.5, 4.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -0.73570679497874124,
+            -0.40546510810816438,
+            -3.8712010109078909,
+            f64::NEG_INFINITY,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+
+        let d = new!(200);
+        assert::close(d.log_density(0.5), -995.86995875728655, 1e-11);
+        assert::close(d.log_density(1.5), -777.24611331233272, 1e-11);
+        assert::close(d.log_density(199.5), -995.86995875728655, 1e-11);
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(4).mean(), 2.0);
+    }
+
+    #[test]
+    fn median() {
+        assert_eq!(new!(4).median(), 2.0);
+    }
+
+    #[test]
+    fn mgf() {
+        let d = new!(4);
+        let t = vec![-0.7, -0.1, 0.2, 0.45];
+        let m = vec![
+            0.26749237025110834,
+            0.8200963282069584,
+            1.501800085858923,
+            2.543887007616436,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(4).modes(), vec![2.0]);
+        assert_eq!(new!(1).modes(), vec![]);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(4);
+        let mut source = source::default(42);
+
+        let mean = (0..n).map(|_| d.sample(&mut source)).sum::<f64>() / n as f64;
+        assert!((mean - d.mean()).abs() < 0.01);
+    }
+
+    #[test]
+    fn skewness() {
+        assert_eq!(new!(4).skewness(), 0.0);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::Included;
+        assert_eq!(new!(4).support(), (Included(0.0), Included(4.0)));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(4).variance(), 1.0 / 3.0, 1e-15);
+    }
+}
@@ -0,0 +1,420 @@
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A Johnson’s SU distribution.
+///
+/// The distribution is the distribution of `xi + lambda sinh((Z - gamma) /
+/// delta)` where `Z` is a standard Gaussian variate.
+#[derive(Clone, Copy, Debug)]
+pub struct JohnsonSU {
+    gamma: f64,
+    delta: f64,
+    xi: f64,
+    lambda: f64,
+}
+
+impl JohnsonSU {
+    /// Create a Johnson’s SU distribution with shape parameters `gamma` and
+    /// `delta`, location `xi`, and scale `lambda`.
+    ///
+    /// It should hold that `delta > 0` and `lambda > 0`.
+    #[inline]
+    pub fn new(gamma: f64, delta: f64, xi: f64, lambda: f64) -> Self {
+        should!(delta > 0.0 && lambda > 0.0);
+        JohnsonSU {
+            gamma,
+            delta,
+            xi,
+            lambda,
+        }
+    }
+
+    /// Return the first shape parameter.
+    #[inline(always)]
+    pub fn gamma(&self) -> f64 {
+        self.gamma
+    }
+
+    /// Return the second shape parameter.
+    #[inline(always)]
+    pub fn delta(&self) -> f64 {
+        self.delta
+    }
+
+    /// Return the location parameter.
+    #[inline(always)]
+    pub fn xi(&self) -> f64 {
+        self.xi
+    }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn lambda(&self) -> f64 {
+        self.lambda
+    }
+
+    /// Compute `E[Y^k]` where `Y = (X - xi) / lambda`.
+    ///
+    /// The power of the hyperbolic sine is expanded into exponentials, whose
+    /// expectations are those of a log-normal variate.
+    fn moment(&self, k: i32) -> f64 {
+        let omega = self.gamma / self.delta;
+        let half_variance = 0.5 / (self.delta * self.delta);
+        let mut sum = 0.0;
+        let mut binomial = 1.0;
+        for j in 0..=k {
+            let m = (k - 2 * j) as f64;
+            let term = binomial * (-m * omega + m * m * half_variance).exp();
+            sum += if j % 2 == 0 { term } else { -term };
+            binomial *= (k - j) as f64 / (j + 1) as f64;
+        }
+        sum / 2f64.powi(k)
+    }
+
+    /// Map a value to the underlying standard Gaussian variate.
+    #[inline]
+    fn standardize(&self, x: f64) -> f64 {
+        self.gamma + self.delta * asinh((x - self.xi) / self.lambda)
+    }
+}
+
+impl distribution::CharacteristicFunction for JohnsonSU {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Continuous, Inverse};
+        let (lower, upper) = (self.inverse(0.25), self.inverse(0.75));
+        characteristic::continuous(
+            |x| self.density(x),
+            t,
+            f64::NEG_INFINITY,
+            f64::INFINITY,
+            lower,
+            upper - lower,
+        )
+    }
+}
+
+impl distribution::Continuous for JohnsonSU {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        use core::f64::consts::PI;
+        let y = (x - self.xi) / self.lambda;
+        let z = self.standardize(x);
+        self.delta / (self.lambda * (2.0 * PI * (1.0 + y * y)).sqrt()) * (-0.5 * z * z).exp()
+    }
+
+    #[inline]
+    fn log_density(&self, x: f64) -> f64 {
+        use core::f64::consts::PI;
+        let y = (x - self.xi) / self.lambda;
+        let z = self.standardize(x);
+        (self.delta / self.lambda).ln() - 0.5 * (2.0 * PI * (1.0 + y * y)).ln() - 0.5 * z * z
+    }
+}
+
+impl distribution::Distribution for JohnsonSU {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        use core::f64::consts::FRAC_1_SQRT_2;
+        use special::Error;
+        0.5 * (-self.standardize(x) * FRAC_1_SQRT_2).compl_error()
+    }
+}
+
+impl distribution::Inverse for JohnsonSU {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        use distribution::gaussian;
+        should!((0.0..=1.0).contains(&p));
+        self.xi + self.lambda * sinh((gaussian::inverse(p) - self.gamma) / self.delta)
+    }
+}
+
+impl distribution::Kurtosis for JohnsonSU {
+    fn kurtosis(&self) -> f64 {
+        let (m1, m2, m3, m4) = (
+            self.moment(1),
+            self.moment(2),
+            self.moment(3),
+            self.moment(4),
+        );
+        let m1_2 = m1 * m1;
+        (m4 - 4.0 * m1 * m3 + 6.0 * m1_2 * m2 - 3.0 * m1_2 * m1_2) / (m2 - m1_2).powi(2) - 3.0
+    }
+}
+
+impl distribution::Mean for JohnsonSU {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.xi + self.lambda * self.moment(1)
+    }
+}
+
+impl distribution::Median for JohnsonSU {
+    #[inline]
+    fn median(&self) -> f64 {
+        self.xi - self.lambda * sinh(self.gamma / self.delta)
+    }
+}
+
+impl distribution::Moments for JohnsonSU {
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::moments;
+        moments::shift(|k| self.moment(k as i32), self.xi, self.lambda, n)
+    }
+}
+
+impl distribution::Sample for JohnsonSU {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::gaussian;
+        self.xi + self.lambda * sinh((gaussian::sample(source) - self.gamma) / self.delta)
+    }
+}
+
+impl distribution::Skewness for JohnsonSU {
+    fn skewness(&self) -> f64 {
+        let (m1, m2, m3) = (self.moment(1), self.moment(2), self.moment(3));
+        (m3 - 3.0 * m1 * m2 + 2.0 * m1 * m1 * m1) / (m2 - m1 * m1).powf(1.5)
+    }
+}
+
+impl distribution::Support for JohnsonSU {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Unbounded;
+        (Unbounded, Unbounded)
+    }
+}
+
+impl distribution::Variance for JohnsonSU {
+    #[inline]
+    fn variance(&self) -> f64 {
+        let w = (self.delta * self.delta).recip().exp();
+        let omega = 2.0 * self.gamma / self.delta;
+        let cosh = 0.5 * (omega.exp() + (-omega).exp());
+        0.5 * self.lambda * self.lambda * (w - 1.0) * (w * cosh + 1.0)
+    }
+}
+
+/// Compute the inverse hyperbolic sine.
+fn asinh(x: f64) -> f64 {
+    let y = x.abs();
+    let y = (y + y * y / (1.0 + (1.0 + y * y).sqrt())).ln_1p();
+    if x < 0.0 {
+        -y
+    } else {
+        y
+    }
+}
+
+/// Compute the hyperbolic sine.
+fn sinh(x: f64) -> f64 {
+    let y = x.exp_m1();
+    0.5 * y * (1.0 + 1.0 / (1.0 + y))
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($gamma:expr, $delta:expr, $xi:expr, $lambda:expr) => (
+            JohnsonSU::new($gamma, $delta, $xi, $lambda)
+        );
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(1.0, 2.0, 1.0, 2.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.6932521664431707, 0.9277234613614739, 0.1112466622144541];
+        let im = vec![
+            0.03239551073249052,
+            -0.04295365629917039,
+            0.05378410156117749,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(1.0, 2.0, 1.0, 2.0);
+        let x = vec![-10.0, -5.0, -2.0, -1.0, 0.0, 1.0, 2.0, 4.0, 8.0];
+        let p = vec![
+            4.9870994360197082e-05,
+            3.8997875120334187e-03,
+            8.4276022286916497e-02,
+            2.1089313368450679e-01,
+            3.5657299700821043e-01,
+            2.4197072451914334e-01,
+            5.2023299151955020e-02,
+            7.0825188574607243e-04,
+            5.7408280121348058e-07,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(1.0, 2.0, 1.0, 2.0);
+        let x = vec![-10.0, -5.0, -2.0, -1.0, 0.0, 1.0, 2.0, 4.0, 8.0];
+        let p = vec![
+            6.8890464140064042e-05,
+            4.1834619452984015e-03,
+            8.2336364162726217e-02,
+            2.2280709547864372e-01,
+            5.1498726766876435e-01,
+            8.4134474606854293e-01,
+            9.7514340922633869e-01,
+            9.9964993270926239e-01,
+            9.9999959187376797e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn inverse() {
+        use core::f64::{INFINITY, NEG_INFINITY};
+        let d = new!(1.0, 2.0, 1.0, 2.0);
+        let p = vec![
+            0.0, 0.01, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.99, 1.0,
+        ];
+        let x = vec![
+            NEG_INFINITY,
+            -4.0864936370507898e+00,
+            -1.8096239914218242e+00,
+            -1.1131291617400465e+00,
+            -6.7634758380117144e-01,
+            -3.3700879104029441e-01,
+            -4.2190610987494721e-02,
+            2.3588200797900377e-01,
+            5.1990539831124549e-01,
+            8.4145565055358174e-01,
+            1.2824824438871054e+00,
+            2.4257295176637159e+00,
+            INFINITY,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(
+            new!(1.0, 2.0, 1.0, 2.0).kurtosis(),
+            2.5869657812478080e+00,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(1.0, 2.0, 1.0, 2.0);
+        let x = vec![-40.0, 0.9, 1000000.0];
+        let p = vec![-24.602283332304708, -1.325224432045245, -423.9089287189721];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-11,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(
+            new!(1.0, 2.0, 1.0, 2.0).mean(),
+            -1.8095667864125020e-01,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn median() {
+        assert::close(
+            new!(1.0, 2.0, 1.0, 2.0).median(),
+            -4.2190610987494721e-02,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn moments() {
+        let d = new!(1.0, 2.0, 1.0, 2.0);
+        let m = vec![-85.167560057249406, 672.92373952988165, -6172.3458617272407];
+        assert::close(
+            &(5..8).map(|n| d.raw_moment(n)).collect::<Vec<_>>(),
+            &m,
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(1.0, 2.0, 1.0, 2.0);
+        let mut source = source::default(42);
+
+        let mean = (0..n).map(|_| d.sample(&mut source)).sum::<f64>() / n as f64;
+        assert!((mean - d.mean()).abs() < 0.02);
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(
+            new!(1.0, 2.0, 1.0, 2.0).skewness(),
+            -8.7448385180084420e-01,
+            1e-13,
+        );
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::Unbounded;
+        assert_eq!(new!(1.0, 2.0, 1.0, 2.0).support(), (Unbounded, Unbounded));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(
+            new!(1.0, 2.0, 1.0, 2.0).variance(),
+            1.6935610532233252e+00,
+            1e-14,
+        );
+    }
+}
@@ -0,0 +1,378 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A Kumaraswamy distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct Kumaraswamy {
+    a: f64,
+    b: f64,
+}
+
+impl Kumaraswamy {
+    /// Create a Kumaraswamy distribution with shape parameters `a` and `b`.
+    ///
+    /// It should hold that `a > 0` and `b > 0`.
+    #[inline]
+    pub fn new(a: f64, b: f64) -> Self {
+        should!(a > 0.0 && b > 0.0);
+        Kumaraswamy { a, b }
+    }
+
+    /// Return the first shape parameter.
+    #[inline(always)]
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+
+    /// Return the second shape parameter.
+    #[inline(always)]
+    pub fn b(&self) -> f64 {
+        self.b
+    }
+
+    #[inline]
+    fn moment(&self, n: f64) -> f64 {
+        use special::Beta;
+        (self.b.ln() + (1.0 + n / self.a).ln_beta(self.b)).exp()
+    }
+}
+
+impl distribution::CharacteristicFunction for Kumaraswamy {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Continuous};
+        characteristic::continuous(|x| self.density(x), t, 0.0, 1.0, 0.0, 0.0)
+    }
+}
+
+impl distribution::Continuous for Kumaraswamy {
+    fn density(&self, x: f64) -> f64 {
+        let &Kumaraswamy { a, b } = self;
+        if !(0.0..=1.0).contains(&x) {
+            0.0
+        } else {
+            let x_a = x.powf(a);
+            a * b * x.powf(a - 1.0) * (1.0 - x_a).powf(b - 1.0)
+        }
+    }
+
+    fn log_density(&self, x: f64) -> f64 {
+        let &Kumaraswamy { a, b } = self;
+        if !(0.0..=1.0).contains(&x) {
+            f64::NEG_INFINITY
+        } else if x == 0.0 || x == 1.0 {
+            self.density(x).ln()
+        } else {
+            (a * b).ln() + (a - 1.0) * x.ln() + (b - 1.0) * (-x.powf(a)).ln_1p()
+        }
+    }
+}
+
+impl distribution::Distribution for Kumaraswamy {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else if x >= 1.0 {
+            1.0
+        } else {
+            -(self.b * (-x.powf(self.a)).ln_1p()).exp_m1()
+        }
+    }
+}
+
+impl distribution::Entropy for Kumaraswamy {
+    fn entropy(&self) -> f64 {
+        use special::Gamma;
+        const EULER_MASCHERONI: f64 = 0.5772156649015329;
+        let &Kumaraswamy { a, b } = self;
+        let harmonic = (b + 1.0).digamma() + EULER_MASCHERONI;
+        (1.0 - 1.0 / b) + (1.0 - 1.0 / a) * harmonic - (a * b).ln()
+    }
+}
+
+impl distribution::Inverse for Kumaraswamy {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        should!((0.0..=1.0).contains(&p));
+        (-((-p).ln_1p() / self.b).exp_m1()).powf(1.0 / self.a)
+    }
+}
+
+impl distribution::Kurtosis for Kumaraswamy {
+    fn kurtosis(&self) -> f64 {
+        let (m1, m2, m3, m4) = (
+            self.moment(1.0),
+            self.moment(2.0),
+            self.moment(3.0),
+            self.moment(4.0),
+        );
+        let m1_2 = m1 * m1;
+        (m4 - 4.0 * m1 * m3 + 6.0 * m1_2 * m2 - 3.0 * m1_2 * m1_2) / (m2 - m1_2).powi(2) - 3.0
+    }
+}
+
+impl distribution::Mean for Kumaraswamy {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.moment(1.0)
+    }
+}
+
+impl distribution::Median for Kumaraswamy {
+    #[inline]
+    fn median(&self) -> f64 {
+        (1.0 - 2f64.powf(-1.0 / self.b)).powf(1.0 / self.a)
+    }
+}
+
+impl distribution::Modes for Kumaraswamy {
+    fn modes(&self) -> Vec<f64> {
+        let &Kumaraswamy { a, b } = self;
+        if a == 1.0 && b == 1.0 {
+            vec![]
+        } else if a < 1.0 && b < 1.0 {
+            vec![0.0, 1.0]
+        } else if a < 1.0 {
+            vec![0.0]
+        } else if b < 1.0 {
+            vec![1.0]
+        } else {
+            vec![((a - 1.0) / (a * b - 1.0)).powf(1.0 / a)]
+        }
+    }
+}
+
+impl distribution::Moments for Kumaraswamy {
+    fn raw_moment(&self, n: usize) -> f64 {
+        self.moment(n as f64)
+    }
+}
+
+impl distribution::Sample for Kumaraswamy {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::Inverse;
+        self.inverse(source.read::<f64>())
+    }
+}
+
+impl distribution::Skewness for Kumaraswamy {
+    fn skewness(&self) -> f64 {
+        let (m1, m2, m3) = (self.moment(1.0), self.moment(2.0), self.moment(3.0));
+        (m3 - 3.0 * m1 * m2 + 2.0 * m1 * m1 * m1) / (m2 - m1 * m1).powf(1.5)
+    }
+}
+
+impl distribution::Support for Kumaraswamy {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Included;
+        (Included(0.0), Included(1.0))
+    }
+}
+
+impl distribution::Variance for Kumaraswamy {
+    #[inline]
+    fn variance(&self) -> f64 {
+        let m1 = self.moment(1.0);
+        self.moment(2.0) - m1 * m1
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($a:expr, $b:expr) => (Kumaraswamy::new($a, $b));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(2.0, 5.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.9596401489901023, 0.9925160533611226, 0.6968746858365349];
+        let im = vec![
+            -0.25375211815186327,
+            0.11043946935604276,
+            0.6324407448585883,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(2.0, 5.0);
+        let x = vec![-0.5, 0.0, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.8, 1.0, 1.5];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            9.6059601000000006e-01,
+            1.6986931200000002e+00,
+            2.0572488299999998e+00,
+            1.9914854399999999e+00,
+            1.5820312500000000e+00,
+            1.0066329600000001e+00,
+            1.3436927999999990e-01,
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(2.0, 5.0);
+        let x = vec![-0.5, 0.0, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.8, 1.0, 1.5];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            4.9009950100000005e-02,
+            1.8462730240000003e-01,
+            3.7596785489999995e-01,
+            5.8178805760000007e-01,
+            7.6269531250000000e-01,
+            8.9262581759999993e-01,
+            9.9395338239999997e-01,
+            1.0000000000000000e+00,
+            1.0000000000000000e+00,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        use distribution::Uniform;
+        assert::close(new!(2.0, 5.0).entropy(), -3.6091842632737903e-01, 1e-14);
+        assert::close(
+            new!(1.0, 1.0).entropy(),
+            Uniform::new(0.0, 1.0).entropy(),
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn inverse() {
+        let d = new!(2.0, 5.0);
+        let p = vec![
+            0.0, 0.01, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.99, 1.0,
+        ];
+        let x = vec![
+            0.0000000000000000e+00,
+            4.4811252365607901e-02,
+            1.4440096135075836e-01,
+            2.0891984158993376e-01,
+            2.6239299705815761e-01,
+            3.1164009458438707e-01,
+            3.5979082354039527e-01,
+            4.0920263024341236e-01,
+            4.6259800518741667e-01,
+            5.2461446446157434e-01,
+            6.0748881102437335e-01,
+            7.7581752329172271e-01,
+            1.0000000000000000e+00,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(2.0, 5.0).kurtosis(), -5.3357878763522548e-01, 1e-12);
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(2.0, 5.0);
+        let x = vec![-40.0, 0.05, 0.5, 0.9];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -0.7031597014324191,
+            0.4587096226269769,
+            -4.445700249950384,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-13,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(2.0, 5.0).mean(), 3.6940836940836941e-01, 1e-15);
+    }
+
+    #[test]
+    fn median() {
+        assert::close(new!(2.0, 5.0).median(), 3.5979082354039527e-01, 1e-15);
+    }
+
+    #[test]
+    fn modes() {
+        assert!(new!(1.0, 1.0).modes().is_empty());
+        assert_eq!(new!(0.5, 0.5).modes(), vec![0.0, 1.0]);
+        assert_eq!(new!(0.5, 2.0).modes(), vec![0.0]);
+        assert_eq!(new!(2.0, 0.5).modes(), vec![1.0]);
+        assert::close(new!(2.0, 5.0).modes(), vec![1.0 / 3.0], 1e-15);
+    }
+
+    #[test]
+    fn sample() {
+        for x in Independent(&new!(2.0, 5.0), &mut source::default(42)).take(100) {
+            assert!((0.0..=1.0).contains(&x));
+        }
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(2.0, 5.0).skewness(), 2.5998099537695268e-01, 1e-13);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::Included;
+        assert_eq!(new!(2.0, 5.0).support(), (Included(0.0), Included(1.0)));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(2.0, 5.0).variance(), 3.0204123277716353e-02, 1e-15);
+    }
+}
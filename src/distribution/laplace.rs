@@ -1,4 +1,5 @@
 use alloc::{vec, vec::Vec};
+use core::ops::Bound;
 #[allow(unused_imports)]
 use special::Primitive;
 
@@ -23,24 +24,46 @@ impl Laplace {
         Laplace { mu, b }
     }
 
-    // Return the location parameter
+    /// Return the location parameter.
     #[inline(always)]
     pub fn mu(&self) -> f64 {
         self.mu
     }
 
-    // Return the scale parameter
+    /// Return the scale parameter.
     #[inline(always)]
     pub fn b(&self) -> f64 {
         self.b
     }
 }
 
+impl Default for Laplace {
+    #[inline]
+    fn default() -> Self {
+        Laplace::new(0.0, 1.0)
+    }
+}
+
+impl distribution::CharacteristicFunction for Laplace {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use core::f64::consts::FRAC_PI_2;
+        let x = self.b * t;
+        let modulus = 1.0 / (1.0 + x * x);
+        let phase = self.mu * t;
+        (modulus * (FRAC_PI_2 - phase).sin(), modulus * phase.sin())
+    }
+}
+
 impl distribution::Continuous for Laplace {
     #[inline]
     fn density(&self, x: f64) -> f64 {
         self.b.recip() * 0.5 * (-(x - self.mu).abs() / self.b).exp()
     }
+
+    #[inline]
+    fn log_density(&self, x: f64) -> f64 {
+        -(2.0 * self.b).ln() - (x - self.mu).abs() / self.b
+    }
 }
 
 impl distribution::Distribution for Laplace {
@@ -69,12 +92,12 @@ impl distribution::Inverse for Laplace {
         should!((0.0..=1.0).contains(&p));
         if p > 0.5 {
             if p == 1.0 {
-                return core::f64::INFINITY;
+                return f64::INFINITY;
             }
             self.mu - self.b * (2.0 - 2.0 * p).ln()
         } else {
             if p == 0.0 {
-                return core::f64::NEG_INFINITY;
+                return f64::NEG_INFINITY;
             }
             self.mu + self.b * (2.0 * p).ln()
         }
@@ -102,6 +125,20 @@ impl distribution::Median for Laplace {
     }
 }
 
+impl distribution::Mgf for Laplace {
+    /// Compute the moment-generating function.
+    ///
+    /// The function is infinite if `|t| >= 1 / b`.
+    fn mgf(&self, t: f64) -> f64 {
+        let x = self.b * t;
+        if x.abs() >= 1.0 {
+            f64::INFINITY
+        } else {
+            (self.mu * t).exp() / (1.0 - x * x)
+        }
+    }
+}
+
 impl distribution::Modes for Laplace {
     #[inline]
     fn modes(&self) -> Vec<f64> {
@@ -109,6 +146,20 @@ impl distribution::Modes for Laplace {
     }
 }
 
+impl distribution::Moments for Laplace {
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::moments;
+        let moment = |k: usize| {
+            if k % 2 == 1 {
+                0.0
+            } else {
+                (1..=k).fold(1.0, |product, j| product * j as f64)
+            }
+        };
+        moments::shift(moment, self.mu, self.b, n)
+    }
+}
+
 impl distribution::Sample for Laplace {
     #[inline]
     fn sample<S>(&self, source: &mut S) -> f64
@@ -126,6 +177,13 @@ impl distribution::Skewness for Laplace {
     }
 }
 
+impl distribution::Support for Laplace {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Unbounded;
+        (Unbounded, Unbounded)
+    }
+}
+
 impl distribution::Variance for Laplace {
     #[inline]
     fn variance(&self) -> f64 {
@@ -139,6 +197,11 @@ impl distribution::Variance for Laplace {
 }
 
 #[cfg(test)]
+#[allow(
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
 mod tests {
     use alloc::{vec, vec::Vec};
     use assert;
@@ -148,6 +211,26 @@ mod tests {
         ($mu:expr, $b:expr) => (Laplace::new($mu, $b));
     );
 
+    #[test]
+    fn cf() {
+        let d = new!(2.0, 1.5);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            0.08084049602865215,
+            0.6863497837086723,
+            -0.06536436208636119,
+        ];
+        let im = vec![
+            -0.46870379547608104,
+            0.4695571504324619,
+            -0.07568024953079283,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
     #[test]
     fn density() {
         let d = new!(2.0, 8.0);
@@ -234,6 +317,23 @@ mod tests {
         assert_eq!(new!(2.0, 9.0).kurtosis(), 3.0);
     }
 
+    #[test]
+    fn log_density() {
+        let d = new!(2.0, 1.5);
+        let x = vec![-40.0, 0.5, 400.0, 10000.0];
+        let p = vec![
+            -29.09861228866811,
+            -2.09861228866811,
+            -266.4319456220014,
+            -6666.431945622001,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-10,
+        );
+    }
+
     #[test]
     fn mean() {
         assert_eq!(new!(2.0, 1.0).mean(), 2.0);
@@ -244,16 +344,49 @@ mod tests {
         assert_eq!(new!(2.0, 1.0).median(), 2.0);
     }
 
+    #[test]
+    fn mgf() {
+        let d = new!(2.0, 1.5);
+        let t = vec![-0.6, -0.1, 0.2, 0.45];
+        let m = vec![
+            1.5852326942747474,
+            0.8375762179825901,
+            1.6393677996057918,
+            4.518214670322756,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+        assert_eq!(d.mgf(1.0), f64::INFINITY);
+    }
+
     #[test]
     fn modes() {
         assert_eq!(new!(2.0, 1.0).modes(), vec![2.0]);
     }
 
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(2.0, 3.0);
+        let mut source = source::default(42);
+
+        let cross_entropy = -(0..n)
+            .map(|_| d.density(d.sample(&mut source)).ln())
+            .sum::<f64>()
+            / n as f64;
+        assert!((cross_entropy - d.entropy()).abs() < 0.01);
+    }
+
     #[test]
     fn skewness() {
         assert_eq!(new!(2.0, 1.0).skewness(), 0.0);
     }
 
+    #[test]
+    fn support() {
+        use core::ops::Bound::Unbounded;
+        assert_eq!(new!(2.0, 1.5).support(), (Unbounded, Unbounded));
+    }
+
     #[test]
     fn variance() {
         assert_eq!(new!(2.0, 3.0).variance(), 18.0);
@@ -0,0 +1,343 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A Lévy distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct Levy {
+    mu: f64,
+    c: f64,
+}
+
+impl Levy {
+    /// Create a Lévy distribution with location `mu` and scale `c`.
+    ///
+    /// It should hold that `c > 0`.
+    #[inline]
+    pub fn new(mu: f64, c: f64) -> Self {
+        should!(c > 0.0);
+        Levy { mu, c }
+    }
+
+    /// Return the location parameter, which is also the left endpoint of the
+    /// support.
+    #[inline(always)]
+    pub fn mu(&self) -> f64 {
+        self.mu
+    }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn c(&self) -> f64 {
+        self.c
+    }
+}
+
+impl distribution::CharacteristicFunction for Levy {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::complex;
+        let root = (self.c * t.abs()).sqrt();
+        complex::exp((-root, self.mu * t + root.copysign(t)))
+    }
+}
+
+impl distribution::Continuous for Levy {
+    fn density(&self, x: f64) -> f64 {
+        use core::f64::consts::PI;
+        if x <= self.mu {
+            0.0
+        } else {
+            let z = x - self.mu;
+            (self.c / (2.0 * PI)).sqrt() * (-0.5 * self.c / z).exp() / z.powf(1.5)
+        }
+    }
+
+    fn log_density(&self, x: f64) -> f64 {
+        use core::f64::consts::PI;
+        if x <= self.mu {
+            f64::NEG_INFINITY
+        } else {
+            let z = x - self.mu;
+            0.5 * (self.c / (2.0 * PI)).ln() - 0.5 * self.c / z - 1.5 * z.ln()
+        }
+    }
+}
+
+impl distribution::Distribution for Levy {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        use special::Error;
+        if x <= self.mu {
+            0.0
+        } else {
+            (0.5 * self.c / (x - self.mu)).sqrt().compl_error()
+        }
+    }
+}
+
+impl distribution::Entropy for Levy {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        use core::f64::consts::PI;
+        const EULER_MASCHERONI: f64 = 0.5772156649015329;
+        0.5 * (1.0 + 3.0 * EULER_MASCHERONI + (16.0 * PI * self.c * self.c).ln())
+    }
+}
+
+impl distribution::Inverse for Levy {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        use distribution::gaussian;
+        should!((0.0..=1.0).contains(&p));
+        let z = gaussian::inverse(0.5 * p);
+        self.mu + self.c / (z * z)
+    }
+}
+
+impl distribution::Median for Levy {
+    #[inline]
+    fn median(&self) -> f64 {
+        use distribution::Inverse;
+        self.inverse(0.5)
+    }
+}
+
+impl distribution::Mgf for Levy {
+    /// Compute the moment-generating function.
+    ///
+    /// The function is infinite if `t > 0`.
+    fn mgf(&self, t: f64) -> f64 {
+        if t > 0.0 {
+            f64::INFINITY
+        } else {
+            (self.mu * t - (-2.0 * self.c * t).sqrt()).exp()
+        }
+    }
+}
+
+impl distribution::Modes for Levy {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![self.mu + self.c / 3.0]
+    }
+}
+
+impl distribution::Sample for Levy {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::gaussian;
+        let z = gaussian::sample(source);
+        self.mu + self.c / (z * z)
+    }
+}
+
+impl distribution::Support for Levy {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Excluded, Unbounded};
+        (Excluded(self.mu), Unbounded)
+    }
+}
+
+impl distribution::TryMean for Levy {
+    /// Compute the expected value.
+    ///
+    /// The expected value does not exist.
+    #[inline]
+    fn try_mean(&self) -> Option<f64> {
+        None
+    }
+}
+
+impl distribution::TryVariance for Levy {
+    /// Compute the variance.
+    ///
+    /// The variance does not exist.
+    #[inline]
+    fn try_variance(&self) -> Option<f64> {
+        None
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($mu:expr, $c:expr) => (Levy::new($mu, $c));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(1.0, 2.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![-0.0941425729049463, 0.21942354972311548, -0.088461044565382];
+        let im = vec![
+            -0.2914653416067883,
+            0.4053055156298177,
+            -0.10242208005667372,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(1.0, 2.0);
+        let x = vec![0.0, 1.0, 1.25, 1.5, 2.0, 3.0, 5.0, 10.0, 50.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            8.2667941416368212e-02,
+            2.1596386605275220e-01,
+            2.0755374871029736e-01,
+            1.2098536225957167e-01,
+            5.4923911183465297e-02,
+            1.8698482277616963e-02,
+            1.6116390838547094e-03,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(1.0, 2.0);
+        let x = vec![0.0, 1.0, 1.25, 1.5, 2.0, 3.0, 5.0, 10.0, 50.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            4.6777349810472662e-03,
+            4.5500263896358417e-02,
+            1.5729920705028513e-01,
+            3.1731050786291409e-01,
+            4.7950012218695348e-01,
+            6.3735188823393707e-01,
+            8.3989287327126882e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(1.0, 2.0).entropy(), 4.0176299819568353e+00, 1e-15);
+    }
+
+    #[test]
+    fn inverse() {
+        use core::f64::INFINITY;
+        let d = new!(1.0, 2.0);
+        let p = vec![
+            0.0, 0.01, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.99, 1.0,
+        ];
+        let x = vec![
+            1.0000000000000000e+00,
+            1.3014364986022795e+00,
+            1.7392230189363898e+00,
+            2.2177491207554896e+00,
+            2.8618607829563025e+00,
+            3.8235574448370939e+00,
+            5.3962186766354652e+00,
+            8.2728357641716332e+00,
+            1.4470565905987675e+01,
+            3.2160047434424257e+01,
+            1.2765623535403354e+02,
+            1.2732728770212439e+04,
+            INFINITY,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(1.0, 2.0);
+        let x = vec![-40.0, 1.5, 400.0, 1000000.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -1.532644172084782,
+            -9.558313333923655,
+            -21.29563027987136,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-13,
+        );
+    }
+
+    #[test]
+    fn median() {
+        assert::close(new!(1.0, 2.0).median(), 5.3962186766354652e+00, 1e-13);
+    }
+
+    #[test]
+    fn mgf() {
+        let d = new!(1.0, 2.0);
+        let t = vec![-0.7, -0.1, -0.01];
+        let m = vec![0.09317087994371571, 0.4807270988075366, 0.8105842459701871];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+        assert_eq!(d.mgf(0.1), f64::INFINITY);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(1.0, 3.0).modes(), vec![2.0]);
+    }
+
+    #[test]
+    fn sample() {
+        for x in Independent(&new!(1.0, 2.0), &mut source::default(42)).take(100) {
+            assert!(x >= 1.0);
+        }
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Excluded, Unbounded};
+        assert_eq!(new!(1.0, 2.0).support(), (Excluded(1.0), Unbounded));
+    }
+
+    #[test]
+    fn try_mean() {
+        assert_eq!(new!(0.0, 1.0).try_mean(), None);
+        assert_eq!(new!(-1.0, 2.5).try_mean(), None);
+    }
+
+    #[test]
+    fn try_variance() {
+        assert_eq!(new!(0.0, 1.0).try_variance(), None);
+        assert_eq!(new!(-1.0, 2.5).try_variance(), None);
+    }
+}
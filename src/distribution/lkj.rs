@@ -0,0 +1,200 @@
+use alloc::{vec, vec::Vec};
+#[allow(unused_imports)]
+use special::Primitive;
+
+use source::Source;
+
+/// A Lewandowski–Kurowicka–Joe distribution.
+///
+/// The distribution is supported on correlation matrices of order `d`, which
+/// are stored in row-major order, and has density proportional to
+/// `det(R)^(eta - 1)`.
+#[derive(Clone, Copy, Debug)]
+pub struct Lkj {
+    eta: f64,
+    d: usize,
+    ln_norm: f64,
+}
+
+impl Lkj {
+    /// Create a Lewandowski–Kurowicka–Joe distribution with shape `eta` over
+    /// correlation matrices of order `d`.
+    ///
+    /// It should hold that `eta > 0` and `d >= 2`.
+    pub fn new(eta: f64, d: usize) -> Self {
+        use core::f64::consts::LN_2;
+        use special::Beta;
+        should!(eta > 0.0 && d >= 2);
+        let mut ln_norm = 0.0;
+        for k in 1..d {
+            let m = (d - k) as f64;
+            let a = eta + 0.5 * (m - 1.0);
+            ln_norm += (2.0 * eta - 2.0 + m) * m * LN_2 + m * a.ln_beta(a);
+        }
+        Lkj { eta, d, ln_norm }
+    }
+
+    /// Return the shape parameter.
+    #[inline(always)]
+    pub fn eta(&self) -> f64 {
+        self.eta
+    }
+
+    /// Return the order of the matrices.
+    #[inline(always)]
+    pub fn d(&self) -> usize {
+        self.d
+    }
+
+    /// Compute the probability density function.
+    #[inline]
+    pub fn density(&self, x: &[f64]) -> f64 {
+        self.log_density(x).exp()
+    }
+
+    /// Compute the natural logarithm of the probability density function.
+    ///
+    /// The logarithm is negative infinity if `x` is not positive definite.
+    ///
+    /// ## References
+    ///
+    /// 1. D. Lewandowski, D. Kurowicka, and H. Joe, “Generating random
+    ///    correlation matrices based on vines and extended onion method,”
+    ///    Journal of Multivariate Analysis, vol. 100, no. 9, pp. 1989–2001,
+    ///    2009.
+    pub fn log_density(&self, x: &[f64]) -> f64 {
+        use distribution::cholesky;
+        let d = self.d;
+        should!(x.len() == d * d && (0..d).all(|i| x[i * d + i] == 1.0));
+        let l = cholesky::decompose(x, d);
+        if !cholesky::is_positive_definite(&l, d) {
+            return f64::NEG_INFINITY;
+        }
+        (self.eta - 1.0) * cholesky::ln_det(&l, d) - self.ln_norm
+    }
+
+    /// Draw a sample.
+    ///
+    /// The sample is obtained by the onion method, which grows the Cholesky
+    /// factor of the matrix one row at a time.
+    pub fn sample<S>(&self, source: &mut S) -> Vec<f64>
+    where
+        S: Source,
+    {
+        use distribution::{gamma, gaussian};
+        let d = self.d;
+        let mut beta = self.eta + 0.5 * (d as f64 - 2.0);
+        let mut l = vec![0.0; d * d];
+        l[0] = 1.0;
+        let x = gamma::sample(beta, source);
+        let r = 2.0 * x / (x + gamma::sample(beta, source)) - 1.0;
+        l[d] = r;
+        l[d + 1] = (1.0 - r * r).sqrt();
+        for k in 2..d {
+            beta -= 0.5;
+            let x = gamma::sample(0.5 * k as f64, source);
+            let y = x / (x + gamma::sample(beta, source));
+            let u = (0..k).map(|_| gaussian::sample(source)).collect::<Vec<_>>();
+            let norm = u.iter().map(|u| u * u).sum::<f64>().sqrt();
+            for (j, u) in u.iter().enumerate() {
+                l[k * d + j] = y.sqrt() * u / norm;
+            }
+            l[k * d + k] = (1.0 - y).sqrt();
+        }
+        let mut r = vec![0.0; d * d];
+        for i in 0..d {
+            r[i * d + i] = 1.0;
+            for j in 0..i {
+                let value = (0..=j).map(|k| l[i * d + k] * l[j * d + k]).sum();
+                r[i * d + j] = value;
+                r[j * d + i] = value;
+            }
+        }
+        r
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($eta:expr, $d:expr) => (Lkj::new($eta, $d));
+    );
+
+    #[test]
+    fn density() {
+        let d = new!(2.0, 3);
+        let x = vec![
+            vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0],
+            vec![1.0, 0.5, 0.2, 0.5, 1.0, -0.3, 0.2, -0.3, 1.0],
+            vec![1.0, 0.9, 0.8, 0.9, 1.0, 0.7, 0.8, 0.7, 1.0],
+        ];
+        let p = vec![
+            5.4037964609246814e-01,
+            3.0261260181178212e-01,
+            3.6745815934287815e-02,
+        ];
+
+        assert::close(
+            &x.iter().map(|x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(2.0, 3);
+        let x = vec![
+            vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0],
+            vec![1.0, 0.5, 0.2, 0.5, 1.0, -0.3, 0.2, -0.3, 1.0],
+            vec![1.0, 0.9, 0.8, 0.9, 1.0, 0.7, 0.8, 0.7, 1.0],
+        ];
+        let p = vec![
+            -6.1548333812712885e-01,
+            -1.1953018333800709e+00,
+            -3.3037309119331595e+00,
+        ];
+
+        assert::close(
+            &x.iter().map(|x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+        assert_eq!(
+            d.log_density(&[1.0, 0.9, -0.9, 0.9, 1.0, 0.9, -0.9, 0.9, 1.0]),
+            f64::NEG_INFINITY,
+        );
+
+        let x = vec![
+            1.0, 0.3, 0.0, 0.1, 0.3, 1.0, 0.2, 0.0, 0.0, 0.2, 1.0, -0.4, 0.1, 0.0, -0.4, 1.0,
+        ];
+        assert::close(new!(0.5, 4).log_density(&x), -3.7212424743847761e+00, 1e-14);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(2.0, 3);
+        let mut source = source::default(42);
+
+        let mut moments = vec![0.0; 3];
+        for _ in 0..n {
+            let x = d.sample(&mut source);
+            assert!(d.log_density(&x).is_finite());
+            assert!((0..3).all(|i| x[i * 3 + i] == 1.0));
+            moments[0] += x[1] * x[1] / n as f64;
+            moments[1] += x[2] * x[2] / n as f64;
+            moments[2] += x[5] * x[5] / n as f64;
+        }
+        assert::close(&moments, &vec![1.0 / 6.0; 3], 0.01);
+    }
+}
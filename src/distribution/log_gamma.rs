@@ -0,0 +1,338 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A log-gamma distribution.
+///
+/// The distribution is the distribution of the logarithm of a gamma variate.
+#[derive(Clone, Copy, Debug)]
+pub struct LogGamma {
+    k: f64,
+    theta: f64,
+    ln_norm: f64,
+}
+
+impl LogGamma {
+    /// Create a log-gamma distribution with shape parameter `k` and scale
+    /// parameter `theta` of the underlying gamma distribution.
+    ///
+    /// It should hold that `k > 0` and `theta > 0`.
+    #[inline]
+    pub fn new(k: f64, theta: f64) -> Self {
+        use special::Gamma;
+        should!(k > 0.0 && theta > 0.0);
+        LogGamma {
+            k,
+            theta,
+            ln_norm: Gamma::ln_gamma(k).0 + k * theta.ln(),
+        }
+    }
+
+    /// Return the shape parameter.
+    #[inline(always)]
+    pub fn k(&self) -> f64 {
+        self.k
+    }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn theta(&self) -> f64 {
+        self.theta
+    }
+}
+
+impl distribution::CharacteristicFunction for LogGamma {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::complex;
+        use special::Gamma;
+        if t == 0.0 {
+            return (1.0, 0.0);
+        }
+        let (re, im) = complex::ln_gamma((self.k, t));
+        complex::exp((re - Gamma::ln_gamma(self.k).0, im + t * self.theta.ln()))
+    }
+}
+
+impl distribution::Continuous for LogGamma {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        self.log_density(x).exp()
+    }
+
+    #[inline]
+    fn log_density(&self, x: f64) -> f64 {
+        self.k * x - x.exp() / self.theta - self.ln_norm
+    }
+}
+
+impl distribution::Distribution for LogGamma {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        use special::Gamma;
+        (x.exp() / self.theta).inc_gamma(self.k)
+    }
+}
+
+impl distribution::Entropy for LogGamma {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        use special::Gamma;
+        let k = self.k;
+        Gamma::ln_gamma(k).0 - k * k.digamma() + k
+    }
+}
+
+impl distribution::Kurtosis for LogGamma {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        use special::Gamma;
+        polygamma(3, self.k) / self.k.trigamma().powi(2)
+    }
+}
+
+impl distribution::Mean for LogGamma {
+    #[inline]
+    fn mean(&self) -> f64 {
+        use special::Gamma;
+        self.k.digamma() + self.theta.ln()
+    }
+}
+
+impl distribution::Modes for LogGamma {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![(self.k * self.theta).ln()]
+    }
+}
+
+impl distribution::Moments for LogGamma {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is computed from the cumulants, which are given by the
+    /// polygamma functions.
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::{moments, Mean};
+        let cumulant = |k: usize| {
+            if k == 1 {
+                self.mean()
+            } else {
+                polygamma(k - 1, self.k)
+            }
+        };
+        moments::cumulants(cumulant, n)
+    }
+}
+
+impl distribution::Sample for LogGamma {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::gamma;
+        (self.theta * gamma::sample(self.k, source)).ln()
+    }
+}
+
+impl distribution::Skewness for LogGamma {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        use special::Gamma;
+        polygamma(2, self.k) / self.k.trigamma().powf(1.5)
+    }
+}
+
+impl distribution::Support for LogGamma {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Unbounded;
+        (Unbounded, Unbounded)
+    }
+}
+
+impl distribution::Variance for LogGamma {
+    #[inline]
+    fn variance(&self) -> f64 {
+        use special::Gamma;
+        self.k.trigamma()
+    }
+}
+
+/// Compute the polygamma function of order `n >= 1`.
+///
+/// The argument is shifted upward by the recurrence relation, and the
+/// asymptotic expansion is then applied.
+fn polygamma(n: usize, x: f64) -> f64 {
+    const THRESHOLD: f64 = 20.0;
+    // The Bernoulli numbers B_2, B_4, …, B_14.
+    const BERNOULLI: [f64; 7] = [
+        1.0 / 6.0,
+        -1.0 / 30.0,
+        1.0 / 42.0,
+        -1.0 / 30.0,
+        5.0 / 66.0,
+        -691.0 / 2730.0,
+        7.0 / 6.0,
+    ];
+    let factorial = |k: usize| (1..=k).fold(1.0, |product, i| product * i as f64);
+    let (mut x, mut sum) = (x, 0.0);
+    while x < THRESHOLD {
+        sum += factorial(n) / x.powi(n as i32 + 1);
+        x += 1.0;
+    }
+    sum += factorial(n - 1) / x.powi(n as i32) + 0.5 * factorial(n) / x.powi(n as i32 + 1);
+    for (k, b) in BERNOULLI.iter().enumerate() {
+        let m = 2 * (k + 1);
+        sum += b * factorial(m + n - 1) / (factorial(m) * x.powi((m + n) as i32));
+    }
+    if n % 2 == 0 {
+        -sum
+    } else {
+        sum
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($k:expr, $theta:expr) => (LogGamma::new($k, $theta));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(2.5, 2.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.48725563636025077, 0.8932491134022422, -0.4180804496549088];
+        let im = vec![-0.7432244199323764, 0.3988445712534986, 0.03972044669094826];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(2.5, 2.0);
+        let x = vec![-4.0, -2.0, -1.0, 0.0, 0.5, 1.0, 1.5, 2.0, 3.0];
+        let p = vec![
+            5.9822809000152822e-06,
+            8.3739182775960743e-04,
+            9.0817303035508855e-03,
+            8.0656908173047784e-02,
+            2.0353618895180425e-01,
+            4.1615739533740786e-01,
+            6.0145993332964642e-01,
+            4.9062319406537058e-01,
+            1.0458719114183900e-02,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(2.5, 2.0);
+        let x = vec![-4.0, -2.0, -1.0, 0.0, 0.5, 1.0, 1.5, 2.0, 3.0];
+        let p = vec![
+            2.3991862257085716e-06,
+            3.4153124532056048e-04,
+            3.8316775977046535e-03,
+            3.7434226752703630e-02,
+            1.0470438301225045e-01,
+            2.5667907570271353e-01,
+            5.1766289019585077e-01,
+            8.0672408694719955e-01,
+            9.9879562917783649e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(2.5, 2.0).entropy(), 1.0267912688598111e+00, 1e-14);
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(2.5, 2.0).kurtosis(), 9.3119222294963055e-01, 1e-14);
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(2.5, 2.0);
+        let x = vec![-40.0, 0.3, 4.0, 10000.0];
+        let p = vec![
+            -102.01755082187279,
+            -1.9424802256607838,
+            -19.3166258384449,
+            f64::NEG_INFINITY,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(2.5, 2.0).mean(), 1.3963038212051886e+00, 1e-14);
+    }
+
+    #[test]
+    fn modes() {
+        assert::close(new!(2.5, 2.0).modes(), vec![1.6094379124341003e+00], 1e-15);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(2.5, 2.0);
+        let mut source = source::default(42);
+
+        let cross_entropy = -(0..n)
+            .map(|_| d.density(d.sample(&mut source)).ln())
+            .sum::<f64>()
+            / n as f64;
+        assert!((cross_entropy - d.entropy()).abs() < 0.01);
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(2.5, 2.0).skewness(), -6.8788805692658372e-01, 1e-14);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::Unbounded;
+        assert_eq!(new!(2.5, 2.0).support(), (Unbounded, Unbounded));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(2.5, 2.0).variance(), 4.9035775610023485e-01, 1e-14);
+    }
+}
@@ -0,0 +1,485 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A log-logistic distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct LogLogistic {
+    alpha: f64,
+    beta: f64,
+}
+
+impl LogLogistic {
+    /// Create a log-logistic distribution with scale `alpha` and shape `beta`.
+    ///
+    /// It should hold that `alpha > 0` and `beta > 0`.
+    #[inline]
+    pub fn new(alpha: f64, beta: f64) -> Self {
+        should!(alpha > 0.0 && beta > 0.0);
+        LogLogistic { alpha, beta }
+    }
+
+    /// Return the scale parameter, which is also the median.
+    #[inline(always)]
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Return the shape parameter.
+    #[inline(always)]
+    pub fn beta(&self) -> f64 {
+        self.beta
+    }
+
+    #[inline]
+    fn moment(&self, i: f64) -> f64 {
+        use special::Gamma;
+        let b = i / self.beta;
+        (Gamma::ln_gamma(1.0 + b).0 + Gamma::ln_gamma(1.0 - b).0).exp()
+    }
+}
+
+impl distribution::CharacteristicFunction for LogLogistic {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Continuous, Inverse};
+        let (lower, upper) = (self.inverse(0.25), self.inverse(0.75));
+        characteristic::continuous(
+            |x| self.density(x),
+            t,
+            0.0,
+            f64::INFINITY,
+            lower,
+            upper - lower,
+        )
+    }
+}
+
+impl distribution::Continuous for LogLogistic {
+    fn density(&self, x: f64) -> f64 {
+        let &LogLogistic { alpha, beta } = self;
+        if x < 0.0 {
+            0.0
+        } else if x == 0.0 {
+            if beta < 1.0 {
+                f64::INFINITY
+            } else if beta == 1.0 {
+                1.0 / alpha
+            } else {
+                0.0
+            }
+        } else {
+            let z = x / alpha;
+            let z_beta = z.powf(beta);
+            beta / alpha * z_beta / z / (1.0 + z_beta).powi(2)
+        }
+    }
+
+    fn log_density(&self, x: f64) -> f64 {
+        let &LogLogistic { alpha, beta } = self;
+        if x < 0.0 {
+            f64::NEG_INFINITY
+        } else if x == 0.0 {
+            if beta < 1.0 {
+                f64::INFINITY
+            } else if beta == 1.0 {
+                -alpha.ln()
+            } else {
+                f64::NEG_INFINITY
+            }
+        } else {
+            let ln_z = (x / alpha).ln();
+            let ln_z_beta = beta * ln_z;
+            let ln_1p_z_beta = if ln_z_beta > 0.0 {
+                ln_z_beta + (-ln_z_beta).exp().ln_1p()
+            } else {
+                ln_z_beta.exp().ln_1p()
+            };
+            (beta / alpha).ln() + ln_z_beta - ln_z - 2.0 * ln_1p_z_beta
+        }
+    }
+}
+
+impl distribution::Distribution for LogLogistic {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else {
+            1.0 / (1.0 + (x / self.alpha).powf(-self.beta))
+        }
+    }
+}
+
+impl distribution::Entropy for LogLogistic {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        (self.alpha / self.beta).ln() + 2.0
+    }
+}
+
+impl distribution::Inverse for LogLogistic {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        should!((0.0..=1.0).contains(&p));
+        self.alpha * (p / (1.0 - p)).powf(1.0 / self.beta)
+    }
+}
+
+impl distribution::Kurtosis for LogLogistic {
+    /// Compute the excess kurtosis.
+    ///
+    /// The excess kurtosis is infinite if `2 < beta <= 4` and undefined,
+    /// which is signified by `NaN`, if `beta <= 2`.
+    fn kurtosis(&self) -> f64 {
+        if self.beta <= 2.0 {
+            return f64::NAN;
+        }
+        if self.beta <= 4.0 {
+            return f64::INFINITY;
+        }
+        let (g1, g2, g3, g4) = (
+            self.moment(1.0),
+            self.moment(2.0),
+            self.moment(3.0),
+            self.moment(4.0),
+        );
+        let g1_2 = g1 * g1;
+        (g4 - 4.0 * g1 * g3 + 6.0 * g1_2 * g2 - 3.0 * g1_2 * g1_2) / (g2 - g1_2).powi(2) - 3.0
+    }
+}
+
+impl distribution::Mean for LogLogistic {
+    /// Compute the expected value.
+    ///
+    /// The expected value is infinite if `beta <= 1`.
+    #[inline]
+    fn mean(&self) -> f64 {
+        if self.beta <= 1.0 {
+            f64::INFINITY
+        } else {
+            self.alpha * self.moment(1.0)
+        }
+    }
+}
+
+impl distribution::Median for LogLogistic {
+    #[inline]
+    fn median(&self) -> f64 {
+        self.alpha
+    }
+}
+
+impl distribution::Modes for LogLogistic {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        let &LogLogistic { alpha, beta } = self;
+        if beta <= 1.0 {
+            vec![0.0]
+        } else {
+            vec![alpha * ((beta - 1.0) / (beta + 1.0)).powf(1.0 / beta)]
+        }
+    }
+}
+
+impl distribution::Moments for LogLogistic {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is infinite if `n >= beta`.
+    fn raw_moment(&self, n: usize) -> f64 {
+        if n as f64 >= self.beta {
+            f64::INFINITY
+        } else {
+            self.alpha.powi(n as i32) * self.moment(n as f64)
+        }
+    }
+}
+
+impl distribution::Sample for LogLogistic {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::Inverse;
+        self.inverse(source.read::<f64>())
+    }
+}
+
+impl distribution::Skewness for LogLogistic {
+    /// Compute the skewness.
+    ///
+    /// The skewness is infinite if `2 < beta <= 3` and undefined, which is
+    /// signified by `NaN`, if `beta <= 2`.
+    fn skewness(&self) -> f64 {
+        if self.beta <= 2.0 {
+            return f64::NAN;
+        }
+        if self.beta <= 3.0 {
+            return f64::INFINITY;
+        }
+        let (g1, g2, g3) = (self.moment(1.0), self.moment(2.0), self.moment(3.0));
+        (g3 - 3.0 * g1 * g2 + 2.0 * g1 * g1 * g1) / (g2 - g1 * g1).powf(1.5)
+    }
+}
+
+impl distribution::Support for LogLogistic {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Included, Unbounded};
+        (Included(0.0), Unbounded)
+    }
+}
+
+impl distribution::TryMean for LogLogistic {
+    /// Compute the expected value.
+    ///
+    /// The expected value does not exist if `beta <= 1`.
+    #[inline]
+    fn try_mean(&self) -> Option<f64> {
+        use distribution::Mean;
+        if self.beta > 1.0 {
+            Some(self.mean())
+        } else {
+            None
+        }
+    }
+}
+
+impl distribution::TryVariance for LogLogistic {
+    /// Compute the variance.
+    ///
+    /// The variance does not exist if `beta <= 2`.
+    #[inline]
+    fn try_variance(&self) -> Option<f64> {
+        use distribution::Variance;
+        if self.beta > 2.0 {
+            Some(self.variance())
+        } else {
+            None
+        }
+    }
+}
+
+impl distribution::Variance for LogLogistic {
+    /// Compute the variance.
+    ///
+    /// The variance is infinite if `beta <= 2`.
+    #[inline]
+    fn variance(&self) -> f64 {
+        if self.beta <= 2.0 {
+            f64::INFINITY
+        } else {
+            let g1 = self.moment(1.0);
+            self.alpha * self.alpha * (self.moment(2.0) - g1 * g1)
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($alpha:expr, $beta:expr) => (LogLogistic::new($alpha, $beta));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(2.0, 5.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.10282593349920108, 0.7805784453156002, -0.3372396649837919];
+        let im = vec![-0.8614747086295539, 0.5765853940781688, -0.2893999154936871];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(2.0, 5.0);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 4.0, 6.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            9.7465794170136823e-03,
+            1.4692378328741965e-01,
+            5.1669200997452791e-01,
+            6.2500000000000000e-01,
+            3.7178606198452080e-01,
+            1.7137190082644629e-01,
+            3.6730945821854911e-02,
+            3.4013034130610052e-03,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+
+        assert_eq!(new!(2.0, 1.0).density(0.0), 0.5);
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(2.0, 5.0);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 4.0, 6.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            9.7560975609756097e-04,
+            3.0303030303030304e-02,
+            1.9179163378058406e-01,
+            5.0000000000000000e-01,
+            7.5319354061219568e-01,
+            8.8363636363636366e-01,
+            9.6969696969696972e-01,
+            9.9590163934426235e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(2.0, 5.0).entropy(), 1.0837092681258449e+00, 1e-15);
+    }
+
+    #[test]
+    fn inverse() {
+        use core::f64::INFINITY;
+        let d = new!(2.0, 5.0);
+        let p = vec![
+            0.0, 0.01, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.99, 1.0,
+        ];
+        let x = vec![
+            0.0000000000000000e+00,
+            7.9781639499344836e-01,
+            1.2887880299545085e+00,
+            1.5157165665103982e+00,
+            1.6882417596882202e+00,
+            1.8442158229634555e+00,
+            2.0000000000000000e+00,
+            2.1689435423953971e+00,
+            2.3693289050844881e+00,
+            2.6390158215457888e+00,
+            3.1036911478307196e+00,
+            5.0136848842681996e+00,
+            INFINITY,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(2.0, 5.0).kurtosis(), 2.6556191909249179e+01, 1e-10);
+        assert_eq!(new!(2.0, 4.0).kurtosis(), f64::INFINITY);
+        assert!(new!(2.0, 2.0).kurtosis().is_nan());
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(2.0, 5.0);
+        let x = vec![-40.0, 0.05, 4.0, 1000000.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -13.839227104112839,
+            -3.3041356688190247,
+            -77.81788953255182,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(2.0, 5.0).mean(), 2.1379186642311900e+00, 1e-14);
+        assert_eq!(new!(2.0, 1.0).mean(), f64::INFINITY);
+    }
+
+    #[test]
+    fn median() {
+        assert_eq!(new!(2.0, 5.0).median(), 2.0);
+    }
+
+    #[test]
+    fn modes() {
+        assert::close(new!(2.0, 5.0).modes(), vec![1.8442158229634555e+00], 1e-15);
+        assert_eq!(new!(2.0, 1.0).modes(), vec![0.0]);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(2.0, 5.0);
+        let mut source = source::default(42);
+
+        let cross_entropy = -(0..n)
+            .map(|_| d.density(d.sample(&mut source)).ln())
+            .sum::<f64>()
+            / n as f64;
+        assert!((cross_entropy - d.entropy()).abs() < 0.01);
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(2.0, 5.0).skewness(), 2.4852755496867189e+00, 1e-12);
+        assert_eq!(new!(2.0, 3.0).skewness(), f64::INFINITY);
+        assert!(new!(2.0, 2.0).skewness().is_nan());
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Included, Unbounded};
+        assert_eq!(new!(2.0, 5.0).support(), (Included(0.0), Unbounded));
+    }
+
+    #[test]
+    fn try_mean() {
+        assert_eq!(new!(1.0, 1.0).try_mean(), None);
+        let d = new!(2.0, 3.0);
+        assert_eq!(d.try_mean(), Some(d.mean()));
+    }
+
+    #[test]
+    fn try_variance() {
+        assert_eq!(new!(1.0, 2.0).try_variance(), None);
+        let d = new!(2.0, 3.0);
+        assert_eq!(d.try_variance(), Some(d.variance()));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(2.0, 5.0).variance(), 7.1452938384252185e-01, 1e-13);
+        assert_eq!(new!(2.0, 2.0).variance(), f64::INFINITY);
+    }
+}
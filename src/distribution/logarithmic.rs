@@ -0,0 +1,348 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A logarithmic distribution, also known as the log-series distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct Logarithmic {
+    p: f64,
+    ln_q: f64,
+}
+
+impl Logarithmic {
+    /// Create a logarithmic distribution with parameter `p`.
+    ///
+    /// It should hold that `p > 0` and `p < 1`.
+    #[inline]
+    pub fn new(p: f64) -> Self {
+        should!(p > 0.0 && p < 1.0);
+        Logarithmic {
+            p,
+            ln_q: (-p).ln_1p(),
+        }
+    }
+
+    /// Return the parameter.
+    #[inline(always)]
+    pub fn p(&self) -> f64 {
+        self.p
+    }
+
+    /// Compute the `k`th raw moment for `k <= 4`.
+    fn moment(&self, k: i32) -> f64 {
+        let p = self.p;
+        let polynomial = match k {
+            1 | 2 => 1.0,
+            3 => 1.0 + p,
+            _ => 1.0 + p * (4.0 + p),
+        };
+        -p * polynomial / ((1.0 - p).powi(k) * self.ln_q)
+    }
+}
+
+impl distribution::CharacteristicFunction for Logarithmic {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use core::f64::consts::FRAC_PI_2;
+        use distribution::complex;
+        let p = self.p;
+        let cos = (FRAC_PI_2 - t).sin();
+        let re = 0.5 * (p * (p - 2.0 * cos)).ln_1p();
+        let im = complex::arg((1.0 - p * cos, -p * t.sin()));
+        (re / self.ln_q, im / self.ln_q)
+    }
+}
+
+impl distribution::Discrete for Logarithmic {
+    #[inline]
+    fn mass(&self, x: usize) -> f64 {
+        if x < 1 {
+            0.0
+        } else {
+            let k = x as f64;
+            -(k * self.p.ln()).exp() / (k * self.ln_q)
+        }
+    }
+
+    #[inline]
+    fn log_mass(&self, x: usize) -> f64 {
+        if x < 1 {
+            f64::NEG_INFINITY
+        } else {
+            let k = x as f64;
+            k * self.p.ln() - k.ln() - (-self.ln_q).ln()
+        }
+    }
+}
+
+impl distribution::Distribution for Logarithmic {
+    type Value = usize;
+
+    /// Compute the cumulative distribution function.
+    ///
+    /// The complementary probability is computed by summing the tail of the
+    /// series until the terms become negligible.
+    fn distribution(&self, x: f64) -> f64 {
+        if x < 1.0 {
+            return 0.0;
+        }
+        let mut k = x.floor() + 1.0;
+        let mut power = (k * self.p.ln()).exp();
+        let mut sum = 0.0;
+        loop {
+            let term = power / k;
+            sum += term;
+            if term <= f64::EPSILON * sum {
+                break;
+            }
+            power *= self.p;
+            k += 1.0;
+        }
+        (1.0 + sum / self.ln_q).max(0.0)
+    }
+}
+
+impl distribution::Kurtosis for Logarithmic {
+    fn kurtosis(&self) -> f64 {
+        let (m1, m2, m3, m4) = (
+            self.moment(1),
+            self.moment(2),
+            self.moment(3),
+            self.moment(4),
+        );
+        let variance = m2 - m1 * m1;
+        (m4 - 4.0 * m1 * m3 + 6.0 * m1 * m1 * m2 - 3.0 * m1.powi(4)) / (variance * variance) - 3.0
+    }
+}
+
+impl distribution::Mean for Logarithmic {
+    #[inline]
+    fn mean(&self) -> f64 {
+        -self.p / ((1.0 - self.p) * self.ln_q)
+    }
+}
+
+impl distribution::Mgf for Logarithmic {
+    /// Compute the moment-generating function.
+    ///
+    /// The function is infinite if `t >= -ln(p)`.
+    fn mgf(&self, t: f64) -> f64 {
+        if t >= -self.p.ln() {
+            f64::INFINITY
+        } else {
+            (-self.p * t.exp()).ln_1p() / self.ln_q
+        }
+    }
+}
+
+impl distribution::Modes for Logarithmic {
+    #[inline]
+    fn modes(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+impl distribution::Moments for Logarithmic {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is computed by summing the probability mass function.
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::{moments, Discrete, Mean};
+        moments::discrete(|x| self.mass(x), n, 1, usize::MAX, self.mean())
+    }
+}
+
+impl distribution::Sample for Logarithmic {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained by Kemp’s second algorithm, which inverts a
+    /// mixture of geometric distributions.
+    ///
+    /// ## References
+    ///
+    /// 1. A. W. Kemp, “Efficient generation of logarithmically distributed
+    ///    pseudo-random variables,” Journal of the Royal Statistical Society.
+    ///    Series C (Applied Statistics), vol. 30, no. 3, pp. 249–253, 1981.
+    fn sample<S>(&self, source: &mut S) -> usize
+    where
+        S: Source,
+    {
+        let v = source.read::<f64>();
+        if v >= self.p {
+            return 1;
+        }
+        let q = -(self.ln_q * source.read::<f64>()).exp_m1();
+        if v <= q * q {
+            (1.0 + v.ln() / q.ln()).floor() as usize
+        } else if v <= q {
+            2
+        } else {
+            1
+        }
+    }
+}
+
+impl distribution::Skewness for Logarithmic {
+    fn skewness(&self) -> f64 {
+        let (m1, m2, m3) = (self.moment(1), self.moment(2), self.moment(3));
+        let variance = m2 - m1 * m1;
+        (m3 - 3.0 * m1 * m2 + 2.0 * m1.powi(3)) / variance.powf(1.5)
+    }
+}
+
+impl distribution::Support for Logarithmic {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Included, Unbounded};
+        (Included(1.0), Unbounded)
+    }
+}
+
+impl distribution::Variance for Logarithmic {
+    #[inline]
+    fn variance(&self) -> f64 {
+        let (p, ln_q) = (self.p, self.ln_q);
+        -p * (p + ln_q) / ((1.0 - p).powi(2) * ln_q * ln_q)
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($p:expr) => (Logarithmic::new($p));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(0.5);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.5217369948363321, 0.881429838454461, -0.3682577750886528];
+        let im = vec![-0.6935786052449198, 0.39772571582153343, 0.5192932619411642];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(0.7);
+        let x = vec![0.0, 1.0, 1.5, 2.0, 3.0, 10.0, 50.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            5.8140848155777614e-01,
+            5.8140848155777614e-01,
+            7.8490145010299783e-01,
+            8.7986483542410121e-01,
+            9.9575329570862903e-01,
+            9.9999999934484030e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn log_mass() {
+        let d = new!(0.5);
+        let x = vec![0, 1, 5, 100, 10000];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -0.32663425997828094,
+            -4.708660894652162,
+            -73.55337532140095,
+            -6940.315633050847,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_mass(x)).collect::<Vec<_>>(),
+            &p,
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn mass() {
+        let d = new!(0.7);
+        let x = vec![0, 1, 2, 3, 5, 10, 50];
+        let p = vec![
+            0.0000000000000000e+00,
+            5.8140848155777614e-01,
+            2.0349296854522164e-01,
+            9.4963385321103433e-02,
+            2.7919235284404410e-02,
+            2.3461929371249245e-03,
+            2.9875509416582085e-10,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.mass(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(0.7).kurtosis(), 1.4423790814671920e+01, 1e-12);
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(0.7).mean(), 1.9380282718592539e+00, 1e-15);
+    }
+
+    #[test]
+    fn mgf() {
+        let d = new!(0.5);
+        let t = vec![-0.7, -0.1, 0.2, 0.45];
+        let m = vec![
+            0.4117569883223324,
+            0.868854939505131,
+            1.3610508609022007,
+            2.211939725262317,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+        assert_eq!(d.mgf(1.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(0.7).modes(), vec![1]);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(0.7);
+        let mut source = source::default(42);
+
+        let mean = (0..n).map(|_| d.sample(&mut source) as f64).sum::<f64>() / n as f64;
+        assert!((mean - d.mean()).abs() < 0.05);
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(0.7).skewness(), 3.0597437760944834e+00, 1e-13);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Included, Unbounded};
+        assert_eq!(new!(0.5).support(), (Included(1.0), Unbounded));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(0.7).variance(), 2.7041406570050803e+00, 1e-14);
+    }
+}
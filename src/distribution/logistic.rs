@@ -1,4 +1,5 @@
 use alloc::{vec, vec::Vec};
+use core::ops::Bound;
 #[allow(unused_imports)]
 use special::Primitive;
 
@@ -42,12 +43,32 @@ impl Default for Logistic {
     }
 }
 
+impl distribution::CharacteristicFunction for Logistic {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use core::f64::consts::{FRAC_PI_2, PI};
+        let x = PI * self.s * t.abs();
+        let modulus = if x == 0.0 {
+            1.0
+        } else {
+            -2.0 * x * (-x).exp() / (-2.0 * x).exp_m1()
+        };
+        let phase = self.mu * t;
+        (modulus * (FRAC_PI_2 - phase).sin(), modulus * phase.sin())
+    }
+}
+
 impl distribution::Continuous for Logistic {
     #[inline]
     fn density(&self, x: f64) -> f64 {
-        let exp = (-(x - self.mu) / self.s).exp();
+        let exp = (-(x - self.mu).abs() / self.s).exp();
         exp / (self.s * (1.0 + exp).powi(2))
     }
+
+    #[inline]
+    fn log_density(&self, x: f64) -> f64 {
+        let z = (x - self.mu).abs() / self.s;
+        -z - self.s.ln() - 2.0 * (-z).exp().ln_1p()
+    }
 }
 
 impl distribution::Distribution for Logistic {
@@ -95,6 +116,23 @@ impl distribution::Median for Logistic {
     }
 }
 
+impl distribution::Mgf for Logistic {
+    /// Compute the moment-generating function.
+    ///
+    /// The function is infinite if `|t| >= 1 / s`.
+    fn mgf(&self, t: f64) -> f64 {
+        use core::f64::consts::PI;
+        let x = PI * self.s * t;
+        if t == 0.0 {
+            1.0
+        } else if x.abs() >= PI {
+            f64::INFINITY
+        } else {
+            (self.mu * t).exp() * x / x.sin()
+        }
+    }
+}
+
 impl distribution::Modes for Logistic {
     #[inline]
     fn modes(&self) -> Vec<f64> {
@@ -102,6 +140,29 @@ impl distribution::Modes for Logistic {
     }
 }
 
+impl distribution::Moments for Logistic {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is computed from the cumulants, which are `mu` and `2 s^k (k
+    /// - 1)! zeta(k)` for even `k`.
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::{harmonic, moments};
+        let (mu, s) = (self.mu, self.s);
+        let cumulant = |k: usize| {
+            if k == 1 {
+                mu
+            } else if k % 2 == 1 {
+                0.0
+            } else {
+                (1..k).fold(2.0 * s * harmonic::zeta(k as f64), |product, j| {
+                    product * j as f64 * s
+                })
+            }
+        };
+        moments::cumulants(cumulant, n)
+    }
+}
+
 impl distribution::Sample for Logistic {
     #[inline]
     fn sample<S>(&self, source: &mut S) -> f64
@@ -120,6 +181,13 @@ impl distribution::Skewness for Logistic {
     }
 }
 
+impl distribution::Support for Logistic {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Unbounded;
+        (Unbounded, Unbounded)
+    }
+}
+
 impl distribution::Variance for Logistic {
     #[inline]
     fn variance(&self) -> f64 {
@@ -129,6 +197,12 @@ impl distribution::Variance for Logistic {
 }
 
 #[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
 mod tests {
     use alloc::{vec, vec::Vec};
     use assert;
@@ -139,6 +213,26 @@ mod tests {
         ($mu:expr, $s:expr) => (Logistic::new($mu, $s));
     );
 
+    #[test]
+    fn cf() {
+        let d = new!(1.0, 2.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            0.08275963053418144,
+            0.5597462111484471,
+            -3.64738024799629e-05,
+        ];
+        let im = vec![
+            -0.0697074751703585,
+            0.1731497936898569,
+            7.969671238344578e-05,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
     #[test]
     fn density() {
         let d = new!(5.0, 5.0);
@@ -162,6 +256,9 @@ mod tests {
             &p,
             1e-15,
         );
+
+        assert_eq!(new!(5.0, 5.0).density(-1e4), 0.0);
+        assert_eq!(new!(5.0, 5.0).density(1e4), 0.0);
     }
 
     #[test]
@@ -185,7 +282,7 @@ mod tests {
         assert::close(
             &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
             &p,
-            1e-7,
+            1e-15,
         );
     }
 
@@ -224,6 +321,23 @@ mod tests {
         assert_eq!(new!(2.0, 1.0).kurtosis(), 1.2);
     }
 
+    #[test]
+    fn log_density() {
+        let d = new!(1.0, 2.0);
+        let x = vec![-40.0, 0.5, 400.0, 10000.0];
+        let p = vec![
+            -21.193147183060248,
+            -2.0950260203176323,
+            -200.19314718055995,
+            -5000.19314718056,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-10,
+        );
+    }
+
     #[test]
     fn mean() {
         assert_eq!(new!(2.0, 1.0).mean(), 2.0);
@@ -234,16 +348,49 @@ mod tests {
         assert_eq!(new!(2.0, 1.0).median(), 2.0);
     }
 
+    #[test]
+    fn mgf() {
+        let d = new!(1.0, 2.0);
+        let t = vec![-0.4, -0.1, 0.2, 0.45];
+        let m = vec![
+            2.8661794748558083,
+            0.9672344020569187,
+            1.613847280940965,
+            14.34969052557595,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+        assert_eq!(d.mgf(0.5), f64::INFINITY);
+    }
+
     #[test]
     fn modes() {
         assert_eq!(new!(2.0, 1.0).modes(), vec![2.0]);
     }
 
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(5.0, 5.0);
+        let mut source = source::default(42);
+
+        let cross_entropy = -(0..n)
+            .map(|_| d.density(d.sample(&mut source)).ln())
+            .sum::<f64>()
+            / n as f64;
+        assert!((cross_entropy - d.entropy()).abs() < 0.01);
+    }
+
     #[test]
     fn skewness() {
         assert_eq!(new!(2.0, 1.0).skewness(), 0.0);
     }
 
+    #[test]
+    fn support() {
+        use core::ops::Bound::Unbounded;
+        assert_eq!(new!(1.0, 2.0).support(), (Unbounded, Unbounded));
+    }
+
     #[test]
     fn variance() {
         use core::f64::consts::PI;
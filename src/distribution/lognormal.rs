@@ -1,4 +1,5 @@
 use alloc::{vec, vec::Vec};
+use core::ops::Bound;
 #[allow(unused_imports)]
 use special::Primitive;
 
@@ -47,6 +48,24 @@ impl Default for Lognormal {
     }
 }
 
+impl distribution::CharacteristicFunction for Lognormal {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Continuous, Inverse};
+        let (lower, upper) = (self.inverse(0.25), self.inverse(0.75));
+        characteristic::continuous(
+            |x| self.density(x),
+            t,
+            0.0,
+            f64::INFINITY,
+            lower,
+            upper - lower,
+        )
+    }
+}
+
 impl distribution::Continuous for Lognormal {
     fn density(&self, x: f64) -> f64 {
         use core::f64::consts::PI;
@@ -57,6 +76,17 @@ impl distribution::Continuous for Lognormal {
             (-(x.ln() - mu).powi(2) / (2.0 * sigma * sigma)).exp() / (x * sigma * (2.0 * PI).sqrt())
         }
     }
+
+    fn log_density(&self, x: f64) -> f64 {
+        use core::f64::consts::PI;
+        if x <= 0.0 {
+            f64::NEG_INFINITY
+        } else {
+            let &Lognormal { mu, sigma, .. } = self;
+            let ln_x = x.ln();
+            -(ln_x - mu).powi(2) / (2.0 * sigma * sigma) - ln_x - (sigma * (2.0 * PI).sqrt()).ln()
+        }
+    }
 }
 
 impl distribution::Distribution for Lognormal {
@@ -114,6 +144,13 @@ impl distribution::Modes for Lognormal {
     }
 }
 
+impl distribution::Moments for Lognormal {
+    fn raw_moment(&self, n: usize) -> f64 {
+        let n = n as f64;
+        (n * self.mu + 0.5 * n * n * self.sigma * self.sigma).exp()
+    }
+}
+
 impl distribution::Sample for Lognormal {
     #[inline]
     fn sample<S>(&self, source: &mut S) -> f64
@@ -132,6 +169,13 @@ impl distribution::Skewness for Lognormal {
     }
 }
 
+impl distribution::Support for Lognormal {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Excluded, Unbounded};
+        (Excluded(0.0), Unbounded)
+    }
+}
+
 impl distribution::Variance for Lognormal {
     #[inline]
     fn variance(&self) -> f64 {
@@ -141,6 +185,13 @@ impl distribution::Variance for Lognormal {
 }
 
 #[cfg(test)]
+#[allow(
+    clippy::approx_constant,
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
 mod tests {
     use alloc::{vec, vec::Vec};
     use assert;
@@ -150,6 +201,22 @@ mod tests {
         ($mu:expr, $sigma:expr) => (Lognormal::new($mu, $sigma));
     );
 
+    #[test]
+    fn cf() {
+        let d = new!(1.0, 2.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.2650804442571224, 0.43791800608054665, 0.1054775171797135];
+        let im = vec![
+            -0.2578535689180356,
+            0.28968643606679173,
+            0.17655691749756355,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
     #[test]
     fn density() {
         let d = new!(1.0, 2.0);
@@ -250,6 +317,23 @@ mod tests {
         assert::close(new!(0.0, 1.0).kurtosis(), 1.1093639217631153e+02, 1e-15);
     }
 
+    #[test]
+    fn log_density() {
+        let d = new!(1.0, 2.0);
+        let x = vec![-40.0, 0.05, 4.0, 1000000.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -0.612087990450745,
+            -3.0170329915636365,
+            -35.95726012939061,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-12,
+        );
+    }
+
     #[test]
     fn mean() {
         assert_eq!(new!(-2.0, 2.0).mean(), 1.0);
@@ -265,18 +349,42 @@ mod tests {
         assert_eq!(new!(1.0, 1.0).modes(), vec![1.0]);
     }
 
+    #[test]
+    fn moments() {
+        let d = new!(0.0, 2f64.ln().sqrt());
+        let m = vec![5792.6187514801973, 262144.0, 23726566.406062888];
+        assert::close(
+            &(5..8).map(|n| d.raw_moment(n)).collect::<Vec<_>>(),
+            &m,
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn sample() {
+        for x in Independent(&new!(1.0, 2.0), &mut source::default(42)).take(100) {
+            assert!(x > 0.0);
+        }
+    }
+
     #[test]
     fn skewness() {
-        assert!(4.0 - new!(0.0, 2f64.ln().sqrt()).skewness() < 1e-10);
+        assert::close(new!(0.0, 2f64.ln().sqrt()).skewness(), 4.0, 1e-10);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Excluded, Unbounded};
+        assert_eq!(new!(1.0, 2.0).support(), (Excluded(0.0), Unbounded));
     }
 
     #[test]
     fn variance() {
-        assert!(2.0 - new!(0.0, 2f64.ln().sqrt()).variance() < 1e-10);
+        assert::close(new!(0.0, 2f64.ln().sqrt()).variance(), 2.0, 1e-10);
     }
 
     #[test]
     fn deviation() {
-        assert!(2f64.sqrt() - new!(0.0, 2f64.ln().sqrt()).variance() < 1e-10);
+        assert::close(new!(0.0, 2f64.ln().sqrt()).deviation(), 2f64.sqrt(), 1e-10);
     }
 }
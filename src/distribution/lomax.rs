@@ -0,0 +1,431 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A Lomax distribution, also known as the Pareto distribution of type II.
+///
+/// The distribution is a Pareto distribution shifted to start at zero.
+#[derive(Clone, Copy, Debug)]
+pub struct Lomax {
+    lambda: f64,
+    alpha: f64,
+}
+
+impl Lomax {
+    /// Create a Lomax distribution with scale `lambda` and shape `alpha`.
+    ///
+    /// It should hold that `lambda > 0` and `alpha > 0`.
+    #[inline]
+    pub fn new(lambda: f64, alpha: f64) -> Self {
+        should!(lambda > 0.0 && alpha > 0.0);
+        Lomax { lambda, alpha }
+    }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn lambda(&self) -> f64 {
+        self.lambda
+    }
+
+    /// Return the shape parameter.
+    #[inline(always)]
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+}
+
+impl distribution::CharacteristicFunction for Lomax {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Continuous, Inverse};
+        let (lower, upper) = (self.inverse(0.25), self.inverse(0.75));
+        characteristic::continuous(
+            |x| self.density(x),
+            t,
+            0.0,
+            f64::INFINITY,
+            lower,
+            upper - lower,
+        )
+    }
+}
+
+impl distribution::Continuous for Lomax {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        let &Lomax { lambda, alpha } = self;
+        if x < 0.0 {
+            0.0
+        } else {
+            alpha / lambda * (-(alpha + 1.0) * (x / lambda).ln_1p()).exp()
+        }
+    }
+
+    #[inline]
+    fn log_density(&self, x: f64) -> f64 {
+        let &Lomax { lambda, alpha } = self;
+        if x < 0.0 {
+            f64::NEG_INFINITY
+        } else {
+            (alpha / lambda).ln() - (alpha + 1.0) * (x / lambda).ln_1p()
+        }
+    }
+}
+
+impl distribution::Distribution for Lomax {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else {
+            -(-self.alpha * (x / self.lambda).ln_1p()).exp_m1()
+        }
+    }
+}
+
+impl distribution::Entropy for Lomax {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        (self.lambda / self.alpha).ln() + 1.0 / self.alpha + 1.0
+    }
+}
+
+impl distribution::Inverse for Lomax {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        should!((0.0..=1.0).contains(&p));
+        self.lambda * (-(-p).ln_1p() / self.alpha).exp_m1()
+    }
+}
+
+impl distribution::Kurtosis for Lomax {
+    /// Compute the excess kurtosis.
+    ///
+    /// The excess kurtosis is undefined, which is signified by `NaN`, if
+    /// `alpha <= 4`.
+    fn kurtosis(&self) -> f64 {
+        let alpha = self.alpha;
+        if alpha <= 4.0 {
+            return f64::NAN;
+        }
+        6.0 * (alpha * alpha * alpha + alpha * alpha - 6.0 * alpha - 2.0)
+            / (alpha * (alpha - 3.0) * (alpha - 4.0))
+    }
+}
+
+impl distribution::Mean for Lomax {
+    /// Compute the expected value.
+    ///
+    /// The expected value is infinite if `alpha <= 1`.
+    #[inline]
+    fn mean(&self) -> f64 {
+        if self.alpha <= 1.0 {
+            f64::INFINITY
+        } else {
+            self.lambda / (self.alpha - 1.0)
+        }
+    }
+}
+
+impl distribution::Median for Lomax {
+    #[inline]
+    fn median(&self) -> f64 {
+        use core::f64::consts::LN_2;
+        self.lambda * (LN_2 / self.alpha).exp_m1()
+    }
+}
+
+impl distribution::Modes for Lomax {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![0.0]
+    }
+}
+
+impl distribution::Moments for Lomax {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is infinite if `n >= alpha`.
+    fn raw_moment(&self, n: usize) -> f64 {
+        if n as f64 >= self.alpha {
+            return f64::INFINITY;
+        }
+        (1..=n).fold(1.0, |product, j| {
+            product * j as f64 * self.lambda / (self.alpha - j as f64)
+        })
+    }
+}
+
+impl distribution::Sample for Lomax {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::Inverse;
+        self.inverse(source.read::<f64>())
+    }
+}
+
+impl distribution::Skewness for Lomax {
+    /// Compute the skewness.
+    ///
+    /// The skewness is undefined, which is signified by `NaN`, if
+    /// `alpha <= 3`.
+    fn skewness(&self) -> f64 {
+        let alpha = self.alpha;
+        if alpha <= 3.0 {
+            return f64::NAN;
+        }
+        2.0 * (1.0 + alpha) / (alpha - 3.0) * ((alpha - 2.0) / alpha).sqrt()
+    }
+}
+
+impl distribution::Support for Lomax {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Included, Unbounded};
+        (Included(0.0), Unbounded)
+    }
+}
+
+impl distribution::TryMean for Lomax {
+    /// Compute the expected value.
+    ///
+    /// The expected value does not exist if `alpha <= 1`.
+    #[inline]
+    fn try_mean(&self) -> Option<f64> {
+        use distribution::Mean;
+        if self.alpha > 1.0 {
+            Some(self.mean())
+        } else {
+            None
+        }
+    }
+}
+
+impl distribution::TryVariance for Lomax {
+    /// Compute the variance.
+    ///
+    /// The variance does not exist if `alpha <= 2`.
+    #[inline]
+    fn try_variance(&self) -> Option<f64> {
+        use distribution::Variance;
+        if self.alpha > 2.0 {
+            Some(self.variance())
+        } else {
+            None
+        }
+    }
+}
+
+impl distribution::Variance for Lomax {
+    /// Compute the variance.
+    ///
+    /// The variance is infinite if `alpha <= 2`.
+    fn variance(&self) -> f64 {
+        let alpha = self.alpha;
+        if alpha <= 2.0 {
+            f64::INFINITY
+        } else {
+            self.lambda * self.lambda * alpha / ((alpha - 1.0).powi(2) * (alpha - 2.0))
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($lambda:expr, $alpha:expr) => (Lomax::new($lambda, $alpha));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(2.0, 5.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.8827256797624408, 0.972813004139541, 0.5544504309535152];
+        let im = vec![
+            -0.28753678463065474,
+            0.14285728666516861,
+            0.45293463866268807,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(2.0, 5.0);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 2.0, 3.0, 5.0, 10.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            2.5000000000000000e+00,
+            6.5536000000000005e-01,
+            2.1947873799725651e-01,
+            3.9062500000000000e-02,
+            1.0240000000000001e-02,
+            1.3599775603702539e-03,
+            5.3583676268861452e-05,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(2.0, 5.0);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 2.0, 3.0, 5.0, 10.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            6.7232000000000003e-01,
+            8.6831275720164613e-01,
+            9.6875000000000000e-01,
+            9.8975999999999997e-01,
+            9.9809603141548164e-01,
+            9.9987139917695478e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(2.0, 5.0).entropy(), 2.8370926812584496e-01, 1e-15);
+    }
+
+    #[test]
+    fn inverse() {
+        use core::f64::INFINITY;
+        let d = new!(2.0, 5.0);
+        let p = vec![
+            0.0, 0.01, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.99, 1.0,
+        ];
+        let x = vec![
+            0.0000000000000000e+00,
+            4.0241774199306184e-03,
+            4.2591375200270135e-02,
+            9.1279105182546463e-02,
+            1.4788184757155870e-01,
+            2.1513268649657993e-01,
+            2.9739670999407003e-01,
+            4.0224886796286247e-01,
+            5.4451927307878412e-01,
+            7.5945932292242979e-01,
+            1.1697863849222272e+00,
+            3.0237728630191594e+00,
+            INFINITY,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(2.0, 5.0).kurtosis(), 70.8, 1e-13);
+        assert!(new!(2.0, 4.0).kurtosis().is_nan());
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(2.0, 5.0);
+        let x = vec![-40.0, 0.05, 4.0, 1000000.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            0.7681350563319261,
+            -5.675383000134502,
+            -77.8179015325398,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(2.0, 5.0).mean(), 0.5);
+        assert_eq!(new!(2.0, 1.0).mean(), f64::INFINITY);
+    }
+
+    #[test]
+    fn median() {
+        assert::close(new!(2.0, 5.0).median(), 2.9739670999407003e-01, 1e-15);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(2.0, 5.0).modes(), vec![0.0]);
+    }
+
+    #[test]
+    fn sample() {
+        for x in Independent(&new!(2.0, 5.0), &mut source::default(42)).take(100) {
+            assert!(x >= 0.0);
+        }
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(2.0, 5.0).skewness(), 4.6475800154489004e+00, 1e-14);
+        assert!(new!(2.0, 3.0).skewness().is_nan());
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Included, Unbounded};
+        assert_eq!(new!(2.0, 5.0).support(), (Included(0.0), Unbounded));
+    }
+
+    #[test]
+    fn try_mean() {
+        assert_eq!(new!(2.0, 1.0).try_mean(), None);
+        let d = new!(2.0, 3.0);
+        assert_eq!(d.try_mean(), Some(d.mean()));
+    }
+
+    #[test]
+    fn try_variance() {
+        assert_eq!(new!(2.0, 2.0).try_variance(), None);
+        let d = new!(2.0, 3.0);
+        assert_eq!(d.try_variance(), Some(d.variance()));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(2.0, 5.0).variance(), 4.1666666666666669e-01, 1e-15);
+        assert_eq!(new!(2.0, 2.0).variance(), f64::INFINITY);
+    }
+}
@@ -0,0 +1,250 @@
+use alloc::{vec, vec::Vec};
+#[allow(unused_imports)]
+use special::Primitive;
+
+use source::Source;
+
+/// A matrix normal distribution.
+///
+/// The distribution is over matrices with `n` rows and `p` columns whose
+/// vectorization is Gaussian with covariance matrix `V ⊗ U`. All matrices are
+/// stored in row-major order.
+#[derive(Clone, Debug)]
+pub struct MatrixNormal {
+    m: Vec<f64>,
+    u: Vec<f64>,
+    v: Vec<f64>,
+    rows: usize,
+    columns: usize,
+    cholesky_u: Vec<f64>,
+    cholesky_v: Vec<f64>,
+    ln_norm: f64,
+}
+
+impl MatrixNormal {
+    /// Create a matrix normal distribution with mean `m`, among-row covariance
+    /// matrix `u` of order `n`, and among-column covariance matrix `v` of
+    /// order `p`.
+    ///
+    /// It should hold that `m` has `n * p` elements and that `u` and `v` are
+    /// symmetric positive-definite matrices.
+    pub fn new(m: Vec<f64>, u: Vec<f64>, v: Vec<f64>) -> Self {
+        use core::f64::consts::PI;
+        use distribution::cholesky;
+        let rows = (u.len() as f64).sqrt() as usize;
+        let columns = (v.len() as f64).sqrt() as usize;
+        should!(rows > 0 && u.len() == rows * rows);
+        should!(columns > 0 && v.len() == columns * columns);
+        should!(m.len() == rows * columns);
+        let cholesky_u = cholesky::decompose(&u, rows);
+        let cholesky_v = cholesky::decompose(&v, columns);
+        should!(cholesky::is_positive_definite(&cholesky_u, rows));
+        should!(cholesky::is_positive_definite(&cholesky_v, columns));
+        let (n, p) = (rows as f64, columns as f64);
+        let ln_norm = 0.5
+            * (n * p * (2.0 * PI).ln()
+                + n * cholesky::ln_det(&cholesky_v, columns)
+                + p * cholesky::ln_det(&cholesky_u, rows));
+        MatrixNormal {
+            m,
+            u,
+            v,
+            rows,
+            columns,
+            cholesky_u,
+            cholesky_v,
+            ln_norm,
+        }
+    }
+
+    /// Return the mean matrix.
+    #[inline(always)]
+    pub fn m(&self) -> &[f64] {
+        &self.m
+    }
+
+    /// Return the among-row covariance matrix.
+    #[inline(always)]
+    pub fn u(&self) -> &[f64] {
+        &self.u
+    }
+
+    /// Return the among-column covariance matrix.
+    #[inline(always)]
+    pub fn v(&self) -> &[f64] {
+        &self.v
+    }
+
+    /// Return the number of rows.
+    #[inline(always)]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Return the number of columns.
+    #[inline(always)]
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Compute the probability density function.
+    #[inline]
+    pub fn density(&self, x: &[f64]) -> f64 {
+        self.log_density(x).exp()
+    }
+
+    /// Compute the natural logarithm of the probability density function.
+    ///
+    /// The trace `tr[V^-1 (X - M)^T U^-1 (X - M)]` is evaluated as the squared
+    /// Frobenius norm of `L_U^-1 (X - M) L_V^-T` obtained by forward
+    /// substitution with the two Cholesky factors.
+    pub fn log_density(&self, x: &[f64]) -> f64 {
+        use distribution::cholesky;
+        let (n, p) = (self.rows, self.columns);
+        should!(x.len() == n * p);
+        let mut z = vec![0.0; n * p];
+        for j in 0..p {
+            let column = (0..n)
+                .map(|i| x[i * p + j] - self.m[i * p + j])
+                .collect::<Vec<_>>();
+            for (i, value) in cholesky::solve(&self.cholesky_u, n, &column)
+                .into_iter()
+                .enumerate()
+            {
+                z[i * p + j] = value;
+            }
+        }
+        let distance = z
+            .chunks(p)
+            .flat_map(|row| cholesky::solve(&self.cholesky_v, p, row))
+            .map(|w| w * w)
+            .sum::<f64>();
+        -0.5 * distance - self.ln_norm
+    }
+
+    /// Draw a sample.
+    ///
+    /// The sample is obtained as `M + L_U Z L_V^T` where `L_U` and `L_V` are
+    /// the Cholesky factors and `Z` is a matrix of independent standard
+    /// Gaussian variates.
+    pub fn sample<S>(&self, source: &mut S) -> Vec<f64>
+    where
+        S: Source,
+    {
+        use distribution::{cholesky, gaussian};
+        let (n, p) = (self.rows, self.columns);
+        let mut a = vec![0.0; n * p];
+        for j in 0..p {
+            let z = (0..n).map(|_| gaussian::sample(source)).collect::<Vec<_>>();
+            for (i, value) in cholesky::multiply(&self.cholesky_u, n, &z)
+                .into_iter()
+                .enumerate()
+            {
+                a[i * p + j] = value;
+            }
+        }
+        a.chunks(p)
+            .flat_map(|row| cholesky::multiply(&self.cholesky_v, p, row))
+            .zip(&self.m)
+            .map(|(x, m)| x + m)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        () => (
+            MatrixNormal::new(
+                vec![1.0, 0.0, -1.0, 0.5, 2.0, 0.0],
+                vec![2.0, 0.5, 0.5, 1.0],
+                vec![1.0, 0.3, 0.0, 0.3, 2.0, -0.4, 0.0, -0.4, 1.5],
+            )
+        );
+    );
+
+    #[test]
+    fn density() {
+        let d = new!();
+        let x = vec![
+            vec![1.0, 0.0, -1.0, 0.5, 2.0, 0.0],
+            vec![0.0; 6],
+            vec![2.0, 1.0, -2.0, 1.0, 1.0, 0.5],
+        ];
+        let p = vec![
+            6.4377788306222295e-04,
+            1.0881980480539754e-04,
+            2.1564035922756473e-04,
+        ];
+
+        assert::close(
+            &x.iter().map(|x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!();
+        let x = vec![
+            vec![1.0, 0.0, -1.0, 0.5, 2.0, 0.0],
+            vec![0.0; 6],
+            vec![2.0, 1.0, -2.0, 1.0, 1.0, 0.5],
+        ];
+        let p = vec![
+            -7.3481567934296157e+00,
+            -9.1258172106464102e+00,
+            -8.4418985415151706e+00,
+        ];
+
+        assert::close(
+            &x.iter().map(|x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+
+        let d = MatrixNormal::new(vec![1.0, 2.0], vec![2.0], vec![1.0, 0.5, 0.5, 3.0]);
+        let gaussian = MultivariateGaussian::new(vec![1.0, 2.0], vec![2.0, 1.0, 1.0, 6.0]);
+        assert::close(
+            d.log_density(&[0.5, 3.0]),
+            gaussian.log_density(&[0.5, 3.0]),
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!();
+        let mut source = source::default(42);
+
+        let samples = (0..n).map(|_| d.sample(&mut source)).collect::<Vec<_>>();
+        let mut mean = vec![0.0; 6];
+        for x in &samples {
+            for (mean, x) in mean.iter_mut().zip(x) {
+                *mean += x / n as f64;
+            }
+        }
+        assert::close(&mean, d.m(), 0.02);
+
+        let covariance = |a: usize, b: usize| {
+            samples
+                .iter()
+                .map(|x| (x[a] - d.m()[a]) * (x[b] - d.m()[b]))
+                .sum::<f64>()
+                / n as f64
+        };
+        assert!((covariance(0, 3) - 0.5).abs() < 0.03);
+        assert!((covariance(0, 1) - 0.6).abs() < 0.03);
+    }
+}
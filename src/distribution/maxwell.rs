@@ -0,0 +1,375 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A Maxwell–Boltzmann distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct Maxwell {
+    a: f64,
+}
+
+impl Maxwell {
+    /// Create a Maxwell–Boltzmann distribution with scale `a`.
+    ///
+    /// It should hold that `a > 0`.
+    #[inline]
+    pub fn new(a: f64) -> Self {
+        should!(a > 0.0);
+        Maxwell { a }
+    }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+}
+
+impl distribution::CharacteristicFunction for Maxwell {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Continuous, Mean, Variance};
+        characteristic::continuous(
+            |x| self.density(x),
+            t,
+            0.0,
+            f64::INFINITY,
+            self.mean(),
+            self.deviation(),
+        )
+    }
+}
+
+impl distribution::Continuous for Maxwell {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        use core::f64::consts::{FRAC_1_SQRT_2, FRAC_2_SQRT_PI};
+        if x <= 0.0 {
+            0.0
+        } else {
+            let z = x / self.a;
+            FRAC_2_SQRT_PI * FRAC_1_SQRT_2 * z * z * (-0.5 * z * z).exp() / self.a
+        }
+    }
+
+    #[inline]
+    fn log_density(&self, x: f64) -> f64 {
+        use core::f64::consts::FRAC_2_PI;
+        if x <= 0.0 {
+            f64::NEG_INFINITY
+        } else {
+            let z = x / self.a;
+            0.5 * FRAC_2_PI.ln() + 2.0 * z.ln() - 0.5 * z * z - self.a.ln()
+        }
+    }
+}
+
+impl distribution::Distribution for Maxwell {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        use core::f64::consts::{FRAC_1_SQRT_2, FRAC_2_SQRT_PI};
+        use special::Error;
+        if x <= 0.0 {
+            0.0
+        } else {
+            let z = x / self.a;
+            (z * FRAC_1_SQRT_2).error() - FRAC_2_SQRT_PI * FRAC_1_SQRT_2 * z * (-0.5 * z * z).exp()
+        }
+    }
+}
+
+impl distribution::Entropy for Maxwell {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        use core::f64::consts::PI;
+        const EULER_MASCHERONI: f64 = 0.5772156649015329;
+        (self.a * (2.0 * PI).sqrt()).ln() + EULER_MASCHERONI - 0.5
+    }
+}
+
+impl distribution::Inverse for Maxwell {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        use distribution::gamma;
+        should!((0.0..=1.0).contains(&p));
+        self.a * (2.0 * gamma::inverse(p, 1.5)).sqrt()
+    }
+}
+
+impl distribution::Kurtosis for Maxwell {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        use core::f64::consts::PI;
+        4.0 * (-96.0 + 40.0 * PI - 3.0 * PI * PI) / (3.0 * PI - 8.0).powi(2)
+    }
+}
+
+impl distribution::Mean for Maxwell {
+    #[inline]
+    fn mean(&self) -> f64 {
+        use core::f64::consts::{FRAC_2_SQRT_PI, SQRT_2};
+        self.a * SQRT_2 * FRAC_2_SQRT_PI
+    }
+}
+
+impl distribution::Median for Maxwell {
+    #[inline]
+    fn median(&self) -> f64 {
+        use distribution::Inverse;
+        self.inverse(0.5)
+    }
+}
+
+impl distribution::Modes for Maxwell {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        use core::f64::consts::SQRT_2;
+        vec![SQRT_2 * self.a]
+    }
+}
+
+impl distribution::Moments for Maxwell {
+    fn raw_moment(&self, n: usize) -> f64 {
+        use core::f64::consts::{LN_2, PI};
+        use special::Gamma;
+        let n = n as f64;
+        let ln = n * self.a.ln() + (0.5 * n + 1.0) * LN_2 + Gamma::ln_gamma(0.5 * (n + 3.0)).0;
+        (ln - 0.5 * PI.ln()).exp()
+    }
+}
+
+impl distribution::Sample for Maxwell {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained as the norm of a vector of three independent
+    /// Gaussian variates.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::gaussian;
+        let (x, y, z) = (
+            gaussian::sample(source),
+            gaussian::sample(source),
+            gaussian::sample(source),
+        );
+        self.a * (x * x + y * y + z * z).sqrt()
+    }
+}
+
+impl distribution::Skewness for Maxwell {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        use core::f64::consts::{PI, SQRT_2};
+        2.0 * SQRT_2 * (16.0 - 5.0 * PI) / (3.0 * PI - 8.0).powf(1.5)
+    }
+}
+
+impl distribution::Support for Maxwell {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Excluded, Unbounded};
+        (Excluded(0.0), Unbounded)
+    }
+}
+
+impl distribution::Variance for Maxwell {
+    #[inline]
+    fn variance(&self) -> f64 {
+        use core::f64::consts::PI;
+        self.a * self.a * (3.0 * PI - 8.0) / PI
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($a:expr) => (Maxwell::new($a));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(2.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            -0.3602986548973437,
+            0.5345729353032141,
+            -0.005031939418537503,
+        ];
+        let im = vec![
+            -0.5333887707424406,
+            0.7508682078205203,
+            -0.044637202705508104,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(2.0);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 2.0, 3.0, 4.0, 6.0, 10.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            2.4166757300178074e-02,
+            8.8016331691074867e-02,
+            2.4197072451914334e-01,
+            2.9141459024825639e-01,
+            2.1596386605275220e-01,
+            3.9886635707442067e-02,
+            3.7167987868357440e-05,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(2.0);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 2.0, 3.0, 4.0, 6.0, 10.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            4.0785929644228452e-03,
+            3.0859595783726729e-02,
+            1.9874804309879920e-01,
+            4.7783281046460868e-01,
+            7.3853587005088939e-01,
+            9.7070911346511179e-01,
+            9.9998455950170895e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(2.0).entropy(), 1.6893013786661508e+00, 1e-15);
+    }
+
+    #[test]
+    fn inverse() {
+        use core::f64::INFINITY;
+        let d = new!(2.0);
+        let p = vec![
+            0.0, 0.01, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.99, 1.0,
+        ];
+        let x = vec![
+            0.0000000000000000e+00,
+            6.7773682768200527e-01,
+            1.5288876664492828e+00,
+            2.0051673377076034e+00,
+            2.3863379836354106e+00,
+            2.7343506749418340e+00,
+            3.0763445089101045e+00,
+            3.4328798831895941e+00,
+            3.8287704465900365e+00,
+            4.3088874091057177e+00,
+            5.0005554216188122e+00,
+            6.7364283504374542e+00,
+            INFINITY,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(2.0).kurtosis(), 1.0816384281629415e-01, 1e-14);
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(2.0);
+        let x = vec![-40.0, 0.05, 0.9, 40.0, 400.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -8.297009941432545,
+            -2.617203925640216,
+            -194.9274739860967,
+            -19990.322303800105,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(2.0).mean(), 3.1915382432114616e+00, 1e-15);
+    }
+
+    #[test]
+    fn median() {
+        assert::close(new!(2.0).median(), 3.0763445089101045e+00, 1e-12);
+    }
+
+    #[test]
+    fn modes() {
+        assert::close(new!(2.0).modes(), vec![2.8284271247461903e+00], 1e-15);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(2.0);
+        let mut source = source::default(42);
+
+        let cross_entropy = -(0..n)
+            .map(|_| d.density(d.sample(&mut source)).ln())
+            .sum::<f64>()
+            / n as f64;
+        assert!((cross_entropy - d.entropy()).abs() < 0.01);
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(2.0).skewness(), 4.8569282804959080e-01, 1e-14);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Excluded, Unbounded};
+        assert_eq!(new!(2.0).support(), (Excluded(0.0), Unbounded));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(2.0).variance(), 1.8140836421186985e+00, 1e-15);
+    }
+}
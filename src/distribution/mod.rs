@@ -1,6 +1,7 @@
 //! Probability distributions.
 
 use alloc::vec::Vec;
+use core::ops::Bound;
 #[allow(unused_imports)]
 use special::Primitive;
 
@@ -10,12 +11,30 @@ use source::Source;
 pub trait Continuous: Distribution {
     /// Compute the probability density function.
     fn density(&self, x: f64) -> f64;
+
+    /// Compute the natural logarithm of the probability density function.
+    ///
+    /// The default implementation takes the logarithm of the density, which
+    /// underflows far in the tails; distributions override it where the
+    /// logarithm can be computed directly.
+    fn log_density(&self, x: f64) -> f64 {
+        self.density(x).ln()
+    }
 }
 
 /// A discrete distribution.
 pub trait Discrete: Distribution {
     /// Compute the probability mass function.
     fn mass(&self, x: Self::Value) -> f64;
+
+    /// Compute the natural logarithm of the probability mass function.
+    ///
+    /// The default implementation takes the logarithm of the mass, which
+    /// underflows far in the tails; distributions override it where the
+    /// logarithm can be computed directly.
+    fn log_mass(&self, x: Self::Value) -> f64 {
+        self.mass(x).ln()
+    }
 }
 
 /// A distribution.
@@ -24,9 +43,19 @@ pub trait Distribution {
     type Value;
 
     /// Compute the cumulative distribution function.
+    ///
+    /// Continuous distributions without a closed form can delegate to
+    /// `numeric::distribution`, which integrates the density over the support.
     fn distribution(&self, x: f64) -> f64;
 }
 
+/// A distribution capable of computing the characteristic function.
+pub trait CharacteristicFunction: Distribution {
+    /// Compute the characteristic function, that is, `E[exp(i t X)]`, and
+    /// return its real and imaginary parts.
+    fn cf(&self, t: f64) -> (f64, f64);
+}
+
 /// A distribution capable of computing the differential entropy.
 pub trait Entropy: Distribution {
     /// Compute the differential entropy.
@@ -35,13 +64,30 @@ pub trait Entropy: Distribution {
     fn entropy(&self) -> f64;
 }
 
+/// A distribution capable of computing the hazard function.
+pub trait Hazard: Continuous {
+    /// Compute the hazard function, that is, `f(x) / P(X > x)`.
+    fn hazard(&self, x: f64) -> f64;
+
+    /// Compute the cumulative hazard function, that is, `-ln P(X > x)`.
+    fn cumulative_hazard(&self, x: f64) -> f64;
+}
+
 /// A distribution capable of inverting the distribution function.
 pub trait Inverse: Distribution {
     /// Compute the inverse of the cumulative distribution function.
+    ///
+    /// Continuous distributions without a closed form can delegate to
+    /// `numeric::inverse`, which solves `F(x) = p` numerically.
     fn inverse(&self, p: f64) -> Self::Value;
 }
 
 /// A distribution capable of computing the excess kurtosis.
+///
+/// For parameters where the excess kurtosis does not exist, it is signified by
+/// infinity if the fourth moment is infinite and by `NaN` if it is undefined.
+/// Distributions without a variance for any parameters, such as the Cauchy
+/// distribution, do not implement the trait.
 pub trait Kurtosis: Skewness {
     /// Compute the excess kurtosis.
     fn kurtosis(&self) -> f64;
@@ -49,7 +95,10 @@ pub trait Kurtosis: Skewness {
 
 /// A distribution capable of computing the expected value.
 ///
-/// The trait is applicable when the expected value exists, that is, finite.
+/// For parameters where the expected value does not exist, it is signified by
+/// an infinity of the appropriate sign if it is infinite and by `NaN` if it is
+/// undefined. Distributions without an expected value for any parameters, such
+/// as the Cauchy distribution, do not implement the trait; see `TryMean`.
 pub trait Mean: Distribution {
     /// Compute the expected value.
     fn mean(&self) -> f64;
@@ -63,6 +112,14 @@ pub trait Median: Distribution {
     fn median(&self) -> f64;
 }
 
+/// A distribution capable of computing the moment-generating function.
+///
+/// The function is infinite where the defining expectation diverges.
+pub trait Mgf: Distribution {
+    /// Compute the moment-generating function, that is, `E[exp(t X)]`.
+    fn mgf(&self, t: f64) -> f64;
+}
+
 /// A distribution capable of computing the modes.
 ///
 /// The trait is applicable when the number of modes is finite.
@@ -71,6 +128,28 @@ pub trait Modes: Distribution {
     fn modes(&self) -> Vec<Self::Value>;
 }
 
+/// A distribution capable of computing moments of arbitrary order.
+///
+/// A moment that does not exist is signified by an infinite value if the
+/// corresponding integral diverges to infinity and by `NaN` otherwise.
+pub trait Moments: Mean {
+    /// Compute the `n`th raw moment, that is, `E[X^n]`.
+    fn raw_moment(&self, n: usize) -> f64;
+
+    /// Compute the `n`th central moment, that is, `E[(X - E[X])^n]`.
+    ///
+    /// The default implementation expands the power in terms of the raw
+    /// moments.
+    fn central_moment(&self, n: usize) -> f64 {
+        let mean = self.mean();
+        match n {
+            0 => 1.0,
+            1 if mean.is_finite() => 0.0,
+            _ => moments::shift(|k| self.raw_moment(k), -mean, 1.0, n),
+        }
+    }
+}
+
 /// A distribution capable of drawing samples.
 pub trait Sample: Distribution {
     /// Draw a sample.
@@ -80,14 +159,69 @@ pub trait Sample: Distribution {
 }
 
 /// A distribution capable of computing the skewness.
+///
+/// For parameters where the skewness does not exist, it is signified by an
+/// infinity of the appropriate sign if the third central moment is infinite
+/// and by `NaN` if it is undefined.
 pub trait Skewness: Variance {
     /// Compute the skewness.
     fn skewness(&self) -> f64;
 }
 
+/// A distribution capable of describing its support.
+pub trait Support: Distribution {
+    /// Return the lower and upper bounds of the support.
+    ///
+    /// An infinite bound is signified by `Unbounded`. The support of a
+    /// discrete distribution consists of the integers between the bounds.
+    fn support(&self) -> (Bound<f64>, Bound<f64>);
+}
+
+/// A distribution capable of computing the survival function.
+pub trait Survival: Distribution {
+    /// Compute the survival function, that is, `P(X > x)`.
+    ///
+    /// Unlike `1 - distribution(x)`, the function retains its relative
+    /// accuracy far in the upper tail.
+    fn survival(&self, x: f64) -> f64;
+}
+
+/// A distribution capable of computing the expected value if it exists.
+///
+/// Unlike `Mean`, the trait is applicable to distributions whose expected
+/// value might not exist, such as the Cauchy distribution.
+pub trait TryMean: Distribution {
+    /// Compute the expected value.
+    ///
+    /// The expected value is signified by `None` if it does not exist, that is,
+    /// if it is infinite or undefined.
+    fn try_mean(&self) -> Option<f64>;
+}
+
+/// A distribution capable of computing the variance if it exists.
+///
+/// Unlike `Variance`, the trait is applicable to distributions whose variance
+/// might not exist, such as the Cauchy distribution.
+pub trait TryVariance: TryMean {
+    /// Compute the variance.
+    ///
+    /// The variance is signified by `None` if it does not exist, that is, if it
+    /// is infinite or undefined.
+    fn try_variance(&self) -> Option<f64>;
+
+    /// Compute the standard deviation.
+    #[inline(always)]
+    fn try_deviation(&self) -> Option<f64> {
+        self.try_variance().map(|variance| variance.sqrt())
+    }
+}
+
 /// A distribution capable of computing the variance.
 ///
-/// The trait is applicable when the variance exists, that is, finite.
+/// For parameters where the variance does not exist, it is signified by
+/// infinity if it is infinite and by `NaN` if it is undefined. Distributions
+/// without a variance for any parameters, such as the Cauchy distribution, do
+/// not implement the trait; see `TryVariance`.
 pub trait Variance: Mean {
     /// Compute the variance.
     fn variance(&self) -> f64;
@@ -99,32 +233,201 @@ pub trait Variance: Mean {
     }
 }
 
+mod arcsine;
+mod bates;
+mod benford;
 mod bernoulli;
+mod bessel;
 mod beta;
+mod beta_binomial;
+mod beta_prime;
 mod binomial;
+mod birnbaum_saunders;
+mod borel;
+mod burr;
 mod categorical;
 mod cauchy;
+mod characteristic;
+mod chi;
+mod chi_squared;
+mod cholesky;
+mod com_poisson;
+mod complex;
+mod dirichlet;
+mod dirichlet_multinomial;
+mod discrete_uniform;
+mod discrete_weibull;
+pub mod divergence;
+mod erlang;
+mod exp_modified_gaussian;
 mod exponential;
+mod fisher_snedecor;
+mod folded_normal;
+mod frechet;
 mod gamma;
 mod gaussian;
+mod generalized_extreme_value;
+mod generalized_gamma;
+mod generalized_normal;
+mod generalized_pareto;
+mod geometric;
+mod gompertz;
+mod gumbel;
+mod half_cauchy;
+mod half_normal;
+mod harmonic;
+mod hyperbolic_secant;
+mod hypergeometric;
+mod inverse_gamma;
+mod inverse_gaussian;
+mod inverse_wishart;
+mod irwin_hall;
+mod johnson_su;
+mod kumaraswamy;
 mod laplace;
+mod levy;
+mod lkj;
+mod log_gamma;
+mod log_logistic;
+mod logarithmic;
 mod logistic;
 mod lognormal;
+mod lomax;
+mod matrix_normal;
+mod maxwell;
+mod moments;
+mod multinomial;
+mod multivariate_gaussian;
+mod multivariate_student_t;
+mod nakagami;
+mod negative_binomial;
+mod negative_hypergeometric;
+mod noncentral_chi_squared;
+mod noncentral_f;
+mod noncentral_t;
+mod normal_inverse_gaussian;
+pub mod numeric;
+mod pareto;
 mod pert;
+mod poisson;
+mod poisson_binomial;
+mod quadrature;
+mod rademacher;
+mod raised_cosine;
+mod rayleigh;
+mod rice;
+mod semicircle;
+mod skellam;
+mod skew_normal;
+mod stable;
+mod student_t;
 mod triangular;
+mod truncated_gaussian;
+mod u_quadratic;
 mod uniform;
+mod variance_gamma;
+mod von_mises;
+mod von_mises_fisher;
+mod weibull;
+mod wishart;
+mod wrapped_cauchy;
+mod wrapped_normal;
+mod yule_simon;
+mod zero_truncated_poisson;
+mod zeta;
+mod zipf;
 
+pub use self::arcsine::Arcsine;
+pub use self::bates::Bates;
+pub use self::benford::Benford;
 pub use self::bernoulli::Bernoulli;
 pub use self::beta::Beta;
+pub use self::beta_binomial::BetaBinomial;
+pub use self::beta_prime::BetaPrime;
 pub use self::binomial::Binomial;
+pub use self::birnbaum_saunders::BirnbaumSaunders;
+pub use self::borel::Borel;
+pub use self::burr::Burr;
 pub use self::categorical::Categorical;
 pub use self::cauchy::Cauchy;
+pub use self::chi::Chi;
+pub use self::chi_squared::ChiSquared;
+pub use self::com_poisson::ComPoisson;
+pub use self::dirichlet::Dirichlet;
+pub use self::dirichlet_multinomial::DirichletMultinomial;
+pub use self::discrete_uniform::DiscreteUniform;
+pub use self::discrete_weibull::DiscreteWeibull;
+pub use self::erlang::Erlang;
+pub use self::exp_modified_gaussian::ExpModifiedGaussian;
 pub use self::exponential::Exponential;
+pub use self::fisher_snedecor::FisherSnedecor;
+pub use self::folded_normal::FoldedNormal;
+pub use self::frechet::Frechet;
 pub use self::gamma::Gamma;
 pub use self::gaussian::Gaussian;
+pub use self::generalized_extreme_value::GeneralizedExtremeValue;
+pub use self::generalized_gamma::GeneralizedGamma;
+pub use self::generalized_normal::GeneralizedNormal;
+pub use self::generalized_pareto::GeneralizedPareto;
+pub use self::geometric::Geometric;
+pub use self::gompertz::Gompertz;
+pub use self::gumbel::Gumbel;
+pub use self::half_cauchy::HalfCauchy;
+pub use self::half_normal::HalfNormal;
+pub use self::hyperbolic_secant::HyperbolicSecant;
+pub use self::hypergeometric::Hypergeometric;
+pub use self::inverse_gamma::InverseGamma;
+pub use self::inverse_gaussian::InverseGaussian;
+pub use self::inverse_wishart::InverseWishart;
+pub use self::irwin_hall::IrwinHall;
+pub use self::johnson_su::JohnsonSU;
+pub use self::kumaraswamy::Kumaraswamy;
 pub use self::laplace::Laplace;
+pub use self::levy::Levy;
+pub use self::lkj::Lkj;
+pub use self::log_gamma::LogGamma;
+pub use self::log_logistic::LogLogistic;
+pub use self::logarithmic::Logarithmic;
 pub use self::logistic::Logistic;
 pub use self::lognormal::Lognormal;
+pub use self::lomax::Lomax;
+pub use self::matrix_normal::MatrixNormal;
+pub use self::maxwell::Maxwell;
+pub use self::multinomial::Multinomial;
+pub use self::multivariate_gaussian::MultivariateGaussian;
+pub use self::multivariate_student_t::MultivariateStudentT;
+pub use self::nakagami::Nakagami;
+pub use self::negative_binomial::NegativeBinomial;
+pub use self::negative_hypergeometric::NegativeHypergeometric;
+pub use self::noncentral_chi_squared::NoncentralChiSquared;
+pub use self::noncentral_f::NoncentralF;
+pub use self::noncentral_t::NoncentralT;
+pub use self::normal_inverse_gaussian::NormalInverseGaussian;
+pub use self::pareto::Pareto;
 pub use self::pert::Pert;
+pub use self::poisson::Poisson;
+pub use self::poisson_binomial::PoissonBinomial;
+pub use self::rademacher::Rademacher;
+pub use self::raised_cosine::RaisedCosine;
+pub use self::rayleigh::Rayleigh;
+pub use self::rice::Rice;
+pub use self::semicircle::Semicircle;
+pub use self::skellam::Skellam;
+pub use self::skew_normal::SkewNormal;
+pub use self::stable::Stable;
+pub use self::student_t::StudentT;
 pub use self::triangular::Triangular;
+pub use self::truncated_gaussian::TruncatedGaussian;
+pub use self::u_quadratic::UQuadratic;
 pub use self::uniform::Uniform;
+pub use self::variance_gamma::VarianceGamma;
+pub use self::von_mises::VonMises;
+pub use self::von_mises_fisher::VonMisesFisher;
+pub use self::weibull::Weibull;
+pub use self::wishart::Wishart;
+pub use self::wrapped_cauchy::WrappedCauchy;
+pub use self::wrapped_normal::WrappedNormal;
+pub use self::yule_simon::YuleSimon;
+pub use self::zero_truncated_poisson::ZeroTruncatedPoisson;
+pub use self::zeta::Zeta;
+pub use self::zipf::Zipf;
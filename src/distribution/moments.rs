@@ -0,0 +1,133 @@
+//! Moments of arbitrary order.
+
+use alloc::{vec, vec::Vec};
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution::quadrature;
+
+/// Compute the `n`th raw moment of a continuous distribution by integrating
+/// the density over `[a, b]`.
+///
+/// The integration is delegated to `quadrature::unbounded`, which treats
+/// infinite endpoints as lying `quadrature::SPAN` widths away from `center`
+/// and integrates the tails beyond them separately.
+pub fn continuous<F>(density: F, n: usize, a: f64, b: f64, center: f64, width: f64) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    let f = |x: f64| {
+        let p = density(x);
+        if p == 0.0 {
+            0.0
+        } else {
+            x.powi(n as i32) * p
+        }
+    };
+    quadrature::unbounded(f, a, b, center, width)
+}
+
+/// Compute the `n`th raw moment from the cumulants.
+///
+/// The moments are obtained by the recurrence `m_j = sum_{i = 1}^j
+/// binomial(j - 1, i - 1) kappa_i m_(j - i)`.
+pub fn cumulants<F>(cumulant: F, n: usize) -> f64
+where
+    F: Fn(usize) -> f64,
+{
+    let cumulants = (1..=n).map(cumulant).collect::<Vec<_>>();
+    let mut moments = vec![1.0];
+    for j in 1..=n {
+        let (mut sum, mut binomial) = (0.0, 1.0);
+        for i in 1..=j {
+            sum += binomial * cumulants[i - 1] * moments[j - i];
+            binomial *= (j - i) as f64 / i as f64;
+        }
+        moments.push(sum);
+    }
+    moments[n]
+}
+
+/// Compute the `n`th raw moment of a discrete distribution supported on `{a,
+/// …, b}` by summing the probability mass function.
+///
+/// The summation stops early once the terms beyond `mean` become negligible
+/// relative to the sum.
+pub fn discrete<F>(mass: F, n: usize, a: usize, b: usize, mean: f64) -> f64
+where
+    F: Fn(usize) -> f64,
+{
+    let mut sum = 0.0;
+    for x in a..=b {
+        let term = mass(x) * (x as f64).powi(n as i32);
+        sum += term;
+        if x as f64 > mean && term <= f64::EPSILON * sum {
+            break;
+        }
+    }
+    sum
+}
+
+/// Compute the `n`th raw moment of `location + scale * X` given the raw
+/// moments of `X`.
+pub fn shift<F>(moment: F, location: f64, scale: f64, n: usize) -> f64
+where
+    F: Fn(usize) -> f64,
+{
+    if location == 0.0 {
+        return scale.powi(n as i32) * moment(n);
+    }
+    let (mut sum, mut binomial) = (0.0, 1.0);
+    for k in 0..=n {
+        sum += binomial * scale.powi(k as i32) * moment(k) * location.powi((n - k) as i32);
+        binomial *= (n - k) as f64 / (k + 1) as f64;
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+
+    #[test]
+    fn continuous() {
+        use core::f64::consts::PI;
+        let density = |x: f64| (-0.5 * x * x).exp() / (2.0 * PI).sqrt();
+        let (a, b) = (f64::NEG_INFINITY, f64::INFINITY);
+        assert::close(super::continuous(density, 0, a, b, 0.0, 1.0), 1.0, 1e-15);
+        assert::close(super::continuous(density, 3, a, b, 0.0, 1.0), 0.0, 1e-15);
+        assert::close(super::continuous(density, 6, a, b, 0.0, 1.0), 15.0, 1e-13);
+
+        let density = |x: f64| if x < 1.0 { 2.0 * x } else { 0.0 };
+        assert::close(
+            super::continuous(density, 3, 0.0, 1.0, 0.0, 0.0),
+            0.4,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn cumulants() {
+        assert_eq!(super::cumulants(|_| 2.0, 0), 1.0);
+        assert_eq!(super::cumulants(|_| 2.0, 1), 2.0);
+        assert_eq!(super::cumulants(|_| 2.0, 2), 6.0);
+        assert_eq!(super::cumulants(|_| 2.0, 3), 22.0);
+        assert_eq!(super::cumulants(|_| 2.0, 4), 94.0);
+    }
+
+    #[test]
+    fn discrete() {
+        let mass = |x: usize| 0.5f64.powi(x as i32 + 1);
+        assert::close(super::discrete(mass, 0, 0, usize::MAX, 1.0), 1.0, 1e-15);
+        assert::close(super::discrete(mass, 2, 0, usize::MAX, 1.0), 3.0, 1e-14);
+        assert::close(super::discrete(mass, 2, 0, 1, 1.0), 0.25, 1e-15);
+    }
+
+    #[test]
+    fn shift() {
+        let moment = |k: usize| [1.0, 0.0, 1.0, 0.0, 3.0][k];
+        assert_eq!(super::shift(moment, 0.0, 2.0, 4), 48.0);
+        assert_eq!(super::shift(moment, 1.0, 2.0, 2), 5.0);
+        assert_eq!(super::shift(moment, 1.0, 2.0, 4), 73.0);
+    }
+}
@@ -0,0 +1,233 @@
+use alloc::{vec, vec::Vec};
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A multinomial distribution.
+///
+/// The distribution describes the counts of each category in `n` independent
+/// trials of a categorical distribution.
+#[derive(Clone, Debug)]
+pub struct Multinomial {
+    n: usize,
+    p: Vec<f64>,
+}
+
+impl Multinomial {
+    /// Create a multinomial distribution with `n` trials and event
+    /// probabilities `p`.
+    ///
+    /// It should hold that `p[i] >= 0`, `p[i] <= 1`, and `sum(p) == 1`.
+    pub fn new(n: usize, p: &[f64]) -> Self {
+        should!(is_probability_vector(p), {
+            const EPSILON: f64 = 1e-12;
+            p.iter().all(|&p| (0.0..=1.0).contains(&p))
+                && (p.iter().fold(0.0, |sum, &p| sum + p) - 1.0).abs() < EPSILON
+        });
+        Multinomial { n, p: p.to_vec() }
+    }
+
+    /// Return the number of trials.
+    #[inline(always)]
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// Return the number of categories.
+    #[inline(always)]
+    pub fn k(&self) -> usize {
+        self.p.len()
+    }
+
+    /// Return the event probabilities.
+    #[inline(always)]
+    pub fn p(&self) -> &[f64] {
+        &self.p
+    }
+
+    /// Compute the probability mass function.
+    #[inline]
+    pub fn mass(&self, x: &[usize]) -> f64 {
+        self.log_mass(x).exp()
+    }
+
+    /// Compute the natural logarithm of the probability mass function.
+    pub fn log_mass(&self, x: &[usize]) -> f64 {
+        use distribution::hypergeometric::ln_factorial;
+        should!(x.len() == self.k());
+        if x.iter().sum::<usize>() != self.n {
+            return f64::NEG_INFINITY;
+        }
+        let mut sum = ln_factorial(self.n);
+        for (&x, &p) in x.iter().zip(&self.p) {
+            if x > 0 {
+                sum += x as f64 * p.ln() - ln_factorial(x);
+            }
+        }
+        sum
+    }
+
+    /// Compute the mean vector.
+    pub fn mean(&self) -> Vec<f64> {
+        let n = self.n as f64;
+        self.p.iter().map(|p| n * p).collect()
+    }
+
+    /// Compute the variances of the components.
+    pub fn variance(&self) -> Vec<f64> {
+        let n = self.n as f64;
+        self.p.iter().map(|p| n * p * (1.0 - p)).collect()
+    }
+
+    /// Compute the covariance matrix in row-major order.
+    pub fn covariance(&self) -> Vec<f64> {
+        let (n, k) = (self.n as f64, self.k());
+        let mut covariance = vec![0.0; k * k];
+        for (i, p_i) in self.p.iter().enumerate() {
+            for (j, p_j) in self.p.iter().enumerate() {
+                covariance[i * k + j] = if i == j {
+                    n * p_i * (1.0 - p_i)
+                } else {
+                    -n * (p_i * p_j)
+                };
+            }
+        }
+        covariance
+    }
+
+    /// Draw a sample.
+    ///
+    /// The sample is obtained by drawing the count of each category from the
+    /// binomial distribution conditional on the counts of the preceding ones.
+    pub fn sample<S>(&self, source: &mut S) -> Vec<usize>
+    where
+        S: Source,
+    {
+        use distribution::Sample;
+        let k = self.k();
+        let mut x = vec![0; k];
+        let (mut remaining, mut mass) = (self.n, 1.0);
+        for (i, &p) in self.p.iter().enumerate() {
+            if remaining == 0 {
+                break;
+            }
+            if i + 1 == k || p >= mass {
+                x[i] = remaining;
+                break;
+            }
+            if p > 0.0 {
+                x[i] = distribution::Binomial::new(remaining, p / mass).sample(source);
+                remaining -= x[i];
+            }
+            mass -= p;
+        }
+        x
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($n:expr, $p:expr) => (Multinomial::new($n, &$p));
+    );
+
+    #[test]
+    fn covariance() {
+        let d = new!(10, [0.2, 0.5, 0.3]);
+        assert::close(
+            d.covariance(),
+            vec![1.6, -1.0, -0.6, -1.0, 2.5, -1.5, -0.6, -1.5, 2.1],
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn log_mass() {
+        let d = new!(10, [0.2, 0.5, 0.3]);
+        let x = vec![vec![2, 5, 3], vec![0, 10, 0], vec![10, 0, 0], vec![3, 3, 4]];
+        let p = vec![
+            -2.4645159601402664e+00,
+            -6.9314718055994531e+00,
+            -1.6094379124341003e+01,
+            -3.3808066920144215e+00,
+        ];
+
+        assert::close(
+            &x.iter().map(|x| d.log_mass(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+        assert_eq!(d.log_mass(&[1, 2, 3]), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn mass() {
+        let d = new!(10, [0.2, 0.5, 0.3]);
+        let x = vec![
+            vec![2, 5, 3],
+            vec![0, 10, 0],
+            vec![10, 0, 0],
+            vec![3, 3, 4],
+            vec![1, 2, 3],
+        ];
+        let p = vec![
+            8.5050000000000001e-02,
+            9.7656250000000000e-04,
+            1.0240000000000000e-07,
+            3.4020000000000002e-02,
+            0.0000000000000000e+00,
+        ];
+
+        assert::close(&x.iter().map(|x| d.mass(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let d = new!(4, [0.0, 1.0]);
+        assert_eq!(d.mass(&[0, 4]), 1.0);
+        assert_eq!(d.mass(&[1, 3]), 0.0);
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(10, [0.2, 0.5, 0.3]).mean(), vec![2.0, 5.0, 3.0], 1e-15);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(10, [0.2, 0.5, 0.3]);
+        let mut source = source::default(42);
+
+        let samples = (0..n).map(|_| d.sample(&mut source)).collect::<Vec<_>>();
+        assert!(samples.iter().all(|x| x.iter().sum::<usize>() == 10));
+        let mean = d.mean();
+        for i in 0..3 {
+            let sample_mean = samples.iter().map(|x| x[i] as f64).sum::<f64>() / n as f64;
+            assert!((sample_mean - mean[i]).abs() < 0.05);
+        }
+
+        let d = new!(5, [0.0, 0.4, 0.0, 0.6, 0.0]);
+        for x in (0..100).map(|_| d.sample(&mut source)) {
+            assert_eq!(x[0] + x[2] + x[4], 0);
+            assert_eq!(x[1] + x[3], 5);
+        }
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(
+            new!(10, [0.2, 0.5, 0.3]).variance(),
+            vec![1.6, 2.5, 2.1],
+            1e-15,
+        );
+    }
+}
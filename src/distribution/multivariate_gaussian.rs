@@ -0,0 +1,249 @@
+use alloc::vec::Vec;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use source::Source;
+
+/// A multivariate Gaussian distribution.
+///
+/// The covariance matrix is stored in row-major order, and its Cholesky
+/// factor is computed once upon creation.
+#[derive(Clone, Debug)]
+pub struct MultivariateGaussian {
+    mean: Vec<f64>,
+    covariance: Vec<f64>,
+    cholesky: Vec<f64>,
+    ln_det: f64,
+}
+
+impl MultivariateGaussian {
+    /// Create a multivariate Gaussian distribution with mean vector `mean` and
+    /// covariance matrix `covariance` given in row-major order.
+    ///
+    /// It should hold that `covariance` is a symmetric positive-definite
+    /// matrix of order `mean.len()`.
+    pub fn new(mean: Vec<f64>, covariance: Vec<f64>) -> Self {
+        use distribution::cholesky;
+        let d = mean.len();
+        should!(d > 0 && covariance.len() == d * d);
+        should!(is_symmetric(covariance), {
+            (0..d).all(|i| (0..i).all(|j| covariance[i * d + j] == covariance[j * d + i]))
+        });
+        let cholesky = cholesky::decompose(&covariance, d);
+        should!(cholesky::is_positive_definite(&cholesky, d));
+        let ln_det = cholesky::ln_det(&cholesky, d);
+        MultivariateGaussian {
+            mean,
+            covariance,
+            cholesky,
+            ln_det,
+        }
+    }
+
+    /// Return the number of dimensions.
+    #[inline(always)]
+    pub fn dimension(&self) -> usize {
+        self.mean.len()
+    }
+
+    /// Return the mean vector.
+    #[inline(always)]
+    pub fn mean(&self) -> &[f64] {
+        &self.mean
+    }
+
+    /// Return the covariance matrix in row-major order.
+    #[inline(always)]
+    pub fn covariance(&self) -> &[f64] {
+        &self.covariance
+    }
+
+    /// Return the lower-triangular Cholesky factor of the covariance matrix in
+    /// row-major order.
+    #[inline(always)]
+    pub fn cholesky(&self) -> &[f64] {
+        &self.cholesky
+    }
+
+    /// Compute the probability density function.
+    #[inline]
+    pub fn density(&self, x: &[f64]) -> f64 {
+        self.log_density(x).exp()
+    }
+
+    /// Compute the natural logarithm of the probability density function.
+    ///
+    /// The quadratic form is evaluated by forward substitution with the
+    /// Cholesky factor.
+    pub fn log_density(&self, x: &[f64]) -> f64 {
+        use core::f64::consts::PI;
+        use distribution::cholesky;
+        let d = self.dimension();
+        should!(x.len() == d);
+        let deviation = x
+            .iter()
+            .zip(&self.mean)
+            .map(|(x, mean)| x - mean)
+            .collect::<Vec<_>>();
+        let z = cholesky::solve(&self.cholesky, d, &deviation);
+        let distance = z.iter().map(|z| z * z).sum::<f64>();
+        -0.5 * (d as f64 * (2.0 * PI).ln() + self.ln_det + distance)
+    }
+
+    /// Compute the differential entropy.
+    ///
+    /// The entropy is computed in nats.
+    #[inline]
+    pub fn entropy(&self) -> f64 {
+        use core::f64::consts::PI;
+        0.5 * (self.dimension() as f64 * (1.0 + (2.0 * PI).ln()) + self.ln_det)
+    }
+
+    /// Extract the marginal distribution of the components with indices
+    /// `indices`.
+    pub fn marginal(&self, indices: &[usize]) -> Self {
+        let d = self.dimension();
+        should!(indices.iter().all(|&i| i < d));
+        let mean = indices.iter().map(|&i| self.mean[i]).collect();
+        let covariance = indices
+            .iter()
+            .flat_map(|&i| indices.iter().map(move |&j| self.covariance[i * d + j]))
+            .collect();
+        MultivariateGaussian::new(mean, covariance)
+    }
+
+    /// Draw a sample.
+    ///
+    /// The sample is obtained as `mu + L z` where `L` is the Cholesky factor
+    /// and `z` is a vector of independent standard Gaussian variates.
+    pub fn sample<S>(&self, source: &mut S) -> Vec<f64>
+    where
+        S: Source,
+    {
+        use distribution::{cholesky, gaussian};
+        let d = self.dimension();
+        let z = (0..d).map(|_| gaussian::sample(source)).collect::<Vec<_>>();
+        cholesky::multiply(&self.cholesky, d, &z)
+            .into_iter()
+            .zip(&self.mean)
+            .map(|(x, mean)| x + mean)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        () => (
+            MultivariateGaussian::new(
+                vec![1.0, -1.0, 0.5],
+                vec![2.0, 0.5, 0.3, 0.5, 1.0, -0.2, 0.3, -0.2, 1.5],
+            )
+        );
+    );
+
+    #[test]
+    fn cholesky() {
+        let d = new!();
+        let l = d.cholesky();
+        let mut product = vec![0.0; 9];
+        for i in 0..3 {
+            for j in 0..3 {
+                product[i * 3 + j] = (0..3).map(|k| l[i * 3 + k] * l[j * 3 + k]).sum();
+            }
+        }
+        assert::close(&product, d.covariance(), 1e-15);
+        assert!((0..3).all(|i| ((i + 1)..3).all(|j| l[i * 3 + j] == 0.0)));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!();
+        let x = vec![
+            vec![1.0, -1.0, 0.5],
+            vec![0.0, 0.0, 0.0],
+            vec![2.0, -1.5, 1.0],
+            vec![-3.0, 2.0, 4.0],
+        ];
+        let p = vec![
+            4.1027725256569787e-02,
+            1.3075233655862993e-02,
+            2.3058803944774921e-02,
+            2.2471063844988458e-12,
+        ];
+
+        assert::close(
+            &x.iter().map(|x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!().entropy(), 4.6935072150454573e+00, 1e-15);
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!();
+        let x = vec![
+            vec![1.0, -1.0, 0.5],
+            vec![0.0, 0.0, 0.0],
+            vec![2.0, -1.5, 1.0],
+            vec![-3.0, 2.0, 4.0],
+        ];
+        let p = vec![
+            -3.1935072150454573e+00,
+            -4.3370353987615324e+00,
+            -3.7697076325819920e+00,
+            -2.6821377778719778e+01,
+        ];
+
+        assert::close(
+            &x.iter().map(|x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn marginal() {
+        let d = new!().marginal(&[2, 0]);
+        assert_eq!(d.mean(), &[0.5, 1.0]);
+        assert_eq!(d.covariance(), &[1.5, 0.3, 0.3, 2.0]);
+        assert::close(d.log_density(&[0.0, 0.0]), -2.6640498269323176e+00, 1e-15);
+
+        let d = new!().marginal(&[1]);
+        let gaussian = Gaussian::new(-1.0, 1.0);
+        assert::close(d.density(&[0.3]), gaussian.density(0.3), 1e-15);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!();
+        let mut source = source::default(42);
+
+        let samples = (0..n).map(|_| d.sample(&mut source)).collect::<Vec<_>>();
+        for i in 0..3 {
+            let mean = samples.iter().map(|x| x[i]).sum::<f64>() / n as f64;
+            assert!((mean - d.mean()[i]).abs() < 0.02);
+        }
+        let covariance = samples
+            .iter()
+            .map(|x| (x[0] - 1.0) * (x[1] + 1.0))
+            .sum::<f64>()
+            / n as f64;
+        assert!((covariance - 0.5).abs() < 0.02);
+    }
+}
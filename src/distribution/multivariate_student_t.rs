@@ -0,0 +1,292 @@
+use alloc::vec::Vec;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use source::Source;
+
+/// A multivariate Student’s t-distribution.
+///
+/// The scale matrix is stored in row-major order, and its Cholesky factor is
+/// computed once upon creation.
+#[derive(Clone, Debug)]
+pub struct MultivariateStudentT {
+    nu: f64,
+    mu: Vec<f64>,
+    sigma: Vec<f64>,
+    cholesky: Vec<f64>,
+    ln_norm: f64,
+}
+
+impl MultivariateStudentT {
+    /// Create a multivariate Student’s t-distribution with `nu` degrees of
+    /// freedom, location vector `mu`, and scale matrix `sigma` given in
+    /// row-major order.
+    ///
+    /// It should hold that `nu > 0` and `sigma` is a symmetric
+    /// positive-definite matrix of order `mu.len()`.
+    pub fn new(nu: f64, mu: Vec<f64>, sigma: Vec<f64>) -> Self {
+        use core::f64::consts::PI;
+        use distribution::cholesky;
+        use special::Gamma;
+        let d = mu.len();
+        should!(nu > 0.0 && d > 0 && sigma.len() == d * d);
+        let cholesky = cholesky::decompose(&sigma, d);
+        should!(cholesky::is_positive_definite(&cholesky, d));
+        let p = d as f64;
+        let ln_norm = Gamma::ln_gamma(0.5 * nu).0 - Gamma::ln_gamma(0.5 * (nu + p)).0
+            + 0.5 * (p * (nu * PI).ln() + cholesky::ln_det(&cholesky, d));
+        MultivariateStudentT {
+            nu,
+            mu,
+            sigma,
+            cholesky,
+            ln_norm,
+        }
+    }
+
+    /// Return the number of degrees of freedom.
+    #[inline(always)]
+    pub fn nu(&self) -> f64 {
+        self.nu
+    }
+
+    /// Return the location vector.
+    #[inline(always)]
+    pub fn mu(&self) -> &[f64] {
+        &self.mu
+    }
+
+    /// Return the scale matrix in row-major order.
+    #[inline(always)]
+    pub fn sigma(&self) -> &[f64] {
+        &self.sigma
+    }
+
+    /// Return the number of dimensions.
+    #[inline(always)]
+    pub fn dimension(&self) -> usize {
+        self.mu.len()
+    }
+
+    /// Compute the probability density function.
+    #[inline]
+    pub fn density(&self, x: &[f64]) -> f64 {
+        self.log_density(x).exp()
+    }
+
+    /// Compute the natural logarithm of the probability density function.
+    ///
+    /// The quadratic form is evaluated by forward substitution with the
+    /// Cholesky factor.
+    pub fn log_density(&self, x: &[f64]) -> f64 {
+        use distribution::cholesky;
+        let d = self.dimension();
+        should!(x.len() == d);
+        let deviation = x
+            .iter()
+            .zip(&self.mu)
+            .map(|(x, mu)| x - mu)
+            .collect::<Vec<_>>();
+        let z = cholesky::solve(&self.cholesky, d, &deviation);
+        let distance = z.iter().map(|z| z * z).sum::<f64>();
+        -0.5 * (self.nu + d as f64) * (distance / self.nu).ln_1p() - self.ln_norm
+    }
+
+    /// Extract the marginal distribution of the components with indices
+    /// `indices`.
+    pub fn marginal(&self, indices: &[usize]) -> Self {
+        let d = self.dimension();
+        should!(indices.iter().all(|&i| i < d));
+        let mu = indices.iter().map(|&i| self.mu[i]).collect();
+        let sigma = indices
+            .iter()
+            .flat_map(|&i| indices.iter().map(move |&j| self.sigma[i * d + j]))
+            .collect();
+        MultivariateStudentT::new(self.nu, mu, sigma)
+    }
+
+    /// Compute the conditional distribution of the remaining components given
+    /// that the components with indices `indices` take values `values`.
+    ///
+    /// The remaining components retain their original order. It should hold
+    /// that at least one component remains.
+    pub fn conditional(&self, indices: &[usize], values: &[f64]) -> Self {
+        use distribution::cholesky;
+        let d = self.dimension();
+        should!(indices.len() == values.len() && indices.len() < d);
+        should!(indices.iter().all(|&i| i < d));
+        let remaining = (0..d).filter(|i| !indices.contains(i)).collect::<Vec<_>>();
+        let (m, n) = (indices.len(), remaining.len());
+        let sigma = |i: usize, j: usize| self.sigma[i * d + j];
+
+        let observed = self.marginal(indices);
+        let precision = cholesky::invert(&observed.cholesky, m);
+        let deviation = indices
+            .iter()
+            .zip(values)
+            .map(|(&i, value)| value - self.mu[i])
+            .collect::<Vec<_>>();
+        let weights = (0..m)
+            .map(|j| {
+                let row = &precision[(j * m)..((j + 1) * m)];
+                row.iter().zip(&deviation).map(|(a, b)| a * b).sum::<f64>()
+            })
+            .collect::<Vec<_>>();
+        let distance = weights
+            .iter()
+            .zip(&deviation)
+            .map(|(a, b)| a * b)
+            .sum::<f64>();
+
+        let mu = remaining
+            .iter()
+            .map(|&i| {
+                let shift = indices
+                    .iter()
+                    .zip(&weights)
+                    .map(|(&j, weight)| sigma(i, j) * weight)
+                    .sum::<f64>();
+                self.mu[i] + shift
+            })
+            .collect();
+        let factor = (self.nu + distance) / (self.nu + m as f64);
+        let mut scale = Vec::with_capacity(n * n);
+        for &i in &remaining {
+            for &k in &remaining {
+                let mut correction = 0.0;
+                for (j, &b_j) in indices.iter().enumerate() {
+                    for (l, &b_l) in indices.iter().enumerate() {
+                        correction += sigma(i, b_j) * precision[j * m + l] * sigma(b_l, k);
+                    }
+                }
+                scale.push(factor * (sigma(i, k) - correction));
+            }
+        }
+        MultivariateStudentT::new(self.nu + m as f64, mu, scale)
+    }
+
+    /// Draw a sample.
+    ///
+    /// The sample is obtained as `mu + L z / sqrt(w / nu)` where `L` is the
+    /// Cholesky factor, `z` is a vector of independent standard Gaussian
+    /// variates, and `w` is a chi-squared variate with `nu` degrees of
+    /// freedom.
+    pub fn sample<S>(&self, source: &mut S) -> Vec<f64>
+    where
+        S: Source,
+    {
+        use distribution::{cholesky, gamma, gaussian};
+        let d = self.dimension();
+        let z = (0..d).map(|_| gaussian::sample(source)).collect::<Vec<_>>();
+        let scale = (0.5 * self.nu / gamma::sample(0.5 * self.nu, source)).sqrt();
+        cholesky::multiply(&self.cholesky, d, &z)
+            .into_iter()
+            .zip(&self.mu)
+            .map(|(x, mu)| mu + scale * x)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($nu:expr) => (
+            MultivariateStudentT::new(
+                $nu,
+                vec![1.0, -1.0, 0.5],
+                vec![2.0, 0.5, 0.3, 0.5, 1.0, -0.2, 0.3, -0.2, 1.5],
+            )
+        );
+    );
+
+    #[test]
+    fn conditional() {
+        let d = new!(4.0).conditional(&[1], &[0.0]);
+        assert_eq!(d.nu(), 5.0);
+        assert::close(d.mu(), &[1.5, 0.3], 1e-15);
+        assert::close(d.sigma(), &[1.75, 0.4, 0.4, 1.46], 1e-15);
+        assert::close(d.log_density(&[0.0, 0.0]), -3.0762614010764455e+00, 1e-14);
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(4.0);
+        let x = vec![
+            vec![1.0, -1.0, 0.5],
+            vec![0.0, 0.0, 0.0],
+            vec![2.0, -1.5, 1.0],
+            vec![-3.0, 2.0, 4.0],
+        ];
+        let p = vec![
+            4.8206838830582931e-02,
+            9.9026831592697109e-03,
+            1.9873981276793119e-02,
+            6.4005870981874380e-06,
+        ];
+
+        assert::close(
+            &x.iter().map(|x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(4.0);
+        let x = vec![
+            vec![1.0, -1.0, 0.5],
+            vec![0.0, 0.0, 0.0],
+            vec![2.0, -1.5, 1.0],
+            vec![-3.0, 2.0, 4.0],
+        ];
+        let p = vec![
+            -3.0322543835383011e+00,
+            -4.6149495323736955e+00,
+            -3.9183438762689553e+00,
+            -1.1959120837714176e+01,
+        ];
+
+        assert::close(
+            &x.iter().map(|x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn marginal() {
+        let d = new!(4.0).marginal(&[0]);
+        let t = StudentT::new(4.0);
+        let scale = 2f64.sqrt();
+        assert::close(d.density(&[2.5]), t.density(1.5 / scale) / scale, 1e-15);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(6.0);
+        let mut source = source::default(42);
+
+        let samples = (0..n).map(|_| d.sample(&mut source)).collect::<Vec<_>>();
+        for i in 0..3 {
+            let mean = samples.iter().map(|x| x[i]).sum::<f64>() / n as f64;
+            assert!((mean - d.mu()[i]).abs() < 0.02);
+        }
+        let covariance = samples
+            .iter()
+            .map(|x| (x[0] - 1.0) * (x[1] + 1.0))
+            .sum::<f64>()
+            / n as f64;
+        assert!((covariance - 0.75).abs() < 0.05);
+    }
+}
@@ -0,0 +1,382 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A Nakagami distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct Nakagami {
+    m: f64,
+    omega: f64,
+    ln_norm: f64,
+}
+
+impl Nakagami {
+    /// Create a Nakagami distribution with shape `m` and spread `omega`.
+    ///
+    /// It should hold that `m >= 0.5` and `omega > 0`.
+    #[inline]
+    pub fn new(m: f64, omega: f64) -> Self {
+        use core::f64::consts::LN_2;
+        use special::Gamma;
+        should!(m >= 0.5 && omega > 0.0);
+        Nakagami {
+            m,
+            omega,
+            ln_norm: LN_2 + m * (m / omega).ln() - Gamma::ln_gamma(m).0,
+        }
+    }
+
+    /// Return the shape parameter.
+    #[inline(always)]
+    pub fn m(&self) -> f64 {
+        self.m
+    }
+
+    /// Return the spread parameter, which is the second raw moment.
+    #[inline(always)]
+    pub fn omega(&self) -> f64 {
+        self.omega
+    }
+
+    #[inline]
+    fn moment(&self, i: f64) -> f64 {
+        use special::Gamma;
+        let &Nakagami { m, omega, .. } = self;
+        (Gamma::ln_gamma(m + 0.5 * i).0 - Gamma::ln_gamma(m).0 + 0.5 * i * (omega / m).ln()).exp()
+    }
+}
+
+impl distribution::CharacteristicFunction for Nakagami {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Continuous, Mean, Variance};
+        characteristic::continuous(
+            |x| self.density(x),
+            t,
+            0.0,
+            f64::INFINITY,
+            self.mean(),
+            self.deviation(),
+        )
+    }
+}
+
+impl distribution::Continuous for Nakagami {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        self.log_density(x).exp()
+    }
+
+    fn log_density(&self, x: f64) -> f64 {
+        let &Nakagami { m, omega, ln_norm } = self;
+        if x <= 0.0 {
+            f64::NEG_INFINITY
+        } else {
+            (2.0 * m - 1.0) * x.ln() - m * x * x / omega + ln_norm
+        }
+    }
+}
+
+impl distribution::Distribution for Nakagami {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        use special::Gamma;
+        if x <= 0.0 {
+            0.0
+        } else {
+            (self.m * x * x / self.omega).inc_gamma(self.m)
+        }
+    }
+}
+
+impl distribution::Entropy for Nakagami {
+    fn entropy(&self) -> f64 {
+        use core::f64::consts::LN_2;
+        use special::Gamma;
+        let &Nakagami { m, omega, .. } = self;
+        Gamma::ln_gamma(m).0 + 0.5 * (omega / m).ln() - LN_2 - (m - 0.5) * m.digamma() + m
+    }
+}
+
+impl distribution::Inverse for Nakagami {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        use distribution::gamma;
+        should!((0.0..=1.0).contains(&p));
+        (gamma::inverse(p, self.m) * self.omega / self.m).sqrt()
+    }
+}
+
+impl distribution::Kurtosis for Nakagami {
+    fn kurtosis(&self) -> f64 {
+        let (m1, m2, m3, m4) = (
+            self.moment(1.0),
+            self.moment(2.0),
+            self.moment(3.0),
+            self.moment(4.0),
+        );
+        let m1_2 = m1 * m1;
+        (m4 - 4.0 * m1 * m3 + 6.0 * m1_2 * m2 - 3.0 * m1_2 * m1_2) / (m2 - m1_2).powi(2) - 3.0
+    }
+}
+
+impl distribution::Mean for Nakagami {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.moment(1.0)
+    }
+}
+
+impl distribution::Median for Nakagami {
+    #[inline]
+    fn median(&self) -> f64 {
+        use distribution::Inverse;
+        self.inverse(0.5)
+    }
+}
+
+impl distribution::Modes for Nakagami {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        let &Nakagami { m, omega, .. } = self;
+        vec![((2.0 * m - 1.0) * omega / (2.0 * m)).sqrt()]
+    }
+}
+
+impl distribution::Moments for Nakagami {
+    fn raw_moment(&self, n: usize) -> f64 {
+        self.moment(n as f64)
+    }
+}
+
+impl distribution::Sample for Nakagami {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained as the square root of a gamma variate with
+    /// shape `m` and scale `omega / m`.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::gamma;
+        (gamma::sample(self.m, source) * self.omega / self.m).sqrt()
+    }
+}
+
+impl distribution::Skewness for Nakagami {
+    fn skewness(&self) -> f64 {
+        let (m1, m2, m3) = (self.moment(1.0), self.moment(2.0), self.moment(3.0));
+        (m3 - 3.0 * m1 * m2 + 2.0 * m1 * m1 * m1) / (m2 - m1 * m1).powf(1.5)
+    }
+}
+
+impl distribution::Support for Nakagami {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Excluded, Unbounded};
+        (Excluded(0.0), Unbounded)
+    }
+}
+
+impl distribution::Variance for Nakagami {
+    #[inline]
+    fn variance(&self) -> f64 {
+        let m1 = self.moment(1.0);
+        self.omega - m1 * m1
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($m:expr, $omega:expr) => (Nakagami::new($m, $omega));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(2.0, 3.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.38764836430055594, 0.8694752172099528, -0.4999999999999999];
+        let im = vec![
+            -0.8321955389035346,
+            0.4615963980877201,
+            1.0069720200272886e-18,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(2.0, 3.0);
+        let x = vec![-1.0, 0.0, 0.25, 0.5, 1.0, 1.5, 2.0, 3.0, 4.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            1.3322075793182475e-02,
+            9.4053524987846013e-02,
+            4.5637077247341512e-01,
+            6.6939048044528948e-01,
+            4.9410454202881093e-01,
+            5.9490052239992604e-02,
+            1.3260288650204161e-03,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(2.0, 3.0);
+        let x = vec![-1.0, 0.0, 0.25, 0.5, 1.0, 1.5, 2.0, 3.0, 4.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            8.4431551131438731e-04,
+            1.2437987627616914e-02,
+            1.4430480161234663e-01,
+            4.4217459962892541e-01,
+            7.4522734551639436e-01,
+            9.8264873476333547e-01,
+            9.9972806048666574e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        use distribution::Rayleigh;
+        assert::close(new!(2.0, 3.0).entropy(), 8.7540887084643615e-01, 1e-14);
+        assert::close(
+            new!(1.0, 2.0).entropy(),
+            Rayleigh::new(1.0).entropy(),
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn inverse() {
+        use core::f64::INFINITY;
+        let d = new!(2.0, 3.0);
+        let p = vec![
+            0.0, 0.01, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.99, 1.0,
+        ];
+        let x = vec![
+            0.0000000000000000e+00,
+            4.7205096163433341e-01,
+            8.9315027435724281e-01,
+            1.1120172946269662e+00,
+            1.2829745968082289e+00,
+            1.4368827415952667e+00,
+            1.5866696206283748e+00,
+            1.7416859269073128e+00,
+            1.9128054592457400e+00,
+            2.1193070849933910e+00,
+            2.4154875811730316e+00,
+            3.1555551178819101e+00,
+            INFINITY,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(2.0, 3.0).kurtosis(), 5.9295089399549514e-02, 1e-13);
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(2.0, 3.0);
+        let x = vec![-40.0, 0.05, 0.9, 4.0, 40.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -9.106646522985024,
+            -0.9738645826298624,
+            -6.625566618963378,
+            -1055.7178113399814,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-11,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(2.0, 3.0).mean(), 1.6281028227561023e+00, 1e-15);
+    }
+
+    #[test]
+    fn median() {
+        assert::close(new!(2.0, 3.0).median(), 1.5866696206283748e+00, 1e-12);
+    }
+
+    #[test]
+    fn modes() {
+        assert::close(new!(2.0, 3.0).modes(), vec![1.5], 1e-15);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(2.0, 3.0);
+        let mut source = source::default(42);
+
+        let cross_entropy = -(0..n)
+            .map(|_| d.density(d.sample(&mut source)).ln())
+            .sum::<f64>()
+            / n as f64;
+        assert!((cross_entropy - d.entropy()).abs() < 0.01);
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(2.0, 3.0).skewness(), 4.0569507726267173e-01, 1e-13);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Excluded, Unbounded};
+        assert_eq!(new!(2.0, 3.0).support(), (Excluded(0.0), Unbounded));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(2.0, 3.0).variance(), 3.4928119853361195e-01, 1e-14);
+    }
+}
@@ -0,0 +1,353 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A negative binomial distribution.
+///
+/// The distribution describes the number of failures before `r` successes in
+/// a sequence of Bernoulli trials. A real-valued `r` yields the
+/// gamma–Poisson mixture, which is often used for overdispersed count data.
+#[derive(Clone, Copy, Debug)]
+pub struct NegativeBinomial {
+    r: f64,
+    p: f64,
+    q: f64,
+    ln_q: f64,
+    ln_norm: f64,
+}
+
+impl NegativeBinomial {
+    /// Create a negative binomial distribution with `r` successes and success
+    /// probability `p`.
+    ///
+    /// It should hold that `r > 0`, `p > 0`, and `p < 1`.
+    #[inline]
+    pub fn new(r: f64, p: f64) -> Self {
+        use special::Gamma;
+        should!(r > 0.0 && p > 0.0 && p < 1.0);
+        NegativeBinomial {
+            r,
+            p,
+            q: 1.0 - p,
+            ln_q: (-p).ln_1p(),
+            ln_norm: r * p.ln() - Gamma::ln_gamma(r).0,
+        }
+    }
+
+    /// Return the number of successes.
+    #[inline(always)]
+    pub fn r(&self) -> f64 {
+        self.r
+    }
+
+    /// Return the success probability.
+    #[inline(always)]
+    pub fn p(&self) -> f64 {
+        self.p
+    }
+
+    /// Return the failure probability.
+    #[inline(always)]
+    pub fn q(&self) -> f64 {
+        self.q
+    }
+}
+
+impl distribution::CharacteristicFunction for NegativeBinomial {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use core::f64::consts::FRAC_PI_2;
+        use distribution::complex;
+        let q = self.q;
+        let ratio = complex::div(
+            (self.p, 0.0),
+            (1.0 - q * (FRAC_PI_2 - t).sin(), -q * t.sin()),
+        );
+        complex::powf(ratio, self.r)
+    }
+}
+
+impl distribution::Discrete for NegativeBinomial {
+    #[inline]
+    fn mass(&self, x: usize) -> f64 {
+        self.log_mass(x).exp()
+    }
+
+    #[inline]
+    fn log_mass(&self, x: usize) -> f64 {
+        use special::Gamma;
+        let x = x as f64;
+        let ln_choose = Gamma::ln_gamma(x + self.r).0 - Gamma::ln_gamma(x + 1.0).0;
+        ln_choose + x * self.ln_q + self.ln_norm
+    }
+}
+
+impl distribution::Distribution for NegativeBinomial {
+    type Value = usize;
+
+    /// Compute the cumulative distribution function.
+    ///
+    /// The implementation is based on the incomplete beta function.
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        use special::Beta;
+        if x < 0.0 {
+            return 0.0;
+        }
+        let (a, b) = (self.r, x.floor() + 1.0);
+        self.p.inc_beta(a, b, a.ln_beta(b))
+    }
+}
+
+impl distribution::Kurtosis for NegativeBinomial {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        6.0 / self.r + self.p * self.p / (self.r * self.q)
+    }
+}
+
+impl distribution::Mean for NegativeBinomial {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.r * self.q / self.p
+    }
+}
+
+impl distribution::Mgf for NegativeBinomial {
+    /// Compute the moment-generating function.
+    ///
+    /// The function is infinite if `t >= -ln(1 - p)`.
+    fn mgf(&self, t: f64) -> f64 {
+        if t >= -self.ln_q {
+            f64::INFINITY
+        } else {
+            (self.p / (1.0 - self.q * t.exp())).powf(self.r)
+        }
+    }
+}
+
+impl distribution::Modes for NegativeBinomial {
+    fn modes(&self) -> Vec<usize> {
+        if self.r <= 1.0 {
+            return vec![0];
+        }
+        let mode = (self.r - 1.0) * self.q / self.p;
+        let floor = mode.floor();
+        if floor == mode && floor > 0.0 {
+            vec![floor as usize - 1, floor as usize]
+        } else {
+            vec![floor as usize]
+        }
+    }
+}
+
+impl distribution::Moments for NegativeBinomial {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is computed by summing the probability mass function.
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::{moments, Discrete, Mean};
+        moments::discrete(|x| self.mass(x), n, 0, usize::MAX, self.mean())
+    }
+}
+
+impl distribution::Sample for NegativeBinomial {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained by drawing a Poisson variate whose rate is
+    /// itself a gamma variate.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> usize
+    where
+        S: Source,
+    {
+        use distribution::{gamma, poisson};
+        let lambda = gamma::sample(self.r, source) * self.q / self.p;
+        poisson::sample(lambda, source)
+    }
+}
+
+impl distribution::Skewness for NegativeBinomial {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        (2.0 - self.p) / (self.r * self.q).sqrt()
+    }
+}
+
+impl distribution::Support for NegativeBinomial {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Included, Unbounded};
+        (Included(0.0), Unbounded)
+    }
+}
+
+impl distribution::Variance for NegativeBinomial {
+    #[inline]
+    fn variance(&self) -> f64 {
+        self.r * self.q / (self.p * self.p)
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($r:expr, $p:expr) => (NegativeBinomial::new($r, $p));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(2.5, 0.7);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.5955566955105766, 0.8912682883967327, 0.23559045738744067];
+        let im = vec![-0.4201274961208902, 0.28461077525011197, 0.159383741984885];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(2.5, 0.4);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 2.0, 3.5, 5.0, 10.0, 20.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            1.0119288512538814e-01,
+            1.0119288512538814e-01,
+            2.5298221281347033e-01,
+            4.1236100688595667e-01,
+            5.5580192155119434e-01,
+            7.6644490473709592e-01,
+            9.6456086190980239e-01,
+            9.9951569395631545e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(2.5, 0.4).kurtosis(), 2.5066666666666668e+00, 1e-15);
+    }
+
+    #[test]
+    fn log_mass() {
+        let d = new!(2.5, 0.7);
+        let x = vec![0, 5, 100, 10000];
+        let p = vec![
+            -0.8916873598468311,
+            -4.449361757972742,
+            -114.64726922471823,
+            -12027.088715444213,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_mass(x)).collect::<Vec<_>>(),
+            &p,
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn mass() {
+        let d = new!(2.5, 0.4);
+        let x = vec![0, 1, 2, 3, 5, 10, 20];
+        let p = vec![
+            1.0119288512538814e-01,
+            1.5178932768808220e-01,
+            1.5937879407248631e-01,
+            1.4344091466523767e-01,
+            9.2304228587080456e-02,
+            1.7357492890014710e-02,
+            2.7258506146019180e-04,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.mass(x)).collect::<Vec<_>>(), &p, 1e-14);
+
+        let d = new!(1.0, 0.4);
+        let geometric = Geometric::new(0.4);
+        assert::close(
+            &x.iter().map(|&x| d.mass(x)).collect::<Vec<_>>(),
+            &x.iter().map(|&x| geometric.mass(x)).collect::<Vec<_>>(),
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(2.5, 0.4).mean(), 3.75, 1e-15);
+    }
+
+    #[test]
+    fn mgf() {
+        let d = new!(2.5, 0.7);
+        let t = vec![-0.7, -0.1, 0.2, 0.45];
+        let m = vec![
+            0.6136066512886752,
+            0.9048956904711146,
+            1.2830478966750647,
+            2.009403618302028,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+        assert_eq!(d.mgf(1.5), f64::INFINITY);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(2.5, 0.4).modes(), vec![2]);
+        assert_eq!(new!(3.0, 0.5).modes(), vec![1, 2]);
+        assert_eq!(new!(0.5, 0.4).modes(), vec![0]);
+    }
+
+    #[test]
+    fn moments() {
+        let d = new!(2.5, 0.4);
+        let m = vec![25274.1943359375, 364433.29467773438, 5980108.0032348633];
+        assert::close(
+            &(5..8).map(|n| d.raw_moment(n)).collect::<Vec<_>>(),
+            &m,
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(2.5, 0.4);
+        let mut source = source::default(42);
+
+        let mean = (0..n).map(|_| d.sample(&mut source) as f64).sum::<f64>() / n as f64;
+        assert!((mean - d.mean()).abs() < 0.05);
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(2.5, 0.4).skewness(), 1.3063945294843617e+00, 1e-15);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Included, Unbounded};
+        assert_eq!(new!(2.5, 0.7).support(), (Included(0.0), Unbounded));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(2.5, 0.4).variance(), 9.375, 1e-14);
+    }
+}
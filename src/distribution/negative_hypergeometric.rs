@@ -0,0 +1,362 @@
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A negative hypergeometric distribution.
+///
+/// The distribution describes the number of successes drawn without
+/// replacement from a population of size `population` that contains
+/// `successes` successes before the `failures`th failure is drawn.
+#[derive(Clone, Copy, Debug)]
+pub struct NegativeHypergeometric {
+    population: usize,
+    successes: usize,
+    failures: usize,
+    ln_norm: f64,
+}
+
+impl NegativeHypergeometric {
+    /// Create a negative hypergeometric distribution with population size
+    /// `population`, `successes` successes in the population, and drawing
+    /// stopped after `failures` failures.
+    ///
+    /// It should hold that `successes < population`, `failures > 0`, and
+    /// `failures <= population - successes`.
+    #[inline]
+    pub fn new(population: usize, successes: usize, failures: usize) -> Self {
+        use distribution::hypergeometric::ln_choose;
+        should!(successes < population && failures > 0 && failures <= population - successes);
+        NegativeHypergeometric {
+            population,
+            successes,
+            failures,
+            ln_norm: ln_choose(population, successes),
+        }
+    }
+
+    /// Return the population size.
+    #[inline(always)]
+    pub fn population(&self) -> usize {
+        self.population
+    }
+
+    /// Return the number of successes in the population.
+    #[inline(always)]
+    pub fn successes(&self) -> usize {
+        self.successes
+    }
+
+    /// Return the number of failures after which drawing is stopped.
+    #[inline(always)]
+    pub fn failures(&self) -> usize {
+        self.failures
+    }
+
+    /// Compute the `k`th raw moment.
+    fn moment(&self, k: i32) -> f64 {
+        use distribution::Discrete;
+        (0..=self.successes)
+            .map(|x| self.mass(x) * (x as f64).powi(k))
+            .sum()
+    }
+
+    /// Return the parameters as floating-point numbers.
+    #[inline]
+    fn parameters(&self) -> (f64, f64, f64) {
+        (
+            self.population as f64,
+            self.successes as f64,
+            self.failures as f64,
+        )
+    }
+}
+
+impl distribution::CharacteristicFunction for NegativeHypergeometric {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Discrete};
+        characteristic::discrete(|x| self.mass(x), t, 0, self.successes, f64::INFINITY)
+    }
+}
+
+impl distribution::Discrete for NegativeHypergeometric {
+    #[inline]
+    fn mass(&self, x: usize) -> f64 {
+        self.log_mass(x).exp()
+    }
+
+    /// Compute the natural logarithm of the probability mass function.
+    ///
+    /// The function is evaluated via logarithms of binomial coefficients.
+    fn log_mass(&self, x: usize) -> f64 {
+        use distribution::hypergeometric::ln_choose;
+        let &NegativeHypergeometric {
+            population: n,
+            successes: k,
+            failures: r,
+            ..
+        } = self;
+        if x > k {
+            return f64::NEG_INFINITY;
+        }
+        ln_choose(x + r - 1, x) + ln_choose(n - r - x, k - x) - self.ln_norm
+    }
+}
+
+impl distribution::Distribution for NegativeHypergeometric {
+    type Value = usize;
+
+    /// Compute the cumulative distribution function.
+    ///
+    /// The probability masses are summed over whichever tail lies farther from
+    /// the expected value, which avoids cancellation.
+    fn distribution(&self, x: f64) -> f64 {
+        use distribution::{Discrete, Mean};
+        if x < 0.0 {
+            return 0.0;
+        }
+        let x = x.floor() as usize;
+        if x >= self.successes {
+            return 1.0;
+        }
+        if (x as f64) < self.mean() {
+            (0..=x).map(|i| self.mass(i)).sum::<f64>().min(1.0)
+        } else {
+            let tail = ((x + 1)..=self.successes).map(|i| self.mass(i));
+            (1.0 - tail.sum::<f64>()).max(0.0)
+        }
+    }
+}
+
+impl distribution::Kurtosis for NegativeHypergeometric {
+    fn kurtosis(&self) -> f64 {
+        let (m1, m2, m3, m4) = (
+            self.moment(1),
+            self.moment(2),
+            self.moment(3),
+            self.moment(4),
+        );
+        let variance = m2 - m1 * m1;
+        (m4 - 4.0 * m1 * m3 + 6.0 * m1 * m1 * m2 - 3.0 * m1.powi(4)) / (variance * variance) - 3.0
+    }
+}
+
+impl distribution::Mean for NegativeHypergeometric {
+    #[inline]
+    fn mean(&self) -> f64 {
+        let (n, k, r) = self.parameters();
+        r * k / (n - k + 1.0)
+    }
+}
+
+impl distribution::Mgf for NegativeHypergeometric {
+    fn mgf(&self, t: f64) -> f64 {
+        use distribution::Discrete;
+        (0..=self.successes)
+            .map(|x| self.mass(x) * (t * x as f64).exp())
+            .sum()
+    }
+}
+
+impl distribution::Moments for NegativeHypergeometric {
+    fn raw_moment(&self, n: usize) -> f64 {
+        self.moment(n as i32)
+    }
+}
+
+impl distribution::Sample for NegativeHypergeometric {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained by simulating draws from the urn until the
+    /// required number of failures has been drawn.
+    fn sample<S>(&self, source: &mut S) -> usize
+    where
+        S: Source,
+    {
+        let mut successes = self.successes;
+        let mut failures = self.population - self.successes;
+        let mut count = 0;
+        while failures > self.population - self.successes - self.failures {
+            let remaining = (successes + failures) as f64;
+            if source.read::<f64>() * remaining < successes as f64 {
+                successes -= 1;
+                count += 1;
+            } else {
+                failures -= 1;
+            }
+        }
+        count
+    }
+}
+
+impl distribution::Skewness for NegativeHypergeometric {
+    fn skewness(&self) -> f64 {
+        let (m1, m2, m3) = (self.moment(1), self.moment(2), self.moment(3));
+        let variance = m2 - m1 * m1;
+        (m3 - 3.0 * m1 * m2 + 2.0 * m1.powi(3)) / variance.powf(1.5)
+    }
+}
+
+impl distribution::Support for NegativeHypergeometric {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Included;
+        (Included(0.0), Included(self.successes as f64))
+    }
+}
+
+impl distribution::Variance for NegativeHypergeometric {
+    #[inline]
+    fn variance(&self) -> f64 {
+        let (n, k, r) = self.parameters();
+        r * k * (n + 1.0) * (n - k - r + 1.0) / ((n - k + 1.0).powi(2) * (n - k + 2.0))
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($population:expr, $successes:expr, $failures:expr) => (
+            NegativeHypergeometric::new($population, $successes, $failures)
+        );
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(20, 8, 3);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            0.24189693505211135,
+            0.7777636154250362,
+            0.043583387039499946,
+        ];
+        let im = vec![
+            -0.5495901461267894,
+            0.47016031040082906,
+            0.08336302967710156,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(20, 8, 3);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 3.0, 5.0, 8.0, 9.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            1.9298245614035087e-01,
+            1.9298245614035087e-01,
+            4.6542827657378744e-01,
+            8.6274509803921573e-01,
+            9.8455981582916563e-01,
+            1.0000000000000000e+00,
+            1.0000000000000000e+00,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn log_mass() {
+        let d = new!(20, 8, 3);
+        let x = vec![0, 2, 5, 20];
+        let p = vec![
+            -1.645155995036177,
+            -1.4338469013689732,
+            -3.3056490782705623,
+            f64::NEG_INFINITY,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_mass(x)).collect::<Vec<_>>(),
+            &p,
+            1e-13,
+        );
+    }
+
+    #[test]
+    fn mass() {
+        let d = new!(20, 8, 3);
+        let x = vec![0, 1, 2, 4, 6, 8, 9];
+        let p = vec![
+            1.9298245614035087e-01,
+            2.7244582043343651e-01,
+            2.3839009287925697e-01,
+            8.5139318885448914e-02,
+            1.2225132968167024e-02,
+            3.5722791140747797e-04,
+            0.0000000000000000e+00,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.mass(x)).collect::<Vec<_>>(), &p, 1e-14);
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(20, 8, 3).kurtosis(), 1.4972222222222223e-01, 1e-13);
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(20, 8, 3).mean(), 1.8461538461538463e+00, 1e-15);
+    }
+
+    #[test]
+    fn mgf() {
+        let d = new!(20, 8, 3);
+        let t = vec![-0.7, -0.1, 0.2, 0.45];
+        let m = vec![
+            0.4130136726367814,
+            0.8400190525832638,
+            1.514090527330169,
+            2.9443559104059243,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(20, 8, 3);
+        let mut source = source::default(42);
+
+        let mean = (0..n).map(|_| d.sample(&mut source) as f64).sum::<f64>() / n as f64;
+        assert!((mean - d.mean()).abs() < 0.02);
+
+        for x in Independent(&d, &mut source).take(100) {
+            assert!(x <= 8);
+        }
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(20, 8, 3).skewness(), 7.1326929446020115e-01, 1e-14);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::Included;
+        assert_eq!(new!(20, 8, 3).support(), (Included(0.0), Included(8.0)));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(20, 8, 3).variance(), 2.1301775147928996e+00, 1e-15);
+    }
+}
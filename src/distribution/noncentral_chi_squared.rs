@@ -0,0 +1,378 @@
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A noncentral chi-squared distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct NoncentralChiSquared {
+    k: f64,
+    lambda: f64,
+}
+
+impl NoncentralChiSquared {
+    /// Create a noncentral chi-squared distribution with `k` degrees of
+    /// freedom and noncentrality `lambda`.
+    ///
+    /// It should hold that `k > 0` and `lambda >= 0`.
+    #[inline]
+    pub fn new(k: f64, lambda: f64) -> Self {
+        should!(k > 0.0 && lambda >= 0.0);
+        NoncentralChiSquared { k, lambda }
+    }
+
+    /// Return the degrees of freedom.
+    #[inline(always)]
+    pub fn k(&self) -> f64 {
+        self.k
+    }
+
+    /// Return the noncentrality parameter.
+    #[inline(always)]
+    pub fn lambda(&self) -> f64 {
+        self.lambda
+    }
+}
+
+impl distribution::CharacteristicFunction for NoncentralChiSquared {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::complex;
+        let &NoncentralChiSquared { k, lambda } = self;
+        let scale = lambda / (1.0 + 4.0 * t * t);
+        let (re, im) = complex::exp((-2.0 * t * t * scale, t * scale));
+        complex::mul((re, im), complex::powf((1.0, -2.0 * t), -0.5 * k))
+    }
+}
+
+impl distribution::Continuous for NoncentralChiSquared {
+    /// Compute the probability density function.
+    ///
+    /// The function is evaluated as a Poisson mixture of central chi-squared
+    /// densities.
+    fn density(&self, x: f64) -> f64 {
+        use core::f64::consts::LN_2;
+        use distribution::poisson;
+        use special::Gamma;
+        let &NoncentralChiSquared { k, lambda } = self;
+        if x < 0.0 {
+            0.0
+        } else if x == 0.0 {
+            if k < 2.0 {
+                f64::INFINITY
+            } else if k == 2.0 {
+                0.5 * (-0.5 * lambda).exp()
+            } else {
+                0.0
+            }
+        } else {
+            let ln_x = x.ln();
+            poisson::mixture(0.5 * lambda, |j| {
+                let h = 0.5 * k + j;
+                ((h - 1.0) * ln_x - 0.5 * x - h * LN_2 - Gamma::ln_gamma(h).0).exp()
+            })
+        }
+    }
+
+    /// Compute the natural logarithm of the probability density function.
+    ///
+    /// The function is evaluated as a Poisson mixture in log space.
+    fn log_density(&self, x: f64) -> f64 {
+        use core::f64::consts::LN_2;
+        use distribution::poisson;
+        use special::Gamma;
+        let &NoncentralChiSquared { k, lambda } = self;
+        if x <= 0.0 {
+            return self.density(x).ln();
+        }
+        let ln_x = x.ln();
+        poisson::log_mixture(0.5 * lambda, |j| {
+            let h = 0.5 * k + j;
+            (h - 1.0) * ln_x - 0.5 * x - h * LN_2 - Gamma::ln_gamma(h).0
+        })
+    }
+}
+
+impl distribution::Distribution for NoncentralChiSquared {
+    type Value = f64;
+
+    /// Compute the cumulative distribution function.
+    ///
+    /// The function is evaluated as a Poisson mixture of central chi-squared
+    /// distribution functions.
+    fn distribution(&self, x: f64) -> f64 {
+        use distribution::poisson;
+        use special::Gamma;
+        if x <= 0.0 {
+            0.0
+        } else {
+            let k = self.k;
+            poisson::mixture(0.5 * self.lambda, |j| (0.5 * x).inc_gamma(0.5 * k + j)).min(1.0)
+        }
+    }
+}
+
+impl distribution::Kurtosis for NoncentralChiSquared {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        let &NoncentralChiSquared { k, lambda } = self;
+        12.0 * (k + 4.0 * lambda) / (k + 2.0 * lambda).powi(2)
+    }
+}
+
+impl distribution::Mean for NoncentralChiSquared {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.k + self.lambda
+    }
+}
+
+impl distribution::Mgf for NoncentralChiSquared {
+    /// Compute the moment-generating function.
+    ///
+    /// The function is infinite if `t >= 1/2`.
+    fn mgf(&self, t: f64) -> f64 {
+        let &NoncentralChiSquared { k, lambda } = self;
+        if t >= 0.5 {
+            f64::INFINITY
+        } else {
+            let x = 1.0 - 2.0 * t;
+            (lambda * t / x - 0.5 * k * x.ln()).exp()
+        }
+    }
+}
+
+impl distribution::Moments for NoncentralChiSquared {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is computed from the cumulants, the `j`th of which is `2^(j -
+    /// 1) (j - 1)! (k + j lambda)`.
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::moments;
+        let &NoncentralChiSquared { k, lambda } = self;
+        let cumulant =
+            |j: usize| (1..j).fold(k + j as f64 * lambda, |product, i| product * 2.0 * i as f64);
+        moments::cumulants(cumulant, n)
+    }
+}
+
+impl distribution::Sample for NoncentralChiSquared {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained from a central chi-squared distribution whose
+    /// degrees of freedom are increased by twice a Poisson variate with rate
+    /// `lambda / 2`.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::{gamma, poisson};
+        let j = poisson::sample(0.5 * self.lambda, source);
+        2.0 * gamma::sample(0.5 * self.k + j as f64, source)
+    }
+}
+
+impl distribution::Skewness for NoncentralChiSquared {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        let &NoncentralChiSquared { k, lambda } = self;
+        8f64.sqrt() * (k + 3.0 * lambda) / (k + 2.0 * lambda).powf(1.5)
+    }
+}
+
+impl distribution::Support for NoncentralChiSquared {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Included, Unbounded};
+        (Included(0.0), Unbounded)
+    }
+}
+
+impl distribution::Variance for NoncentralChiSquared {
+    #[inline]
+    fn variance(&self) -> f64 {
+        2.0 * (self.k + 2.0 * self.lambda)
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($k:expr, $lambda:expr) => (NoncentralChiSquared::new($k, $lambda));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(3.0, 2.5);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            -0.08358391586631563,
+            0.1181689645951681,
+            -0.024065674738377552,
+        ];
+        let im = vec![
+            -0.17470594977469894,
+            0.5579795693419886,
+            0.027882974732188745,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(3.0, 2.5);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 2.0, 4.0, 6.0, 10.0, 20.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            7.6901561505609256e-02,
+            1.0204478813180075e-01,
+            1.2299006053428375e-01,
+            1.1535432626766383e-01,
+            8.6493993184598383e-02,
+            3.6142837576849524e-02,
+            1.9320686915513976e-03,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+
+        let d = new!(3.0, 0.0);
+        let chi_squared = ChiSquared::new(3.0);
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &x.iter()
+                .map(|&x| chi_squared.density(x))
+                .collect::<Vec<_>>(),
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(3.0, 2.5);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 2.0, 4.0, 6.0, 10.0, 20.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            2.6185666340326989e-02,
+            7.1560120260761001e-02,
+            1.8636366488062314e-01,
+            4.3146149918696319e-01,
+            6.3438307046900944e-01,
+            8.7079012512499387e-01,
+            9.9421575373944515e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(3.0, 2.5).kurtosis(), 2.4375, 1e-15);
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(3.0, 2.0);
+        let x = vec![0.5, 40.0, 1600.0, 1e5];
+        let p = vec![
+            -2.3540727619134498,
+            -14.014387411071099,
+            -746.39011680912079,
+            -49555.745063804087,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-10,
+        );
+
+        let d = new!(1.5, 300.0);
+        let x = vec![1e-3, 300.0, 1600.0];
+        let p = vec![-148.89942841671725, -4.4636639284597341, -262.2712596718599];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(3.0, 2.5).mean(), 5.5);
+    }
+
+    #[test]
+    fn mgf() {
+        let d = new!(3.0, 2.5);
+        let t = vec![-0.7, -0.1, 0.2, 0.45];
+        let m = vec![
+            0.12972093721034428,
+            0.6176609056180912,
+            4.950911836362604,
+            2431156.5278737834,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+        assert_eq!(d.mgf(0.5), f64::INFINITY);
+    }
+
+    #[test]
+    fn moments() {
+        let d = new!(3.0, 2.5);
+        let m = vec![114734.84375, 2149241.640625, 45503992.7734375];
+        assert::close(
+            &(5..8).map(|n| d.raw_moment(n)).collect::<Vec<_>>(),
+            &m,
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let mut source = source::default(42);
+
+        for &lambda in &[2.5, 50.0] {
+            let d = new!(3.0, lambda);
+            let mean = (0..n).map(|_| d.sample(&mut source)).sum::<f64>() / n as f64;
+            assert!((mean - d.mean()).abs() < 0.01 * d.mean());
+        }
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(3.0, 2.5).skewness(), 1.3125, 1e-15);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Included, Unbounded};
+        assert_eq!(new!(3.0, 2.5).support(), (Included(0.0), Unbounded));
+    }
+
+    #[test]
+    fn variance() {
+        assert_eq!(new!(3.0, 2.5).variance(), 16.0);
+    }
+}
@@ -0,0 +1,429 @@
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution::{self, NoncentralChiSquared};
+use source::Source;
+
+/// A noncentral Fisher–Snedecor distribution, also known as the noncentral
+/// F-distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct NoncentralF {
+    d1: f64,
+    d2: f64,
+    lambda: f64,
+    numerator: NoncentralChiSquared,
+}
+
+impl NoncentralF {
+    /// Create a noncentral Fisher–Snedecor distribution with `d1` and `d2`
+    /// degrees of freedom and noncentrality `lambda`.
+    ///
+    /// It should hold that `d1 > 0`, `d2 > 0`, and `lambda >= 0`.
+    #[inline]
+    pub fn new(d1: f64, d2: f64, lambda: f64) -> Self {
+        should!(d1 > 0.0 && d2 > 0.0 && lambda >= 0.0);
+        NoncentralF {
+            d1,
+            d2,
+            lambda,
+            numerator: NoncentralChiSquared::new(d1, lambda),
+        }
+    }
+
+    /// Return the degrees of freedom of the numerator.
+    #[inline(always)]
+    pub fn d1(&self) -> f64 {
+        self.d1
+    }
+
+    /// Return the degrees of freedom of the denominator.
+    #[inline(always)]
+    pub fn d2(&self) -> f64 {
+        self.d2
+    }
+
+    /// Return the noncentrality parameter.
+    #[inline(always)]
+    pub fn lambda(&self) -> f64 {
+        self.lambda
+    }
+
+    /// Compute `E[X^k]` for `2 k < d2`.
+    fn moment(&self, k: i32) -> f64 {
+        use special::Gamma;
+        let &NoncentralF { d1, d2, lambda, .. } = self;
+        let a = d1 + lambda;
+        let b = d1 + 2.0 * lambda;
+        let numerator = match k {
+            1 => a,
+            2 => a * a + 2.0 * b,
+            3 => a * a * a + 6.0 * a * b + 8.0 * (d1 + 3.0 * lambda),
+            4 => {
+                a.powi(4)
+                    + 12.0 * a * a * b
+                    + 4.0 * (11.0 * d1 * d1 + 44.0 * d1 * lambda + 36.0 * lambda * lambda)
+                    + 48.0 * (d1 + 4.0 * lambda)
+            }
+            _ => unreachable!(),
+        };
+        let h = 0.5 * d2;
+        let ln_denominator = Gamma::ln_gamma(h - k as f64).0 - Gamma::ln_gamma(h).0;
+        (0.5 * d2 / d1).powi(k) * numerator * ln_denominator.exp()
+    }
+}
+
+impl distribution::CharacteristicFunction for NoncentralF {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Continuous};
+        characteristic::continuous(
+            |x| self.density(x),
+            t,
+            0.0,
+            f64::INFINITY,
+            0.0,
+            1.0 + self.lambda / self.d1,
+        )
+    }
+}
+
+impl distribution::Continuous for NoncentralF {
+    /// Compute the probability density function.
+    ///
+    /// The function is evaluated as a Poisson mixture of central
+    /// Fisher–Snedecor densities.
+    fn density(&self, x: f64) -> f64 {
+        use distribution::poisson;
+        use special::Beta;
+        let &NoncentralF { d1, d2, lambda, .. } = self;
+        if x < 0.0 {
+            0.0
+        } else if x == 0.0 {
+            if d1 < 2.0 {
+                f64::INFINITY
+            } else if d1 == 2.0 {
+                (-0.5 * lambda).exp()
+            } else {
+                0.0
+            }
+        } else {
+            let d1_x = d1 * x;
+            let ln_sum = (d1_x + d2).ln();
+            let ln_y = d1_x.ln() - ln_sum;
+            let ln_1my = d2.ln() - ln_sum;
+            let b = 0.5 * d2;
+            let mixture = poisson::mixture(0.5 * lambda, |j| {
+                let a = 0.5 * d1 + j;
+                ((a - 1.0) * ln_y + (b - 1.0) * ln_1my - a.ln_beta(b)).exp()
+            });
+            mixture * d1 * d2 / (d1_x + d2).powi(2)
+        }
+    }
+
+    /// Compute the natural logarithm of the probability density function.
+    ///
+    /// The function is evaluated as a Poisson mixture in log space.
+    fn log_density(&self, x: f64) -> f64 {
+        use distribution::poisson;
+        use special::Beta;
+        let &NoncentralF { d1, d2, lambda, .. } = self;
+        if x <= 0.0 {
+            return self.density(x).ln();
+        }
+        let d1_x = d1 * x;
+        let ln_sum = (d1_x + d2).ln();
+        let ln_y = d1_x.ln() - ln_sum;
+        let ln_1my = d2.ln() - ln_sum;
+        let b = 0.5 * d2;
+        let ln_mixture = poisson::log_mixture(0.5 * lambda, |j| {
+            let a = 0.5 * d1 + j;
+            (a - 1.0) * ln_y + (b - 1.0) * ln_1my - a.ln_beta(b)
+        });
+        ln_mixture + (d1 * d2).ln() - 2.0 * ln_sum
+    }
+}
+
+impl distribution::Distribution for NoncentralF {
+    type Value = f64;
+
+    /// Compute the cumulative distribution function.
+    ///
+    /// The function is evaluated as a Poisson mixture of regularized
+    /// incomplete beta functions.
+    fn distribution(&self, x: f64) -> f64 {
+        use distribution::poisson;
+        use special::Beta;
+        let &NoncentralF { d1, d2, lambda, .. } = self;
+        if x <= 0.0 {
+            0.0
+        } else {
+            let d1_x = d1 * x;
+            let y = d1_x / (d1_x + d2);
+            let b = 0.5 * d2;
+            poisson::mixture(0.5 * lambda, |j| {
+                let a = 0.5 * d1 + j;
+                y.inc_beta(a, b, a.ln_beta(b))
+            })
+            .min(1.0)
+        }
+    }
+}
+
+impl distribution::Kurtosis for NoncentralF {
+    /// Compute the excess kurtosis.
+    ///
+    /// The excess kurtosis is undefined, which is signified by `NaN`, if
+    /// `d2 <= 8`.
+    fn kurtosis(&self) -> f64 {
+        if self.d2 <= 8.0 {
+            return f64::NAN;
+        }
+        let (m1, m2, m3, m4) = (
+            self.moment(1),
+            self.moment(2),
+            self.moment(3),
+            self.moment(4),
+        );
+        let m1_2 = m1 * m1;
+        (m4 - 4.0 * m1 * m3 + 6.0 * m1_2 * m2 - 3.0 * m1_2 * m1_2) / (m2 - m1_2).powi(2) - 3.0
+    }
+}
+
+impl distribution::Mean for NoncentralF {
+    /// Compute the expected value.
+    ///
+    /// The expected value is infinite if `d2 <= 2`.
+    #[inline]
+    fn mean(&self) -> f64 {
+        let &NoncentralF { d1, d2, lambda, .. } = self;
+        if d2 > 2.0 {
+            d2 * (d1 + lambda) / (d1 * (d2 - 2.0))
+        } else {
+            f64::INFINITY
+        }
+    }
+}
+
+impl distribution::Moments for NoncentralF {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is infinite if `2 n >= d2`.
+    fn raw_moment(&self, n: usize) -> f64 {
+        let &NoncentralF { d1, d2, .. } = self;
+        if 2.0 * n as f64 >= d2 {
+            return f64::INFINITY;
+        }
+        let h = 0.5 * d2;
+        let factor = (1..=n).fold(1.0, |product, j| product * h / (d1 * (h - j as f64)));
+        factor * self.numerator.raw_moment(n)
+    }
+}
+
+impl distribution::Sample for NoncentralF {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained as the ratio of a noncentral and a central
+    /// chi-squared variate, each divided by its degrees of freedom.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::gamma;
+        let x = self.numerator.sample(source);
+        let y = 2.0 * gamma::sample(0.5 * self.d2, source);
+        (x * self.d2) / (y * self.d1)
+    }
+}
+
+impl distribution::Skewness for NoncentralF {
+    /// Compute the skewness.
+    ///
+    /// The skewness is undefined, which is signified by `NaN`, if `d2 <= 6`.
+    fn skewness(&self) -> f64 {
+        if self.d2 <= 6.0 {
+            return f64::NAN;
+        }
+        let (m1, m2, m3) = (self.moment(1), self.moment(2), self.moment(3));
+        (m3 - 3.0 * m1 * m2 + 2.0 * m1 * m1 * m1) / (m2 - m1 * m1).powf(1.5)
+    }
+}
+
+impl distribution::Support for NoncentralF {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Included, Unbounded};
+        (Included(0.0), Unbounded)
+    }
+}
+
+impl distribution::Variance for NoncentralF {
+    /// Compute the variance.
+    ///
+    /// The variance is infinite if `2 < d2 <= 4` and undefined, which is
+    /// signified by `NaN`, if `d2 <= 2`.
+    fn variance(&self) -> f64 {
+        let &NoncentralF { d1, d2, lambda, .. } = self;
+        if d2 > 4.0 {
+            let a = d1 + lambda;
+            2.0 * (d2 / d1).powi(2) * (a * a + (d1 + 2.0 * lambda) * (d2 - 2.0))
+                / ((d2 - 2.0).powi(2) * (d2 - 4.0))
+        } else if d2 > 2.0 {
+            f64::INFINITY
+        } else {
+            f64::NAN
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($d1:expr, $d2:expr, $lambda:expr) => (NoncentralF::new($d1, $d2, $lambda));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(4.0, 12.0, 3.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.2645351447170148, 0.7429110383040327, -0.11650787689071171];
+        let im = vec![-0.592105206679484, 0.48807810493139564, 0.19221327074524377];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(4.0, 12.0, 3.0);
+        let x = vec![-1.0, 0.0, 0.25, 0.5, 1.0, 1.5, 2.0, 3.0, 5.0, 10.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            2.1263322967432841e-01,
+            3.3192815033048867e-01,
+            3.8268381152577768e-01,
+            3.2527354558792354e-01,
+            2.4836834519556056e-01,
+            1.3043824049806449e-01,
+            3.5350070666734991e-02,
+            2.4717718089699533e-03,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+
+        let d = new!(4.0, 12.0, 0.0);
+        let fisher = FisherSnedecor::new(4.0, 12.0);
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &x.iter().map(|&x| fisher.density(x)).collect::<Vec<_>>(),
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(4.0, 12.0, 3.0);
+        let x = vec![-1.0, 0.0, 0.25, 0.5, 1.0, 1.5, 2.0, 3.0, 5.0, 10.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            2.8614851609276588e-02,
+            9.8450148403000065e-02,
+            2.8468440537892215e-01,
+            4.6369855060870457e-01,
+            6.0702476819298823e-01,
+            7.9120109200311028e-01,
+            9.3576821380984831e-01,
+            9.9366371497645334e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(
+            new!(4.0, 12.0, 3.0).kurtosis(),
+            2.3035854240799964e+01,
+            1e-11,
+        );
+        assert!(new!(4.0, 8.0, 3.0).kurtosis().is_nan());
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(3.0, 5.0, 2.0);
+        let x = vec![0.5, 40.0, 1e100, 1e300];
+        let p = vec![
+            -0.95896441523995914,
+            -9.0840814865258671,
+            -801.83988615903049,
+            -2413.6494512548625,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(4.0, 12.0, 3.0).mean(), 2.1, 1e-15);
+        assert_eq!(new!(4.0, 2.0, 3.0).mean(), f64::INFINITY);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(4.0, 12.0, 3.0);
+        let mut source = source::default(42);
+
+        let mean = (0..n).map(|_| d.sample(&mut source)).sum::<f64>() / n as f64;
+        assert!((mean - d.mean()).abs() < 0.02);
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(
+            new!(4.0, 12.0, 3.0).skewness(),
+            2.9954198006629076e+00,
+            1e-13,
+        );
+        assert!(new!(4.0, 6.0, 3.0).skewness().is_nan());
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Included, Unbounded};
+        assert_eq!(new!(4.0, 12.0, 3.0).support(), (Included(0.0), Unbounded));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(4.0, 12.0, 3.0).variance(), 3.3525, 1e-14);
+        assert_eq!(new!(4.0, 4.0, 3.0).variance(), f64::INFINITY);
+        assert!(new!(4.0, 2.0, 3.0).variance().is_nan());
+    }
+}
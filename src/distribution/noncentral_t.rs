@@ -0,0 +1,480 @@
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A noncentral t-distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct NoncentralT {
+    nu: f64,
+    delta: f64,
+}
+
+impl NoncentralT {
+    /// Create a noncentral t-distribution with `nu` degrees of freedom and
+    /// noncentrality `delta`.
+    ///
+    /// It should hold that `nu > 0`.
+    #[inline]
+    pub fn new(nu: f64, delta: f64) -> Self {
+        should!(nu > 0.0);
+        NoncentralT { nu, delta }
+    }
+
+    /// Return the degrees of freedom.
+    #[inline(always)]
+    pub fn nu(&self) -> f64 {
+        self.nu
+    }
+
+    /// Return the noncentrality parameter.
+    #[inline(always)]
+    pub fn delta(&self) -> f64 {
+        self.delta
+    }
+
+    /// Compute `E[T^k]` for `k < nu`.
+    fn moment(&self, k: i32) -> f64 {
+        use special::Gamma;
+        let &NoncentralT { nu, delta } = self;
+        let d2 = delta * delta;
+        let gaussian = match k {
+            1 => delta,
+            2 => d2 + 1.0,
+            3 => delta * (d2 + 3.0),
+            4 => d2 * (d2 + 6.0) + 3.0,
+            _ => unreachable!(),
+        };
+        let k = k as f64;
+        let ln_ratio = Gamma::ln_gamma(0.5 * (nu - k)).0 - Gamma::ln_gamma(0.5 * nu).0;
+        (0.5 * nu).powf(0.5 * k) * ln_ratio.exp() * gaussian
+    }
+}
+
+impl distribution::CharacteristicFunction for NoncentralT {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Continuous};
+        characteristic::continuous(
+            |x| self.density(x),
+            t,
+            f64::NEG_INFINITY,
+            f64::INFINITY,
+            self.delta,
+            (1.0 + self.delta * self.delta).sqrt(),
+        )
+    }
+}
+
+impl distribution::Continuous for NoncentralT {
+    /// Compute the probability density function.
+    ///
+    /// The function is evaluated as the exponential of `log_density`.
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        self.log_density(x).exp()
+    }
+
+    /// Compute the natural logarithm of the probability density function.
+    ///
+    /// The function is evaluated via the representation of the density in
+    /// terms of `int_0^inf t^nu exp(-t^2 / 2 + q t) dt` with `q = delta x /
+    /// sqrt(nu + x^2)`, whose integrand is positive and is integrated around
+    /// its peak.
+    fn log_density(&self, x: f64) -> f64 {
+        use core::f64::consts::{LN_2, PI};
+        use distribution::quadrature;
+        use special::Gamma;
+
+        const TOLERANCE: f64 = 1e-14;
+
+        let &NoncentralT { nu, delta } = self;
+        let ln_sum = if x.abs() > 1.0 {
+            2.0 * x.abs().ln() + (nu / (x * x)).ln_1p()
+        } else {
+            (nu + x * x).ln()
+        };
+        let q = delta * x / (0.5 * ln_sum).exp();
+        let root = (q * q + 4.0 * nu).sqrt();
+        let peak = if q > 0.0 {
+            0.5 * (q + root)
+        } else {
+            2.0 * nu / (root - q)
+        };
+        let ln_f = |t: f64| nu * t.ln() - 0.5 * t * t + q * t;
+        let ln_f_peak = ln_f(peak);
+        let f = |t: f64| (ln_f(t) - ln_f_peak).exp();
+        let width = peak / (peak * peak + nu).sqrt();
+        let integral =
+            quadrature::adaptive(f, 0.0, peak, TOLERANCE) + quadrature::tail(f, peak, width);
+        0.5 * (nu * nu.ln() - delta * delta - (nu - 1.0) * LN_2 - PI.ln() - (nu + 1.0) * ln_sum)
+            - Gamma::ln_gamma(0.5 * nu).0
+            + ln_f_peak
+            + integral.ln()
+    }
+}
+
+impl distribution::Distribution for NoncentralT {
+    type Value = f64;
+
+    /// Compute the cumulative distribution function.
+    ///
+    /// The function is evaluated using the series of Lenth, which is known as
+    /// algorithm AS 243.
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        distribution(self.nu, self.delta, x)
+    }
+}
+
+impl distribution::Kurtosis for NoncentralT {
+    /// Compute the excess kurtosis.
+    ///
+    /// The excess kurtosis is infinite if `2 < nu <= 4` and undefined, which
+    /// is signified by `NaN`, if `nu <= 2`.
+    fn kurtosis(&self) -> f64 {
+        if self.nu > 4.0 {
+            let (m1, m2, m3, m4) = (
+                self.moment(1),
+                self.moment(2),
+                self.moment(3),
+                self.moment(4),
+            );
+            let m1_2 = m1 * m1;
+            (m4 - 4.0 * m1 * m3 + 6.0 * m1_2 * m2 - 3.0 * m1_2 * m1_2) / (m2 - m1_2).powi(2) - 3.0
+        } else if self.nu > 2.0 {
+            f64::INFINITY
+        } else {
+            f64::NAN
+        }
+    }
+}
+
+impl distribution::Mean for NoncentralT {
+    /// Compute the expected value.
+    ///
+    /// The expected value is undefined, which is signified by `NaN`, if
+    /// `nu <= 1`.
+    #[inline]
+    fn mean(&self) -> f64 {
+        if self.nu > 1.0 {
+            self.moment(1)
+        } else {
+            f64::NAN
+        }
+    }
+}
+
+impl distribution::Moments for NoncentralT {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is infinite if `n >= nu` and `n` is even and undefined, which
+    /// is signified by `NaN`, if `n >= nu` and `n` is odd.
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::Gaussian;
+        use special::Gamma;
+        let &NoncentralT { nu, delta } = self;
+        if n as f64 >= nu {
+            return if n % 2 == 0 { f64::INFINITY } else { f64::NAN };
+        }
+        let k = n as f64;
+        let ln_ratio = Gamma::ln_gamma(0.5 * (nu - k)).0 - Gamma::ln_gamma(0.5 * nu).0;
+        let gaussian = Gaussian::new(delta, 1.0);
+        (0.5 * k * (0.5 * nu).ln() + ln_ratio).exp() * gaussian.raw_moment(n)
+    }
+}
+
+impl distribution::Sample for NoncentralT {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::{gamma, gaussian};
+        let z = gaussian::sample(source) + self.delta;
+        let v = 2.0 * gamma::sample(0.5 * self.nu, source);
+        z / (v / self.nu).sqrt()
+    }
+}
+
+impl distribution::Skewness for NoncentralT {
+    /// Compute the skewness.
+    ///
+    /// The skewness is undefined, which is signified by `NaN`, if `nu <= 3`.
+    fn skewness(&self) -> f64 {
+        if self.nu > 3.0 {
+            let (m1, m2, m3) = (self.moment(1), self.moment(2), self.moment(3));
+            (m3 - 3.0 * m1 * m2 + 2.0 * m1 * m1 * m1) / (m2 - m1 * m1).powf(1.5)
+        } else {
+            f64::NAN
+        }
+    }
+}
+
+impl distribution::Support for NoncentralT {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Unbounded;
+        (Unbounded, Unbounded)
+    }
+}
+
+impl distribution::Variance for NoncentralT {
+    /// Compute the variance.
+    ///
+    /// The variance is infinite if `1 < nu <= 2` and undefined, which is
+    /// signified by `NaN`, if `nu <= 1`.
+    #[inline]
+    fn variance(&self) -> f64 {
+        if self.nu > 2.0 {
+            let m1 = self.moment(1);
+            self.moment(2) - m1 * m1
+        } else if self.nu > 1.0 {
+            f64::INFINITY
+        } else {
+            f64::NAN
+        }
+    }
+}
+
+fn distribution(nu: f64, delta: f64, t: f64) -> f64 {
+    use core::f64::consts::{FRAC_1_SQRT_2, FRAC_2_PI};
+    use special::{Beta, Error};
+
+    const MAX_ITERATIONS: usize = 1000;
+    const TOLERANCE: f64 = 1e-15;
+
+    let (t, delta, negative) = if t < 0.0 {
+        (-t, -delta, true)
+    } else {
+        (t, delta, false)
+    };
+
+    let mut sum = 0.0;
+    let x = t * t / (t * t + nu);
+    if x > 0.0 {
+        let lambda = delta * delta;
+        let mut p = 0.5 * (-0.5 * lambda).exp();
+        let mut q = FRAC_2_PI.sqrt() * p * delta;
+        let mut s = 0.5 - p;
+        let mut a = 0.5;
+        let b = 0.5 * nu;
+        let rxb = (1.0 - x).powf(b);
+        let ln_beta = a.ln_beta(b);
+        let mut x_odd = x.inc_beta(a, b, ln_beta);
+        let mut g_odd = 2.0 * rxb * (a * x.ln() - ln_beta).exp();
+        let mut x_even = if b * x < f64::EPSILON {
+            b * x
+        } else {
+            1.0 - rxb
+        };
+        let mut g_even = b * x * rxb;
+        sum = p * x_odd + q * x_even;
+        for i in 1..(MAX_ITERATIONS + 1) {
+            let i = i as f64;
+            a += 1.0;
+            x_odd -= g_odd;
+            x_even -= g_even;
+            g_odd *= x * (a + b - 1.0) / a;
+            g_even *= x * (a + b - 0.5) / (a + 0.5);
+            p *= lambda / (2.0 * i);
+            q *= lambda / (2.0 * i + 1.0);
+            sum += p * x_odd + q * x_even;
+            s -= p;
+            if s <= 0.0 || (2.0 * s * (x_odd - g_odd)).abs() < TOLERANCE {
+                break;
+            }
+        }
+    }
+    sum += 0.5 * (delta * FRAC_1_SQRT_2).compl_error();
+    if negative {
+        (1.0 - sum).clamp(0.0, 1.0)
+    } else {
+        sum.clamp(0.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($nu:expr, $delta:expr) => (NoncentralT::new($nu, $delta));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(5.0, 1.5);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.2931197746956839, 0.793743296555688, -0.09640413514317539];
+        let im = vec![-0.5957845257708774, 0.4517769686690567, 0.06964948791803816];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(5.0, 1.5);
+        let x = vec![-3.0, -1.0, -0.5, 0.0, 0.5, 1.0, 2.0, 3.0, 5.0, 10.0];
+        let p = vec![
+            4.5603167750931251e-04,
+            1.8424961092073550e-02,
+            5.0579077631637853e-02,
+            1.2324024847660857e-01,
+            2.3584544443413896e-01,
+            3.3024279506418008e-01,
+            2.8634378071050864e-01,
+            1.3805111294113462e-01,
+            2.3329020990046193e-02,
+            7.3165710119079904e-04,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-12,
+        );
+
+        let d = new!(5.0, 2.0);
+        let x = vec![-400.0, -30.0, -5.0];
+        let p = vec![
+            3.2446362630796573e-17,
+            1.8089289946844271e-10,
+            6.5232388808541914e-06,
+        ];
+        assert::close(
+            &x.iter()
+                .zip(&p)
+                .map(|(&x, &p)| d.density(x) / p)
+                .collect::<Vec<_>>(),
+            &vec![1.0; 3],
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(5.0, 1.5);
+        let x = vec![-3.0, -1.0, -0.5, 0.0, 0.5, 1.0, 2.0, 3.0, 5.0, 10.0];
+        let p = vec![
+            3.4076871608229462e-04,
+            9.3876456217091616e-03,
+            2.5386352528454316e-02,
+            6.6807201268858071e-02,
+            1.5568997078793823e-01,
+            2.9967516104596015e-01,
+            6.3144924725567175e-01,
+            8.4020530924481107e-01,
+            9.6922979448936819e-01,
+            9.9842296012458653e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-13,
+        );
+
+        let d = new!(5.0, 0.0);
+        let student = StudentT::new(5.0);
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &x.iter()
+                .map(|&x| student.distribution(x))
+                .collect::<Vec<_>>(),
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(7.0, 1.5).kurtosis(), 3.6190796481837149e+00, 1e-13);
+        assert_eq!(new!(4.0, 1.5).kurtosis(), f64::INFINITY);
+        assert!(new!(2.0, 1.5).kurtosis().is_nan());
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(3.0, 2.0);
+        let x = vec![-1e6, -3.0, 0.5, 40.0, 1e200];
+        let p = vec![
+            -59.053165198669677,
+            -8.43571848420662,
+            -2.0427980852950257,
+            -10.703437611433101,
+            -1838.0065012071475,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-12,
+        );
+
+        let d = new!(0.5, -4.0);
+        let x = vec![-1e6, 1e-3, 1e6];
+        let p = vec![-20.979987998263454, -9.3164452837049559, -32.88447299065174];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(5.0, 1.5).mean(), 1.7841241161527712e+00, 1e-14);
+        assert!(new!(1.0, 1.5).mean().is_nan());
+    }
+
+    #[test]
+    fn moments() {
+        let d = new!(9.0, 1.5);
+        let m = vec![235.77916209882244, 1413.4138392857143, 11440.872238428488];
+        assert::close(
+            &(5..8).map(|n| d.raw_moment(n)).collect::<Vec<_>>(),
+            &m,
+            1e-9,
+        );
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(5.0, 1.5);
+        let mut source = source::default(42);
+
+        let mean = (0..n).map(|_| d.sample(&mut source)).sum::<f64>() / n as f64;
+        assert!((mean - d.mean()).abs() < 0.02);
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(5.0, 1.5).skewness(), 1.7323349900113403e+00, 1e-13);
+        assert!(new!(3.0, 1.5).skewness().is_nan());
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::Unbounded;
+        assert_eq!(new!(5.0, 1.5).support(), (Unbounded, Unbounded));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(5.0, 1.5).variance(), 2.2335678048287599e+00, 1e-14);
+        assert_eq!(new!(2.0, 1.5).variance(), f64::INFINITY);
+        assert!(new!(1.0, 1.5).variance().is_nan());
+    }
+}
@@ -0,0 +1,388 @@
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution::{self, InverseGaussian};
+use source::Source;
+
+/// A normal-inverse Gaussian distribution.
+///
+/// The distribution is a variance-mean mixture of Gaussian distributions with
+/// the inverse Gaussian distribution as the mixing distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct NormalInverseGaussian {
+    alpha: f64,
+    beta: f64,
+    mu: f64,
+    delta: f64,
+    gamma: f64,
+    mixing: InverseGaussian,
+}
+
+impl NormalInverseGaussian {
+    /// Create a normal-inverse Gaussian distribution with tail heaviness
+    /// `alpha`, asymmetry `beta`, location `mu`, and scale `delta`.
+    ///
+    /// It should hold that `alpha > |beta|` and `delta > 0`.
+    #[inline]
+    pub fn new(alpha: f64, beta: f64, mu: f64, delta: f64) -> Self {
+        should!(alpha > beta.abs() && delta > 0.0);
+        let gamma = (alpha * alpha - beta * beta).sqrt();
+        NormalInverseGaussian {
+            alpha,
+            beta,
+            mu,
+            delta,
+            gamma,
+            mixing: InverseGaussian::new(delta / gamma, delta * delta),
+        }
+    }
+
+    /// Return the tail-heaviness parameter.
+    #[inline(always)]
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Return the asymmetry parameter.
+    #[inline(always)]
+    pub fn beta(&self) -> f64 {
+        self.beta
+    }
+
+    /// Return the location parameter.
+    #[inline(always)]
+    pub fn mu(&self) -> f64 {
+        self.mu
+    }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn delta(&self) -> f64 {
+        self.delta
+    }
+}
+
+impl distribution::CharacteristicFunction for NormalInverseGaussian {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::complex;
+        let &NormalInverseGaussian {
+            beta,
+            mu,
+            delta,
+            gamma,
+            ..
+        } = self;
+        let root = complex::sqrt((gamma * gamma + t * t, -2.0 * beta * t));
+        complex::exp((delta * (gamma - root.0), mu * t - delta * root.1))
+    }
+}
+
+impl distribution::Continuous for NormalInverseGaussian {
+    fn density(&self, x: f64) -> f64 {
+        use core::f64::consts::PI;
+        use distribution::bessel;
+        let &NormalInverseGaussian {
+            alpha,
+            beta,
+            mu,
+            delta,
+            gamma,
+            ..
+        } = self;
+        let s = (delta * delta + (x - mu) * (x - mu)).sqrt();
+        let exponent = delta * gamma + beta * (x - mu) - alpha * s;
+        alpha * delta / (PI * s) * bessel::kve(1.0, alpha * s) * exponent.exp()
+    }
+
+    fn log_density(&self, x: f64) -> f64 {
+        use core::f64::consts::PI;
+        use distribution::bessel;
+        let &NormalInverseGaussian {
+            alpha,
+            beta,
+            mu,
+            delta,
+            gamma,
+            ..
+        } = self;
+        let s = (delta * delta + (x - mu) * (x - mu)).sqrt();
+        let exponent = delta * gamma + beta * (x - mu) - alpha * s;
+        (alpha * delta / (PI * s)).ln() + bessel::kve(1.0, alpha * s).ln() + exponent
+    }
+}
+
+impl distribution::Distribution for NormalInverseGaussian {
+    type Value = f64;
+
+    /// Compute the cumulative distribution function.
+    ///
+    /// The function is computed by numerical integration of the density over
+    /// the tail that lies on the same side of the mean as `x`.
+    fn distribution(&self, x: f64) -> f64 {
+        use distribution::{quadrature, Continuous, Mean};
+        let &NormalInverseGaussian {
+            alpha, beta, delta, ..
+        } = self;
+        let width = 0.5 * delta.min(1.0 / (alpha - beta.abs()));
+        if x <= self.mean() {
+            quadrature::tail(|x| self.density(x), x, -width).min(1.0)
+        } else {
+            (1.0 - quadrature::tail(|x| self.density(x), x, width)).max(0.0)
+        }
+    }
+}
+
+impl distribution::Kurtosis for NormalInverseGaussian {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        let ratio = self.beta / self.alpha;
+        3.0 * (1.0 + 4.0 * ratio * ratio) / (self.delta * self.gamma)
+    }
+}
+
+impl distribution::Mean for NormalInverseGaussian {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.mu + self.delta * self.beta / self.gamma
+    }
+}
+
+impl distribution::Mgf for NormalInverseGaussian {
+    /// Compute the moment-generating function.
+    ///
+    /// The function is infinite if `|beta + t| > alpha`.
+    fn mgf(&self, t: f64) -> f64 {
+        let &NormalInverseGaussian {
+            alpha,
+            beta,
+            mu,
+            delta,
+            gamma,
+            ..
+        } = self;
+        let x = beta + t;
+        if x.abs() > alpha {
+            f64::INFINITY
+        } else {
+            (mu * t + delta * (gamma - (alpha * alpha - x * x).sqrt())).exp()
+        }
+    }
+}
+
+impl distribution::Moments for NormalInverseGaussian {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is computed by numerical integration.
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::{moments, Continuous, Mean, Variance};
+        moments::continuous(
+            |x| self.density(x),
+            n,
+            f64::NEG_INFINITY,
+            f64::INFINITY,
+            self.mean(),
+            self.deviation(),
+        )
+    }
+}
+
+impl distribution::Sample for NormalInverseGaussian {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained by drawing the variance of a Gaussian variate
+    /// from an inverse Gaussian distribution.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::gaussian;
+        let v = self.mixing.sample(source);
+        self.mu + self.beta * v + v.sqrt() * gaussian::sample(source)
+    }
+}
+
+impl distribution::Skewness for NormalInverseGaussian {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        3.0 * self.beta / (self.alpha * (self.delta * self.gamma).sqrt())
+    }
+}
+
+impl distribution::Support for NormalInverseGaussian {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Unbounded;
+        (Unbounded, Unbounded)
+    }
+}
+
+impl distribution::Variance for NormalInverseGaussian {
+    #[inline]
+    fn variance(&self) -> f64 {
+        self.delta * self.alpha * self.alpha / self.gamma.powi(3)
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($alpha:expr, $beta:expr, $mu:expr, $delta:expr) => (
+            NormalInverseGaussian::new($alpha, $beta, $mu, $delta)
+        );
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(2.0, 1.0, 1.0, 1.5);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.2425585470047428, 0.807764882638375, -0.21881436812713695];
+        let im = vec![
+            -0.7336096697140922,
+            0.5006054647251833,
+            0.010601923538414297,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(2.0, 1.0, 1.0, 1.5);
+        let x = vec![-5.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0, 5.0, 10.0];
+        let p = vec![
+            8.0139830469723372e-09,
+            1.1855795767878577e-04,
+            2.8096329855610419e-03,
+            5.1501438996327399e-02,
+            3.4353057269085679e-01,
+            3.8054702191951784e-01,
+            1.5340076328373276e-01,
+            1.4272805193390392e-02,
+            4.0529305993078658e-05,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(2.0, 1.0, 1.0, 1.5);
+        let x = vec![-5.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0, 5.0, 10.0];
+        let p = vec![
+            2.5242465312797534e-09,
+            3.6966919116277273e-05,
+            8.9365642095886623e-04,
+            1.8292881670750858e-02,
+            1.9391526124561634e-01,
+            6.0846406400021091e-01,
+            8.6898934365901026e-01,
+            9.8809221854001705e-01,
+            9.9996406700252305e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(
+            new!(2.0, 1.0, 1.0, 1.5).kurtosis(),
+            2.3094010767585029e+00,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(2.0, 1.0, 1.0, 1.5);
+        let x = vec![-40.0, 0.5, 400.0, 10000.0];
+        let p = vec![
+            -126.19050205895499,
+            -1.8146970531450035,
+            -405.5574457989723,
+            -10010.384390461899,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(
+            new!(2.0, 1.0, 1.0, 1.5).mean(),
+            1.8660254037844386e+00,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn mgf() {
+        let d = new!(2.0, 1.0, 1.0, 1.5);
+        let t = vec![-0.7, -0.1, 0.2, 0.45];
+        let m = vec![
+            0.34370148357974467,
+            0.8344274703396294,
+            1.4889575010495126,
+            2.6693356314037473,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+        assert_eq!(d.mgf(1.5), f64::INFINITY);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(2.0, 1.0, 1.0, 1.5);
+        let mut source = source::default(42);
+
+        let mean = (0..n).map(|_| d.sample(&mut source)).sum::<f64>() / n as f64;
+        assert!((mean - d.mean()).abs() < 0.01);
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(
+            new!(2.0, 1.0, 1.0, 1.5).skewness(),
+            9.3060485910209956e-01,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::Unbounded;
+        assert_eq!(new!(2.0, 1.0, 1.0, 1.5).support(), (Unbounded, Unbounded));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(
+            new!(2.0, 1.0, 1.0, 1.5).variance(),
+            1.1547005383792515e+00,
+            1e-15,
+        );
+    }
+}
@@ -0,0 +1,476 @@
+//! Numerical evaluation of distribution functions and their inverses.
+//!
+//! The functions are meant to serve as implementations of trait methods for
+//! distributions, including user-defined ones, that lack closed forms. Since
+//! a method of a trait cannot be specialized for the implementors of another
+//! one, a distribution opts in by delegating to the corresponding function,
+//! and a closed form can be provided instead at any time.
+
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution::{quadrature, Continuous, Support};
+
+/// The relative tolerance of integration.
+const TOLERANCE: f64 = 1e-14;
+
+/// The maximal number of iterations of root finding.
+const ITERATIONS: usize = 200;
+
+/// The maximal number of times the step of numerical differentiation is
+/// adjusted.
+const STEPS: usize = 100;
+
+/// The range of changes in the logarithm of the density across a step of
+/// numerical differentiation that is sought.
+const CHANGE: (f64, f64) = (1e-2, 1.0);
+
+/// Compute the cumulative distribution function of a continuous distribution
+/// by integrating the density over the support.
+///
+/// The density is integrated from `x` toward the end of the support that it
+/// decreases toward, and the result is subtracted from one if the end is the
+/// upper one, which keeps the range of integration in a tail. The range is
+/// mapped onto a finite one by `t = x ± w (1 - s) / s`, where `w` is the
+/// local scale of the density estimated from the derivatives of its
+/// logarithm at `x`, and integrated adaptively using the Gauss–Kronrod rule.
+/// The density is assumed to be unimodal; mass separated from `x` by a
+/// distant mode might be resolved poorly.
+///
+/// # Example
+///
+/// ```
+/// use probability::distribution::{numeric, Continuous, Distribution, Support};
+/// use std::ops::Bound;
+///
+/// struct Triangle;
+///
+/// impl Distribution for Triangle {
+///     type Value = f64;
+///
+///     fn distribution(&self, x: f64) -> f64 {
+///         numeric::distribution(self, x)
+///     }
+/// }
+///
+/// impl Continuous for Triangle {
+///     fn density(&self, x: f64) -> f64 {
+///         if 0.0 <= x && x <= 1.0 { 2.0 * x } else { 0.0 }
+///     }
+/// }
+///
+/// impl Support for Triangle {
+///     fn support(&self) -> (Bound<f64>, Bound<f64>) {
+///         (Bound::Included(0.0), Bound::Included(1.0))
+///     }
+/// }
+///
+/// assert!((Triangle.distribution(0.5) - 0.25).abs() < 1e-14);
+/// ```
+pub fn distribution<D>(distribution: &D, x: f64) -> f64
+where
+    D: Continuous + Support,
+{
+    let (a, b) = distribution.support();
+    let (a, b) = (endpoint(a, f64::NEG_INFINITY), endpoint(b, f64::INFINITY));
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    if x <= a {
+        return 0.0;
+    }
+    if x >= b {
+        return 1.0;
+    }
+    let (slope, width) = scale(distribution, x, a, b);
+    if slope > 0.0 {
+        tail(distribution, x, -width, a, b).min(1.0)
+    } else {
+        (1.0 - tail(distribution, x, width, a, b)).max(0.0)
+    }
+}
+
+/// Compute the inverse of the cumulative distribution function of a
+/// continuous distribution by solving `F(x) = p`.
+///
+/// A root is first bracketed by stepping away from a finite end of the
+/// support, or from zero if there is none, with steps that double in length.
+/// It is then located using Brent’s method, in which a Newton step based on
+/// the density is attempted first and accepted whenever it stays within the
+/// bracket and shrinks the step sufficiently; otherwise, inverse quadratic
+/// interpolation, the secant method, or bisection is used as usual. The
+/// cumulative distribution function can be either a closed form or
+/// `distribution`; since `F(x) - p` is computed directly, the relative
+/// accuracy deteriorates as `p` approaches one.
+///
+/// # Example
+///
+/// ```
+/// use probability::distribution::{numeric, Continuous, Distribution, Inverse, Support};
+/// use std::ops::Bound;
+///
+/// struct Triangle;
+///
+/// impl Distribution for Triangle {
+///     type Value = f64;
+///
+///     fn distribution(&self, x: f64) -> f64 {
+///         numeric::distribution(self, x)
+///     }
+/// }
+///
+/// impl Continuous for Triangle {
+///     fn density(&self, x: f64) -> f64 {
+///         if 0.0 <= x && x <= 1.0 { 2.0 * x } else { 0.0 }
+///     }
+/// }
+///
+/// impl Inverse for Triangle {
+///     fn inverse(&self, p: f64) -> f64 {
+///         numeric::inverse(self, p)
+///     }
+/// }
+///
+/// impl Support for Triangle {
+///     fn support(&self) -> (Bound<f64>, Bound<f64>) {
+///         (Bound::Included(0.0), Bound::Included(1.0))
+///     }
+/// }
+///
+/// assert!((Triangle.inverse(0.25) - 0.5).abs() < 1e-14);
+/// ```
+pub fn inverse<D>(distribution: &D, p: f64) -> f64
+where
+    D: Continuous + Support,
+{
+    should!((0.0..=1.0).contains(&p));
+    let (a, b) = distribution.support();
+    let (a, b) = (endpoint(a, f64::NEG_INFINITY), endpoint(b, f64::INFINITY));
+    if p.is_nan() {
+        return f64::NAN;
+    }
+    if p <= 0.0 {
+        return a;
+    }
+    if p >= 1.0 {
+        return b;
+    }
+    let f = |x: f64| distribution.distribution(x) - p;
+    let (lower, upper) = if a.is_finite() && b.is_finite() {
+        (a, b)
+    } else if a.is_finite() {
+        match bracket(&f, a, 1.0) {
+            Some(bracket) => bracket,
+            None => return b,
+        }
+    } else if b.is_finite() {
+        match bracket(&f, b, -1.0) {
+            Some(bracket) => bracket,
+            None => return a,
+        }
+    } else if f(0.0) < 0.0 {
+        match bracket(&f, 0.0, 1.0) {
+            Some(bracket) => bracket,
+            None => return b,
+        }
+    } else {
+        match bracket(&f, 0.0, -1.0) {
+            Some(bracket) => bracket,
+            None => return a,
+        }
+    };
+    brent(&f, &|x| distribution.density(x), lower, upper)
+}
+
+fn bracket<F>(f: &F, x: f64, step: f64) -> Option<(f64, f64)>
+where
+    F: Fn(f64) -> f64,
+{
+    let (mut last, mut step) = (x, step);
+    loop {
+        let next = x + step;
+        if !next.is_finite() {
+            return None;
+        }
+        let value = f(next);
+        if step > 0.0 && value >= 0.0 {
+            return Some((last, next));
+        }
+        if step < 0.0 && value < 0.0 {
+            return Some((next, last));
+        }
+        last = next;
+        step *= 2.0;
+    }
+}
+
+fn brent<F, G>(f: &F, derivative: &G, lower: f64, upper: f64) -> f64
+where
+    F: Fn(f64) -> f64,
+    G: Fn(f64) -> f64,
+{
+    let (mut a, mut b) = (lower, upper);
+    let (mut fa, mut fb) = (f(a), f(b));
+    if fa >= 0.0 {
+        return a;
+    }
+    let (mut c, mut fc) = (b, fb);
+    let (mut d, mut e) = (b - a, b - a);
+    for _ in 0..ITERATIONS {
+        if (fb > 0.0) == (fc > 0.0) {
+            c = a;
+            fc = fa;
+            d = b - a;
+            e = d;
+        }
+        if fc.abs() < fb.abs() {
+            a = b;
+            b = c;
+            c = a;
+            fa = fb;
+            fb = fc;
+            fc = fa;
+        }
+        let tolerance = 2.0 * f64::EPSILON * b.abs() + f64::MIN_POSITIVE;
+        let middle = 0.5 * (c - b);
+        if middle.abs() <= tolerance || fb == 0.0 {
+            return b;
+        }
+        let newton = -fb / derivative(b);
+        if newton.is_finite()
+            && newton.abs() < (2.0 * middle.abs()).min(0.5 * e.abs())
+            && (newton > 0.0) == (middle > 0.0)
+        {
+            e = d;
+            d = newton;
+        } else if e.abs() >= tolerance && fa.abs() > fb.abs() {
+            let s = fb / fa;
+            let (mut p, mut q) = if a == c {
+                (2.0 * middle * s, 1.0 - s)
+            } else {
+                let q = fa / fc;
+                let r = fb / fc;
+                (
+                    s * (2.0 * middle * q * (q - r) - (b - a) * (r - 1.0)),
+                    (q - 1.0) * (r - 1.0) * (s - 1.0),
+                )
+            };
+            if p > 0.0 {
+                q = -q;
+            }
+            p = p.abs();
+            if 2.0 * p < (3.0 * middle * q - (tolerance * q).abs()).min((e * q).abs()) {
+                e = d;
+                d = p / q;
+            } else {
+                d = middle;
+                e = d;
+            }
+        } else {
+            d = middle;
+            e = d;
+        }
+        a = b;
+        fa = fb;
+        b += if d.abs() > tolerance {
+            d
+        } else {
+            tolerance.copysign(middle)
+        };
+        fb = f(b);
+    }
+    b
+}
+
+fn endpoint(bound: Bound<f64>, infinity: f64) -> f64 {
+    match bound {
+        Bound::Included(x) | Bound::Excluded(x) => x,
+        Bound::Unbounded => infinity,
+    }
+}
+
+fn scale<D>(distribution: &D, x: f64, a: f64, b: f64) -> (f64, f64)
+where
+    D: Continuous,
+{
+    let log_density = |t: f64| {
+        if a < t && t < b {
+            distribution.log_density(t)
+        } else {
+            f64::NEG_INFINITY
+        }
+    };
+    let middle = log_density(x);
+    let mut step = 1e-3 * x.abs().max(1.0);
+    if !middle.is_finite() {
+        for _ in 0..STEPS * 10 {
+            let (left, right) = (log_density(x - step), log_density(x + step));
+            if left != right {
+                return (right - left, step);
+            }
+            step *= 2.0;
+        }
+        return (0.0, step);
+    }
+    let mut direction = 0.0;
+    for _ in 0..STEPS {
+        let (left, right) = (log_density(x - step), log_density(x + step));
+        let change = (left - middle).abs().max((right - middle).abs());
+        let factor = if change > CHANGE.1 || change.is_nan() {
+            0.25
+        } else if change < CHANGE.0 {
+            4.0
+        } else {
+            break;
+        };
+        if direction != 0.0 && direction != factor {
+            if factor < 1.0 {
+                step *= factor;
+            }
+            break;
+        }
+        direction = factor;
+        step *= factor;
+    }
+    let (left, right) = (log_density(x - step), log_density(x + step));
+    let slope = (right - left) / (2.0 * step);
+    let curvature = (left - 2.0 * middle + right) / (step * step);
+    let rate = slope.abs().max(curvature.abs().sqrt());
+    if rate > 0.0 && rate.is_finite() {
+        (slope, 1.0 / rate)
+    } else {
+        (0.0, step)
+    }
+}
+
+fn tail<D>(distribution: &D, x: f64, width: f64, a: f64, b: f64) -> f64
+where
+    D: Continuous,
+{
+    let scale = width.abs();
+    let value = |t: f64, factor: f64| {
+        if t <= a || t >= b {
+            return 0.0;
+        }
+        let p = distribution.density(t);
+        if p > 0.0 && p.is_finite() {
+            p * factor
+        } else {
+            0.0
+        }
+    };
+    let length = if width > 0.0 { b - x } else { x - a };
+    if length.is_finite() {
+        // The distance to the end is computed directly, and the substitution
+        // s = start + range v^2 removes inverse square-root singularities
+        // there.
+        let end = if width > 0.0 { b } else { a };
+        let start = scale / (scale + length);
+        let range = 1.0 - start;
+        let f = |v: f64| {
+            let s = start + range * v * v;
+            let distance = (scale + length) * range * v * v / s;
+            value(
+                end - distance.copysign(width),
+                2.0 * range * v * scale / (s * s),
+            )
+        };
+        quadrature::adaptive(f, 0.0, 1.0, TOLERANCE)
+    } else {
+        let f = |s: f64| {
+            if s <= 0.0 {
+                return 0.0;
+            }
+            value(x + width * (1.0 - s) / s, scale / (s * s))
+        };
+        quadrature::adaptive(f, 0.0, 1.0, TOLERANCE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    #[test]
+    fn distribution() {
+        fn check<D>(distribution: &D, xs: &[f64], tolerance: f64)
+        where
+            D: Continuous + Support,
+        {
+            for &x in xs {
+                assert::close(
+                    super::distribution(distribution, x),
+                    distribution.distribution(x),
+                    tolerance,
+                );
+            }
+        }
+
+        let d = Gaussian::new(1.0, 2.0);
+        check(&d, &[-5.0, 0.0, 1.0, 2.5, 7.0, 30.0], 1e-15);
+        assert::close(super::distribution(&d, -30.0), 1.73446079179387e-54, 1e-66);
+
+        let d = Gaussian::new(1e6, 1e-3);
+        check(&d, &[1e6 - 5e-3, 1e6, 1e6 + 1e-3], 1e-10);
+
+        check(
+            &Beta::new(0.5, 3.0, 0.0, 1.0),
+            &[0.0, 0.2, 0.5, 0.9, 1.0],
+            1e-15,
+        );
+        check(&Beta::new(0.5, 3.0, 0.0, 1.0), &[1e-10], 1e-11);
+        check(
+            &Beta::new(2.0, 5.0, -1.0, 3.0),
+            &[-1.0, -0.5, 0.5, 2.0, 2.9],
+            1e-15,
+        );
+        check(
+            &Cauchy::new(-1.0, 0.5),
+            &[-1e6, -10.0, -1.0, 0.0, 3.0, 1e6],
+            1e-15,
+        );
+        check(
+            &Gamma::new(0.5, 2.0),
+            &[-1.0, 0.0, 1e-8, 0.1, 1.0, 5.0, 50.0, 1e6],
+            1e-15,
+        );
+        check(&Pareto::new(1.5, 2.0), &[1.0, 2.0, 2.5, 10.0, 1e4], 1e-15);
+        check(
+            &Uniform::new(-2.0, 3.0),
+            &[-3.0, -2.0, -1.5, 0.5, 2.9, 3.0],
+            1e-15,
+        );
+
+        assert!(super::distribution(&Gaussian::new(0.0, 1.0), f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn inverse() {
+        fn check<D>(distribution: &D, ps: &[f64], tolerance: f64)
+        where
+            D: Continuous + Inverse<Value = f64> + Support,
+        {
+            for &p in ps {
+                assert::close(
+                    super::inverse(distribution, p),
+                    distribution.inverse(p),
+                    tolerance,
+                );
+            }
+        }
+
+        let ps = [0.0, 1e-3, 0.1, 0.5, 0.9, 0.999, 1.0];
+        check(&Gaussian::new(1.0, 2.0), &ps, 1e-13);
+        check(&Gaussian::new(1e6, 1e-3), &ps, 1e-9);
+        check(&Cauchy::new(-1.0, 0.5), &ps, 1e-11);
+        check(&Beta::new(0.5, 3.0, 0.0, 1.0), &ps, 1e-14);
+        check(&Exponential::new(1e-3), &ps, 1e-10);
+        check(&Gumbel::new(-3.0, 0.1), &ps, 1e-14);
+        check(&Pareto::new(1.5, 2.0), &ps, 1e-12);
+
+        let d = Beta::new(0.5, 3.0, 0.0, 1.0);
+        assert::close(super::inverse(&d, 1e-12), 2.8444444444444444e-25, 1e-39);
+    }
+}
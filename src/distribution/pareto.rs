@@ -0,0 +1,430 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A Pareto distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct Pareto {
+    x_m: f64,
+    alpha: f64,
+}
+
+impl Pareto {
+    /// Create a Pareto distribution with scale `x_m` and shape `alpha`.
+    ///
+    /// It should hold that `x_m > 0` and `alpha > 0`.
+    #[inline]
+    pub fn new(x_m: f64, alpha: f64) -> Self {
+        should!(x_m > 0.0 && alpha > 0.0);
+        Pareto { x_m, alpha }
+    }
+
+    /// Return the scale parameter, which is also the left endpoint of the
+    /// support.
+    #[inline(always)]
+    pub fn x_m(&self) -> f64 {
+        self.x_m
+    }
+
+    /// Return the shape parameter.
+    #[inline(always)]
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+}
+
+impl distribution::CharacteristicFunction for Pareto {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Continuous, Inverse};
+        let (lower, upper) = (self.inverse(0.25), self.inverse(0.75));
+        characteristic::continuous(
+            |x| self.density(x),
+            t,
+            self.x_m,
+            f64::INFINITY,
+            lower,
+            upper - lower,
+        )
+    }
+}
+
+impl distribution::Continuous for Pareto {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        if x < self.x_m {
+            0.0
+        } else {
+            self.alpha * self.x_m.powf(self.alpha) / x.powf(self.alpha + 1.0)
+        }
+    }
+
+    #[inline]
+    fn log_density(&self, x: f64) -> f64 {
+        if x < self.x_m {
+            f64::NEG_INFINITY
+        } else {
+            self.alpha.ln() + self.alpha * self.x_m.ln() - (self.alpha + 1.0) * x.ln()
+        }
+    }
+}
+
+impl distribution::Distribution for Pareto {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        if x <= self.x_m {
+            0.0
+        } else {
+            1.0 - (self.x_m / x).powf(self.alpha)
+        }
+    }
+}
+
+impl distribution::Entropy for Pareto {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        (self.x_m / self.alpha).ln() + 1.0 / self.alpha + 1.0
+    }
+}
+
+impl distribution::Inverse for Pareto {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        should!((0.0..=1.0).contains(&p));
+        self.x_m * (1.0 - p).powf(-1.0 / self.alpha)
+    }
+}
+
+impl distribution::Kurtosis for Pareto {
+    /// Compute the excess kurtosis.
+    ///
+    /// The excess kurtosis is undefined, which is signified by `NaN`, if
+    /// `alpha <= 4`.
+    fn kurtosis(&self) -> f64 {
+        let alpha = self.alpha;
+        if alpha <= 4.0 {
+            return f64::NAN;
+        }
+        6.0 * (alpha * alpha * alpha + alpha * alpha - 6.0 * alpha - 2.0)
+            / (alpha * (alpha - 3.0) * (alpha - 4.0))
+    }
+}
+
+impl distribution::Mean for Pareto {
+    /// Compute the expected value.
+    ///
+    /// The expected value is infinite if `alpha <= 1`.
+    #[inline]
+    fn mean(&self) -> f64 {
+        if self.alpha <= 1.0 {
+            f64::INFINITY
+        } else {
+            self.alpha * self.x_m / (self.alpha - 1.0)
+        }
+    }
+}
+
+impl distribution::Median for Pareto {
+    #[inline]
+    fn median(&self) -> f64 {
+        self.x_m * 2f64.powf(1.0 / self.alpha)
+    }
+}
+
+impl distribution::Modes for Pareto {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![self.x_m]
+    }
+}
+
+impl distribution::Moments for Pareto {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is infinite if `n >= alpha`.
+    fn raw_moment(&self, n: usize) -> f64 {
+        let &Pareto { x_m, alpha } = self;
+        if n as f64 >= alpha {
+            f64::INFINITY
+        } else {
+            alpha * x_m.powi(n as i32) / (alpha - n as f64)
+        }
+    }
+}
+
+impl distribution::Sample for Pareto {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::Inverse;
+        self.inverse(source.read::<f64>())
+    }
+}
+
+impl distribution::Skewness for Pareto {
+    /// Compute the skewness.
+    ///
+    /// The skewness is undefined, which is signified by `NaN`, if
+    /// `alpha <= 3`.
+    fn skewness(&self) -> f64 {
+        let alpha = self.alpha;
+        if alpha <= 3.0 {
+            return f64::NAN;
+        }
+        2.0 * (1.0 + alpha) / (alpha - 3.0) * ((alpha - 2.0) / alpha).sqrt()
+    }
+}
+
+impl distribution::Support for Pareto {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Included, Unbounded};
+        (Included(self.x_m), Unbounded)
+    }
+}
+
+impl distribution::TryMean for Pareto {
+    /// Compute the expected value.
+    ///
+    /// The expected value does not exist if `alpha <= 1`.
+    #[inline]
+    fn try_mean(&self) -> Option<f64> {
+        use distribution::Mean;
+        if self.alpha > 1.0 {
+            Some(self.mean())
+        } else {
+            None
+        }
+    }
+}
+
+impl distribution::TryVariance for Pareto {
+    /// Compute the variance.
+    ///
+    /// The variance does not exist if `alpha <= 2`.
+    #[inline]
+    fn try_variance(&self) -> Option<f64> {
+        use distribution::Variance;
+        if self.alpha > 2.0 {
+            Some(self.variance())
+        } else {
+            None
+        }
+    }
+}
+
+impl distribution::Variance for Pareto {
+    /// Compute the variance.
+    ///
+    /// The variance is infinite if `alpha <= 2`.
+    fn variance(&self) -> f64 {
+        let alpha = self.alpha;
+        if alpha <= 2.0 {
+            f64::INFINITY
+        } else {
+            self.x_m * self.x_m * alpha / ((alpha - 1.0).powi(2) * (alpha - 2.0))
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($x_m:expr, $alpha:expr) => (Pareto::new($x_m, $alpha));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(1.5, 3.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.10534771678763874, 0.7627051344150636, -0.4977649902499587];
+        let im = vec![
+            -0.8604374978067958,
+            0.5811560266814713,
+            -0.36390666200219896,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(1.5, 3.0);
+        let x = vec![0.0, 1.0, 1.5, 2.0, 2.5, 3.0, 4.0, 5.0, 7.5, 10.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            2.0000000000000000e+00,
+            6.3281250000000000e-01,
+            2.5919999999999999e-01,
+            1.2500000000000000e-01,
+            3.9550781250000000e-02,
+            1.6199999999999999e-02,
+            3.2000000000000002e-03,
+            1.0124999999999999e-03,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(1.5, 3.0);
+        let x = vec![0.0, 1.0, 1.5, 2.0, 2.5, 3.0, 4.0, 5.0, 7.5, 10.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            5.7812500000000000e-01,
+            7.8400000000000003e-01,
+            8.7500000000000000e-01,
+            9.4726562500000000e-01,
+            9.7299999999999998e-01,
+            9.9199999999999999e-01,
+            9.9662499999999998e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(1.5, 3.0).entropy(), 6.4018615277338797e-01, 1e-15);
+        assert::close(new!(1.5, 5.0).entropy(), -3.9728043259359925e-03, 1e-15);
+    }
+
+    #[test]
+    fn inverse() {
+        use core::f64::INFINITY;
+        let d = new!(1.5, 3.0);
+        let p = vec![0.0, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0];
+        let x = vec![
+            1.5000000000000000e+00,
+            1.5536162529769295e+00,
+            1.6158260175239127e+00,
+            1.6893718206654091e+00,
+            1.7784466522450315e+00,
+            1.8898815748423097e+00,
+            2.0358132124461799e+00,
+            2.2407023732785825e+00,
+            2.5649639200150456e+00,
+            3.2316520350478259e+00,
+            INFINITY,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert!(new!(1.5, 3.0).kurtosis().is_nan());
+        assert::close(new!(1.5, 5.0).kurtosis(), 70.8, 1e-12);
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(1.5, 3.0);
+        let x = vec![-40.0, 1.5, 400.0, 1000000.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            0.6931471805599454,
+            -21.650850575439325,
+            -52.94703461886449,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(1.5, 0.5).mean(), f64::INFINITY);
+        assert_eq!(new!(1.5, 3.0).mean(), 2.25);
+        assert_eq!(new!(1.5, 5.0).mean(), 1.875);
+    }
+
+    #[test]
+    fn median() {
+        assert::close(new!(1.5, 3.0).median(), 1.8898815748423097e+00, 1e-15);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(1.5, 3.0).modes(), vec![1.5]);
+    }
+
+    #[test]
+    fn sample() {
+        for x in Independent(&new!(1.5, 3.0), &mut source::default(42)).take(100) {
+            assert!(x >= 1.5);
+        }
+    }
+
+    #[test]
+    fn skewness() {
+        assert!(new!(1.5, 3.0).skewness().is_nan());
+        assert::close(new!(1.5, 5.0).skewness(), 4.6475800154489004e+00, 1e-14);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Included, Unbounded};
+        assert_eq!(new!(1.5, 3.0).support(), (Included(1.5), Unbounded));
+    }
+
+    #[test]
+    fn try_mean() {
+        assert_eq!(new!(1.5, 0.5).try_mean(), None);
+        assert_eq!(new!(1.5, 1.0).try_mean(), None);
+        assert_eq!(new!(1.5, 3.0).try_mean(), Some(2.25));
+    }
+
+    #[test]
+    fn try_variance() {
+        assert_eq!(new!(1.5, 2.0).try_variance(), None);
+        assert_eq!(new!(1.5, 5.0).try_variance(), Some(0.234375));
+        assert_eq!(new!(1.5, 5.0).try_deviation(), Some(0.4841229182759271));
+    }
+
+    #[test]
+    fn variance() {
+        assert_eq!(new!(1.5, 2.0).variance(), f64::INFINITY);
+        assert::close(new!(1.5, 3.0).variance(), 1.6875, 1e-15);
+        assert::close(new!(1.5, 5.0).variance(), 0.234375, 1e-15);
+    }
+}
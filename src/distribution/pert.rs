@@ -1,4 +1,5 @@
 use alloc::{vec, vec::Vec};
+use core::ops::Bound;
 #[allow(unused_imports)]
 use special::Primitive;
 
@@ -68,6 +69,16 @@ impl Pert {
     }
 }
 
+impl distribution::CharacteristicFunction for Pert {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Continuous};
+        characteristic::continuous(|x| self.density(x), t, self.a, self.c, 0.0, 0.0)
+    }
+}
+
 impl distribution::Continuous for Pert {
     fn density(&self, x: f64) -> f64 {
         if x < self.a || x > self.c {
@@ -79,6 +90,18 @@ impl distribution::Continuous for Pert {
                 / scale
         }
     }
+
+    fn log_density(&self, x: f64) -> f64 {
+        if x < self.a || x > self.c {
+            f64::NEG_INFINITY
+        } else {
+            let scale = self.c - self.a;
+            let x = (x - self.a) / scale;
+            (self.alpha - 1.0) * x.ln() + (self.beta - 1.0) * (-x).ln_1p()
+                - self.ln_beta
+                - scale.ln()
+        }
+    }
 }
 
 impl distribution::Distribution for Pert {
@@ -146,6 +169,21 @@ impl distribution::Modes for Pert {
     }
 }
 
+impl distribution::Moments for Pert {
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::moments;
+        let &Pert {
+            a, c, alpha, beta, ..
+        } = self;
+        let moment = |k: usize| {
+            (0..k).fold(1.0, |product, j| {
+                product * (alpha + j as f64) / (alpha + beta + j as f64)
+            })
+        };
+        moments::shift(moment, a, c - a, n)
+    }
+}
+
 impl distribution::Sample for Pert {
     #[inline]
     fn sample<S>(&self, source: &mut S) -> f64
@@ -167,6 +205,13 @@ impl distribution::Skewness for Pert {
     }
 }
 
+impl distribution::Support for Pert {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Included;
+        (Included(self.a), Included(self.c))
+    }
+}
+
 impl distribution::Variance for Pert {
     fn variance(&self) -> f64 {
         use distribution::Mean;
@@ -175,6 +220,7 @@ impl distribution::Variance for Pert {
 }
 
 #[cfg(test)]
+#[allow(clippy::needless_borrows_for_generic_args, clippy::useless_vec)]
 mod tests {
     use alloc::{vec, vec::Vec};
     use assert;
@@ -184,6 +230,22 @@ mod tests {
         ($a:expr, $b:expr, $c:expr) => (Pert::new($a, $b, $c));
     );
 
+    #[test]
+    fn cf() {
+        let d = new!(-1.0, 0.5, 2.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.8676251685532227, 0.9745494122136035, 0.2689242138145143];
+        let im = vec![
+            -0.31670790935397636,
+            0.14728873792348626,
+            0.41882464794147123,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
     #[test]
     fn density() {
         let d = new!(-1.0, 0.5, 2.0);
@@ -284,6 +346,23 @@ mod tests {
         assert::close(new!(0.0, 0.5, 1.0).kurtosis(), -2.0 / 3.0, 1e-14);
     }
 
+    #[test]
+    fn log_density() {
+        let d = new!(-1.0, 0.5, 2.0);
+        let x = vec![-40.0, -0.5, 0.5, 1.5];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -1.645576959049974,
+            -0.4700036292457359,
+            -1.6455769590499745,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
     #[test]
     fn mean() {
         assert::close(new!(0.0, 0.5, 1.0).mean(), 0.5, 1e-14);
@@ -313,7 +392,7 @@ mod tests {
     #[test]
     fn sample() {
         for x in Independent(&new!(7.0, 20.0, 42.0), &mut source::default(42)).take(100) {
-            assert!(7.0 <= x && x <= 42.0);
+            assert!((7.0..=42.0).contains(&x));
         }
     }
 
@@ -324,6 +403,15 @@ mod tests {
         assert::close(new!(-1.0, 0.8, 2.0).skewness(), -0.17797249266332246, 1e-14);
     }
 
+    #[test]
+    fn support() {
+        use core::ops::Bound::Included;
+        assert_eq!(
+            new!(-1.0, 0.5, 2.0).support(),
+            (Included(-1.0), Included(2.0))
+        );
+    }
+
     #[test]
     fn variance() {
         assert::close(new!(0.0, 0.5, 1.0).variance(), 0.25 / 7.0, 1e-14);
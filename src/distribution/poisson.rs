@@ -0,0 +1,488 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A Poisson distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct Poisson {
+    lambda: f64,
+    ln_lambda: f64,
+}
+
+impl Poisson {
+    /// Create a Poisson distribution with rate `lambda`.
+    ///
+    /// It should hold that `lambda > 0`.
+    #[inline]
+    pub fn new(lambda: f64) -> Self {
+        should!(lambda > 0.0);
+        Poisson {
+            lambda,
+            ln_lambda: lambda.ln(),
+        }
+    }
+
+    /// Return the rate parameter.
+    #[inline(always)]
+    pub fn lambda(&self) -> f64 {
+        self.lambda
+    }
+}
+
+impl distribution::CharacteristicFunction for Poisson {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::complex;
+        let lambda = self.lambda;
+        complex::exp((-2.0 * lambda * (0.5 * t).sin().powi(2), lambda * t.sin()))
+    }
+}
+
+impl distribution::Discrete for Poisson {
+    #[inline]
+    fn mass(&self, x: usize) -> f64 {
+        self.log_mass(x).exp()
+    }
+
+    #[inline]
+    fn log_mass(&self, x: usize) -> f64 {
+        use special::Gamma;
+        let x = x as f64;
+        x * self.ln_lambda - self.lambda - Gamma::ln_gamma(x + 1.0).0
+    }
+}
+
+impl distribution::Distribution for Poisson {
+    type Value = usize;
+
+    /// Compute the cumulative distribution function.
+    ///
+    /// The function is evaluated via the upper tail, which is a regularized
+    /// incomplete gamma function.
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        use special::Gamma;
+        if x < 0.0 {
+            return 0.0;
+        }
+        1.0 - self.lambda.inc_gamma(x.floor() + 1.0)
+    }
+}
+
+impl distribution::Kurtosis for Poisson {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        1.0 / self.lambda
+    }
+}
+
+impl distribution::Mean for Poisson {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.lambda
+    }
+}
+
+impl distribution::Mgf for Poisson {
+    fn mgf(&self, t: f64) -> f64 {
+        (self.lambda * t.exp_m1()).exp()
+    }
+}
+
+impl distribution::Modes for Poisson {
+    fn modes(&self) -> Vec<usize> {
+        let mode = self.lambda.floor();
+        if mode == self.lambda && mode > 0.0 {
+            vec![mode as usize - 1, mode as usize]
+        } else {
+            vec![mode as usize]
+        }
+    }
+}
+
+impl distribution::Moments for Poisson {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is obtained from the cumulants, which all equal `lambda`.
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::moments;
+        moments::cumulants(|_| self.lambda, n)
+    }
+}
+
+impl distribution::Sample for Poisson {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> usize
+    where
+        S: Source,
+    {
+        sample(self.lambda, source)
+    }
+}
+
+impl distribution::Skewness for Poisson {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        1.0 / self.lambda.sqrt()
+    }
+}
+
+impl distribution::Support for Poisson {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Included, Unbounded};
+        (Included(0.0), Unbounded)
+    }
+}
+
+impl distribution::Survival for Poisson {
+    /// Compute the survival function.
+    ///
+    /// The function is the regularized lower incomplete gamma function, which
+    /// retains its relative accuracy in the upper tail.
+    #[inline]
+    fn survival(&self, x: f64) -> f64 {
+        use special::Gamma;
+        if x < 0.0 {
+            return 1.0;
+        }
+        self.lambda.inc_gamma(x.floor() + 1.0)
+    }
+}
+
+impl distribution::Variance for Poisson {
+    #[inline]
+    fn variance(&self) -> f64 {
+        self.lambda
+    }
+}
+
+/// Compute `ln sum_j exp(-lambda) lambda^j / j! exp(f(j))`.
+///
+/// The terms are summed in log space outward from the dominant one, which is
+/// found by a search starting at the mode of the Poisson weights. The search
+/// presumes that `f` is concave.
+pub fn log_mixture<F>(lambda: f64, f: F) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    use special::Gamma;
+    if lambda == 0.0 {
+        return f(0.0);
+    }
+    let ln_lambda = lambda.ln();
+    let term = |j: f64| j * ln_lambda - lambda - Gamma::ln_gamma(j + 1.0).0 + f(j);
+    let rising = |j: f64| term(j + 1.0) > term(j);
+    let (mut low, mut high, mut step) = (-1.0, lambda.floor(), 1.0);
+    if rising(high) {
+        while rising(high) {
+            low = high;
+            high += step;
+            step *= 2.0;
+        }
+    } else {
+        while high > 0.0 {
+            let j = (high - step).max(0.0);
+            if rising(j) {
+                low = j;
+                break;
+            }
+            high = j;
+            step *= 2.0;
+        }
+    }
+    while high - low > 1.0 {
+        let middle = (0.5 * (low + high)).floor();
+        if rising(middle) {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    let mode = high;
+    let peak = term(mode);
+    let mut sum = 1.0;
+    let mut j = mode;
+    loop {
+        j += 1.0;
+        let w = (term(j) - peak).exp();
+        sum += w;
+        if w <= f64::EPSILON * sum {
+            break;
+        }
+    }
+    let mut j = mode;
+    while j > 0.0 {
+        j -= 1.0;
+        let w = (term(j) - peak).exp();
+        sum += w;
+        if w <= f64::EPSILON * sum {
+            break;
+        }
+    }
+    peak + sum.ln()
+}
+
+/// Compute `sum_j exp(-lambda) lambda^j / j! f(j)`.
+///
+/// The terms are summed outward from the mode of the Poisson weights until the
+/// weights become negligible relative to the sum, which presumes that `f` is
+/// bounded away from the mode.
+pub fn mixture<F>(lambda: f64, f: F) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    use special::Gamma;
+    let mode = lambda.floor();
+    let ln_weight = if mode > 0.0 {
+        mode * lambda.ln() - Gamma::ln_gamma(mode + 1.0).0
+    } else {
+        0.0
+    };
+    let weight = (ln_weight - lambda).exp();
+    let mut sum = weight * f(mode);
+    let (mut j, mut w) = (mode, weight);
+    loop {
+        j += 1.0;
+        w *= lambda / j;
+        sum += w * f(j);
+        if w <= f64::EPSILON * sum {
+            break;
+        }
+    }
+    let (mut j, mut w) = (mode, weight);
+    while j > 0.0 {
+        w *= j / lambda;
+        j -= 1.0;
+        sum += w * f(j);
+        if w <= f64::EPSILON * sum {
+            break;
+        }
+    }
+    sum
+}
+
+/// Draw a sample from the Poisson distribution with rate `lambda`.
+///
+/// Small rates are handled by multiplying uniform variates, and large ones by
+/// the transformed rejection method of Hörmann.
+pub fn sample<S: Source>(lambda: f64, source: &mut S) -> usize {
+    use special::Gamma;
+
+    if lambda < 10.0 {
+        let limit = (-lambda).exp();
+        let mut product = source.read::<f64>();
+        let mut k = 0;
+        while product > limit {
+            product *= source.read::<f64>();
+            k += 1;
+        }
+        return k;
+    }
+
+    let ln_lambda = lambda.ln();
+    let b = 0.931 + 2.53 * lambda.sqrt();
+    let a = -0.059 + 0.02483 * b;
+    let ln_alpha = (1.1239 + 1.1328 / (b - 3.4)).ln();
+    let v_r = 0.9277 - 3.6224 / (b - 2.0);
+
+    loop {
+        let u = source.read::<f64>() - 0.5;
+        let v = source.read::<f64>();
+        let u_s = 0.5 - u.abs();
+        let k = ((2.0 * a / u_s + b) * u + lambda + 0.43).floor();
+        if u_s >= 0.07 && v <= v_r {
+            return k as usize;
+        }
+        if k < 0.0 || (u_s < 0.013 && v > u_s) {
+            continue;
+        }
+        if v.ln() + ln_alpha - (a / (u_s * u_s) + b).ln()
+            <= -lambda + k * ln_lambda - Gamma::ln_gamma(k + 1.0).0
+        {
+            return k as usize;
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($lambda:expr) => (Poisson::new($lambda));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(1.5);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            0.39939795744242877,
+            0.84481219560979608,
+            0.024548068499127483,
+        ];
+        let im = vec![
+            -0.57823522148542074,
+            0.40111182837855584,
+            0.11697815407851206,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-15);
+        assert::close(&y, &im, 1e-15);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(1.5);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 2.5, 3.0, 5.0, 8.0, 12.0];
+        let p = vec![
+            0.0,
+            0.22313016014842983,
+            0.22313016014842983,
+            0.55782540037107457,
+            0.80884683053805813,
+            0.93435754562154991,
+            0.99554401922475215,
+            0.99997226418075314,
+            0.99999999219670165,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(1.5).kurtosis(), 2.0 / 3.0, 1e-15);
+    }
+
+    #[test]
+    fn log_mass() {
+        let d = new!(1000.0);
+        let x = vec![0, 500, 1000, 3000];
+        let p = vec![
+            -1000.0,
+            -157.45281896908756,
+            -4.3728995060262968,
+            -1300.7590160991365,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_mass(x)).collect::<Vec<_>>(),
+            &p,
+            1e-11,
+        );
+    }
+
+    #[test]
+    fn mass() {
+        let d = new!(1.5);
+        let x = vec![0, 1, 2, 3, 5, 10];
+        let p = vec![
+            0.22313016014842983,
+            0.33469524022264474,
+            0.25102143016698356,
+            0.12551071508349178,
+            0.014119955446892825,
+            3.5457477405701849e-6,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.mass(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(1.5).mean(), 1.5);
+    }
+
+    #[test]
+    fn mgf() {
+        let d = new!(1.5);
+        let t = vec![-1.0, 0.0, 0.5, 2.0];
+        let m = vec![
+            0.38744520825312426,
+            1.0,
+            2.6460868970126119,
+            14524.383669043493,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-11);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(1.5).modes(), vec![1]);
+        assert_eq!(new!(0.5).modes(), vec![0]);
+        assert_eq!(new!(3.0).modes(), vec![2, 3]);
+    }
+
+    #[test]
+    fn moments() {
+        let d = new!(1.5);
+        let m = vec![
+            1.0,
+            1.5,
+            3.75,
+            11.625,
+            42.5625,
+            177.84375,
+            829.359375,
+            4250.4140625,
+        ];
+        assert::close(
+            &(0..8).map(|n| d.raw_moment(n)).collect::<Vec<_>>(),
+            &m,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let mut source = source::default(42);
+
+        let d = new!(1.5);
+        let mean = (0..n).map(|_| d.sample(&mut source) as f64).sum::<f64>() / n as f64;
+        assert!((mean - d.mean()).abs() < 0.02);
+
+        let d = new!(50.0);
+        let mean = (0..n).map(|_| d.sample(&mut source) as f64).sum::<f64>() / n as f64;
+        assert!((mean - d.mean()).abs() < 0.1);
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(4.0).skewness(), 0.5, 1e-15);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Included, Unbounded};
+        assert_eq!(new!(1.5).support(), (Included(0.0), Unbounded));
+    }
+
+    #[test]
+    fn survival() {
+        let d = new!(1.5);
+        assert_eq!(d.survival(-1.0), 1.0);
+        assert::close(d.survival(1.0), 1.0 - 0.55782540037107457, 1e-15);
+        assert::close(d.survival(30.0), 8.1881169088037552e-30, 1e-42);
+    }
+
+    #[test]
+    fn variance() {
+        assert_eq!(new!(1.5).variance(), 1.5);
+    }
+}
@@ -0,0 +1,417 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A Poisson binomial distribution.
+///
+/// The distribution describes the number of successes in independent
+/// Bernoulli trials with possibly different success probabilities.
+#[derive(Clone, Debug)]
+pub struct PoissonBinomial {
+    p: Vec<f64>,
+    mass: Vec<f64>,
+    cumsum: Vec<f64>,
+}
+
+impl PoissonBinomial {
+    /// Create a Poisson binomial distribution with success probabilities `p`.
+    ///
+    /// It should hold that `p[i] >= 0` and `p[i] <= 1`.
+    ///
+    /// The probability masses are computed upfront by a recursive dynamic
+    /// programming algorithm, which adds one trial at a time and takes
+    /// quadratic time in the number of trials.
+    pub fn new(p: &[f64]) -> Self {
+        should!(p.iter().all(|&p| (0.0..=1.0).contains(&p)));
+        let n = p.len();
+        let mut mass = vec![0.0; n + 1];
+        mass[0] = 1.0;
+        for (i, &p) in p.iter().enumerate() {
+            let q = 1.0 - p;
+            for k in (1..=(i + 1)).rev() {
+                mass[k] = mass[k] * q + mass[k - 1] * p;
+            }
+            mass[0] *= q;
+        }
+        let mut cumsum = mass.clone();
+        for k in 1..(n + 1) {
+            cumsum[k] += cumsum[k - 1];
+        }
+        cumsum[n] = 1.0;
+        PoissonBinomial {
+            p: p.to_vec(),
+            mass,
+            cumsum,
+        }
+    }
+
+    /// Return the number of trials.
+    #[inline(always)]
+    pub fn n(&self) -> usize {
+        self.p.len()
+    }
+
+    /// Return the success probabilities.
+    #[inline(always)]
+    pub fn p(&self) -> &[f64] {
+        &self.p
+    }
+}
+
+impl distribution::CharacteristicFunction for PoissonBinomial {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::complex;
+        let (re, im) = (-2.0 * (0.5 * t).sin().powi(2), t.sin());
+        self.p
+            .iter()
+            .fold((1.0, 0.0), |z, &p| complex::mul(z, (1.0 + p * re, p * im)))
+    }
+}
+
+impl distribution::Discrete for PoissonBinomial {
+    #[inline]
+    fn mass(&self, x: usize) -> f64 {
+        if x < self.mass.len() {
+            self.mass[x]
+        } else {
+            0.0
+        }
+    }
+
+    /// Compute the natural logarithm of the probability mass function.
+    ///
+    /// If the stored mass has underflowed, the recursion is repeated in log
+    /// space up to `x`.
+    fn log_mass(&self, x: usize) -> f64 {
+        if x >= self.mass.len() {
+            return f64::NEG_INFINITY;
+        }
+        if self.mass[x] >= f64::MIN_POSITIVE {
+            return self.mass[x].ln();
+        }
+        let add = |a: f64, b: f64| {
+            let (high, low) = if a > b { (a, b) } else { (b, a) };
+            if low == f64::NEG_INFINITY {
+                high
+            } else {
+                high + (low - high).exp().ln_1p()
+            }
+        };
+        let mut ln_mass = vec![f64::NEG_INFINITY; x + 1];
+        ln_mass[0] = 0.0;
+        for (i, &p) in self.p.iter().enumerate() {
+            let (ln_p, ln_q) = (p.ln(), (-p).ln_1p());
+            for k in (1..=(i + 1).min(x)).rev() {
+                ln_mass[k] = add(ln_mass[k] + ln_q, ln_mass[k - 1] + ln_p);
+            }
+            ln_mass[0] += ln_q;
+        }
+        ln_mass[x]
+    }
+}
+
+impl distribution::Distribution for PoissonBinomial {
+    type Value = usize;
+
+    fn distribution(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            return 0.0;
+        }
+        let x = x as usize;
+        if x >= self.p.len() {
+            return 1.0;
+        }
+        self.cumsum[x].min(1.0)
+    }
+}
+
+impl distribution::Entropy for PoissonBinomial {
+    fn entropy(&self) -> f64 {
+        -self
+            .mass
+            .iter()
+            .filter(|&&p| p > 0.0)
+            .fold(0.0, |sum, p| sum + p * p.ln())
+    }
+}
+
+impl distribution::Inverse for PoissonBinomial {
+    fn inverse(&self, p: f64) -> usize {
+        should!((0.0..=1.0).contains(&p));
+        self.cumsum
+            .iter()
+            .position(|&sum| sum > 0.0 && sum >= p)
+            .unwrap_or_else(|| self.mass.iter().rposition(|&p| p > 0.0).unwrap())
+    }
+}
+
+impl distribution::Kurtosis for PoissonBinomial {
+    fn kurtosis(&self) -> f64 {
+        use distribution::Variance;
+        let sum = self.p.iter().fold(0.0, |sum, &p| {
+            sum + p * (1.0 - p) * (1.0 - 6.0 * p * (1.0 - p))
+        });
+        sum / self.variance().powi(2)
+    }
+}
+
+impl distribution::Mean for PoissonBinomial {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.p.iter().sum()
+    }
+}
+
+impl distribution::Mgf for PoissonBinomial {
+    fn mgf(&self, t: f64) -> f64 {
+        self.p.iter().map(|&p| 1.0 + p * t.exp_m1()).product()
+    }
+}
+
+impl distribution::Modes for PoissonBinomial {
+    fn modes(&self) -> Vec<usize> {
+        let max = self.mass.iter().fold(0.0, |max: f64, &p| max.max(p));
+        (0..self.mass.len())
+            .filter(|&k| self.mass[k] == max)
+            .collect()
+    }
+}
+
+impl distribution::Moments for PoissonBinomial {
+    fn raw_moment(&self, n: usize) -> f64 {
+        self.mass
+            .iter()
+            .enumerate()
+            .map(|(x, &p)| p * (x as f64).powi(n as i32))
+            .sum()
+    }
+}
+
+impl distribution::Sample for PoissonBinomial {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained by simulating the Bernoulli trials.
+    fn sample<S>(&self, source: &mut S) -> usize
+    where
+        S: Source,
+    {
+        self.p.iter().filter(|&&p| source.read::<f64>() < p).count()
+    }
+}
+
+impl distribution::Skewness for PoissonBinomial {
+    fn skewness(&self) -> f64 {
+        use distribution::Variance;
+        let sum = self
+            .p
+            .iter()
+            .fold(0.0, |sum, &p| sum + p * (1.0 - p) * (1.0 - 2.0 * p));
+        sum / self.variance().powf(1.5)
+    }
+}
+
+impl distribution::Support for PoissonBinomial {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Included;
+        (Included(0.0), Included(self.p.len() as f64))
+    }
+}
+
+impl distribution::Variance for PoissonBinomial {
+    #[inline]
+    fn variance(&self) -> f64 {
+        self.p.iter().fold(0.0, |sum, &p| sum + p * (1.0 - p))
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($p:expr) => (PoissonBinomial::new(&$p));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!([0.1, 0.5, 0.7, 0.9]);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            0.022039627025188302,
+            0.7673130136888813,
+            -0.039627231929913215,
+        ];
+        let im = vec![-0.8537458350580185, 0.5959834853181382, -0.2531843282917997];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!([0.1, 0.5, 0.7, 0.9]);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 2.0, 3.0, 4.0, 5.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            1.3500000000000000e-02,
+            1.3500000000000000e-02,
+            1.8149999999999999e-01,
+            6.3649999999999995e-01,
+            9.6850000000000003e-01,
+            1.0000000000000000e+00,
+            1.0000000000000000e+00,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(
+            new!([0.1, 0.5, 0.7, 0.9]).entropy(),
+            1.1910782766922388e+00,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn inverse() {
+        let d = new!([0.1, 0.5, 0.7, 0.9]);
+        let p = vec![0.0, 0.01, 0.1, 0.2, 0.5, 0.7, 0.99, 1.0];
+        let x = vec![0, 0, 1, 2, 2, 3, 4, 4];
+        assert_eq!(&p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(), &x);
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(
+            new!([0.1, 0.5, 0.7, 0.9]).kurtosis(),
+            -2.3632812500000000e-01,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn log_mass() {
+        let d = new!([0.1, 0.5, 0.7, 0.9]);
+        let x = vec![0, 2, 4, 5];
+        assert::close(
+            &x.iter().map(|&x| d.log_mass(x)).collect::<Vec<_>>(),
+            &x.iter().map(|&x| d.mass(x).ln()).collect::<Vec<_>>(),
+            1e-15,
+        );
+
+        let d = new!(vec![0.5; 1100]);
+        let x = vec![0, 1, 3, 550, 1100];
+        let p = vec![
+            -762.46189861593984,
+            -755.45883315715338,
+            -743.24719104990834,
+            -3.7275513547339264,
+            -762.46189861593984,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_mass(x)).collect::<Vec<_>>(),
+            &p,
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn mass() {
+        let d = new!([0.1, 0.5, 0.7, 0.9]);
+        let p = vec![
+            1.3500000000000000e-02,
+            1.6800000000000001e-01,
+            4.5500000000000002e-01,
+            3.3200000000000002e-01,
+            3.1500000000000000e-02,
+            0.0000000000000000e+00,
+        ];
+        assert::close(&(0..6).map(|x| d.mass(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let d = new!([0.3; 5]);
+        let p = vec![
+            1.6807000000000001e-01,
+            3.6014999999999997e-01,
+            3.0869999999999997e-01,
+            1.3230000000000000e-01,
+            2.8349999999999997e-02,
+            2.4299999999999999e-03,
+        ];
+        assert::close(&(0..6).map(|x| d.mass(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!([0.1, 0.5, 0.7, 0.9]).mean(), 2.2, 1e-15);
+    }
+
+    #[test]
+    fn mgf() {
+        let d = new!([0.1, 0.5, 0.7, 0.9]);
+        let t = vec![-0.7, -0.1, 0.2, 0.45];
+        let m = vec![
+            0.2516990007830721,
+            0.805101909596976,
+            1.572523881774848,
+            2.867325034059164,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!([0.1, 0.5, 0.7, 0.9]).modes(), vec![2]);
+        assert_eq!(new!([0.5]).modes(), vec![0, 1]);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!([0.1, 0.5, 0.7, 0.9]);
+        let mut source = source::default(42);
+
+        let mean = (0..n).map(|_| d.sample(&mut source) as f64).sum::<f64>() / n as f64;
+        assert!((mean - d.mean()).abs() < 0.01);
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(
+            new!([0.1, 0.5, 0.7, 0.9]).skewness(),
+            -1.6406250000000000e-01,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::Included;
+        assert_eq!(
+            new!([0.1, 0.5, 0.7, 0.9]).support(),
+            (Included(0.0), Included(4.0))
+        );
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!([0.1, 0.5, 0.7, 0.9]).variance(), 0.64, 1e-15);
+    }
+}
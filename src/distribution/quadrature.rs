@@ -0,0 +1,226 @@
+//! Numerical integration.
+
+use alloc::vec;
+
+/// The maximal number of subranges in adaptive integration.
+const LIMIT: usize = 256;
+
+/// The number of widths covered by the central part of an unbounded range.
+pub const SPAN: f64 = 10.0;
+
+/// The number of panels the central part of a range is initially split into.
+pub const PANELS: usize = 16;
+
+/// The maximal number of times a panel is bisected.
+const DEPTH: usize = 60;
+
+const NODES: [f64; 8] = [
+    0.09501250983763744,
+    0.2816035507792589,
+    0.45801677765722737,
+    0.6178762444026438,
+    0.755404408355003,
+    0.8656312023878318,
+    0.9445750230732326,
+    0.9894009349916499,
+];
+
+const WEIGHTS: [f64; 8] = [
+    0.1894506104550685,
+    0.18260341504492358,
+    0.16915651939500254,
+    0.14959598881657674,
+    0.12462897125553388,
+    0.09515851168249279,
+    0.062253523938647894,
+    0.027152459411754096,
+];
+
+const KRONROD_NODES: [f64; 7] = [
+    0.9914553711208126,
+    0.9491079123427585,
+    0.8648644233597691,
+    0.7415311855993945,
+    0.5860872354676911,
+    0.4058451513773972,
+    0.20778495500789848,
+];
+
+const KRONROD_WEIGHTS: [f64; 8] = [
+    0.022935322010529224,
+    0.06309209262997856,
+    0.10479001032225019,
+    0.14065325971552592,
+    0.1690047266392679,
+    0.19035057806478542,
+    0.20443294007529889,
+    0.20948214108472782,
+];
+
+const GAUSS_WEIGHTS: [f64; 4] = [
+    0.1294849661688697,
+    0.27970539148927664,
+    0.3818300505051189,
+    0.4179591836734694,
+];
+
+/// Integrate a function over `[a, b]` adaptively using the 15-point
+/// Gauss–Kronrod rule.
+///
+/// The subrange with the largest estimated error is bisected until the total
+/// estimated error falls below `tolerance` relative to the result or the
+/// number of subranges reaches a limit.
+pub fn adaptive<F>(f: F, a: f64, b: f64, tolerance: f64) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    let (value, error) = kronrod(&f, a, b);
+    let mut ranges = vec![(a, b, value, error)];
+    let (mut sum, mut total) = (value, error);
+    while ranges.len() < LIMIT && total > tolerance * sum.abs() && sum.is_finite() {
+        let i = (0..ranges.len()).fold(0, |i, j| if ranges[j].3 > ranges[i].3 { j } else { i });
+        let (a, b, value, error) = ranges[i];
+        let c = 0.5 * (a + b);
+        if c <= a || c >= b {
+            break;
+        }
+        let (left, right) = (kronrod(&f, a, c), kronrod(&f, c, b));
+        sum += left.0 + right.0 - value;
+        total += left.1 + right.1 - error;
+        ranges[i] = (a, c, left.0, left.1);
+        ranges.push((c, b, right.0, right.1));
+    }
+    ranges.iter().map(|range| range.2).sum()
+}
+
+/// Integrate a function over `[a, b]` using the 15-point Gauss–Kronrod rule
+/// and estimate the absolute error by the difference from the embedded
+/// 7-point Gauss–Legendre rule.
+pub fn kronrod<F>(f: F, a: f64, b: f64) -> (f64, f64)
+where
+    F: Fn(f64) -> f64,
+{
+    let center = 0.5 * (a + b);
+    let radius = 0.5 * (b - a);
+    let middle = f(center);
+    let mut kronrod = KRONROD_WEIGHTS[7] * middle;
+    let mut gauss = GAUSS_WEIGHTS[3] * middle;
+    for (i, &x) in KRONROD_NODES.iter().enumerate() {
+        let pair = f(center - radius * x) + f(center + radius * x);
+        kronrod += KRONROD_WEIGHTS[i] * pair;
+        if i % 2 == 1 {
+            gauss += GAUSS_WEIGHTS[i / 2] * pair;
+        }
+    }
+    (radius * kronrod, (radius * (kronrod - gauss)).abs())
+}
+
+/// Integrate a function over `[a, b]` using the 16-point Gauss–Legendre
+/// rule.
+pub fn legendre<F>(f: F, a: f64, b: f64) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    let center = 0.5 * (a + b);
+    let radius = 0.5 * (b - a);
+    let sum = NODES.iter().zip(WEIGHTS.iter()).fold(0.0, |sum, (&x, &w)| {
+        sum + w * (f(center - radius * x) + f(center + radius * x))
+    });
+    radius * sum
+}
+
+/// Integrate a function over `[a, b]` split into `n` equal panels using the
+/// 16-point Gauss–Legendre rule.
+///
+/// Each panel is bisected recursively until the halves agree with the whole
+/// to within the absolute `tolerance`.
+pub fn panels<F>(f: F, a: f64, b: f64, n: usize, tolerance: f64) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    let h = (b - a) / n as f64;
+    (0..n)
+        .map(|i| {
+            let (a, b) = (a + i as f64 * h, a + (i + 1) as f64 * h);
+            refine(&f, a, b, legendre(&f, a, b), tolerance, DEPTH)
+        })
+        .sum()
+}
+
+fn refine<F>(f: &F, a: f64, b: f64, whole: f64, tolerance: f64, depth: usize) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    let c = 0.5 * (a + b);
+    let (left, right) = (legendre(f, a, c), legendre(f, c, b));
+    let sum = left + right;
+    if depth == 0 || !sum.is_finite() || (sum - whole).abs() <= tolerance {
+        sum
+    } else {
+        refine(f, a, c, left, tolerance, depth - 1) + refine(f, c, b, right, tolerance, depth - 1)
+    }
+}
+
+/// Integrate a function over `[x, inf)` if `width > 0` or over `(-inf, x]`
+/// if `width < 0`.
+///
+/// The range is split into panels whose width starts at `|width|` and doubles
+/// from one panel to the next until the contribution of a panel becomes
+/// negligible. The function is assumed to decay away from `x`.
+pub fn tail<F>(f: F, x: f64, width: f64) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    let (mut a, mut width, mut sum) = (x, width, 0.0);
+    loop {
+        let b = a + width;
+        let piece = legendre(&f, a.min(b), a.max(b));
+        sum += piece;
+        if !piece.is_finite() || piece.abs() <= f64::EPSILON * sum.abs() {
+            return sum;
+        }
+        a = b;
+        width *= 2.0;
+    }
+}
+
+/// Integrate a function over `[a, b]` where either endpoint may be infinite.
+///
+/// The central part of the range, which extends `SPAN * width` away from
+/// `center` in place of an infinite endpoint, is split into `PANELS` panels
+/// and integrated to within a tolerance relative to the integral of `|f|`.
+/// The remaining tails are integrated panel by panel with the panel width
+/// doubling until the contribution becomes negligible.
+pub fn unbounded<F>(f: F, a: f64, b: f64, center: f64, width: f64) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    let lower = if a.is_finite() {
+        a
+    } else {
+        center - SPAN * width
+    };
+    let upper = if b.is_finite() {
+        b
+    } else {
+        center + SPAN * width
+    };
+    let h = (upper - lower) / PANELS as f64;
+    let scale = (0..PANELS)
+        .map(|i| {
+            let (a, b) = (lower + i as f64 * h, lower + (i + 1) as f64 * h);
+            legendre(|x| f(x).abs(), a, b)
+        })
+        .sum::<f64>();
+    if !scale.is_finite() {
+        return scale;
+    }
+    let mut sum = panels(&f, lower, upper, PANELS, 1e-14 * scale);
+    if !a.is_finite() {
+        sum += tail(&f, lower, -width);
+    }
+    if !b.is_finite() {
+        sum += tail(&f, upper, width);
+    }
+    sum
+}
@@ -0,0 +1,299 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A Rademacher distribution.
+///
+/// The distribution assigns equal probabilities to `-1` and `1`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Rademacher;
+
+impl Rademacher {
+    /// Create a Rademacher distribution.
+    #[inline]
+    pub fn new() -> Self {
+        Rademacher
+    }
+}
+
+impl distribution::CharacteristicFunction for Rademacher {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use core::f64::consts::FRAC_PI_2;
+        ((FRAC_PI_2 - t).sin(), 0.0)
+    }
+}
+
+impl distribution::Discrete for Rademacher {
+    #[inline]
+    fn mass(&self, x: i8) -> f64 {
+        if x == -1 || x == 1 {
+            0.5
+        } else {
+            0.0
+        }
+    }
+
+    #[inline]
+    fn log_mass(&self, x: i8) -> f64 {
+        use core::f64::consts::LN_2;
+        if x == -1 || x == 1 {
+            -LN_2
+        } else {
+            f64::NEG_INFINITY
+        }
+    }
+}
+
+impl distribution::Distribution for Rademacher {
+    type Value = i8;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        if x < -1.0 {
+            0.0
+        } else if x < 1.0 {
+            0.5
+        } else {
+            1.0
+        }
+    }
+}
+
+impl distribution::Entropy for Rademacher {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        core::f64::consts::LN_2
+    }
+}
+
+impl distribution::Inverse for Rademacher {
+    #[inline]
+    fn inverse(&self, p: f64) -> i8 {
+        should!((0.0..=1.0).contains(&p));
+        if p <= 0.5 {
+            -1
+        } else {
+            1
+        }
+    }
+}
+
+impl distribution::Kurtosis for Rademacher {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        -2.0
+    }
+}
+
+impl distribution::Mean for Rademacher {
+    #[inline]
+    fn mean(&self) -> f64 {
+        0.0
+    }
+}
+
+impl distribution::Median for Rademacher {
+    #[inline]
+    fn median(&self) -> f64 {
+        0.0
+    }
+}
+
+impl distribution::Mgf for Rademacher {
+    fn mgf(&self, t: f64) -> f64 {
+        0.5 * (t.exp() + (-t).exp())
+    }
+}
+
+impl distribution::Modes for Rademacher {
+    #[inline]
+    fn modes(&self) -> Vec<i8> {
+        vec![-1, 1]
+    }
+}
+
+impl distribution::Moments for Rademacher {
+    fn raw_moment(&self, n: usize) -> f64 {
+        if n % 2 == 0 {
+            1.0
+        } else {
+            0.0
+        }
+    }
+}
+
+impl distribution::Sample for Rademacher {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained from a single bit of the source.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> i8
+    where
+        S: Source,
+    {
+        if source.read::<u64>() & 1 == 0 {
+            -1
+        } else {
+            1
+        }
+    }
+}
+
+impl distribution::Skewness for Rademacher {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        0.0
+    }
+}
+
+impl distribution::Support for Rademacher {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Included;
+        (Included(-1.0), Included(1.0))
+    }
+}
+
+impl distribution::Variance for Rademacher {
+    #[inline]
+    fn variance(&self) -> f64 {
+        1.0
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::approx_constant,
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use prelude::*;
+
+    #[test]
+    fn cf() {
+        let d = Rademacher::new();
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.7648421872844884, 0.955336489125606, -0.41614683654714246];
+        let im = vec![0.0, 0.0, 0.0];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn distribution() {
+        let d = Rademacher::new();
+        let x = vec![-2.0, -1.0, 0.0, 0.5, 1.0, 2.0];
+        let p = vec![0.0, 0.5, 0.5, 0.5, 1.0, 1.0];
+        assert_eq!(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        assert_eq!(Rademacher::new().entropy(), core::f64::consts::LN_2);
+    }
+
+    #[test]
+    fn inverse() {
+        let d = Rademacher::new();
+        let p = vec![0.0, 0.25, 0.5, 0.50000000001, 1.0];
+        let x = vec![-1, -1, -1, 1, 1];
+        assert_eq!(&p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(), &x);
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert_eq!(Rademacher::new().kurtosis(), -2.0);
+    }
+
+    #[test]
+    fn log_mass() {
+        let d = Rademacher::new();
+        let x = vec![-2, -1, 0, 1];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -0.69314718055994531,
+            f64::NEG_INFINITY,
+            -0.69314718055994531,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_mass(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn mass() {
+        let d = Rademacher::new();
+        assert_eq!(
+            &(-2..3).map(|x| d.mass(x)).collect::<Vec<_>>(),
+            &[0.0, 0.5, 0.0, 0.5, 0.0]
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(Rademacher::new().mean(), 0.0);
+    }
+
+    #[test]
+    fn median() {
+        assert_eq!(Rademacher::new().median(), 0.0);
+    }
+
+    #[test]
+    fn mgf() {
+        let d = Rademacher::new();
+        let t = vec![-0.7, -0.1, 0.2, 0.45];
+        let m = vec![
+            1.255169005630943,
+            1.0050041680558035,
+            1.020066755619076,
+            1.1029701685559712,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(Rademacher::new().modes(), vec![-1, 1]);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let mut source = source::default(42);
+        let sum = Independent(&Rademacher::new(), &mut source)
+            .take(n)
+            .map(|x| x as f64)
+            .sum::<f64>();
+        assert!((sum / n as f64).abs() < 0.01);
+    }
+
+    #[test]
+    fn skewness() {
+        assert_eq!(Rademacher::new().skewness(), 0.0);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::Included;
+        assert_eq!(Rademacher::new().support(), (Included(-1.0), Included(1.0)));
+    }
+
+    #[test]
+    fn variance() {
+        assert_eq!(Rademacher::new().variance(), 1.0);
+    }
+}
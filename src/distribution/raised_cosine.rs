@@ -0,0 +1,382 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A raised-cosine distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct RaisedCosine {
+    mu: f64,
+    s: f64,
+}
+
+impl RaisedCosine {
+    /// Create a raised-cosine distribution with location `mu` and half-width
+    /// `s`.
+    ///
+    /// It should hold that `s > 0`.
+    #[inline]
+    pub fn new(mu: f64, s: f64) -> Self {
+        should!(s > 0.0);
+        RaisedCosine { mu, s }
+    }
+
+    /// Return the location parameter.
+    #[inline(always)]
+    pub fn mu(&self) -> f64 {
+        self.mu
+    }
+
+    /// Return the half-width parameter.
+    #[inline(always)]
+    pub fn s(&self) -> f64 {
+        self.s
+    }
+}
+
+impl distribution::CharacteristicFunction for RaisedCosine {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use core::f64::consts::{FRAC_PI_2, PI};
+        let x = self.s * t;
+        let modulus = if x == 0.0 {
+            1.0
+        } else if x.abs() == PI {
+            0.5
+        } else {
+            x.sin() / (x * (1.0 - x * x / (PI * PI)))
+        };
+        let phase = self.mu * t;
+        (modulus * (FRAC_PI_2 - phase).sin(), modulus * phase.sin())
+    }
+}
+
+impl distribution::Continuous for RaisedCosine {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        use core::f64::consts::{FRAC_PI_2, PI};
+        let z = (x - self.mu) / self.s;
+        if z.abs() > 1.0 {
+            0.0
+        } else {
+            (1.0 + (FRAC_PI_2 - PI * z.abs()).sin()) / (2.0 * self.s)
+        }
+    }
+
+    #[inline]
+    fn log_density(&self, x: f64) -> f64 {
+        use core::f64::consts::FRAC_PI_2;
+        let z = (x - self.mu) / self.s;
+        if z.abs() > 1.0 {
+            f64::NEG_INFINITY
+        } else {
+            2.0 * (FRAC_PI_2 * (1.0 - z.abs())).sin().ln() - self.s.ln()
+        }
+    }
+}
+
+impl distribution::Distribution for RaisedCosine {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        use core::f64::consts::{FRAC_1_PI, PI};
+        let z = (x - self.mu) / self.s;
+        if z <= -1.0 {
+            0.0
+        } else if z >= 1.0 {
+            1.0
+        } else {
+            0.5 * (1.0 + z + FRAC_1_PI * (PI * z).sin())
+        }
+    }
+}
+
+impl distribution::Inverse for RaisedCosine {
+    /// Compute the inverse of the cumulative distribution function.
+    ///
+    /// The equation `z + sin(pi z) / pi = 2 p - 1` is solved for `z` using
+    /// Newton’s method safeguarded by bisection.
+    fn inverse(&self, p: f64) -> f64 {
+        use core::f64::consts::{FRAC_1_PI, FRAC_PI_2, PI};
+        should!((0.0..=1.0).contains(&p));
+        let q = (2.0 * p - 1.0).abs();
+        let (mut a, mut b) = (0.0, 1.0);
+        let mut z = q;
+        for _ in 0..100 {
+            let error = z + FRAC_1_PI * (PI * z).sin() - q;
+            if error == 0.0 {
+                break;
+            }
+            if error < 0.0 {
+                a = z;
+            } else {
+                b = z;
+            }
+            let slope = 1.0 + (FRAC_PI_2 - PI * z).sin();
+            let mut next = z - error / slope;
+            if !(next > a && next < b) {
+                next = 0.5 * (a + b);
+            }
+            if (next - z).abs() <= f64::EPSILON * z {
+                z = next;
+                break;
+            }
+            z = next;
+        }
+        if p < 0.5 {
+            self.mu - self.s * z
+        } else {
+            self.mu + self.s * z
+        }
+    }
+}
+
+impl distribution::Kurtosis for RaisedCosine {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        use core::f64::consts::PI;
+        let pi2 = PI * PI;
+        6.0 * (90.0 - pi2 * pi2) / (5.0 * (pi2 - 6.0).powi(2))
+    }
+}
+
+impl distribution::Mean for RaisedCosine {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.mu
+    }
+}
+
+impl distribution::Median for RaisedCosine {
+    #[inline]
+    fn median(&self) -> f64 {
+        self.mu
+    }
+}
+
+impl distribution::Modes for RaisedCosine {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![self.mu]
+    }
+}
+
+impl distribution::Moments for RaisedCosine {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is computed by numerical integration.
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::{moments, Continuous, Mean, Variance};
+        moments::continuous(
+            |x| self.density(x),
+            n,
+            self.mu - self.s,
+            self.mu + self.s,
+            self.mean(),
+            self.deviation(),
+        )
+    }
+}
+
+impl distribution::Sample for RaisedCosine {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::Inverse;
+        self.inverse(source.read::<f64>())
+    }
+}
+
+impl distribution::Skewness for RaisedCosine {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        0.0
+    }
+}
+
+impl distribution::Support for RaisedCosine {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Included;
+        (Included(self.mu - self.s), Included(self.mu + self.s))
+    }
+}
+
+impl distribution::Variance for RaisedCosine {
+    #[inline]
+    fn variance(&self) -> f64 {
+        use core::f64::consts::PI;
+        self.s * self.s * (1.0 / 3.0 - 2.0 / (PI * PI))
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($mu:expr, $s:expr) => (RaisedCosine::new($mu, $s));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(1.0, 2.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.6717741012302423, 0.9330737114054738, -0.12675946753024336];
+        let im = vec![
+            -0.5658275197622616,
+            0.28863352249550117,
+            0.27697448959938586,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(1.0, 2.0);
+        let x = vec![-2.0, -1.0, -0.5, 0.0, 1.0, 1.5, 2.0, 3.0, 4.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            7.3223304703363121e-02,
+            2.5000000000000000e-01,
+            5.0000000000000000e-01,
+            4.2677669529663687e-01,
+            2.5000000000000000e-01,
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(1.0, 2.0);
+        let x = vec![-2.0, -1.0, -0.5, 0.0, 1.0, 1.5, 2.0, 3.0, 4.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            1.2460460480361741e-02,
+            9.0845056908104668e-02,
+            5.0000000000000000e-01,
+            7.3753953951963824e-01,
+            9.0915494309189537e-01,
+            1.0000000000000000e+00,
+            1.0000000000000000e+00,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn inverse() {
+        let d = new!(1.0, 2.0);
+        let p = vec![
+            0.0, 0.01, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.99, 1.0,
+        ];
+        let x = vec![
+            -1.0000000000000000e+00,
+            -5.3601447172331063e-01,
+            3.5623344340527240e-02,
+            3.4526604299660951e-01,
+            5.8568289475860003e-01,
+            7.9832175194480592e-01,
+            1.0000000000000000e+00,
+            1.2016782480551942e+00,
+            1.4143171052413999e+00,
+            1.6547339570033905e+00,
+            1.9643766556594728e+00,
+            2.5360144717233104e+00,
+            3.0000000000000000e+00,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(1.0, 2.0).kurtosis(), -5.9376287559828100e-01, 1e-14);
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(1.0, 2.0);
+        let x = vec![-40.0, -0.5, 0.5, 1.5];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -2.614241538419406,
+            -0.8514943643803202,
+            -0.8514943643803202,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-13,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(1.0, 2.0).mean(), 1.0);
+    }
+
+    #[test]
+    fn median() {
+        assert_eq!(new!(1.0, 2.0).median(), 1.0);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(1.0, 2.0).modes(), vec![1.0]);
+    }
+
+    #[test]
+    fn sample() {
+        for x in Independent(&new!(1.0, 2.0), &mut source::default(42)).take(100) {
+            assert!((-1.0..=3.0).contains(&x));
+        }
+    }
+
+    #[test]
+    fn skewness() {
+        assert_eq!(new!(1.0, 2.0).skewness(), 0.0);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::Included;
+        assert_eq!(new!(1.0, 2.0).support(), (Included(-1.0), Included(3.0)));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(1.0, 2.0).variance(), 5.2276386419463117e-01, 1e-15);
+    }
+}
@@ -0,0 +1,363 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A Rayleigh distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct Rayleigh {
+    sigma: f64,
+}
+
+impl Rayleigh {
+    /// Create a Rayleigh distribution with scale `sigma`.
+    ///
+    /// It should hold that `sigma > 0`.
+    #[inline]
+    pub fn new(sigma: f64) -> Self {
+        should!(sigma > 0.0);
+        Rayleigh { sigma }
+    }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn sigma(&self) -> f64 {
+        self.sigma
+    }
+}
+
+impl Default for Rayleigh {
+    #[inline]
+    fn default() -> Self {
+        Rayleigh::new(1.0)
+    }
+}
+
+impl distribution::CharacteristicFunction for Rayleigh {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Continuous, Mean, Variance};
+        characteristic::continuous(
+            |x| self.density(x),
+            t,
+            0.0,
+            f64::INFINITY,
+            self.mean(),
+            self.deviation(),
+        )
+    }
+}
+
+impl distribution::Continuous for Rayleigh {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            0.0
+        } else {
+            let sigma2 = self.sigma * self.sigma;
+            x / sigma2 * (-0.5 * x * x / sigma2).exp()
+        }
+    }
+
+    #[inline]
+    fn log_density(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            f64::NEG_INFINITY
+        } else {
+            let sigma2 = self.sigma * self.sigma;
+            (x / sigma2).ln() - 0.5 * x * x / sigma2
+        }
+    }
+}
+
+impl distribution::Distribution for Rayleigh {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else {
+            -(-0.5 * x * x / (self.sigma * self.sigma)).exp_m1()
+        }
+    }
+}
+
+impl distribution::Entropy for Rayleigh {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        use core::f64::consts::SQRT_2;
+        const EULER_MASCHERONI: f64 = 0.5772156649015329;
+        1.0 + (self.sigma / SQRT_2).ln() + 0.5 * EULER_MASCHERONI
+    }
+}
+
+impl distribution::Inverse for Rayleigh {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        should!((0.0..=1.0).contains(&p));
+        self.sigma * (-2.0 * (-p).ln_1p()).sqrt()
+    }
+}
+
+impl distribution::Kurtosis for Rayleigh {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        use core::f64::consts::PI;
+        -(6.0 * PI * PI - 24.0 * PI + 16.0) / (4.0 - PI).powi(2)
+    }
+}
+
+impl distribution::Mean for Rayleigh {
+    #[inline]
+    fn mean(&self) -> f64 {
+        use core::f64::consts::FRAC_PI_2;
+        self.sigma * FRAC_PI_2.sqrt()
+    }
+}
+
+impl distribution::Median for Rayleigh {
+    #[inline]
+    fn median(&self) -> f64 {
+        use core::f64::consts::LN_2;
+        self.sigma * (2.0 * LN_2).sqrt()
+    }
+}
+
+impl distribution::Modes for Rayleigh {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![self.sigma]
+    }
+}
+
+impl distribution::Moments for Rayleigh {
+    fn raw_moment(&self, n: usize) -> f64 {
+        use core::f64::consts::LN_2;
+        use special::Gamma;
+        let n = n as f64;
+        (n * (self.sigma.ln() + 0.5 * LN_2) + Gamma::ln_gamma(1.0 + 0.5 * n).0).exp()
+    }
+}
+
+impl distribution::Sample for Rayleigh {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::Inverse;
+        self.inverse(source.read::<f64>())
+    }
+}
+
+impl distribution::Skewness for Rayleigh {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        use core::f64::consts::PI;
+        2.0 * PI.sqrt() * (PI - 3.0) / (4.0 - PI).powf(1.5)
+    }
+}
+
+impl distribution::Support for Rayleigh {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Included, Unbounded};
+        (Included(0.0), Unbounded)
+    }
+}
+
+impl distribution::Variance for Rayleigh {
+    #[inline]
+    fn variance(&self) -> f64 {
+        use core::f64::consts::PI;
+        0.5 * (4.0 - PI) * self.sigma * self.sigma
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($sigma:expr) => (Rayleigh::new($sigma));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(2.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            -0.06676545637544622,
+            0.6802433686354273,
+            -0.08158518325360822,
+        ];
+        let im = vec![
+            -0.6585357885146238,
+            0.6281135786641526,
+            0.0016817602163648766,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(2.0);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 1.5, 2.0, 3.0, 4.0, 5.0, 6.0, 8.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            1.2115415430954302e-01,
+            2.2062422564614886e-01,
+            2.8306485074587773e-01,
+            3.0326532985631671e-01,
+            2.4348935051876230e-01,
+            1.3533528323661270e-01,
+            5.4921167029259269e-02,
+            1.6663494807363458e-02,
+            6.7092525580502371e-04,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(2.0);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 1.5, 2.0, 3.0, 4.0, 5.0, 6.0, 8.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            3.0766765523655919e-02,
+            1.1750309741540460e-01,
+            2.4516039801099265e-01,
+            3.9346934028736658e-01,
+            6.7534753264165026e-01,
+            8.6466471676338730e-01,
+            9.5606306637659255e-01,
+            9.8889100346175773e-01,
+            9.9966453737209748e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(2.0).entropy(), 1.6351814227307391e+00, 1e-15);
+    }
+
+    #[test]
+    fn inverse() {
+        use core::f64::INFINITY;
+        let d = new!(2.0);
+        let p = vec![
+            0.0, 0.01, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.99, 1.0,
+        ];
+        let x = vec![
+            0.0000000000000000e+00,
+            2.8355367539147069e-01,
+            9.1808721005284155e-01,
+            1.3360944616731552e+00,
+            1.6892008618011829e+00,
+            2.0215353051895795e+00,
+            2.3548200450309493e+00,
+            2.7074574521113419e+00,
+            3.1035113073110412e+00,
+            3.5882451559882034e+00,
+            4.2919320525786944e+00,
+            6.0697085175405849e+00,
+            INFINITY,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(2.0).kurtosis(), 2.4508930068763807e-01, 1e-14);
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(2.0);
+        let x = vec![-40.0, 0.05, 0.9, 40.0, 400.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -4.382339134673881,
+            -1.592904876777717,
+            -197.69741490700596,
+            -19995.394829814013,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(2.0).mean(), 2.5066282746310007e+00, 1e-15);
+    }
+
+    #[test]
+    fn median() {
+        assert::close(new!(2.0).median(), 2.3548200450309493e+00, 1e-15);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(2.0).modes(), vec![2.0]);
+    }
+
+    #[test]
+    fn sample() {
+        for x in Independent(&new!(2.0), &mut source::default(42)).take(100) {
+            assert!(x >= 0.0);
+        }
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(2.0).skewness(), 6.3111065781893716e-01, 1e-14);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Included, Unbounded};
+        assert_eq!(new!(2.0).support(), (Included(0.0), Unbounded));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(2.0).variance(), 1.7168146928204135e+00, 1e-15);
+    }
+}
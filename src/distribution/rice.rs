@@ -0,0 +1,359 @@
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A Rice distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct Rice {
+    nu: f64,
+    sigma: f64,
+}
+
+impl Rice {
+    /// Create a Rice distribution with noncentrality `nu` and scale `sigma`.
+    ///
+    /// It should hold that `nu >= 0` and `sigma > 0`.
+    #[inline]
+    pub fn new(nu: f64, sigma: f64) -> Self {
+        should!(nu >= 0.0 && sigma > 0.0);
+        Rice { nu, sigma }
+    }
+
+    /// Return the noncentrality parameter.
+    #[inline(always)]
+    pub fn nu(&self) -> f64 {
+        self.nu
+    }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn sigma(&self) -> f64 {
+        self.sigma
+    }
+
+    fn moment(&self, k: usize) -> f64 {
+        use core::f64::consts::FRAC_PI_2;
+        use distribution::bessel;
+        let &Rice { nu, sigma } = self;
+        let (nu2, sigma2) = (nu * nu, sigma * sigma);
+        // Laguerre functions of orders 1/2 and 3/2 evaluated at -t.
+        let laguerre = || {
+            let t = 0.5 * nu2 / sigma2;
+            let (i0, i1) = (bessel::i0e(0.5 * t), bessel::i1e(0.5 * t));
+            let half = (1.0 + t) * i0 + t * i1;
+            (half, ((2.0 + t) * half - 0.5 * i0) / 1.5)
+        };
+        match k {
+            1 => sigma * FRAC_PI_2.sqrt() * laguerre().0,
+            2 => 2.0 * sigma2 + nu2,
+            3 => 3.0 * sigma * sigma2 * FRAC_PI_2.sqrt() * laguerre().1,
+            4 => 8.0 * sigma2 * sigma2 + 8.0 * sigma2 * nu2 + nu2 * nu2,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl distribution::CharacteristicFunction for Rice {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Continuous, Mean, Variance};
+        characteristic::continuous(
+            |x| self.density(x),
+            t,
+            0.0,
+            f64::INFINITY,
+            self.mean(),
+            self.deviation(),
+        )
+    }
+}
+
+impl distribution::Continuous for Rice {
+    fn density(&self, x: f64) -> f64 {
+        use distribution::bessel;
+        let &Rice { nu, sigma } = self;
+        if x <= 0.0 {
+            0.0
+        } else {
+            let sigma2 = sigma * sigma;
+            let z = x - nu;
+            x / sigma2 * (-0.5 * z * z / sigma2).exp() * bessel::i0e(x * nu / sigma2)
+        }
+    }
+
+    fn log_density(&self, x: f64) -> f64 {
+        use distribution::bessel;
+        let &Rice { nu, sigma } = self;
+        if x <= 0.0 {
+            f64::NEG_INFINITY
+        } else {
+            let sigma2 = sigma * sigma;
+            let z = x - nu;
+            (x / sigma2).ln() - 0.5 * z * z / sigma2 + bessel::i0e(x * nu / sigma2).ln()
+        }
+    }
+}
+
+impl distribution::Distribution for Rice {
+    type Value = f64;
+
+    /// Compute the cumulative distribution function.
+    ///
+    /// The function is `1 - Q1(nu / sigma, x / sigma)` where `Q1` is the
+    /// Marcum Q-function. It is evaluated as a Poisson mixture of regularized
+    /// incomplete gamma functions summed outward from the mode of the mixing
+    /// weights.
+    fn distribution(&self, x: f64) -> f64 {
+        use distribution::poisson;
+        use special::Gamma;
+        let &Rice { nu, sigma } = self;
+        if x <= 0.0 {
+            return 0.0;
+        }
+        let sigma2 = sigma * sigma;
+        let lambda = 0.5 * nu * nu / sigma2;
+        let y = 0.5 * x * x / sigma2;
+        poisson::mixture(lambda, |j| y.inc_gamma(j + 1.0)).min(1.0)
+    }
+}
+
+impl distribution::Kurtosis for Rice {
+    fn kurtosis(&self) -> f64 {
+        let (m1, m2, m3, m4) = (
+            self.moment(1),
+            self.moment(2),
+            self.moment(3),
+            self.moment(4),
+        );
+        let m1_2 = m1 * m1;
+        (m4 - 4.0 * m1 * m3 + 6.0 * m1_2 * m2 - 3.0 * m1_2 * m1_2) / (m2 - m1_2).powi(2) - 3.0
+    }
+}
+
+impl distribution::Mean for Rice {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.moment(1)
+    }
+}
+
+impl distribution::Moments for Rice {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moments of order higher than four are computed by numerical
+    /// integration.
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::{moments, Continuous, Mean, Variance};
+        match n {
+            0 => 1.0,
+            1..=4 => self.moment(n),
+            _ => moments::continuous(
+                |x| self.density(x),
+                n,
+                0.0,
+                f64::INFINITY,
+                self.mean(),
+                self.deviation(),
+            ),
+        }
+    }
+}
+
+impl distribution::Sample for Rice {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained as the norm of a bivariate Gaussian vector
+    /// centered at `(nu, 0)`.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::gaussian;
+        let x = self.nu + self.sigma * gaussian::sample(source);
+        let y = self.sigma * gaussian::sample(source);
+        (x * x + y * y).sqrt()
+    }
+}
+
+impl distribution::Skewness for Rice {
+    fn skewness(&self) -> f64 {
+        let (m1, m2, m3) = (self.moment(1), self.moment(2), self.moment(3));
+        (m3 - 3.0 * m1 * m2 + 2.0 * m1 * m1 * m1) / (m2 - m1 * m1).powf(1.5)
+    }
+}
+
+impl distribution::Support for Rice {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Excluded, Unbounded};
+        (Excluded(0.0), Unbounded)
+    }
+}
+
+impl distribution::Variance for Rice {
+    #[inline]
+    fn variance(&self) -> f64 {
+        let m1 = self.moment(1);
+        self.moment(2) - m1 * m1
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($nu:expr, $sigma:expr) => (Rice::new($nu, $sigma));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(2.0, 1.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            -0.008693877697685669,
+            0.7482199757405609,
+            -0.06192229657151775,
+        ];
+        let im = vec![-0.8136051735332026, 0.606286573662154, -0.1550229887902038];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(2.0, 1.0);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 4.0, 6.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            7.5605002900566071e-02,
+            1.8711975640531600e-01,
+            3.2167058981215590e-01,
+            4.1400384244797339e-01,
+            4.0493549656397981e-01,
+            3.0324852769512517e-01,
+            7.7645523290915536e-02,
+            2.3433987675011941e-04,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+
+        let d1 = new!(0.0, 2.0);
+        let d2 = Rayleigh::new(2.0);
+        for &x in &[0.5, 1.0, 3.0] {
+            assert::close(d1.density(x), d2.density(x), 1e-15);
+        }
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(2.0, 1.0);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 4.0, 6.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            1.7930632708335052e-02,
+            8.1892303630593993e-02,
+            2.0923222060322991e-01,
+            3.9649903938800662e-01,
+            6.0589607547245949e-01,
+            7.8563791183735054e-01,
+            9.6586515506855597e-01,
+            9.9994354110555217e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+
+        let d1 = new!(0.0, 2.0);
+        let d2 = Rayleigh::new(2.0);
+        for &x in &[0.5, 1.0, 3.0] {
+            assert::close(d1.distribution(x), d2.distribution(x), 1e-15);
+        }
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(2.0, 1.0).kurtosis(), -1.8504860243684593e-01, 1e-13);
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(2.0, 1.0);
+        let x = vec![-40.0, 0.05, 0.9, 4.0, 400.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -4.994483834320114,
+            -1.8224473302755595,
+            -2.555601383452296,
+            -79200.26962350214,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-9,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(2.0, 1.0).mean(), 2.2723834280687427e+00, 1e-15);
+        assert::close(new!(0.0, 2.0).mean(), Rayleigh::new(2.0).mean(), 1e-15);
+    }
+
+    #[test]
+    fn moments() {
+        let d = new!(2.0, 1.0);
+        let m = vec![190.69244103252905, 688.0, 2611.195948426407];
+        assert::close(
+            &(5..8).map(|n| d.raw_moment(n)).collect::<Vec<_>>(),
+            &m,
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn sample() {
+        for x in Independent(&new!(2.0, 1.0), &mut source::default(42)).take(100) {
+            assert!(x >= 0.0);
+        }
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(2.0, 1.0).skewness(), 2.0968196945744677e-01, 1e-13);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Excluded, Unbounded};
+        assert_eq!(new!(2.0, 1.0).support(), (Excluded(0.0), Unbounded));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(2.0, 1.0).variance(), 8.3627355583855012e-01, 1e-14);
+    }
+}
@@ -0,0 +1,349 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A Wigner semicircle distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct Semicircle {
+    r: f64,
+}
+
+impl Semicircle {
+    /// Create a Wigner semicircle distribution with radius `r`.
+    ///
+    /// It should hold that `r > 0`.
+    #[inline]
+    pub fn new(r: f64) -> Self {
+        should!(r > 0.0);
+        Semicircle { r }
+    }
+
+    /// Return the radius.
+    #[inline(always)]
+    pub fn r(&self) -> f64 {
+        self.r
+    }
+}
+
+impl distribution::CharacteristicFunction for Semicircle {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::bessel;
+        let x = self.r * t;
+        if x == 0.0 {
+            (1.0, 0.0)
+        } else {
+            (2.0 * bessel::jn(1, x) / x, 0.0)
+        }
+    }
+}
+
+impl distribution::Continuous for Semicircle {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        use core::f64::consts::PI;
+        let r = self.r;
+        if x <= -r || x >= r {
+            0.0
+        } else {
+            2.0 / (PI * r * r) * ((r - x) * (r + x)).sqrt()
+        }
+    }
+
+    #[inline]
+    fn log_density(&self, x: f64) -> f64 {
+        use core::f64::consts::PI;
+        let r = self.r;
+        if x <= -r || x >= r {
+            f64::NEG_INFINITY
+        } else {
+            (2.0 / (PI * r * r)).ln() + 0.5 * ((r - x) * (r + x)).ln()
+        }
+    }
+}
+
+impl distribution::Distribution for Semicircle {
+    type Value = f64;
+
+    fn distribution(&self, x: f64) -> f64 {
+        use core::f64::consts::PI;
+        let r = self.r;
+        if x <= -r {
+            0.0
+        } else if x >= r {
+            1.0
+        } else {
+            let y = x / r;
+            let z = ((1.0 - y) * (1.0 + y)).sqrt();
+            let arcsin = 2.0 * (y / (1.0 + z)).atan();
+            0.5 + (y * z + arcsin) / PI
+        }
+    }
+}
+
+impl distribution::Entropy for Semicircle {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        use core::f64::consts::PI;
+        (PI * self.r).ln() - 0.5
+    }
+}
+
+impl distribution::Kurtosis for Semicircle {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        -1.0
+    }
+}
+
+impl distribution::Mean for Semicircle {
+    #[inline]
+    fn mean(&self) -> f64 {
+        0.0
+    }
+}
+
+impl distribution::Median for Semicircle {
+    #[inline]
+    fn median(&self) -> f64 {
+        0.0
+    }
+}
+
+impl distribution::Mgf for Semicircle {
+    fn mgf(&self, t: f64) -> f64 {
+        use distribution::bessel;
+        let x = (self.r * t).abs();
+        if x == 0.0 {
+            1.0
+        } else {
+            2.0 * x.exp() * bessel::i1e(x) / x
+        }
+    }
+}
+
+impl distribution::Modes for Semicircle {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![0.0]
+    }
+}
+
+impl distribution::Moments for Semicircle {
+    fn raw_moment(&self, n: usize) -> f64 {
+        if n % 2 == 1 {
+            return 0.0;
+        }
+        let m = n / 2;
+        let catalan = (1..=m).fold(1.0, |product, j| product * (m + j) as f64 / (j + 1) as f64);
+        catalan * (0.5 * self.r).powi(n as i32)
+    }
+}
+
+impl distribution::Sample for Semicircle {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained by mapping a beta variate with both shape
+    /// parameters equal to `3/2` onto `[-r, r]`.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::gamma;
+        let x = gamma::sample(1.5, source);
+        let y = gamma::sample(1.5, source);
+        self.r * (x - y) / (x + y)
+    }
+}
+
+impl distribution::Skewness for Semicircle {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        0.0
+    }
+}
+
+impl distribution::Support for Semicircle {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Excluded;
+        (Excluded(-self.r), Excluded(self.r))
+    }
+}
+
+impl distribution::Variance for Semicircle {
+    #[inline]
+    fn variance(&self) -> f64 {
+        0.25 * self.r * self.r
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::approx_constant,
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($r:expr) => (Semicircle::new($r));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(2.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            0.7742110199012208,
+            0.9556699602130527,
+            -0.033021664011774546,
+        ];
+        let im = vec![0.0, 0.0, 0.0];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(2.0);
+        let x = vec![-3.0, -2.0, -1.5, -1.0, -0.5, 0.0, 0.5, 1.0, 1.5, 2.0, 3.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            2.1054219967389620e-01,
+            2.7566444771089604e-01,
+            3.0820222203074993e-01,
+            3.1830988618379069e-01,
+            3.0820222203074993e-01,
+            2.7566444771089604e-01,
+            2.1054219967389620e-01,
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(2.0);
+        let x = vec![-3.0, -2.0, -1.5, -1.0, -0.5, 0.0, 0.5, 1.0, 1.5, 2.0, 3.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            7.2146806407193745e-02,
+            1.9550110947788532e-01,
+            3.4251882123714628e-01,
+            5.0000000000000000e-01,
+            6.5748117876285372e-01,
+            8.0449889052211465e-01,
+            9.2785319359280627e-01,
+            1.0000000000000000e+00,
+            1.0000000000000000e+00,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(2.0).entropy(), 1.3378770664093456e+00, 1e-15);
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert_eq!(new!(2.0).kurtosis(), -1.0);
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(2.0);
+        let x = vec![-40.0, -0.5, 0.5, 1.5];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -1.1769991464181855,
+            -1.1769991464181855,
+            -1.558069172441634,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(2.0).mean(), 0.0);
+    }
+
+    #[test]
+    fn median() {
+        assert_eq!(new!(2.0).median(), 0.0);
+    }
+
+    #[test]
+    fn mgf() {
+        let d = new!(2.0);
+        let t = vec![-0.7, -0.1, 0.2, 0.45];
+        let m = vec![
+            1.2658456877347535,
+            1.005008340281251,
+            1.0201337786678528,
+            1.1047254403576985,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(2.0).modes(), vec![0.0]);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(2.0);
+        let mut source = source::default(42);
+
+        let cross_entropy = -(0..n)
+            .map(|_| d.density(d.sample(&mut source)).ln())
+            .sum::<f64>()
+            / n as f64;
+        assert!((cross_entropy - d.entropy()).abs() < 0.01);
+    }
+
+    #[test]
+    fn skewness() {
+        assert_eq!(new!(2.0).skewness(), 0.0);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::Excluded;
+        assert_eq!(new!(2.0).support(), (Excluded(-2.0), Excluded(2.0)));
+    }
+
+    #[test]
+    fn variance() {
+        assert_eq!(new!(2.0).variance(), 1.0);
+    }
+}
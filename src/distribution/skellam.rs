@@ -0,0 +1,325 @@
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution::{self, NoncentralChiSquared};
+use source::Source;
+
+/// A Skellam distribution.
+///
+/// The distribution describes the difference between two independent Poisson
+/// variates.
+#[derive(Clone, Copy, Debug)]
+pub struct Skellam {
+    mu1: f64,
+    mu2: f64,
+}
+
+impl Skellam {
+    /// Create a Skellam distribution with rates `mu1` and `mu2` of the
+    /// minuend and the subtrahend, respectively.
+    ///
+    /// It should hold that `mu1 > 0` and `mu2 > 0`.
+    #[inline]
+    pub fn new(mu1: f64, mu2: f64) -> Self {
+        should!(mu1 > 0.0 && mu2 > 0.0);
+        Skellam { mu1, mu2 }
+    }
+
+    /// Return the rate of the minuend.
+    #[inline(always)]
+    pub fn mu1(&self) -> f64 {
+        self.mu1
+    }
+
+    /// Return the rate of the subtrahend.
+    #[inline(always)]
+    pub fn mu2(&self) -> f64 {
+        self.mu2
+    }
+}
+
+impl distribution::CharacteristicFunction for Skellam {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::complex;
+        let &Skellam { mu1, mu2 } = self;
+        complex::exp((
+            -2.0 * (mu1 + mu2) * (0.5 * t).sin().powi(2),
+            (mu1 - mu2) * t.sin(),
+        ))
+    }
+}
+
+impl distribution::Discrete for Skellam {
+    #[inline]
+    fn mass(&self, x: isize) -> f64 {
+        self.log_mass(x).exp()
+    }
+
+    /// Compute the natural logarithm of the probability mass function.
+    ///
+    /// The function is evaluated via the exponentially scaled modified Bessel
+    /// function of the first kind.
+    fn log_mass(&self, x: isize) -> f64 {
+        use distribution::bessel;
+        let &Skellam { mu1, mu2 } = self;
+        let difference = mu1.sqrt() - mu2.sqrt();
+        let bessel = bessel::ive(x.unsigned_abs(), 2.0 * (mu1 * mu2).sqrt());
+        if bessel == 0.0 {
+            return f64::NEG_INFINITY;
+        }
+        -difference * difference + 0.5 * x as f64 * (mu1 / mu2).ln() + bessel.ln()
+    }
+}
+
+impl distribution::Distribution for Skellam {
+    type Value = isize;
+
+    /// Compute the cumulative distribution function.
+    ///
+    /// The function is evaluated via the cumulative distribution function of
+    /// the noncentral chi-squared distribution.
+    fn distribution(&self, x: f64) -> f64 {
+        let &Skellam { mu1, mu2 } = self;
+        let k = x.floor();
+        if k >= 0.0 {
+            let chi = NoncentralChiSquared::new(2.0 * (k + 1.0), 2.0 * mu2);
+            1.0 - chi.distribution(2.0 * mu1)
+        } else {
+            let chi = NoncentralChiSquared::new(-2.0 * k, 2.0 * mu1);
+            chi.distribution(2.0 * mu2)
+        }
+    }
+}
+
+impl distribution::Kurtosis for Skellam {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        1.0 / (self.mu1 + self.mu2)
+    }
+}
+
+impl distribution::Mean for Skellam {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.mu1 - self.mu2
+    }
+}
+
+impl distribution::Mgf for Skellam {
+    fn mgf(&self, t: f64) -> f64 {
+        (self.mu1 * t.exp_m1() + self.mu2 * (-t).exp_m1()).exp()
+    }
+}
+
+impl distribution::Moments for Skellam {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is computed from the cumulants, which are `mu1 + (-1)^k mu2`.
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::moments;
+        let &Skellam { mu1, mu2 } = self;
+        moments::cumulants(
+            |k| {
+                if k % 2 == 0 {
+                    mu1 + mu2
+                } else {
+                    mu1 - mu2
+                }
+            },
+            n,
+        )
+    }
+}
+
+impl distribution::Sample for Skellam {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained as the difference between two Poisson variates.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> isize
+    where
+        S: Source,
+    {
+        use distribution::poisson;
+        poisson::sample(self.mu1, source) as isize - poisson::sample(self.mu2, source) as isize
+    }
+}
+
+impl distribution::Skewness for Skellam {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        (self.mu1 - self.mu2) / (self.mu1 + self.mu2).powf(1.5)
+    }
+}
+
+impl distribution::Support for Skellam {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Unbounded;
+        (Unbounded, Unbounded)
+    }
+}
+
+impl distribution::Variance for Skellam {
+    #[inline]
+    fn variance(&self) -> f64 {
+        self.mu1 + self.mu2
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($mu1:expr, $mu2:expr) => (Skellam::new($mu1, $mu2));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(3.0, 1.5);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            0.1972525463228186,
+            0.7388713478764136,
+            0.00035070588729708204,
+        ];
+        let im = vec![
+            -0.2855757464107191,
+            0.3508117411459864,
+            0.0016712079535682203,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(3.0, 1.5);
+        let x = vec![-10.0, -3.0, -1.5, -1.0, 0.0, 0.5, 1.0, 2.0, 5.0, 10.0, 20.0];
+        let p = vec![
+            3.0359856092631836e-07,
+            2.4655686748309612e-02,
+            7.0285168793579031e-02,
+            1.6587592333789725e-01,
+            3.2086205712464821e-01,
+            3.2086205712464821e-01,
+            5.1204356621328462e-01,
+            6.9456149439436232e-01,
+            9.6653834427328722e-01,
+            9.9990636392651477e-01,
+            9.9999999999677935e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(3.0, 1.5).kurtosis(), 1.0 / 4.5, 1e-15);
+    }
+
+    #[test]
+    fn log_mass() {
+        let d = new!(3.0, 1.5);
+        let x = vec![-30, 2, 100, 1000];
+        let p = vec![
+            -66.84944914648408,
+            -1.700906874175846,
+            -258.33360195857904,
+            f64::NEG_INFINITY,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_mass(x)).collect::<Vec<_>>(),
+            &p,
+            1e-11,
+        );
+    }
+
+    #[test]
+    fn mass() {
+        let d = new!(3.0, 1.5);
+        let x = vec![-10, -3, -1, 0, 1, 2, 5, 10, 20];
+        let p = vec![
+            2.6398686991787798e-07,
+            1.7375722575312835e-02,
+            9.5590754544318216e-02,
+            1.5498613378675097e-01,
+            1.9118150908863643e-01,
+            1.8251792818107765e-01,
+            4.5946774119272289e-02,
+            2.7032255479590706e-04,
+            1.9705647952284853e-11,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.mass(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(3.0, 1.5).mean(), 1.5);
+    }
+
+    #[test]
+    fn mgf() {
+        let d = new!(3.0, 1.5);
+        let t = vec![-0.7, -0.1, 0.2, 0.45];
+        let m = vec![
+            1.0104390835591488,
+            0.8800897669816476,
+            1.4803882790795022,
+            3.194334538266922,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+    }
+
+    #[test]
+    fn moments() {
+        let d = new!(3.0, 1.5);
+        let m = vec![751.59375, 4975.171875, 33362.3203125];
+        assert::close(
+            &(5..8).map(|n| d.raw_moment(n)).collect::<Vec<_>>(),
+            &m,
+            1e-9,
+        );
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(3.0, 1.5);
+        let mut source = source::default(42);
+
+        let mean = (0..n).map(|_| d.sample(&mut source) as f64).sum::<f64>() / n as f64;
+        assert!((mean - d.mean()).abs() < 0.05);
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(3.0, 1.5).skewness(), 1.5 / 4.5f64.powf(1.5), 1e-15);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::Unbounded;
+        assert_eq!(new!(3.0, 1.5).support(), (Unbounded, Unbounded));
+    }
+
+    #[test]
+    fn variance() {
+        assert_eq!(new!(3.0, 1.5).variance(), 4.5);
+    }
+}
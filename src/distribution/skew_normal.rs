@@ -0,0 +1,471 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A skew-normal distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct SkewNormal {
+    xi: f64,
+    omega: f64,
+    alpha: f64,
+}
+
+impl SkewNormal {
+    /// Create a skew-normal distribution with location `xi`, scale `omega`,
+    /// and shape `alpha`.
+    ///
+    /// It should hold that `omega > 0`.
+    #[inline]
+    pub fn new(xi: f64, omega: f64, alpha: f64) -> Self {
+        should!(omega > 0.0);
+        SkewNormal { xi, omega, alpha }
+    }
+
+    /// Return the location parameter.
+    #[inline(always)]
+    pub fn xi(&self) -> f64 {
+        self.xi
+    }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn omega(&self) -> f64 {
+        self.omega
+    }
+
+    /// Return the shape parameter.
+    #[inline(always)]
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Compute `delta * sqrt(2 / pi)` where `delta = alpha / sqrt(1 + alpha^2)`,
+    /// which is the mean of the standardized distribution.
+    #[inline]
+    fn mean_standard(&self) -> f64 {
+        use core::f64::consts::FRAC_2_PI;
+        self.alpha / (1.0 + self.alpha * self.alpha).sqrt() * FRAC_2_PI.sqrt()
+    }
+}
+
+impl distribution::CharacteristicFunction for SkewNormal {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Continuous, Mean, Variance};
+        characteristic::continuous(
+            |x| self.density(x),
+            t,
+            f64::NEG_INFINITY,
+            f64::INFINITY,
+            self.mean(),
+            self.deviation(),
+        )
+    }
+}
+
+impl distribution::Continuous for SkewNormal {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        use core::f64::consts::PI;
+        let z = (x - self.xi) / self.omega;
+        2.0 / self.omega * (-0.5 * z * z).exp() / (2.0 * PI).sqrt() * phi(self.alpha * z)
+    }
+
+    #[inline]
+    fn log_density(&self, x: f64) -> f64 {
+        use core::f64::consts::{LN_2, PI};
+        use distribution::gaussian;
+        let z = (x - self.xi) / self.omega;
+        LN_2 - self.omega.ln() - 0.5 * z * z - 0.5 * (2.0 * PI).ln()
+            + gaussian::ln_survival(-self.alpha * z)
+    }
+}
+
+impl distribution::Distribution for SkewNormal {
+    type Value = f64;
+
+    /// Compute the cumulative distribution function.
+    ///
+    /// The function is `Phi(z) - 2 T(z, alpha)` where `T` is Owen’s
+    /// T-function.
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        let z = (x - self.xi) / self.omega;
+        (phi(z) - 2.0 * owen_t(z, self.alpha)).clamp(0.0, 1.0)
+    }
+}
+
+impl distribution::Inverse for SkewNormal {
+    fn inverse(&self, p: f64) -> f64 {
+        distribution::numeric::inverse(self, p)
+    }
+}
+
+impl distribution::Kurtosis for SkewNormal {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        use core::f64::consts::PI;
+        let b2 = self.mean_standard().powi(2);
+        2.0 * (PI - 3.0) * b2 * b2 / (1.0 - b2).powi(2)
+    }
+}
+
+impl distribution::Mean for SkewNormal {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.xi + self.omega * self.mean_standard()
+    }
+}
+
+impl distribution::Modes for SkewNormal {
+    /// Compute the modes.
+    ///
+    /// The mode is found numerically by bisection on the derivative of the
+    /// logarithm of the density.
+    fn modes(&self) -> Vec<f64> {
+        use core::f64::consts::{FRAC_2_PI, PI};
+        let alpha = self.alpha.abs();
+        let slope = |z: f64| {
+            let y = alpha * z;
+            -z + alpha * (-0.5 * y * y).exp() / (2.0 * PI).sqrt() / phi(y)
+        };
+        let (mut a, mut b) = (0.0, alpha * FRAC_2_PI.sqrt());
+        while a < b {
+            let c = 0.5 * (a + b);
+            if c <= a || c >= b {
+                break;
+            }
+            if slope(c) > 0.0 {
+                a = c;
+            } else {
+                b = c;
+            }
+        }
+        let z = 0.5 * (a + b);
+        vec![self.xi + self.omega * if self.alpha < 0.0 { -z } else { z }]
+    }
+}
+
+impl distribution::Moments for SkewNormal {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is computed by numerical integration.
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::{moments, Continuous, Mean, Variance};
+        moments::continuous(
+            |x| self.density(x),
+            n,
+            f64::NEG_INFINITY,
+            f64::INFINITY,
+            self.mean(),
+            self.deviation(),
+        )
+    }
+}
+
+impl distribution::Sample for SkewNormal {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained by conditioning one of two correlated Gaussian
+    /// variates on the sign of the other.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::gaussian;
+        let delta = self.alpha / (1.0 + self.alpha * self.alpha).sqrt();
+        let u = gaussian::sample(source);
+        let v = gaussian::sample(source);
+        let w = delta * u + (1.0 - delta * delta).sqrt() * v;
+        self.xi + self.omega * if u >= 0.0 { w } else { -w }
+    }
+}
+
+impl distribution::Skewness for SkewNormal {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        use core::f64::consts::PI;
+        let b = self.mean_standard();
+        let b2 = b * b;
+        0.5 * (4.0 - PI) * b * b2 / (1.0 - b2).powf(1.5)
+    }
+}
+
+impl distribution::Support for SkewNormal {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Unbounded;
+        (Unbounded, Unbounded)
+    }
+}
+
+impl distribution::Variance for SkewNormal {
+    #[inline]
+    fn variance(&self) -> f64 {
+        self.omega * self.omega * (1.0 - self.mean_standard().powi(2))
+    }
+}
+
+/// Compute the standard Gaussian distribution function.
+#[inline]
+fn phi(x: f64) -> f64 {
+    use core::f64::consts::FRAC_1_SQRT_2;
+    use special::Error;
+    0.5 * (-x * FRAC_1_SQRT_2).compl_error()
+}
+
+/// Compute Owen’s T-function.
+fn owen_t(h: f64, a: f64) -> f64 {
+    use core::f64::consts::PI;
+    use distribution::quadrature;
+    if a < 0.0 {
+        return -owen_t(h, -a);
+    }
+    let h = h.abs();
+    if a <= 1.0 {
+        let f = |x: f64| {
+            let y = 1.0 + x * x;
+            (-0.5 * h * h * y).exp() / y
+        };
+        let n = (h * a) as usize + 1;
+        let w = a / n as f64;
+        let sum = (0..n)
+            .map(|i| quadrature::legendre(f, i as f64 * w, (i + 1) as f64 * w))
+            .sum::<f64>();
+        sum / (2.0 * PI)
+    } else {
+        let ah = a * h;
+        let (p, q) = (phi(h), phi(ah));
+        0.5 * (p * phi(-ah) + q * phi(-h)) - owen_t(ah, 1.0 / a)
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($xi:expr, $omega:expr, $alpha:expr) => (SkewNormal::new($xi, $omega, $alpha));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(1.0, 2.0, 3.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            -0.06944880923398197,
+            0.6794990379693883,
+            -0.09416122814548458,
+        ];
+        let im = vec![
+            -0.665036813638661,
+            0.6298046019122465,
+            -0.042724672306460106,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(1.0, 2.0, 3.0);
+        let x = vec![-3.0, -1.0, 0.0, 0.5, 1.0, 2.0, 3.0, 5.0, 8.0];
+        let p = vec![
+            5.3266820505555420e-11,
+            3.2663580474049948e-04,
+            2.3520499144928837e-02,
+            8.7629571559579345e-02,
+            1.9947114020071635e-01,
+            3.2854482761937065e-01,
+            2.4164408871440285e-01,
+            5.3990966459921234e-02,
+            8.7268269504576005e-04,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+
+        let d = new!(1.0, 2.0, 0.0);
+        let gaussian = Gaussian::new(1.0, 2.0);
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &x.iter().map(|&x| gaussian.density(x)).collect::<Vec<_>>(),
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(1.0, 2.0, 3.0);
+        let x = vec![-3.0, -1.0, 0.0, 0.5, 1.0, 2.0, 3.0, 5.0, 8.0];
+        let p = vec![
+            5.0891259751793009e-12,
+            5.6244433711877094e-05,
+            6.3694525739500744e-03,
+            3.1793304731104415e-02,
+            1.0241638234956672e-01,
+            3.8929437512197629e-01,
+            6.8274573657079773e-01,
+            9.5449973610873073e-01,
+            9.9953474184192892e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+
+        let d = new!(1.0, 2.0, -3.0);
+        let q = vec![
+            9.9953474184192892e-01,
+            9.5449973610873073e-01,
+            6.8274573657079773e-01,
+            3.8929437512197629e-01,
+            6.3694525739500744e-03,
+        ];
+        assert::close(
+            &[-6.0, -3.0, -1.0, 0.0, 2.0]
+                .iter()
+                .map(|&x| 1.0 - d.distribution(x))
+                .collect::<Vec<_>>(),
+            &q,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn inverse() {
+        use core::f64::{INFINITY, NEG_INFINITY};
+
+        let d = new!(1.0, 2.0, 3.0);
+        let p = vec![0.0, 0.01, 0.1, 0.25, 0.5, 0.75, 0.9, 0.99, 1.0];
+        let x = vec![
+            NEG_INFINITY,
+            0.12638937683436746,
+            0.9877968693895106,
+            1.5685149629103297,
+            2.343987958287836,
+            3.3006556802235942,
+            4.289707208136253,
+            6.151658607097801,
+            INFINITY,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(
+            new!(1.0, 2.0, 3.0).kurtosis(),
+            5.0977012944941358e-01,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(1.0, 2.0, 3.0);
+        let x = vec![-3.0, 0.5, 40.0, 400.0];
+        let p = vec![
+            -23.655707483179377,
+            -2.434636763124329,
+            -191.04393853320468,
+            -19901.043938533203,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(1.0, 2.0, 3.0).mean(), 2.5138795132120961e+00, 1e-15);
+    }
+
+    #[test]
+    fn modes() {
+        assert::close(
+            new!(1.0, 2.0, 3.0).modes(),
+            vec![1.9467912587336273e+00],
+            1e-14,
+        );
+        assert::close(
+            new!(1.0, 2.0, -3.0).modes(),
+            vec![5.3208741266372747e-02],
+            1e-14,
+        );
+        assert_eq!(new!(1.0, 2.0, 0.0).modes(), vec![1.0]);
+    }
+
+    #[test]
+    fn moments() {
+        let d = new!(1.0, 2.0, 3.0);
+        let m = vec![619.92734541792406, 3229.5674477511898, 18130.774151201663];
+        assert::close(
+            &(5..8).map(|n| d.raw_moment(n)).collect::<Vec<_>>(),
+            &m,
+            1e-9,
+        );
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(1.0, 2.0, 3.0);
+        let mut source = source::default(42);
+
+        let mean = (0..n).map(|_| d.sample(&mut source)).sum::<f64>() / n as f64;
+        assert!((mean - d.mean()).abs() < 0.01);
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(
+            new!(1.0, 2.0, 3.0).skewness(),
+            6.6702357015240799e-01,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::Unbounded;
+        assert_eq!(new!(1.0, 2.0, 3.0).support(), (Unbounded, Unbounded));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(
+            new!(1.0, 2.0, 3.0).variance(),
+            1.7081688194767071e+00,
+            1e-15,
+        );
+    }
+}
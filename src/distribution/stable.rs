@@ -0,0 +1,687 @@
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// The value of `(c t)^alpha` beyond which the characteristic function is
+/// treated as zero.
+const LIMIT: f64 = 50.0;
+
+/// The value of `(c t)^alpha` below which the integrands are linearized.
+const THRESHOLD: f64 = 1e-8;
+
+/// The maximal number of panels used for integration along the real axis.
+const MAX_PANELS: usize = 10000;
+
+/// The value of `t |x - mu|` beyond which the integrands along the imaginary
+/// axis are treated as zero.
+const TAIL: f64 = 750.0;
+
+/// The relative tolerance of integration along the imaginary axis.
+const TOLERANCE: f64 = 1e-14;
+
+/// A stable distribution.
+///
+/// The distribution is parameterized by its characteristic function
+/// `exp(i mu t - |c t|^alpha (1 - i beta sign(t) phi(t)))` where `phi(t) =
+/// tan(pi alpha / 2)` if `alpha != 1` and `phi(t) = -2 ln|t| / pi` otherwise.
+/// The density and distribution functions are computed by numerical inversion
+/// of the characteristic function, which is integrated along the imaginary
+/// axis far in the tails, and their accuracy degrades as `alpha` approaches
+/// zero.
+#[derive(Clone, Copy, Debug)]
+pub struct Stable {
+    alpha: f64,
+    beta: f64,
+    c: f64,
+    mu: f64,
+}
+
+impl Stable {
+    /// Create a stable distribution with stability `alpha`, skewness `beta`,
+    /// scale `c`, and location `mu`.
+    ///
+    /// It should hold that `0 < alpha <= 2`, `-1 <= beta <= 1`, and `c > 0`.
+    #[inline]
+    pub fn new(alpha: f64, beta: f64, c: f64, mu: f64) -> Self {
+        should!(alpha > 0.0 && alpha <= 2.0);
+        should!((-1.0..=1.0).contains(&beta) && c > 0.0);
+        Stable { alpha, beta, c, mu }
+    }
+
+    /// Return the stability parameter.
+    #[inline(always)]
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Return the skewness parameter.
+    #[inline(always)]
+    pub fn beta(&self) -> f64 {
+        self.beta
+    }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn c(&self) -> f64 {
+        self.c
+    }
+
+    /// Return the location parameter.
+    #[inline(always)]
+    pub fn mu(&self) -> f64 {
+        self.mu
+    }
+
+    /// Check if the point lies outside the support, which happens only when
+    /// `alpha < 1` and `beta = ±1`.
+    fn outside(&self, x: f64) -> Option<f64> {
+        if self.alpha < 1.0 && self.beta == 1.0 && x <= self.mu {
+            Some(0.0)
+        } else if self.alpha < 1.0 && self.beta == -1.0 && x >= self.mu {
+            Some(1.0)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn phi(&self, t: f64) -> f64 {
+        use core::f64::consts::{FRAC_2_PI, FRAC_PI_2};
+        if self.alpha == 1.0 {
+            -FRAC_2_PI * t.ln()
+        } else {
+            (FRAC_PI_2 * self.alpha).tan()
+        }
+    }
+
+    /// Compute `(c t)^alpha` and the phase of `exp(-i t x) phi(t)` for
+    /// `t > 0`.
+    #[inline]
+    fn phase(&self, t: f64, z: f64) -> (f64, f64) {
+        let u = (self.c * t).powf(self.alpha);
+        (u, self.beta * u * self.phi(t) - t * z)
+    }
+
+    /// Integrate a function over `[epsilon, infinity)` and return the
+    /// integral together with `epsilon`, which is such that neither `(c
+    /// epsilon)^alpha` nor `epsilon |z|` exceeds `THRESHOLD`, unless the
+    /// integrand oscillates too fast to be resolved.
+    fn integrate<F>(&self, z: f64, f: F) -> Option<(f64, f64)>
+    where
+        F: Fn(f64) -> f64,
+    {
+        use distribution::quadrature;
+        let t_max = LIMIT.powf(1.0 / self.alpha) / self.c;
+        let rotation = z.abs() * t_max + (self.beta * self.phi(t_max)).abs() * LIMIT;
+        let n = 0.5 * rotation + 1.0;
+        if n > MAX_PANELS as f64 || n.is_nan() {
+            return None;
+        }
+        let n = n as usize;
+        let h = t_max / n as f64;
+        let mut sum = (1..n)
+            .map(|i| quadrature::legendre(&f, i as f64 * h, (i + 1) as f64 * h))
+            .sum::<f64>();
+        let epsilon = (THRESHOLD.powf(1.0 / self.alpha) / self.c).min(THRESHOLD / z.abs());
+        let mut b = h;
+        while b > epsilon {
+            sum += quadrature::legendre(&f, 0.5 * b, b);
+            b *= 0.5;
+        }
+        Some((sum, b))
+    }
+
+    /// Compute the density at `z` relative to `mu` by inverting the
+    /// characteristic function along the real axis.
+    fn invert(&self, z: f64) -> Option<f64> {
+        use core::f64::consts::{FRAC_PI_2, PI};
+        let integral = self.integrate(z, |t| {
+            let (u, theta) = self.phase(t, z);
+            (-u).exp() * (FRAC_PI_2 - theta.abs()).sin()
+        });
+        integral.map(|(sum, epsilon)| ((sum + epsilon) / PI).max(0.0))
+    }
+
+    #[inline]
+    fn reflect(&self) -> Self {
+        Stable {
+            beta: -self.beta,
+            ..*self
+        }
+    }
+
+    /// Compute the logarithm of the density at `z > 0` relative to `mu` or,
+    /// if `survival` is set, of the survival function in the right tail.
+    ///
+    /// The inversion integral is taken along the negative imaginary axis,
+    /// where the integrand decays exponentially instead of oscillating. The
+    /// leading power of `c / z` is factored out so that the result does not
+    /// underflow.
+    fn ln_tail(&self, z: f64, survival: bool) -> f64 {
+        use core::f64::consts::{FRAC_2_PI, FRAC_PI_2, PI};
+        use distribution::quadrature;
+        if z.is_infinite() {
+            return f64::NEG_INFINITY;
+        }
+        let &Stable { alpha, beta, c, .. } = self;
+        let sin = (FRAC_PI_2 * alpha.min(2.0 - alpha)).sin();
+        let cos = (FRAC_PI_2 * (1.0 - alpha)).sin();
+        let (k_a, k_b) = (cos - beta * sin * self.phi(1.0), sin * (1.0 + beta));
+        let ln_r = c.ln() - z.ln();
+        let integrand = |sigma: f64| {
+            let u = (c * sigma / z).powf(alpha);
+            let a = if alpha == 1.0 {
+                -FRAC_2_PI * beta * u * (sigma.ln() - z.ln())
+            } else {
+                -k_a * u
+            };
+            let b = k_b * u;
+            let sinc = if b == 0.0 { 1.0 } else { b.sin() / b };
+            (a - sigma).exp() * sinc
+        };
+        let ln_scale = k_b.ln() + alpha * ln_r;
+        if survival {
+            let f = |v: f64| integrand(v.powf(1.0 / alpha));
+            let integral = quadrature::adaptive(f, 0.0, TAIL.powf(alpha), TOLERANCE);
+            ln_scale + integral.ln() - (PI * alpha).ln()
+        } else {
+            let f = |sigma: f64| integrand(sigma) * sigma.powf(alpha);
+            let integral = quadrature::adaptive(f, 0.0, TAIL, TOLERANCE);
+            ln_scale + integral.ln() - PI.ln() - z.ln()
+        }
+    }
+}
+
+impl distribution::CharacteristicFunction for Stable {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::complex;
+        if t == 0.0 {
+            return (1.0, 0.0);
+        }
+        let (u, theta) = self.phase(t.abs(), 0.0);
+        let (re, im) = complex::exp((-u, self.mu * t.abs() + theta));
+        if t < 0.0 {
+            (re, -im)
+        } else {
+            (re, im)
+        }
+    }
+}
+
+impl distribution::Continuous for Stable {
+    fn density(&self, x: f64) -> f64 {
+        if self.outside(x).is_some() {
+            return 0.0;
+        }
+        let z = x - self.mu;
+        match self.invert(z) {
+            Some(p) => p,
+            None if z > 0.0 => self.ln_tail(z, false).exp(),
+            None => self.reflect().ln_tail(-z, false).exp(),
+        }
+    }
+
+    /// Compute the natural logarithm of the probability density function.
+    ///
+    /// The function is evaluated directly in the tails that are too far out
+    /// for the characteristic function to be inverted along the real axis.
+    fn log_density(&self, x: f64) -> f64 {
+        if self.outside(x).is_some() {
+            return f64::NEG_INFINITY;
+        }
+        let z = x - self.mu;
+        match self.invert(z) {
+            Some(p) => p.ln(),
+            None if z > 0.0 => self.ln_tail(z, false),
+            None => self.reflect().ln_tail(-z, false),
+        }
+    }
+}
+
+impl distribution::Distribution for Stable {
+    type Value = f64;
+
+    fn distribution(&self, x: f64) -> f64 {
+        use core::f64::consts::{FRAC_2_PI, PI};
+        if let Some(p) = self.outside(x) {
+            return p;
+        }
+        let z = x - self.mu;
+        let integral = self.integrate(z, |t| {
+            let (u, theta) = self.phase(t, z);
+            (-u).exp() * theta.sin() / t
+        });
+        let (sum, epsilon) = match integral {
+            Some(integral) => integral,
+            None if z > 0.0 => return 1.0 - self.ln_tail(z, true).exp(),
+            None => return self.reflect().ln_tail(-z, true).exp(),
+        };
+        let (u, theta) = self.phase(epsilon, z);
+        let head = if self.alpha == 1.0 {
+            theta + FRAC_2_PI * self.beta * u
+        } else {
+            self.beta * u * self.phi(epsilon) / self.alpha - epsilon * z
+        };
+        (0.5 - (sum + head) / PI).clamp(0.0, 1.0)
+    }
+}
+
+impl distribution::Kurtosis for Stable {
+    /// Compute the excess kurtosis.
+    ///
+    /// The excess kurtosis is undefined, which is signified by `NaN`, if
+    /// `alpha < 2`.
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        if self.alpha == 2.0 {
+            0.0
+        } else {
+            f64::NAN
+        }
+    }
+}
+
+impl distribution::Mean for Stable {
+    /// Compute the expected value.
+    ///
+    /// The expected value is infinite if `alpha < 1` and `beta = ±1` and
+    /// undefined, which is signified by `NaN`, if `alpha <= 1` otherwise.
+    #[inline]
+    fn mean(&self) -> f64 {
+        if self.alpha > 1.0 {
+            self.mu
+        } else if self.alpha < 1.0 && self.beta == 1.0 {
+            f64::INFINITY
+        } else if self.alpha < 1.0 && self.beta == -1.0 {
+            f64::NEG_INFINITY
+        } else {
+            f64::NAN
+        }
+    }
+}
+
+impl distribution::Moments for Stable {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is infinite if `alpha < 2` and `n >= 2` is even and
+    /// undefined, which is signified by `NaN`, if `alpha < 2` and `n >= 3` is
+    /// odd.
+    fn raw_moment(&self, n: usize) -> f64 {
+        use core::f64::consts::SQRT_2;
+        use distribution::{Gaussian, Mean};
+        if self.alpha == 2.0 {
+            Gaussian::new(self.mu, SQRT_2 * self.c).raw_moment(n)
+        } else if n == 0 {
+            1.0
+        } else if n == 1 {
+            self.mean()
+        } else if n % 2 == 0 {
+            f64::INFINITY
+        } else {
+            f64::NAN
+        }
+    }
+}
+
+impl distribution::Sample for Stable {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained using the Chambers–Mallows–Stuck algorithm.
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use core::f64::consts::{FRAC_2_PI, FRAC_PI_2, PI};
+        let &Stable { alpha, beta, c, mu } = self;
+        let cos = |x: f64| (FRAC_PI_2 - x.abs()).sin();
+        let v = PI * (source.read::<f64>() - 0.5);
+        let w = -source.read::<f64>().ln();
+        if alpha == 1.0 {
+            let a = FRAC_PI_2 + beta * v;
+            let x = FRAC_2_PI * (a * v.tan() - beta * (FRAC_PI_2 * w * cos(v) / a).ln());
+            c * x + FRAC_2_PI * beta * c * c.ln() + mu
+        } else {
+            let zeta = -beta * (FRAC_PI_2 * alpha).tan();
+            let xi = (-zeta).atan() / alpha;
+            let x = (1.0 + zeta * zeta).powf(0.5 / alpha) * (alpha * (v + xi)).sin()
+                / cos(v).powf(1.0 / alpha)
+                * (cos(v - alpha * (v + xi)) / w).powf((1.0 - alpha) / alpha);
+            c * x + mu
+        }
+    }
+}
+
+impl distribution::Skewness for Stable {
+    /// Compute the skewness.
+    ///
+    /// The skewness is undefined, which is signified by `NaN`, if `alpha < 2`.
+    #[inline]
+    fn skewness(&self) -> f64 {
+        if self.alpha == 2.0 {
+            0.0
+        } else {
+            f64::NAN
+        }
+    }
+}
+
+impl distribution::Support for Stable {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Excluded, Unbounded};
+        if self.alpha < 1.0 && self.beta == 1.0 {
+            (Excluded(self.mu), Unbounded)
+        } else if self.alpha < 1.0 && self.beta == -1.0 {
+            (Unbounded, Excluded(self.mu))
+        } else {
+            (Unbounded, Unbounded)
+        }
+    }
+}
+
+impl distribution::TryMean for Stable {
+    /// Compute the expected value.
+    ///
+    /// The expected value does not exist if `alpha <= 1`.
+    #[inline]
+    fn try_mean(&self) -> Option<f64> {
+        if self.alpha > 1.0 {
+            Some(self.mu)
+        } else {
+            None
+        }
+    }
+}
+
+impl distribution::TryVariance for Stable {
+    /// Compute the variance.
+    ///
+    /// The variance does not exist if `alpha < 2`.
+    #[inline]
+    fn try_variance(&self) -> Option<f64> {
+        if self.alpha == 2.0 {
+            Some(2.0 * self.c * self.c)
+        } else {
+            None
+        }
+    }
+}
+
+impl distribution::Variance for Stable {
+    /// Compute the variance.
+    ///
+    /// The variance is infinite if `alpha < 2`.
+    #[inline]
+    fn variance(&self) -> f64 {
+        if self.alpha == 2.0 {
+            2.0 * self.c * self.c
+        } else {
+            f64::INFINITY
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($alpha:expr, $beta:expr, $c:expr, $mu:expr) => (Stable::new($alpha, $beta, $c, $mu));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(1.5, 0.5, 2.0, 1.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            0.1892381194536492,
+            0.6268512258631143,
+            -0.0001396017113814217,
+        ];
+        let im = vec![
+            0.024403958831290592,
+            0.04245303289991431,
+            -0.00030503530434793503,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(1.5, 0.5, 2.0, 1.0);
+        let x = vec![-6.0, -3.0, -1.0, 0.0, 1.0, 2.0, 3.0, 5.0, 8.0];
+        let p = vec![
+            9.5160335975511094e-03,
+            6.6653304048096551e-02,
+            1.3402324827723078e-01,
+            1.4214190049428876e-01,
+            1.2705634330111473e-01,
+            9.9286511956699644e-02,
+            7.0756785339933292e-02,
+            3.1912701276000044e-02,
+            1.0390957054365050e-02,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-12,
+        );
+
+        let x = vec![-3.0, -1.0, 0.0, 1.0, 2.0, 3.0, 5.0];
+
+        let d = new!(2.0, 0.3, 1.5, 1.0);
+        let gaussian = Gaussian::new(1.0, 1.5 * 2f64.sqrt());
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &x.iter().map(|&x| gaussian.density(x)).collect::<Vec<_>>(),
+            1e-12,
+        );
+
+        let d = new!(1.0, 0.0, 2.0, 1.0);
+        let cauchy = Cauchy::new(1.0, 2.0);
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &x.iter().map(|&x| cauchy.density(x)).collect::<Vec<_>>(),
+            1e-12,
+        );
+
+        let d = new!(0.5, 1.0, 2.0, 1.0);
+        let levy = Levy::new(1.0, 2.0);
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &x.iter().map(|&x| levy.density(x)).collect::<Vec<_>>(),
+            1e-10,
+        );
+
+        let d = new!(1.5, 0.0, 1.0, 0.0);
+        assert::close(d.density(1e20), 2.9920671030107451e-51, 1e-64);
+        assert::close(d.density(-1e20), 2.9920671030107451e-51, 1e-64);
+        assert_eq!(d.density(f64::INFINITY), 0.0);
+        assert_eq!(d.density(f64::NEG_INFINITY), 0.0);
+
+        let d = new!(0.7, 1.0, 1.0, 0.0);
+        assert::close(d.density(1e3), 4.1517901707437465e-06, 1e-19);
+        assert::close(d.density(1e6), 3.2523760308368395e-11, 1e-24);
+
+        let d = new!(1.0, 0.3, 2.0, 1.0);
+        assert::close(d.density(1e6), 8.2761551071841301e-13, 1e-26);
+        assert::close(d.density(-1e6), 4.4562856024463416e-13, 1e-26);
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(1.5, 0.5, 2.0, 1.0);
+        let x = vec![-6.0, -3.0, -1.0, 0.0, 1.0, 2.0, 3.0, 5.0, 8.0];
+        let p = vec![
+            2.5790224219554767e-02,
+            1.1629980196823657e-01,
+            3.2198715385834925e-01,
+            4.6218656010166803e-01,
+            5.9838907843362221e-01,
+            7.1206355551565981e-01,
+            7.9678068913507127e-01,
+            8.9491743605988849e-01,
+            9.5140966861668319e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-12,
+        );
+
+        let x = vec![-3.0, -1.0, 0.0, 1.0, 2.0, 3.0, 5.0];
+
+        let d = new!(2.0, 0.3, 1.5, 1.0);
+        let gaussian = Gaussian::new(1.0, 1.5 * 2f64.sqrt());
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &x.iter()
+                .map(|&x| gaussian.distribution(x))
+                .collect::<Vec<_>>(),
+            1e-12,
+        );
+
+        let d = new!(1.0, 0.0, 2.0, 1.0);
+        let cauchy = Cauchy::new(1.0, 2.0);
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &x.iter()
+                .map(|&x| cauchy.distribution(x))
+                .collect::<Vec<_>>(),
+            1e-12,
+        );
+
+        let d = new!(0.5, 1.0, 2.0, 1.0);
+        let levy = Levy::new(1.0, 2.0);
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &x.iter().map(|&x| levy.distribution(x)).collect::<Vec<_>>(),
+            1e-10,
+        );
+
+        let d = new!(1.5, 0.0, 1.0, 0.0);
+        assert::close(d.distribution(-1e20), 1.9947114020071634e-31, 1e-44);
+        assert_eq!(d.distribution(1e20), 1.0);
+        assert_eq!(d.distribution(f64::INFINITY), 1.0);
+        assert_eq!(d.distribution(f64::NEG_INFINITY), 0.0);
+
+        let d = new!(0.7, 1.0, 1.0, 0.0);
+        assert::close(d.distribution(1e3), 0.99411016485288815, 1e-15);
+        assert::close(d.distribution(1e4), 0.99883140723111544, 1e-15);
+        assert::close(d.distribution(1e6), 0.99995354007945979, 1e-15);
+
+        let d = new!(1.0, 0.3, 2.0, 1.0);
+        assert::close(d.distribution(1e6), 0.99999917238923455, 1e-15);
+        assert::close(d.distribution(-1e6), 4.4563111533449635e-07, 1e-20);
+
+        for &(alpha, beta) in &[(0.3, 0.5), (0.7, 1.0), (1.0, 0.3), (1.5, -0.5), (1.9, 1.0)] {
+            let d = new!(alpha, beta, 2.0, 1.0);
+            let x = (0..=120).map(|i| 1.3f64.powi(i)).collect::<Vec<_>>();
+            for x in x.windows(2) {
+                assert!(d.distribution(x[0]) <= d.distribution(x[1]) + f64::EPSILON);
+                assert!(d.distribution(-x[1]) <= d.distribution(-x[0]) + f64::EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(1.5, 0.0, 1.0, 0.0);
+        assert::close(d.log_density(1.0), -1.5992986904720074, 1e-12);
+        assert::close(d.log_density(1e300), -1728.1454403511907, 1e-10);
+
+        let d = new!(0.7, 1.0, 1.0, 0.0);
+        assert::close(d.log_density(1e6), -24.149050207295011, 1e-12);
+        assert::close(d.log_density(1e300), -1174.9811913588765, 1e-10);
+
+        let d = new!(1.0, 0.3, 2.0, 1.0);
+        assert::close(d.log_density(-1e300), -1382.3593134456556, 1e-10);
+        assert::close(d.log_density(1e300), -1381.7402742372494, 1e-10);
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert_eq!(new!(2.0, 0.0, 2.0, 1.0).kurtosis(), 0.0);
+        assert!(new!(1.5, 0.5, 2.0, 1.0).kurtosis().is_nan());
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(1.5, 0.5, 2.0, 1.0).mean(), 1.0);
+        assert_eq!(new!(0.5, 1.0, 2.0, 1.0).mean(), f64::INFINITY);
+        assert!(new!(1.0, 0.0, 2.0, 1.0).mean().is_nan());
+        assert_eq!(new!(0.5, -1.0, 2.0, 1.0).mean(), f64::NEG_INFINITY);
+        assert!(new!(0.5, 0.5, 2.0, 1.0).mean().is_nan());
+        assert!(new!(1.0, 1.0, 2.0, 1.0).mean().is_nan());
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let mut source = source::default(42);
+
+        for &(alpha, beta) in &[(1.5, 0.5), (1.0, 0.5), (0.7, -0.3), (0.5, 1.0)] {
+            let d = new!(alpha, beta, 2.0, 1.0);
+            for &x in &[-1.0, 1.0, 3.0] {
+                let count = Independent(&d, &mut source)
+                    .take(n)
+                    .filter(|&y| y <= x)
+                    .count();
+                assert!((count as f64 / n as f64 - d.distribution(x)).abs() < 0.01);
+            }
+        }
+    }
+
+    #[test]
+    fn skewness() {
+        assert_eq!(new!(2.0, 0.0, 2.0, 1.0).skewness(), 0.0);
+        assert!(new!(1.5, 0.5, 2.0, 1.0).skewness().is_nan());
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Excluded, Unbounded};
+        assert_eq!(new!(1.5, 0.5, 2.0, 1.0).support(), (Unbounded, Unbounded));
+        assert_eq!(
+            new!(0.5, 1.0, 2.0, 1.0).support(),
+            (Excluded(1.0), Unbounded)
+        );
+        assert_eq!(
+            new!(0.5, -1.0, 2.0, 1.0).support(),
+            (Unbounded, Excluded(1.0))
+        );
+    }
+
+    #[test]
+    fn try_mean() {
+        assert_eq!(new!(0.5, 1.0, 1.0, 0.0).try_mean(), None);
+        assert_eq!(new!(1.0, 0.0, 1.0, 0.0).try_mean(), None);
+        assert_eq!(new!(1.5, 0.5, 1.0, 2.0).try_mean(), Some(2.0));
+    }
+
+    #[test]
+    fn try_variance() {
+        assert_eq!(new!(1.5, 0.5, 1.0, 2.0).try_variance(), None);
+        assert_eq!(new!(2.0, 0.0, 1.5, 0.0).try_variance(), Some(4.5));
+    }
+
+    #[test]
+    fn variance() {
+        assert_eq!(new!(2.0, 0.0, 2.0, 1.0).variance(), 8.0);
+        assert_eq!(new!(1.5, 0.5, 2.0, 1.0).variance(), f64::INFINITY);
+    }
+}
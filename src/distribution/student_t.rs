@@ -0,0 +1,502 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A Student’s t-distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct StudentT {
+    nu: f64,
+    ln_beta: f64,
+}
+
+impl StudentT {
+    /// Create a Student’s t-distribution with `nu` degrees of freedom.
+    ///
+    /// It should hold that `nu > 0`.
+    #[inline]
+    pub fn new(nu: f64) -> Self {
+        use special::Beta;
+        should!(nu > 0.0);
+        StudentT {
+            nu,
+            ln_beta: (0.5 * nu).ln_beta(0.5),
+        }
+    }
+
+    /// Return the degrees of freedom.
+    #[inline(always)]
+    pub fn nu(&self) -> f64 {
+        self.nu
+    }
+}
+
+impl distribution::CharacteristicFunction for StudentT {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use core::f64::consts::LN_2;
+        use distribution::bessel;
+        use special::Gamma;
+        let nu = self.nu;
+        let x = nu.sqrt() * t.abs();
+        if x == 0.0 {
+            return (1.0, 0.0);
+        }
+        let half = 0.5 * nu;
+        let ln = half * x.ln() + bessel::kve(half, x).ln()
+            - x
+            - Gamma::ln_gamma(half).0
+            - (half - 1.0) * LN_2;
+        (ln.exp(), 0.0)
+    }
+}
+
+impl distribution::Continuous for StudentT {
+    fn density(&self, x: f64) -> f64 {
+        let nu = self.nu;
+        (-0.5 * (nu + 1.0) * (x * x / nu).ln_1p() - self.ln_beta).exp() / nu.sqrt()
+    }
+
+    fn log_density(&self, x: f64) -> f64 {
+        let nu = self.nu;
+        let ln_ratio = if x * x > nu {
+            2.0 * x.abs().ln() - nu.ln() + (nu / (x * x)).ln_1p()
+        } else {
+            (x * x / nu).ln_1p()
+        };
+        -0.5 * (nu + 1.0) * ln_ratio - self.ln_beta - 0.5 * nu.ln()
+    }
+}
+
+impl distribution::Distribution for StudentT {
+    type Value = f64;
+
+    fn distribution(&self, x: f64) -> f64 {
+        use special::Beta;
+        let nu = self.nu;
+        let tail = 0.5 * (nu / (nu + x * x)).inc_beta(0.5 * nu, 0.5, self.ln_beta);
+        if x < 0.0 {
+            tail
+        } else {
+            1.0 - tail
+        }
+    }
+}
+
+impl distribution::Entropy for StudentT {
+    fn entropy(&self) -> f64 {
+        use special::Gamma;
+        let nu = self.nu;
+        0.5 * (nu + 1.0) * ((0.5 * (nu + 1.0)).digamma() - (0.5 * nu).digamma())
+            + 0.5 * nu.ln()
+            + self.ln_beta
+    }
+}
+
+impl distribution::Inverse for StudentT {
+    fn inverse(&self, p: f64) -> f64 {
+        use special::Beta;
+        should!((0.0..=1.0).contains(&p));
+        if p <= 0.0 {
+            return f64::NEG_INFINITY;
+        }
+        if p >= 1.0 {
+            return f64::INFINITY;
+        }
+        if p == 0.5 {
+            return 0.0;
+        }
+        let nu = self.nu;
+        let tail = if p < 0.5 { p } else { 1.0 - p };
+        let z = (2.0 * tail).inv_inc_beta(0.5 * nu, 0.5, self.ln_beta);
+        let x = (nu * (1.0 - z) / z).sqrt();
+        if p < 0.5 {
+            -x
+        } else {
+            x
+        }
+    }
+}
+
+impl distribution::Kurtosis for StudentT {
+    /// Compute the excess kurtosis.
+    ///
+    /// The excess kurtosis is infinite if `2 < nu <= 4` and undefined, which
+    /// is signified by `NaN`, if `nu <= 2`.
+    fn kurtosis(&self) -> f64 {
+        if self.nu > 4.0 {
+            6.0 / (self.nu - 4.0)
+        } else if self.nu > 2.0 {
+            f64::INFINITY
+        } else {
+            f64::NAN
+        }
+    }
+}
+
+impl distribution::Mean for StudentT {
+    /// Compute the expected value.
+    ///
+    /// The expected value is undefined, which is signified by `NaN`, if
+    /// `nu <= 1`.
+    #[inline]
+    fn mean(&self) -> f64 {
+        if self.nu > 1.0 {
+            0.0
+        } else {
+            f64::NAN
+        }
+    }
+}
+
+impl distribution::Median for StudentT {
+    #[inline]
+    fn median(&self) -> f64 {
+        0.0
+    }
+}
+
+impl distribution::Mgf for StudentT {
+    /// Compute the moment-generating function.
+    ///
+    /// The function is infinite if `t != 0`.
+    fn mgf(&self, t: f64) -> f64 {
+        if t == 0.0 {
+            1.0
+        } else {
+            f64::INFINITY
+        }
+    }
+}
+
+impl distribution::Modes for StudentT {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![0.0]
+    }
+}
+
+impl distribution::Moments for StudentT {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is infinite if `n >= nu` and `n` is even and undefined, which
+    /// is signified by `NaN`, if `n >= nu` and `n` is odd.
+    fn raw_moment(&self, n: usize) -> f64 {
+        let nu = self.nu;
+        if n as f64 >= nu {
+            if n % 2 == 0 {
+                f64::INFINITY
+            } else {
+                f64::NAN
+            }
+        } else if n % 2 == 1 {
+            0.0
+        } else {
+            (1..=n / 2).fold(1.0, |product, j| {
+                let j = j as f64;
+                product * nu * (2.0 * j - 1.0) / (nu - 2.0 * j)
+            })
+        }
+    }
+}
+
+impl distribution::Sample for StudentT {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::{gamma, gaussian};
+        let z = gaussian::sample(source);
+        let v = 2.0 * gamma::sample(0.5 * self.nu, source);
+        z / (v / self.nu).sqrt()
+    }
+}
+
+impl distribution::Skewness for StudentT {
+    /// Compute the skewness.
+    ///
+    /// The skewness is undefined, which is signified by `NaN`, if `nu <= 3`.
+    #[inline]
+    fn skewness(&self) -> f64 {
+        if self.nu > 3.0 {
+            0.0
+        } else {
+            f64::NAN
+        }
+    }
+}
+
+impl distribution::Support for StudentT {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Unbounded;
+        (Unbounded, Unbounded)
+    }
+}
+
+impl distribution::TryMean for StudentT {
+    /// Compute the expected value.
+    ///
+    /// The expected value does not exist if `nu <= 1`.
+    #[inline]
+    fn try_mean(&self) -> Option<f64> {
+        use distribution::Mean;
+        if self.nu > 1.0 {
+            Some(self.mean())
+        } else {
+            None
+        }
+    }
+}
+
+impl distribution::TryVariance for StudentT {
+    /// Compute the variance.
+    ///
+    /// The variance does not exist if `nu <= 2`.
+    #[inline]
+    fn try_variance(&self) -> Option<f64> {
+        use distribution::Variance;
+        if self.nu > 2.0 {
+            Some(self.variance())
+        } else {
+            None
+        }
+    }
+}
+
+impl distribution::Variance for StudentT {
+    /// Compute the variance.
+    ///
+    /// The variance is infinite if `1 < nu <= 2` and undefined, which is
+    /// signified by `NaN`, if `nu <= 1`.
+    #[inline]
+    fn variance(&self) -> f64 {
+        if self.nu > 2.0 {
+            self.nu / (self.nu - 2.0)
+        } else if self.nu > 1.0 {
+            f64::INFINITY
+        } else {
+            f64::NAN
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($nu:expr) => (StudentT::new($nu));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(3.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.6581373763165839, 0.9037901598990388, 0.1397313501923147];
+        let im = vec![0.0, 0.0, 0.0];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(3.0);
+        let x = vec![-4.0, -3.0, -2.0, -1.0, -0.5, 0.0, 0.5, 1.0, 2.0, 3.0, 4.0];
+        let p = vec![
+            9.1633611427444656e-03,
+            2.2972037309241335e-02,
+            6.7509660663892898e-02,
+            2.0674833578317200e-01,
+            3.1318091100882861e-01,
+            3.6755259694786135e-01,
+            3.1318091100882861e-01,
+            2.0674833578317200e-01,
+            6.7509660663892898e-02,
+            2.2972037309241335e-02,
+            9.1633611427444656e-03,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(3.0);
+        let x = vec![-4.0, -3.0, -2.0, -1.0, -0.5, 0.0, 0.5, 1.0, 2.0, 3.0, 4.0];
+        let p = vec![
+            1.4004228005073083e-02,
+            2.8834442811218653e-02,
+            6.9662984279421594e-02,
+            1.9550110947788532e-01,
+            3.2572398242407552e-01,
+            5.0000000000000000e-01,
+            6.7427601757592448e-01,
+            8.0449889052211465e-01,
+            9.3033701572057836e-01,
+            9.7116555718878139e-01,
+            9.8599577199492694e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        use distribution::Cauchy;
+        assert::close(new!(3.0).entropy(), 1.7734775718632909e+00, 1e-14);
+        assert::close(new!(10.0).entropy(), 1.5212624929756808e+00, 1e-14);
+        assert::close(new!(1.0).entropy(), Cauchy::new(0.0, 1.0).entropy(), 1e-14);
+    }
+
+    #[test]
+    fn inverse() {
+        use core::f64::{INFINITY, NEG_INFINITY};
+        let d = new!(3.0);
+        let p = vec![
+            0.0, 0.01, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.99, 1.0,
+        ];
+        let x = vec![
+            NEG_INFINITY,
+            -4.5407028585681335e+00,
+            -1.6377443536962100e+00,
+            -9.7847231236330434e-01,
+            -5.8438972743981865e-01,
+            -2.7667066233268983e-01,
+            0.0000000000000000e+00,
+            2.7667066233268983e-01,
+            5.8438972743981854e-01,
+            9.7847231236330467e-01,
+            1.6377443536962104e+00,
+            4.5407028585681317e+00,
+            INFINITY,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert!(new!(2.0).kurtosis().is_nan());
+        assert_eq!(new!(3.0).kurtosis(), f64::INFINITY);
+        assert_eq!(new!(10.0).kurtosis(), 1.0);
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(3.0);
+        let x = vec![-40.0, 0.9, 1000000.0];
+        let p = vec![
+            -13.562928577506398,
+            -1.4789226505645097,
+            -54.065706504150384,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-12,
+        );
+        assert::close(d.log_density(-1e200), -1840.8717386675238, 1e-12);
+        assert::close(d.log_density(1e200), -1840.8717386675238, 1e-12);
+    }
+
+    #[test]
+    fn mean() {
+        assert!(new!(1.0).mean().is_nan());
+        assert_eq!(new!(3.0).mean(), 0.0);
+    }
+
+    #[test]
+    fn median() {
+        assert_eq!(new!(3.0).median(), 0.0);
+    }
+
+    #[test]
+    fn mgf() {
+        let d = new!(3.0);
+        assert_eq!(d.mgf(0.0), 1.0);
+        assert_eq!(d.mgf(-0.1), f64::INFINITY);
+        assert_eq!(d.mgf(0.1), f64::INFINITY);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(3.0).modes(), vec![0.0]);
+    }
+
+    #[test]
+    fn moments() {
+        assert_eq!(new!(3.0).raw_moment(4), f64::INFINITY);
+        assert!(new!(3.0).raw_moment(3).is_nan());
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(5.0);
+        let mut source = source::default(42);
+
+        let cross_entropy = -(0..n)
+            .map(|_| d.density(d.sample(&mut source)).ln())
+            .sum::<f64>()
+            / n as f64;
+        assert!((cross_entropy - d.entropy()).abs() < 0.01);
+    }
+
+    #[test]
+    fn skewness() {
+        assert!(new!(3.0).skewness().is_nan());
+        assert_eq!(new!(4.0).skewness(), 0.0);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::Unbounded;
+        assert_eq!(new!(3.0).support(), (Unbounded, Unbounded));
+    }
+
+    #[test]
+    fn try_mean() {
+        assert_eq!(new!(1.0).try_mean(), None);
+        assert_eq!(new!(1.5).try_mean(), Some(0.0));
+    }
+
+    #[test]
+    fn try_variance() {
+        assert_eq!(new!(1.0).try_variance(), None);
+        assert_eq!(new!(2.0).try_variance(), None);
+        assert_eq!(new!(4.0).try_variance(), Some(2.0));
+        assert_eq!(new!(4.0).try_deviation(), Some(2f64.sqrt()));
+    }
+
+    #[test]
+    fn variance() {
+        assert!(new!(1.0).variance().is_nan());
+        assert_eq!(new!(2.0).variance(), f64::INFINITY);
+        assert_eq!(new!(4.0).variance(), 2.0);
+    }
+}
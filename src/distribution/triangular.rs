@@ -1,4 +1,5 @@
 use alloc::{vec, vec::Vec};
+use core::ops::Bound;
 #[allow(unused_imports)]
 use special::Primitive;
 
@@ -42,6 +43,16 @@ impl Triangular {
     }
 }
 
+impl distribution::CharacteristicFunction for Triangular {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Continuous};
+        characteristic::continuous(|x| self.density(x), t, self.a, self.b, 0.0, 0.0)
+    }
+}
+
 impl distribution::Continuous for Triangular {
     fn density(&self, x: f64) -> f64 {
         nonnan!(x);
@@ -58,6 +69,21 @@ impl distribution::Continuous for Triangular {
             factor
         }
     }
+
+    fn log_density(&self, x: f64) -> f64 {
+        use core::f64::consts::LN_2;
+        nonnan!(x);
+        let &Triangular { a, b, c } = self;
+        if x < a || b < x {
+            f64::NEG_INFINITY
+        } else if x < c {
+            LN_2 - (b - a).ln() + (x - a).ln() - (c - a).ln()
+        } else if x > c {
+            LN_2 - (b - a).ln() + (b - x).ln() - (b - c).ln()
+        } else {
+            LN_2 - (b - a).ln()
+        }
+    }
 }
 
 impl distribution::Distribution for Triangular {
@@ -142,6 +168,23 @@ impl distribution::Modes for Triangular {
     }
 }
 
+impl distribution::Moments for Triangular {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is computed by numerical integration.
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::{moments, Continuous, Mean, Variance};
+        moments::continuous(
+            |x| self.density(x),
+            n,
+            self.a,
+            self.b,
+            self.mean(),
+            self.deviation(),
+        )
+    }
+}
+
 impl distribution::Sample for Triangular {
     #[inline]
     fn sample<S>(&self, source: &mut S) -> f64
@@ -162,6 +205,13 @@ impl distribution::Skewness for Triangular {
     }
 }
 
+impl distribution::Support for Triangular {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Included;
+        (Included(self.a), Included(self.b))
+    }
+}
+
 impl distribution::Variance for Triangular {
     fn variance(&self) -> f64 {
         let &Triangular { a, b, c } = self;
@@ -170,6 +220,12 @@ impl distribution::Variance for Triangular {
 }
 
 #[cfg(test)]
+#[allow(
+    clippy::approx_constant,
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
 mod tests {
     use alloc::{vec, vec::Vec};
     use assert;
@@ -179,6 +235,22 @@ mod tests {
         ($a:expr, $b:expr, $c:expr) => (Triangular::new($a, $b, $c));
     );
 
+    #[test]
+    fn cf() {
+        let d = new!(1.0, 5.0, 3.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![-0.4275906679558198, 0.6031840159667221, 0.19847243368277045];
+        let im = vec![
+            -0.7311144253824882,
+            0.7601072944154799,
+            -0.057756707020885145,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
     #[test]
     fn density() {
         let d = new!(1.0, 5.0, 3.0);
@@ -235,6 +307,24 @@ mod tests {
         assert_eq!(new!(1.0, 5.0, 3.0).kurtosis(), -(3.0 / 5.0));
     }
 
+    #[test]
+    fn log_density() {
+        let d = new!(1.0, 5.0, 3.0);
+        let x = vec![0.5, 2.0, 3.0, 4.5, 5.5];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -1.3862943611198906,
+            -0.69314718055994531,
+            -2.0794415416798359,
+            f64::NEG_INFINITY,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
     #[test]
     fn mean() {
         assert_eq!(new!(1.0, 5.0, 3.0).mean(), 3.0);
@@ -243,6 +333,12 @@ mod tests {
     #[test]
     fn median() {
         assert_eq!(new!(1.0, 5.0, 3.0).median(), 3.0);
+        assert::close(new!(0.0, 4.0, 1.0).median(), 1.5505102572168221e+00, 1e-15);
+        assert::close(
+            new!(0.0, 4.0, 3.0).median(),
+            4.0 - 1.5505102572168221e+00,
+            1e-15,
+        );
     }
 
     #[test]
@@ -250,14 +346,36 @@ mod tests {
         assert_eq!(new!(1.0, 5.0, 3.0).modes(), vec![3.0]);
     }
 
+    #[test]
+    fn sample() {
+        for x in Independent(&new!(0.0, 4.0, 1.0), &mut source::default(42)).take(100) {
+            assert!((0.0..=4.0).contains(&x));
+        }
+    }
+
     #[test]
     fn skewness() {
         assert_eq!(new!(1.0, 5.0, 3.0).skewness(), 0.0);
+        assert::close(
+            new!(0.0, 4.0, 1.0).skewness(),
+            4.2240398337455021e-01,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::Included;
+        assert_eq!(
+            new!(1.0, 5.0, 3.0).support(),
+            (Included(1.0), Included(5.0))
+        );
     }
 
     #[test]
     fn variance() {
         assert_eq!(new!(1.0, 5.0, 3.0).variance(), (12.0 / 18.0));
+        assert::close(new!(0.0, 4.0, 1.0).variance(), 13.0 / 18.0, 1e-15);
     }
 
     #[test]
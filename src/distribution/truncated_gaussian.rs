@@ -0,0 +1,652 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A truncated Gaussian distribution.
+///
+/// The distribution is a Gaussian distribution restricted to interval
+/// `[a, b]`, where either endpoint may be infinite.
+#[derive(Clone, Copy, Debug)]
+pub struct TruncatedGaussian {
+    mu: f64,
+    sigma: f64,
+    a: f64,
+    b: f64,
+    alpha: f64,
+    beta: f64,
+    norm: f64,
+}
+
+impl TruncatedGaussian {
+    /// Create a truncated Gaussian distribution with location `mu` and scale
+    /// `sigma` on interval `[a, b]`.
+    ///
+    /// It should hold that `sigma > 0` and `a < b`.
+    #[inline]
+    pub fn new(mu: f64, sigma: f64, a: f64, b: f64) -> Self {
+        should!(sigma > 0.0 && a < b);
+        let alpha = (a - mu) / sigma;
+        let beta = (b - mu) / sigma;
+        let norm = if alpha > 0.0 {
+            standard_distribution(-alpha) - standard_distribution(-beta)
+        } else {
+            standard_distribution(beta) - standard_distribution(alpha)
+        };
+        TruncatedGaussian {
+            mu,
+            sigma,
+            a,
+            b,
+            alpha,
+            beta,
+            norm,
+        }
+    }
+
+    /// Return the location parameter of the underlying Gaussian distribution.
+    #[inline(always)]
+    pub fn mu(&self) -> f64 {
+        self.mu
+    }
+
+    /// Return the scale parameter of the underlying Gaussian distribution.
+    #[inline(always)]
+    pub fn sigma(&self) -> f64 {
+        self.sigma
+    }
+
+    /// Return the left endpoint of the support.
+    #[inline(always)]
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+
+    /// Return the right endpoint of the support.
+    #[inline(always)]
+    pub fn b(&self) -> f64 {
+        self.b
+    }
+
+    /// Compute the `k`th raw moment of the standardized distribution.
+    ///
+    /// The moments satisfy the recurrence `m_k = (k - 1) m_(k - 2) - (beta^(k
+    /// - 1) phi(beta) - alpha^(k - 1) phi(alpha)) / Z`.
+    fn moment(&self, k: i32) -> f64 {
+        let (alpha, beta) = (self.alpha, self.beta);
+        let term = |x: f64, j: i32| {
+            if x.is_infinite() {
+                0.0
+            } else {
+                x.powi(j) * standard_density(x)
+            }
+        };
+        let mut previous = 1.0;
+        let mut current = (term(alpha, 0) - term(beta, 0)) / self.norm;
+        if k == 0 {
+            return previous;
+        }
+        for j in 2..=k {
+            let next =
+                (j - 1) as f64 * previous - (term(beta, j - 1) - term(alpha, j - 1)) / self.norm;
+            previous = current;
+            current = next;
+        }
+        current
+    }
+}
+
+impl distribution::CharacteristicFunction for TruncatedGaussian {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Continuous, Mean, Variance};
+        characteristic::continuous(
+            |x| self.density(x),
+            t,
+            self.a,
+            self.b,
+            self.mean(),
+            self.deviation(),
+        )
+    }
+}
+
+impl distribution::Continuous for TruncatedGaussian {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        if x < self.a || x > self.b {
+            0.0
+        } else {
+            standard_density((x - self.mu) / self.sigma) / (self.sigma * self.norm)
+        }
+    }
+
+    #[inline]
+    fn log_density(&self, x: f64) -> f64 {
+        use core::f64::consts::PI;
+        if x < self.a || x > self.b {
+            f64::NEG_INFINITY
+        } else {
+            let z = (x - self.mu) / self.sigma;
+            -0.5 * z * z - 0.5 * (2.0 * PI).ln() - (self.sigma * self.norm).ln()
+        }
+    }
+}
+
+impl distribution::Distribution for TruncatedGaussian {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        if x <= self.a {
+            return 0.0;
+        } else if x >= self.b {
+            return 1.0;
+        }
+        let z = (x - self.mu) / self.sigma;
+        let p = if self.alpha > 0.0 {
+            standard_distribution(-self.alpha) - standard_distribution(-z)
+        } else {
+            standard_distribution(z) - standard_distribution(self.alpha)
+        };
+        (p / self.norm).clamp(0.0, 1.0)
+    }
+}
+
+impl distribution::Entropy for TruncatedGaussian {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        use core::f64::consts::{E, PI};
+        let (alpha, beta) = (self.alpha, self.beta);
+        let moment = times_density(alpha) - times_density(beta);
+        (2.0 * PI * E).sqrt().ln() + (self.sigma * self.norm).ln() + 0.5 * moment / self.norm
+    }
+}
+
+impl distribution::Inverse for TruncatedGaussian {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        use distribution::gaussian;
+        should!((0.0..=1.0).contains(&p));
+        if p <= 0.0 {
+            return self.a;
+        } else if p >= 1.0 {
+            return self.b;
+        }
+        let z = if self.alpha > 0.0 {
+            -gaussian::inverse(standard_distribution(-self.alpha) - p * self.norm)
+        } else {
+            gaussian::inverse(standard_distribution(self.alpha) + p * self.norm)
+        };
+        (self.mu + self.sigma * z).clamp(self.a, self.b)
+    }
+}
+
+impl distribution::Kurtosis for TruncatedGaussian {
+    fn kurtosis(&self) -> f64 {
+        let (m1, m2, m3, m4) = (
+            self.moment(1),
+            self.moment(2),
+            self.moment(3),
+            self.moment(4),
+        );
+        let variance = m2 - m1 * m1;
+        (m4 - 4.0 * m1 * m3 + 6.0 * m1 * m1 * m2 - 3.0 * m1.powi(4)) / (variance * variance) - 3.0
+    }
+}
+
+impl distribution::Mean for TruncatedGaussian {
+    #[inline]
+    fn mean(&self) -> f64 {
+        let difference = standard_density(self.alpha) - standard_density(self.beta);
+        self.mu + self.sigma * difference / self.norm
+    }
+}
+
+impl distribution::Median for TruncatedGaussian {
+    #[inline]
+    fn median(&self) -> f64 {
+        use distribution::Inverse;
+        self.inverse(0.5)
+    }
+}
+
+impl distribution::Modes for TruncatedGaussian {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![self.mu.clamp(self.a, self.b)]
+    }
+}
+
+impl distribution::Moments for TruncatedGaussian {
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::moments;
+        moments::shift(|k| self.moment(k as i32), self.mu, self.sigma, n)
+    }
+}
+
+impl distribution::Sample for TruncatedGaussian {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained by rejection sampling with a uniform, Gaussian,
+    /// or exponential proposal depending on the location and width of the
+    /// truncation interval.
+    ///
+    /// ## References
+    ///
+    /// 1. C. P. Robert, “Simulation of truncated normal variables,” Statistics
+    ///    and Computing, vol. 5, no. 2, pp. 121–125, 1995.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        let z = sample(self.alpha, self.beta, source);
+        (self.mu + self.sigma * z).clamp(self.a, self.b)
+    }
+}
+
+impl distribution::Skewness for TruncatedGaussian {
+    fn skewness(&self) -> f64 {
+        let (m1, m2, m3) = (self.moment(1), self.moment(2), self.moment(3));
+        let variance = m2 - m1 * m1;
+        (m3 - 3.0 * m1 * m2 + 2.0 * m1.powi(3)) / variance.powf(1.5)
+    }
+}
+
+impl distribution::Support for TruncatedGaussian {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Included, Unbounded};
+        let bound = |x: f64| {
+            if x.is_finite() {
+                Included(x)
+            } else {
+                Unbounded
+            }
+        };
+        (bound(self.a), bound(self.b))
+    }
+}
+
+impl distribution::Variance for TruncatedGaussian {
+    #[inline]
+    fn variance(&self) -> f64 {
+        let (alpha, beta) = (self.alpha, self.beta);
+        let ratio = (standard_density(alpha) - standard_density(beta)) / self.norm;
+        let moment = (times_density(alpha) - times_density(beta)) / self.norm;
+        self.sigma * self.sigma * (1.0 + moment - ratio * ratio)
+    }
+}
+
+/// Draw a sample from the standard Gaussian distribution truncated to
+/// `[alpha, beta]`.
+fn sample<S: Source>(alpha: f64, beta: f64, source: &mut S) -> f64 {
+    use core::f64::consts::PI;
+    use distribution::gaussian;
+    if alpha >= 0.0 {
+        sample_tail(alpha, beta, source)
+    } else if beta <= 0.0 {
+        -sample_tail(-beta, -alpha, source)
+    } else if beta - alpha >= (2.0 * PI).sqrt() {
+        loop {
+            let z = gaussian::sample(source);
+            if alpha <= z && z <= beta {
+                return z;
+            }
+        }
+    } else {
+        loop {
+            let z = alpha + (beta - alpha) * source.read::<f64>();
+            if source.read::<f64>() <= (-0.5 * z * z).exp() {
+                return z;
+            }
+        }
+    }
+}
+
+/// Draw a sample from the standard Gaussian distribution truncated to
+/// `[alpha, beta]` with `0 <= alpha`.
+fn sample_tail<S: Source>(alpha: f64, beta: f64, source: &mut S) -> f64 {
+    use core::f64::consts::E;
+    let root = (alpha * alpha + 4.0).sqrt();
+    let threshold = alpha + 2.0 * E.sqrt() / (alpha + root) * (0.25 * alpha * (alpha - root)).exp();
+    if beta > threshold {
+        let lambda = 0.5 * (alpha + root);
+        loop {
+            let z = alpha - source.read::<f64>().ln() / lambda;
+            if z <= beta && source.read::<f64>() <= (-0.5 * (z - lambda).powi(2)).exp() {
+                return z;
+            }
+        }
+    } else {
+        loop {
+            let z = alpha + (beta - alpha) * source.read::<f64>();
+            if source.read::<f64>() <= (0.5 * (alpha * alpha - z * z)).exp() {
+                return z;
+            }
+        }
+    }
+}
+
+/// Compute the density of the standard Gaussian distribution.
+#[inline]
+fn standard_density(x: f64) -> f64 {
+    use core::f64::consts::PI;
+    (-0.5 * x * x).exp() / (2.0 * PI).sqrt()
+}
+
+/// Compute the cumulative distribution function of the standard Gaussian
+/// distribution.
+#[inline]
+fn standard_distribution(x: f64) -> f64 {
+    use core::f64::consts::FRAC_1_SQRT_2;
+    use special::Error;
+    0.5 * (-x * FRAC_1_SQRT_2).compl_error()
+}
+
+/// Compute `x` times the density of the standard Gaussian distribution,
+/// which vanishes at infinity.
+#[inline]
+fn times_density(x: f64) -> f64 {
+    if x.is_infinite() {
+        0.0
+    } else {
+        x * standard_density(x)
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use core::f64::INFINITY;
+    use prelude::*;
+
+    macro_rules! new(
+        ($mu:expr, $sigma:expr, $a:expr, $b:expr) => (TruncatedGaussian::new($mu, $sigma, $a, $b));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(1.0, 2.0, 0.0, 4.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.28824314083752534, 0.8284495217862566, 0.02645693641088923];
+        let im = vec![-0.6925978672605569, 0.46580316371713704, 0.1359393894861582];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(1.0, 2.0, 0.0, 4.0);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 2.0, 3.0, 3.5, 4.0, 5.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            2.8180770202863098e-01,
+            3.0950521156234095e-01,
+            3.1932996161606031e-01,
+            2.8180770202863098e-01,
+            1.9368341228499897e-01,
+            1.4619990984108144e-01,
+            1.0367125994010110e-01,
+            0.0000000000000000e+00,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+
+        let d = new!(0.0, 1.0, 10.0, INFINITY);
+        let x = vec![10.0, 10.1, 10.5, 11.0];
+        let p = vec![
+            1.0098093233962512e+01,
+            3.6963528500427194e+00,
+            6.0045457255061309e-02,
+            2.7806563286603321e-04,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-13,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(1.0, 2.0, 0.0, 4.0);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 2.0, 3.0, 3.5, 4.0, 5.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            1.4849172260283841e-01,
+            3.0650900349807836e-01,
+            6.1301800699615672e-01,
+            8.5296201193944798e-01,
+            9.3781758533870885e-01,
+            1.0000000000000000e+00,
+            1.0000000000000000e+00,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+
+        let d = new!(0.0, 1.0, 10.0, INFINITY);
+        let x = vec![10.0, 10.1, 10.5, 11.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            6.3751145028564160e-01,
+            9.9433190337908772e-01,
+            9.9997492524372267e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(
+            new!(1.0, 2.0, 0.0, 4.0).entropy(),
+            1.3451196074242515e+00,
+            1e-15,
+        );
+        assert::close(
+            new!(0.0, 1.0, 10.0, INFINITY).entropy(),
+            -1.3218804474952379e+00,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn inverse() {
+        let d = new!(1.0, 2.0, 0.0, 4.0);
+        let p = vec![
+            0.0, 0.01, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.99, 1.0,
+        ];
+        let x = vec![
+            0.0000000000000000e+00,
+            3.5330548850165990e-02,
+            3.4160425116685933e-01,
+            6.6489967526286553e-01,
+            9.7961633117413804e-01,
+            1.2938259397071403e+00,
+            1.6155076422979910e+00,
+            1.9540660349010233e+00,
+            2.3226386259049079e+00,
+            2.7430172207903074e+00,
+            3.2596714362576424e+00,
+            3.9068376147275772e+00,
+            4.0000000000000000e+00,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-14,
+        );
+
+        let d = new!(0.0, 1.0, 10.0, INFINITY);
+        let p = vec![0.0, 0.1, 0.5, 0.9, 1.0];
+        let x = vec![
+            1.0000000000000000e+01,
+            1.0010428370091844e+01,
+            1.0068411836081429e+01,
+            1.0225526811202201e+01,
+            INFINITY,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(
+            new!(1.0, 2.0, 0.0, 4.0).kurtosis(),
+            -9.4166770608174144e-01,
+            1e-14,
+        );
+        assert::close(new!(0.0, 1.0, -INFINITY, INFINITY).kurtosis(), 0.0, 1e-14);
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(1.0, 2.0, 0.0, 4.0);
+        let x = vec![-40.0, 0.05, 0.9, 4.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -1.2543428483487185,
+            -1.1427803483487184,
+            -2.2665303483487182,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(
+            new!(1.0, 2.0, 0.0, 4.0).mean(),
+            1.7125457683541194e+00,
+            1e-15,
+        );
+        assert::close(
+            new!(0.0, 1.0, 10.0, INFINITY).mean(),
+            1.0098093233962512e+01,
+            1e-13,
+        );
+    }
+
+    #[test]
+    fn median() {
+        assert::close(
+            new!(1.0, 2.0, 0.0, 4.0).median(),
+            1.6155076422979910e+00,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(1.0, 2.0, 0.0, 4.0).modes(), vec![1.0]);
+        assert_eq!(new!(0.0, 1.0, 10.0, INFINITY).modes(), vec![10.0]);
+    }
+
+    #[test]
+    fn moments() {
+        let d = new!(1.0, 2.0, 0.0, 4.0);
+        let m = vec![104.97304362806342, 344.83628065467195, 1165.6394048695776];
+        assert::close(
+            &(5..8).map(|n| d.raw_moment(n)).collect::<Vec<_>>(),
+            &m,
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(1.0, 2.0, 0.0, 4.0);
+        let mut source = source::default(42);
+
+        let cross_entropy = -(0..n)
+            .map(|_| d.density(d.sample(&mut source)).ln())
+            .sum::<f64>()
+            / n as f64;
+        assert!((cross_entropy - d.entropy()).abs() < 0.01);
+
+        let d = new!(0.0, 1.0, 10.0, INFINITY);
+        let mean = (0..n).map(|_| d.sample(&mut source)).sum::<f64>() / n as f64;
+        assert!((mean - d.mean()).abs() < 0.01);
+
+        let d = new!(0.0, 1.0, -3.0, -2.9);
+        for x in Independent(&d, &mut source).take(100) {
+            assert!((-3.0..=-2.9).contains(&x));
+        }
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(
+            new!(1.0, 2.0, 0.0, 4.0).skewness(),
+            2.8426066733025412e-01,
+            1e-14,
+        );
+        assert::close(new!(0.0, 1.0, -INFINITY, INFINITY).skewness(), 0.0, 1e-15);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Included, Unbounded};
+        assert_eq!(
+            new!(1.0, 2.0, 0.0, 4.0).support(),
+            (Included(0.0), Included(4.0))
+        );
+        assert_eq!(
+            new!(1.0, 2.0, f64::NEG_INFINITY, 4.0).support(),
+            (Unbounded, Included(4.0))
+        );
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(
+            new!(1.0, 2.0, 0.0, 4.0).variance(),
+            1.1209926006049005e+00,
+            1e-15,
+        );
+        assert::close(
+            new!(0.0, 1.0, 10.0, INFINITY).variance(),
+            9.4453778256562617e-03,
+            1e-12,
+        );
+    }
+}
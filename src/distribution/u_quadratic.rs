@@ -0,0 +1,362 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A U-quadratic distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct UQuadratic {
+    a: f64,
+    b: f64,
+}
+
+impl UQuadratic {
+    /// Create a U-quadratic distribution on interval `[a, b]`.
+    ///
+    /// It should hold that `a < b`.
+    #[inline]
+    pub fn new(a: f64, b: f64) -> Self {
+        should!(a < b);
+        UQuadratic { a, b }
+    }
+
+    /// Return the left endpoint of the support.
+    #[inline(always)]
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+
+    /// Return the right endpoint of the support.
+    #[inline(always)]
+    pub fn b(&self) -> f64 {
+        self.b
+    }
+
+    /// Map a value onto `[-1, 1]`.
+    #[inline]
+    fn standardize(&self, x: f64) -> f64 {
+        (2.0 * x - self.a - self.b) / (self.b - self.a)
+    }
+}
+
+impl distribution::CharacteristicFunction for UQuadratic {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Continuous};
+        characteristic::continuous(|x| self.density(x), t, self.a, self.b, 0.0, 0.0)
+    }
+}
+
+impl distribution::Continuous for UQuadratic {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        if x < self.a || x > self.b {
+            0.0
+        } else {
+            let z = self.standardize(x);
+            3.0 * z * z / (self.b - self.a)
+        }
+    }
+
+    #[inline]
+    fn log_density(&self, x: f64) -> f64 {
+        if x < self.a || x > self.b {
+            f64::NEG_INFINITY
+        } else {
+            let z = self.standardize(x);
+            3f64.ln() + 2.0 * z.abs().ln() - (self.b - self.a).ln()
+        }
+    }
+}
+
+impl distribution::Distribution for UQuadratic {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        if x <= self.a {
+            0.0
+        } else if x >= self.b {
+            1.0
+        } else {
+            0.5 * (1.0 + self.standardize(x).powi(3))
+        }
+    }
+}
+
+impl distribution::Entropy for UQuadratic {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        ((self.b - self.a) / 3.0).ln() + 2.0 / 3.0
+    }
+}
+
+impl distribution::Inverse for UQuadratic {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        should!((0.0..=1.0).contains(&p));
+        let q = 2.0 * p - 1.0;
+        let z = if q < 0.0 {
+            -(-q).powf(1.0 / 3.0)
+        } else {
+            q.powf(1.0 / 3.0)
+        };
+        0.5 * (self.a + self.b + z * (self.b - self.a))
+    }
+}
+
+impl distribution::Kurtosis for UQuadratic {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        -38.0 / 21.0
+    }
+}
+
+impl distribution::Mean for UQuadratic {
+    #[inline]
+    fn mean(&self) -> f64 {
+        0.5 * (self.a + self.b)
+    }
+}
+
+impl distribution::Median for UQuadratic {
+    #[inline]
+    fn median(&self) -> f64 {
+        0.5 * (self.a + self.b)
+    }
+}
+
+impl distribution::Modes for UQuadratic {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![self.a, self.b]
+    }
+}
+
+impl distribution::Moments for UQuadratic {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is computed by numerical integration.
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::{moments, Continuous, Mean, Variance};
+        moments::continuous(
+            |x| self.density(x),
+            n,
+            self.a,
+            self.b,
+            self.mean(),
+            self.deviation(),
+        )
+    }
+}
+
+impl distribution::Sample for UQuadratic {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::Inverse;
+        self.inverse(source.read::<f64>())
+    }
+}
+
+impl distribution::Skewness for UQuadratic {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        0.0
+    }
+}
+
+impl distribution::Support for UQuadratic {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Included;
+        (Included(self.a), Included(self.b))
+    }
+}
+
+impl distribution::Variance for UQuadratic {
+    #[inline]
+    fn variance(&self) -> f64 {
+        0.15 * (self.b - self.a).powi(2)
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($a:expr, $b:expr) => (UQuadratic::new($a, $b));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(1.0, 4.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            -0.1230512634607753,
+            0.6877717479789327,
+            -0.15608086019725753,
+        ];
+        let im = vec![-0.6792897242482702, 0.6407257256667205, 0.5276336900847045];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(1.0, 4.0);
+        let x = vec![0.0, 1.0, 1.5, 2.0, 2.5, 3.0, 3.5, 4.0, 5.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            1.0000000000000000e+00,
+            4.4444444444444442e-01,
+            1.1111111111111110e-01,
+            0.0000000000000000e+00,
+            1.1111111111111110e-01,
+            4.4444444444444442e-01,
+            1.0000000000000000e+00,
+            0.0000000000000000e+00,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(1.0, 4.0);
+        let x = vec![0.0, 1.0, 1.5, 2.0, 2.5, 3.0, 3.5, 4.0, 5.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            3.5185185185185186e-01,
+            4.8148148148148145e-01,
+            5.0000000000000000e-01,
+            5.1851851851851849e-01,
+            6.4814814814814814e-01,
+            1.0000000000000000e+00,
+            1.0000000000000000e+00,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(1.0, 4.0).entropy(), 2.0 / 3.0, 1e-15);
+    }
+
+    #[test]
+    fn inverse() {
+        let d = new!(1.0, 4.0);
+        let p = vec![
+            0.0, 0.01, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.99, 1.0,
+        ];
+        let x = vec![
+            1.0000000000000000e+00,
+            1.0100674174310971e+00,
+            1.1075233499161663e+00,
+            1.2348510020473762e+00,
+            1.3947905504078839e+00,
+            1.6227946785361402e+00,
+            2.5000000000000000e+00,
+            3.3772053214638595e+00,
+            3.6052094495921159e+00,
+            3.7651489979526240e+00,
+            3.8924766500838337e+00,
+            3.9899325825689029e+00,
+            4.0000000000000000e+00,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert_eq!(new!(1.0, 4.0).kurtosis(), -38.0 / 21.0);
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(1.0, 4.0);
+        let x = vec![0.0, 1.0, 1.5, 2.5, 3.5, 5.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            0.0,
+            -0.81093021621632876,
+            f64::NEG_INFINITY,
+            -0.81093021621632876,
+            f64::NEG_INFINITY,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(1.0, 4.0).mean(), 2.5);
+    }
+
+    #[test]
+    fn median() {
+        assert_eq!(new!(1.0, 4.0).median(), 2.5);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(1.0, 4.0).modes(), vec![1.0, 4.0]);
+    }
+
+    #[test]
+    fn sample() {
+        for x in Independent(&new!(1.0, 4.0), &mut source::default(42)).take(100) {
+            assert!((1.0..=4.0).contains(&x));
+        }
+    }
+
+    #[test]
+    fn skewness() {
+        assert_eq!(new!(1.0, 4.0).skewness(), 0.0);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::Included;
+        assert_eq!(new!(1.0, 4.0).support(), (Included(1.0), Included(4.0)));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(1.0, 4.0).variance(), 1.35, 1e-15);
+    }
+}
@@ -1,3 +1,4 @@
+use core::ops::Bound;
 #[allow(unused_imports)]
 use special::Primitive;
 
@@ -41,6 +42,17 @@ impl Default for Uniform {
     }
 }
 
+impl distribution::CharacteristicFunction for Uniform {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use core::f64::consts::FRAC_PI_2;
+        let &Uniform { a, b } = self;
+        let x = 0.5 * t * (b - a);
+        let modulus = if x == 0.0 { 1.0 } else { x.sin() / x };
+        let phase = 0.5 * t * (a + b);
+        (modulus * (FRAC_PI_2 - phase).sin(), modulus * phase.sin())
+    }
+}
+
 impl distribution::Continuous for Uniform {
     #[inline]
     fn density(&self, x: f64) -> f64 {
@@ -50,6 +62,15 @@ impl distribution::Continuous for Uniform {
             1.0 / (self.b - self.a)
         }
     }
+
+    #[inline]
+    fn log_density(&self, x: f64) -> f64 {
+        if x < self.a || x > self.b {
+            f64::NEG_INFINITY
+        } else {
+            -(self.b - self.a).ln()
+        }
+    }
 }
 
 impl distribution::Distribution for Uniform {
@@ -104,6 +125,25 @@ impl distribution::Median for Uniform {
     }
 }
 
+impl distribution::Mgf for Uniform {
+    fn mgf(&self, t: f64) -> f64 {
+        let &Uniform { a, b } = self;
+        if t == 0.0 {
+            1.0
+        } else {
+            (t * a).exp() * (t * (b - a)).exp_m1() / (t * (b - a))
+        }
+    }
+}
+
+impl distribution::Moments for Uniform {
+    fn raw_moment(&self, n: usize) -> f64 {
+        let &Uniform { a, b } = self;
+        let n = n as i32;
+        (b.powi(n + 1) - a.powi(n + 1)) / ((n + 1) as f64 * (b - a))
+    }
+}
+
 impl distribution::Sample for Uniform {
     #[inline]
     fn sample<S>(&self, source: &mut S) -> f64
@@ -121,6 +161,13 @@ impl distribution::Skewness for Uniform {
     }
 }
 
+impl distribution::Support for Uniform {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Included;
+        (Included(self.a), Included(self.b))
+    }
+}
+
 impl distribution::Variance for Uniform {
     #[inline]
     fn variance(&self) -> f64 {
@@ -129,6 +176,12 @@ impl distribution::Variance for Uniform {
 }
 
 #[cfg(test)]
+#[allow(
+    clippy::approx_constant,
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
 mod tests {
     use alloc::{vec, vec::Vec};
     use prelude::*;
@@ -137,6 +190,22 @@ mod tests {
         ($a:expr, $b:expr) => (Uniform::new($a, $b));
     );
 
+    #[test]
+    fn cf() {
+        let d = new!(-1.0, 3.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.5383668049594874, 0.8990392635740192, 0.07873524107834448];
+        let im = vec![
+            -0.4534601042444092,
+            0.27810543404578464,
+            -0.17203964039968855,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
     #[test]
     fn distribution() {
         let d = new!(-1.0, 1.0);
@@ -178,6 +247,18 @@ mod tests {
         assert_eq!(new!(0.0, 2.0).kurtosis(), -1.2);
     }
 
+    #[test]
+    fn log_density() {
+        let d = new!(-1.0, 1.0);
+        let x = vec![-1.5, 0.0, 1.5];
+        let p = vec![f64::NEG_INFINITY, -0.69314718055994531, f64::NEG_INFINITY];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
     #[test]
     fn mean() {
         assert_eq!(new!(0.0, 2.0).mean(), 1.0);
@@ -188,10 +269,23 @@ mod tests {
         assert_eq!(new!(0.0, 2.0).median(), 1.0);
     }
 
+    #[test]
+    fn mgf() {
+        let d = new!(-1.0, 3.0);
+        let t = vec![-0.7, -0.1, 0.2, 0.45];
+        let m = vec![
+            0.6754629568633911,
+            0.9108817434848245,
+            1.2542350591406588,
+            1.7887763217084454,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+    }
+
     #[test]
     fn sample() {
         for x in Independent(&new!(7.0, 42.0), &mut source::default(42)).take(100) {
-            assert!(7.0 <= x && x <= 42.0);
+            assert!((7.0..=42.0).contains(&x));
         }
     }
 
@@ -200,6 +294,12 @@ mod tests {
         assert_eq!(new!(0.0, 2.0).skewness(), 0.0);
     }
 
+    #[test]
+    fn support() {
+        use core::ops::Bound::Included;
+        assert_eq!(new!(-1.0, 3.0).support(), (Included(-1.0), Included(3.0)));
+    }
+
     #[test]
     fn variance() {
         assert_eq!(new!(0.0, 12.0).variance(), 12.0);
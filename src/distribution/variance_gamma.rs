@@ -0,0 +1,558 @@
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A variance-gamma distribution.
+///
+/// The distribution is a variance-mean mixture of Gaussian distributions with
+/// the gamma distribution as the mixing distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct VarianceGamma {
+    lambda: f64,
+    alpha: f64,
+    beta: f64,
+    mu: f64,
+    gamma: f64,
+    ln_norm: f64,
+}
+
+impl VarianceGamma {
+    /// Create a variance-gamma distribution with shape `lambda`, tail
+    /// heaviness `alpha`, asymmetry `beta`, and location `mu`.
+    ///
+    /// It should hold that `lambda > 0` and `alpha > |beta|`.
+    #[inline]
+    pub fn new(lambda: f64, alpha: f64, beta: f64, mu: f64) -> Self {
+        use core::f64::consts::PI;
+        use special::Gamma;
+        should!(lambda > 0.0 && alpha > beta.abs());
+        let gamma = (alpha * alpha - beta * beta).sqrt();
+        let ln_norm = 2.0 * lambda * gamma.ln()
+            - 0.5 * PI.ln()
+            - Gamma::ln_gamma(lambda).0
+            - (lambda - 0.5) * (2.0 * alpha).ln();
+        VarianceGamma {
+            lambda,
+            alpha,
+            beta,
+            mu,
+            gamma,
+            ln_norm,
+        }
+    }
+
+    /// Return the shape parameter.
+    #[inline(always)]
+    pub fn lambda(&self) -> f64 {
+        self.lambda
+    }
+
+    /// Return the tail-heaviness parameter.
+    #[inline(always)]
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Return the asymmetry parameter.
+    #[inline(always)]
+    pub fn beta(&self) -> f64 {
+        self.beta
+    }
+
+    /// Return the location parameter.
+    #[inline(always)]
+    pub fn mu(&self) -> f64 {
+        self.mu
+    }
+
+    /// Integrate the density between `mu` and `mu + z`.
+    ///
+    /// The panels are refined geometrically toward `mu`, where the density can
+    /// be singular, and the remaining gap is closed using the leading power of
+    /// the density.
+    fn inner(&self, z: f64) -> f64 {
+        use distribution::quadrature;
+        if z == 0.0 {
+            return 0.0;
+        }
+        let f = |z: f64| self.kernel(z);
+        let power = (2.0 * self.lambda).min(1.0);
+        let (mut sum, mut b) = (0.0, z);
+        loop {
+            let a = 0.5 * b;
+            sum += quadrature::legendre(f, a.min(b), a.max(b));
+            let rest = f(a) * a.abs() / power;
+            if rest <= f64::EPSILON * sum || a == 0.0 {
+                return sum + rest;
+            }
+            b = a;
+        }
+    }
+
+    /// Compute the density at `mu + z` for `z != 0`.
+    fn kernel(&self, z: f64) -> f64 {
+        use distribution::bessel;
+        let &VarianceGamma {
+            lambda,
+            alpha,
+            beta,
+            ln_norm,
+            ..
+        } = self;
+        let nu = lambda - 0.5;
+        let r = z.abs();
+        let ln_value = ln_norm + nu * r.ln() + beta * z - alpha * r;
+        ln_value.exp() * bessel::kve(nu, alpha * r)
+    }
+
+    /// Return the scale of the gamma mixing distribution.
+    #[inline]
+    fn scale(&self) -> f64 {
+        2.0 / (self.gamma * self.gamma)
+    }
+}
+
+impl distribution::CharacteristicFunction for VarianceGamma {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::complex;
+        let &VarianceGamma {
+            lambda,
+            beta,
+            mu,
+            gamma,
+            ..
+        } = self;
+        let ratio = complex::div(
+            (gamma * gamma, 0.0),
+            (gamma * gamma + t * t, -2.0 * beta * t),
+        );
+        complex::mul(complex::exp((0.0, mu * t)), complex::powf(ratio, lambda))
+    }
+}
+
+impl distribution::Continuous for VarianceGamma {
+    fn density(&self, x: f64) -> f64 {
+        use core::f64::consts::PI;
+        use special::Gamma;
+        let &VarianceGamma {
+            lambda,
+            alpha,
+            mu,
+            gamma,
+            ..
+        } = self;
+        if x != mu {
+            return self.kernel(x - mu);
+        }
+        let nu = lambda - 0.5;
+        if nu <= 0.0 {
+            return f64::INFINITY;
+        }
+        let ln_value = 2.0 * lambda * gamma.ln() + Gamma::ln_gamma(nu).0
+            - Gamma::ln_gamma(lambda).0
+            - (2.0 * nu) * alpha.ln();
+        0.5 * ln_value.exp() / PI.sqrt()
+    }
+
+    fn log_density(&self, x: f64) -> f64 {
+        use distribution::bessel;
+        let &VarianceGamma {
+            lambda,
+            alpha,
+            beta,
+            mu,
+            ln_norm,
+            ..
+        } = self;
+        let r = (x - mu).abs();
+        if r == 0.0 {
+            return self.density(x).ln();
+        }
+        let nu = lambda - 0.5;
+        ln_norm + nu * r.ln() + beta * (x - mu) - alpha * r + bessel::kve(nu, alpha * r).ln()
+    }
+}
+
+impl distribution::Distribution for VarianceGamma {
+    type Value = f64;
+
+    /// Compute the cumulative distribution function.
+    ///
+    /// The function is computed by numerical integration of the density over
+    /// the tail that lies on the same side of `mu` as `x`. Since the density
+    /// can be singular at `mu`, the integral is accumulated outward from `mu`
+    /// when `x` is close to `mu`.
+    fn distribution(&self, x: f64) -> f64 {
+        use distribution::{quadrature, Continuous};
+        let f = |x: f64| self.density(x);
+        let mu = self.mu;
+        let width = 0.5 / (self.alpha - self.beta.abs());
+        let z = x - mu;
+        if z < -width {
+            quadrature::tail(f, x, -width).min(1.0)
+        } else if z > width {
+            (1.0 - quadrature::tail(f, x, width)).max(0.0)
+        } else {
+            let left = quadrature::tail(f, mu - width, -width) + self.inner(-width);
+            if z < 0.0 {
+                (left - self.inner(z)).max(0.0)
+            } else {
+                (left + self.inner(z)).min(1.0)
+            }
+        }
+    }
+}
+
+impl distribution::Kurtosis for VarianceGamma {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        let (s, b2) = (self.scale(), self.beta * self.beta);
+        let kappa_2 = s * (1.0 + s * b2);
+        let kappa_4 = s * s * (3.0 + 12.0 * s * b2 + 6.0 * s * s * b2 * b2);
+        kappa_4 / (self.lambda * kappa_2 * kappa_2)
+    }
+}
+
+impl distribution::Mean for VarianceGamma {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.mu + self.lambda * self.scale() * self.beta
+    }
+}
+
+impl distribution::Mgf for VarianceGamma {
+    /// Compute the moment-generating function.
+    ///
+    /// The function is infinite if `|beta + t| >= alpha`.
+    fn mgf(&self, t: f64) -> f64 {
+        let &VarianceGamma {
+            lambda,
+            alpha,
+            beta,
+            mu,
+            gamma,
+            ..
+        } = self;
+        let x = beta + t;
+        if x.abs() >= alpha {
+            f64::INFINITY
+        } else {
+            (mu * t + lambda * (gamma * gamma / (alpha * alpha - x * x)).ln()).exp()
+        }
+    }
+}
+
+impl distribution::Moments for VarianceGamma {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is computed from the cumulants, the `j`th of which is
+    /// `lambda (j - 1)! ((alpha - beta)^(-j) + (-alpha - beta)^(-j))` plus `mu`
+    /// for `j = 1`.
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::moments;
+        let &VarianceGamma {
+            lambda,
+            alpha,
+            beta,
+            mu,
+            ..
+        } = self;
+        let cumulant = |j: usize| {
+            let sum = (alpha - beta).powi(-(j as i32)) + (-alpha - beta).powi(-(j as i32));
+            let value = (1..j).fold(lambda * sum, |product, i| product * i as f64);
+            if j == 1 {
+                mu + value
+            } else {
+                value
+            }
+        };
+        moments::cumulants(cumulant, n)
+    }
+}
+
+impl distribution::Sample for VarianceGamma {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained by drawing the variance of a Gaussian variate
+    /// from a gamma distribution.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::{gamma, gaussian};
+        let v = self.scale() * gamma::sample(self.lambda, source);
+        self.mu + self.beta * v + v.sqrt() * gaussian::sample(source)
+    }
+}
+
+impl distribution::Skewness for VarianceGamma {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        let (s, beta) = (self.scale(), self.beta);
+        let kappa_2 = s * (1.0 + s * beta * beta);
+        let kappa_3 = s * s * beta * (3.0 + 2.0 * s * beta * beta);
+        kappa_3 / (self.lambda.sqrt() * kappa_2.powf(1.5))
+    }
+}
+
+impl distribution::Support for VarianceGamma {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Unbounded;
+        (Unbounded, Unbounded)
+    }
+}
+
+impl distribution::Variance for VarianceGamma {
+    #[inline]
+    fn variance(&self) -> f64 {
+        let s = self.scale();
+        self.lambda * s * (1.0 + s * self.beta * self.beta)
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($lambda:expr, $alpha:expr, $beta:expr, $mu:expr) => (
+            VarianceGamma::new($lambda, $alpha, $beta, $mu)
+        );
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(1.5, 2.0, 1.0, 1.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.2096023628521208, 0.7743394847256848, -0.2122037538542086];
+        let im = vec![-0.6810711420465638, 0.5158680459005833, 0.08057100825888694];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(1.5, 2.0, 1.0, 1.0);
+        let x = vec![-5.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0, 5.0, 10.0];
+        let p = vec![
+            2.8175101103110910e-08,
+            1.6600193430561886e-04,
+            2.7943408036296747e-03,
+            4.2551935558211761e-02,
+            4.1349667156634401e-01,
+            3.1441863895770861e-01,
+            1.5256583844030983e-01,
+            2.8061113165873008e-02,
+            2.7690179205869882e-04,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+
+        let d = new!(1.0, 2.0, 0.0, 1.0);
+        let laplace = Laplace::new(1.0, 0.5);
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &x.iter().map(|&x| laplace.density(x)).collect::<Vec<_>>(),
+            1e-15,
+        );
+
+        assert_eq!(new!(0.5, 2.0, 1.0, 1.0).density(1.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn distribution() {
+        let x = vec![-5.0, -2.0, -1.0, 0.0, 0.9, 1.0, 1.1, 2.0, 3.0, 5.0, 10.0];
+
+        let d = new!(1.5, 2.0, 1.0, 1.0);
+        let p = vec![
+            9.6315087523199897e-09,
+            5.7956641476335821e-05,
+            9.9335024722215187e-04,
+            1.5770988575298989e-02,
+            1.5681023768440303e-01,
+            1.9550110947788532e-01,
+            2.3822749508371918e-01,
+            5.9291367261938399e-01,
+            8.1964994125017721e-01,
+            9.6900934893536794e-01,
+            9.9970901027161851e-01,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+
+        let d = new!(0.4, 2.0, 1.0, 1.0);
+        let p = vec![
+            6.6862917819017541e-10,
+            7.9243215527919757e-06,
+            1.9657371477718288e-04,
+            5.5133201603736652e-03,
+            1.7741406071634702e-01,
+            3.5633293732367932e-01,
+            5.4910554786301569e-01,
+            8.9703181042032720e-01,
+            9.7131690103460644e-01,
+            9.9718825427624946e-01,
+            9.9998756551209966e-01,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-12,
+        );
+
+        let x = vec![-0.1, -1e-9, 0.0, 1e-9, 0.1];
+
+        let d = new!(0.3, 1.0, 0.2, 0.0);
+        let p = vec![
+            2.7475544656922665e-01,
+            4.5566465532576086e-01,
+            4.5566839873897009e-01,
+            4.5567214215217989e-01,
+            6.3904416132855566e-01,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+
+        let d = new!(0.05, 1.0, 0.2, 0.0);
+        let p = vec![
+            7.4513419984255379e-02,
+            4.2365901928310696e-01,
+            4.9051233697434728e-01,
+            5.5736565466801862e-01,
+            9.0794369225719009e-01,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(1.5, 2.0, 1.0, 1.0).kurtosis(), 3.28, 1e-14);
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(1.5, 2.0, 1.0, 1.0);
+        let x = vec![-40.0, 0.5, 400.0, 10000.0];
+        let p = vec![
+            -121.44940925915874,
+            -1.890757762177879,
+            -396.3157905319022,
+            -9994.705601936148,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(1.5, 2.0, 1.0, 1.0).mean(), 2.0, 1e-15);
+    }
+
+    #[test]
+    fn mgf() {
+        let d = new!(1.5, 2.0, 1.0, 1.0);
+        let t = vec![-0.7, -0.1, 0.2, 0.45];
+        let m = vec![
+            0.333741803625821,
+            0.8252136617876693,
+            1.5494616457310857,
+            3.117753116978272,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+        assert_eq!(d.mgf(1.5), f64::INFINITY);
+    }
+
+    #[test]
+    fn moments() {
+        let d = new!(1.5, 2.0, 1.0, 1.0);
+        let m = vec![539.33333333333333, 3541.8148148148148, 26757.333333333333];
+        assert::close(
+            &(5..8).map(|n| d.raw_moment(n)).collect::<Vec<_>>(),
+            &m,
+            1e-8,
+        );
+
+        let d = new!(0.4, 2.0, 0.5, 1.0);
+        let m = vec![
+            1.1066666666666667,
+            1.4664888888888889,
+            2.3438862222222223,
+            12.761564957708643,
+            43.00859976772267,
+            175.93806573687544,
+        ];
+        assert::close(
+            &[1, 2, 3, 5, 6, 7]
+                .iter()
+                .map(|&n| d.raw_moment(n))
+                .collect::<Vec<_>>(),
+            &m,
+            1e-12,
+        );
+        assert::close(d.central_moment(2), d.variance(), 1e-14);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(1.5, 2.0, 1.0, 1.0);
+        let mut source = source::default(42);
+
+        let mean = (0..n).map(|_| d.sample(&mut source)).sum::<f64>() / n as f64;
+        assert!((mean - d.mean()).abs() < 0.02);
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(
+            new!(1.5, 2.0, 1.0, 1.0).skewness(),
+            1.3426342266852378e+00,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::Unbounded;
+        assert_eq!(new!(1.5, 2.0, 1.0, 1.0).support(), (Unbounded, Unbounded));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(
+            new!(1.5, 2.0, 1.0, 1.0).variance(),
+            1.6666666666666667e+00,
+            1e-15,
+        );
+    }
+}
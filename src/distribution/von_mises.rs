@@ -0,0 +1,385 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+const SERIES_THRESHOLD: f64 = 50.0;
+
+/// A von Mises distribution, also known as the circular normal distribution.
+///
+/// The distribution is supported on the interval `[mu - pi, mu + pi]`, which
+/// represents one turn around the circle.
+#[derive(Clone, Copy, Debug)]
+pub struct VonMises {
+    mu: f64,
+    kappa: f64,
+    norm: f64,
+}
+
+impl VonMises {
+    /// Create a von Mises distribution with location `mu` and concentration
+    /// `kappa`.
+    ///
+    /// It should hold that `kappa > 0`.
+    #[inline]
+    pub fn new(mu: f64, kappa: f64) -> Self {
+        use core::f64::consts::PI;
+        use distribution::bessel;
+        should!(kappa > 0.0);
+        VonMises {
+            mu,
+            kappa,
+            norm: 2.0 * PI * bessel::i0e(kappa),
+        }
+    }
+
+    /// Return the location parameter.
+    #[inline(always)]
+    pub fn mu(&self) -> f64 {
+        self.mu
+    }
+
+    /// Return the concentration parameter.
+    #[inline(always)]
+    pub fn kappa(&self) -> f64 {
+        self.kappa
+    }
+
+    /// Compute the circular variance, that is, one minus the mean resultant
+    /// length `I1(kappa) / I0(kappa)`.
+    #[inline]
+    pub fn circular_variance(&self) -> f64 {
+        1.0 - self.resultant_length()
+    }
+
+    /// Compute the mean resultant length `I1(kappa) / I0(kappa)`.
+    #[inline]
+    fn resultant_length(&self) -> f64 {
+        use distribution::bessel;
+        bessel::i1e(self.kappa) / bessel::i0e(self.kappa)
+    }
+}
+
+impl distribution::CharacteristicFunction for VonMises {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use core::f64::consts::PI;
+        use distribution::{characteristic, Continuous};
+        characteristic::continuous(|x| self.density(x), t, self.mu - PI, self.mu + PI, 0.0, 0.0)
+    }
+}
+
+impl distribution::Continuous for VonMises {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        use core::f64::consts::{FRAC_PI_2, PI};
+        let z = x - self.mu;
+        if !(-PI..=PI).contains(&z) {
+            0.0
+        } else {
+            (self.kappa * ((FRAC_PI_2 - z).sin() - 1.0)).exp() / self.norm
+        }
+    }
+
+    #[inline]
+    fn log_density(&self, x: f64) -> f64 {
+        use core::f64::consts::{FRAC_PI_2, PI};
+        let z = x - self.mu;
+        if !(-PI..=PI).contains(&z) {
+            f64::NEG_INFINITY
+        } else {
+            self.kappa * ((FRAC_PI_2 - z).sin() - 1.0) - self.norm.ln()
+        }
+    }
+}
+
+impl distribution::Distribution for VonMises {
+    type Value = f64;
+
+    /// Compute the cumulative distribution function.
+    ///
+    /// The function is evaluated via its Fourier series with the Bessel ratios
+    /// obtained by backward recurrence for small concentrations and via a
+    /// normal approximation for large ones.
+    ///
+    /// ## References
+    ///
+    /// 1. G. W. Hill, “Algorithm 518: Incomplete Bessel function I0. The von
+    ///    Mises distribution,” ACM Transactions on Mathematical Software,
+    ///    vol. 3, no. 3, pp. 279–284, 1977.
+    fn distribution(&self, x: f64) -> f64 {
+        use core::f64::consts::{FRAC_2_PI, FRAC_PI_2, PI, SQRT_2};
+        use distribution::bessel;
+        use special::Error;
+        let z = x - self.mu;
+        if z <= -PI {
+            return 0.0;
+        }
+        if z >= PI {
+            return 1.0;
+        }
+        let kappa = self.kappa;
+        let p = if kappa < SERIES_THRESHOLD {
+            let n = -(-(28.0 + 0.5 * kappa - 100.0 / (kappa + 5.0))).floor() as usize;
+            let (sin, cos) = (z.sin(), (FRAC_PI_2 - z).sin());
+            let (mut sin_n, mut cos_n) = ((n as f64 * z).sin(), (FRAC_PI_2 - n as f64 * z).sin());
+            let (mut ratio, mut sum) = (0.0, 0.0);
+            for j in (1..n).rev() {
+                let next = sin_n * cos - cos_n * sin;
+                cos_n = cos_n * cos + sin_n * sin;
+                sin_n = next;
+                ratio = 1.0 / (2.0 * j as f64 / kappa + ratio);
+                sum = ratio * (sin_n / j as f64 + sum);
+            }
+            0.5 + z / (2.0 * PI) + sum / PI
+        } else {
+            let b = FRAC_2_PI.sqrt() / bessel::i0e(kappa);
+            let y = b * (0.5 * z).sin();
+            let y2 = y * y;
+            let c = 24.0 * kappa;
+            let denominator =
+                (c - 2.0 * y2 - 16.0) / 3.0 - (y2 * y2 + 1.75 * y2 + 83.5) / (c - y2 + 3.0);
+            let chi = y - y * y2 / (denominator * denominator);
+            0.5 * (-chi / SQRT_2).compl_error()
+        };
+        p.clamp(0.0, 1.0)
+    }
+}
+
+impl distribution::Entropy for VonMises {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        self.norm.ln() + self.kappa * (1.0 - self.resultant_length())
+    }
+}
+
+impl distribution::Mean for VonMises {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.mu
+    }
+}
+
+impl distribution::Median for VonMises {
+    #[inline]
+    fn median(&self) -> f64 {
+        self.mu
+    }
+}
+
+impl distribution::Modes for VonMises {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![self.mu]
+    }
+}
+
+impl distribution::Sample for VonMises {
+    /// Draw a sample.
+    ///
+    /// ## References
+    ///
+    /// 1. D. J. Best and N. I. Fisher, “Efficient simulation of the von Mises
+    ///    distribution,” Journal of the Royal Statistical Society. Series C
+    ///    (Applied Statistics), vol. 28, no. 2, pp. 152–157, 1979.
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use core::f64::consts::PI;
+        let kappa = self.kappa;
+        let root = (1.0 + 4.0 * kappa * kappa).sqrt();
+        let tau = 1.0 + root;
+        let rho = 2.0 * kappa * tau / ((root + 1.0) * (tau + (2.0 * tau).sqrt()));
+        let r = (1.0 + rho * rho) / (2.0 * rho);
+        let f = loop {
+            let z = (PI * (0.5 - source.read::<f64>())).sin();
+            let f = (1.0 + r * z) / (r + z);
+            let c = kappa * (r - f);
+            let u = source.read::<f64>();
+            if c * (2.0 - c) > u || (c / u).ln() + 1.0 >= c {
+                break f.clamp(-1.0, 1.0);
+            }
+        };
+        let angle = 2.0 * ((1.0 - f) / (1.0 + f)).sqrt().atan();
+        if source.read::<f64>() < 0.5 {
+            self.mu - angle
+        } else {
+            self.mu + angle
+        }
+    }
+}
+
+impl distribution::Support for VonMises {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::f64::consts::PI;
+        use core::ops::Bound::Included;
+        (Included(self.mu - PI), Included(self.mu + PI))
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($mu:expr, $kappa:expr) => (VonMises::new($mu, $kappa));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(1.0, 2.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.6375718582048169, 0.923192862073614, -0.12577012001265617];
+        let im = vec![-0.5370193678761711, 0.2855770176202258, 0.27481272583483907];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn circular_variance() {
+        assert::close(
+            new!(1.0, 2.0).circular_variance(),
+            3.0222534203599200e-01,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(1.0, 2.0);
+        let x = vec![-3.0, -2.0, 0.0, 0.5, 1.0, 2.0, 3.0, 4.1, 4.2];
+        let p = vec![
+            0.0000000000000000e+00,
+            9.6397934099426603e-03,
+            2.0571449951559534e-01,
+            4.0385253335183774e-01,
+            5.1588541201901361e-01,
+            2.0571449951559534e-01,
+            3.0374122063858550e-02,
+            9.4651285912465480e-03,
+            0.0000000000000000e+00,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(1.0, 2.0);
+        let x = vec![-3.0, -2.0, 0.0, 0.5, 1.0, 2.0, 3.0, 4.1, 4.2];
+        let p = vec![
+            0.0000000000000000e+00,
+            1.3468622889293202e-03,
+            1.1042226304496347e-01,
+            2.6180778558147383e-01,
+            5.0000000000000000e-01,
+            8.8957773695503650e-01,
+            9.8269020636932225e-01,
+            9.9960677382348395e-01,
+            1.0000000000000000e+00,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+
+        let d = new!(1.0, 100.0);
+        let x = vec![0.5, 0.8, 0.9, 1.0, 1.1, 1.3];
+        let p = vec![
+            3.8728639434023542e-07,
+            2.3067896788343024e-02,
+            1.5906045738451996e-01,
+            5.0000000000000000e-01,
+            8.4093954261548032e-01,
+            9.9858195732405786e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(1.0, 2.0).entropy(), 1.2663212919642859e+00, 1e-15);
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(1.0, 2.0);
+        let x = vec![-40.0, -0.5, 0.5, 4.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -2.5203962045568957,
+            -0.9067054841115562,
+            -4.641855601093193,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-13,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(1.0, 2.0).mean(), 1.0);
+    }
+
+    #[test]
+    fn median() {
+        assert_eq!(new!(1.0, 2.0).median(), 1.0);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(1.0, 2.0).modes(), vec![1.0]);
+    }
+
+    #[test]
+    fn sample() {
+        use core::f64::consts::PI;
+
+        let n = 100000;
+        let d = new!(1.0, 2.0);
+        let mut source = source::default(42);
+
+        let samples = (0..n).map(|_| d.sample(&mut source)).collect::<Vec<_>>();
+        assert!(samples.iter().all(|&x| (1.0 - PI..=1.0 + PI).contains(&x)));
+        let fraction = samples.iter().filter(|&&x| x <= 0.5).count() as f64 / n as f64;
+        assert!((fraction - d.distribution(0.5)).abs() < 0.01);
+    }
+
+    #[test]
+    fn support() {
+        use core::f64::consts::PI;
+        use core::ops::Bound::Included;
+        assert_eq!(
+            new!(1.0, 2.0).support(),
+            (Included(1.0 - PI), Included(1.0 + PI))
+        );
+    }
+}
@@ -0,0 +1,250 @@
+use alloc::vec::Vec;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use source::Source;
+
+/// A von Mises–Fisher distribution.
+///
+/// The distribution is supported on the unit sphere in `p` dimensions.
+#[derive(Clone, Debug)]
+pub struct VonMisesFisher {
+    mu: Vec<f64>,
+    kappa: f64,
+    ln_norm: f64,
+}
+
+impl VonMisesFisher {
+    /// Create a von Mises–Fisher distribution with mean direction `mu` and
+    /// concentration `kappa`.
+    ///
+    /// It should hold that `mu` is a unit vector with `mu.len() >= 2` and
+    /// `kappa > 0`.
+    pub fn new(mu: Vec<f64>, kappa: f64) -> Self {
+        use core::f64::consts::PI;
+        use distribution::bessel;
+        should!(mu.len() >= 2 && kappa > 0.0);
+        should!(is_unit_vector(mu), {
+            const EPSILON: f64 = 1e-12;
+            (mu.iter().map(|mu| mu * mu).sum::<f64>() - 1.0).abs() < EPSILON
+        });
+        let half = 0.5 * mu.len() as f64;
+        let ln_norm = half * (2.0 * PI).ln() + bessel::ive_real(half - 1.0, kappa).ln() + kappa
+            - (half - 1.0) * kappa.ln();
+        VonMisesFisher { mu, kappa, ln_norm }
+    }
+
+    /// Return the mean direction.
+    #[inline(always)]
+    pub fn mu(&self) -> &[f64] {
+        &self.mu
+    }
+
+    /// Return the concentration parameter.
+    #[inline(always)]
+    pub fn kappa(&self) -> f64 {
+        self.kappa
+    }
+
+    /// Return the number of dimensions.
+    #[inline(always)]
+    pub fn dimension(&self) -> usize {
+        self.mu.len()
+    }
+
+    /// Compute the probability density function with respect to the surface
+    /// measure of the sphere.
+    #[inline]
+    pub fn density(&self, x: &[f64]) -> f64 {
+        self.log_density(x).exp()
+    }
+
+    /// Compute the natural logarithm of the probability density function.
+    ///
+    /// The normalizing constant is evaluated via the modified Bessel function
+    /// of the first kind of order `p / 2 - 1`.
+    pub fn log_density(&self, x: &[f64]) -> f64 {
+        should!(x.len() == self.dimension());
+        let dot = self.mu.iter().zip(x).map(|(mu, x)| mu * x).sum::<f64>();
+        self.kappa * dot - self.ln_norm
+    }
+
+    /// Compute the mean vector, which is the mean direction scaled by the mean
+    /// resultant length `I_(p / 2) / I_(p / 2 - 1)`.
+    pub fn mean(&self) -> Vec<f64> {
+        use distribution::bessel;
+        let half = 0.5 * self.dimension() as f64;
+        let length = bessel::ive_real(half, self.kappa) / bessel::ive_real(half - 1.0, self.kappa);
+        self.mu.iter().map(|mu| length * mu).collect()
+    }
+
+    /// Draw a sample.
+    ///
+    /// The component along the mean direction is drawn by rejection, the
+    /// orthogonal component uniformly, and the result is then reflected onto
+    /// the mean direction.
+    ///
+    /// ## References
+    ///
+    /// 1. A. T. A. Wood, “Simulation of the von Mises Fisher distribution,”
+    ///    Communications in Statistics - Simulation and Computation, vol. 23,
+    ///    no. 1, pp. 157–164, 1994.
+    pub fn sample<S>(&self, source: &mut S) -> Vec<f64>
+    where
+        S: Source,
+    {
+        use distribution::{gamma, gaussian};
+        let p = self.dimension();
+        let (kappa, m) = (self.kappa, (p - 1) as f64);
+        let b = m / (2.0 * kappa + (4.0 * kappa * kappa + m * m).sqrt());
+        let x0 = (1.0 - b) / (1.0 + b);
+        let c = kappa * x0 + m * (1.0 - x0 * x0).ln();
+        let w = loop {
+            let y = gamma::sample(0.5 * m, source);
+            let z = y / (y + gamma::sample(0.5 * m, source));
+            let w = (1.0 - (1.0 + b) * z) / (1.0 - (1.0 - b) * z);
+            let u = source.read::<f64>();
+            if kappa * w + m * (1.0 - x0 * w).ln() - c >= u.ln() {
+                break w;
+            }
+        };
+        let v = (1..p).map(|_| gaussian::sample(source)).collect::<Vec<_>>();
+        let norm = v.iter().map(|v| v * v).sum::<f64>().sqrt();
+        let scale = (1.0 - w * w).max(0.0).sqrt() / norm;
+        let mut x = Vec::with_capacity(p);
+        x.push(w);
+        x.extend(v.iter().map(|v| scale * v));
+
+        let mut u = self.mu.iter().map(|mu| -mu).collect::<Vec<_>>();
+        u[0] += 1.0;
+        let norm = u.iter().map(|u| u * u).sum::<f64>();
+        if norm > 0.0 {
+            let factor = 2.0 * u.iter().zip(&x).map(|(u, x)| u * x).sum::<f64>() / norm;
+            for (x, u) in x.iter_mut().zip(&u) {
+                *x -= factor * u;
+            }
+        }
+        x
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($mu:expr, $kappa:expr) => (VonMisesFisher::new($mu, $kappa));
+    );
+
+    #[test]
+    fn density() {
+        let d = new!(vec![0.0, 0.6, 0.8], 2.0);
+        let x = vec![
+            vec![0.0, 0.6, 0.8],
+            vec![1.0, 0.0, 0.0],
+            vec![0.0, -0.6, -0.8],
+            vec![0.48, 0.6, 0.64],
+        ];
+        let p = vec![
+            3.2424870843767356e-01,
+            4.3882290795518399e-02,
+            5.9388222538828842e-03,
+            2.5101453352828434e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(vec![0.0, 0.6, 0.8], 2.0);
+        let x = vec![
+            vec![0.0, 0.6, 0.8],
+            vec![1.0, 0.0, 0.0],
+            vec![0.0, -0.6, -0.8],
+            vec![0.48, 0.6, 0.64],
+        ];
+        let p = vec![
+            -1.1262444390235136e+00,
+            -3.1262444390235138e+00,
+            -5.1262444390235133e+00,
+            -1.3822444390235136e+00,
+        ];
+
+        assert::close(
+            &x.iter().map(|x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+
+        let d = new!(vec![0.5; 4], 10.0);
+        let x = vec![
+            vec![0.5, 0.5, 0.5, 0.5],
+            vec![1.0, 0.0, 0.0, 0.0],
+            vec![0.0, 0.0, 0.6, -0.8],
+        ];
+        let p = vec![
+            7.3662712607114245e-01,
+            -4.2633728739288577e+00,
+            -1.0263372873928859e+01,
+        ];
+
+        assert::close(
+            &x.iter().map(|x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+
+        let d = new!(vec![0.6, 0.8], 2.0);
+        let circle = VonMises::new(0.8f64.atan2(0.6), 2.0);
+        let angle = 0.3f64;
+        assert::close(
+            d.density(&[angle.cos(), angle.sin()]),
+            circle.density(angle),
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        let length = 5.3731472072754805e-01;
+        assert::close(
+            new!(vec![0.0, 0.6, 0.8], 2.0).mean(),
+            vec![0.0, 0.6 * length, 0.8 * length],
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(vec![0.0, 0.6, 0.8], 2.0);
+        let mut source = source::default(42);
+
+        let mut mean = vec![0.0; 3];
+        for _ in 0..n {
+            let x = d.sample(&mut source);
+            assert!((x.iter().map(|x| x * x).sum::<f64>() - 1.0).abs() < 1e-12);
+            for (mean, x) in mean.iter_mut().zip(&x) {
+                *mean += x / n as f64;
+            }
+        }
+        assert::close(&mean, &d.mean(), 0.01);
+
+        let d = new!(vec![1.0, 0.0], 50.0);
+        for x in (0..100).map(|_| d.sample(&mut source)) {
+            assert!(x[0] > 0.0);
+        }
+    }
+}
@@ -0,0 +1,417 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A Weibull distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct Weibull {
+    k: f64,
+    lambda: f64,
+}
+
+impl Weibull {
+    /// Create a Weibull distribution with shape parameter `k` and scale
+    /// parameter `lambda`.
+    ///
+    /// It should hold that `k > 0` and `lambda > 0`.
+    #[inline]
+    pub fn new(k: f64, lambda: f64) -> Self {
+        should!(k > 0.0 && lambda > 0.0);
+        Weibull { k, lambda }
+    }
+
+    /// Return the shape parameter.
+    #[inline(always)]
+    pub fn k(&self) -> f64 {
+        self.k
+    }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn lambda(&self) -> f64 {
+        self.lambda
+    }
+
+    #[inline]
+    fn moment(&self, i: f64) -> f64 {
+        use special::Gamma;
+        Gamma::gamma(1.0 + i / self.k)
+    }
+}
+
+impl distribution::CharacteristicFunction for Weibull {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Continuous, Mean, Variance};
+        characteristic::continuous(
+            |x| self.density(x),
+            t,
+            0.0,
+            f64::INFINITY,
+            self.mean(),
+            self.deviation(),
+        )
+    }
+}
+
+impl distribution::Continuous for Weibull {
+    fn density(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            0.0
+        } else {
+            let &Weibull { k, lambda } = self;
+            let z = x / lambda;
+            (k / lambda) * z.powf(k - 1.0) * (-z.powf(k)).exp()
+        }
+    }
+
+    fn log_density(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            f64::NEG_INFINITY
+        } else if x == 0.0 {
+            self.density(x).ln()
+        } else {
+            let &Weibull { k, lambda } = self;
+            let z = x / lambda;
+            (k / lambda).ln() + (k - 1.0) * z.ln() - z.powf(k)
+        }
+    }
+}
+
+impl distribution::Distribution for Weibull {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else {
+            -(-(x / self.lambda).powf(self.k)).exp_m1()
+        }
+    }
+}
+
+impl distribution::Entropy for Weibull {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        const EULER_MASCHERONI: f64 = 0.5772156649015329;
+        EULER_MASCHERONI * (1.0 - 1.0 / self.k) + (self.lambda / self.k).ln() + 1.0
+    }
+}
+
+impl distribution::Hazard for Weibull {
+    fn hazard(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            0.0
+        } else {
+            let &Weibull { k, lambda } = self;
+            (k / lambda) * (x / lambda).powf(k - 1.0)
+        }
+    }
+
+    #[inline]
+    fn cumulative_hazard(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else {
+            (x / self.lambda).powf(self.k)
+        }
+    }
+}
+
+impl distribution::Inverse for Weibull {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        should!((0.0..=1.0).contains(&p));
+        self.lambda * (-(-p).ln_1p()).powf(1.0 / self.k)
+    }
+}
+
+impl distribution::Kurtosis for Weibull {
+    fn kurtosis(&self) -> f64 {
+        let (g1, g2, g3, g4) = (
+            self.moment(1.0),
+            self.moment(2.0),
+            self.moment(3.0),
+            self.moment(4.0),
+        );
+        let g1_2 = g1 * g1;
+        (-6.0 * g1_2 * g1_2 + 12.0 * g1_2 * g2 - 3.0 * g2 * g2 - 4.0 * g1 * g3 + g4)
+            / (g2 - g1_2).powi(2)
+    }
+}
+
+impl distribution::Mean for Weibull {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.lambda * self.moment(1.0)
+    }
+}
+
+impl distribution::Median for Weibull {
+    #[inline]
+    fn median(&self) -> f64 {
+        use core::f64::consts::LN_2;
+        self.lambda * LN_2.powf(1.0 / self.k)
+    }
+}
+
+impl distribution::Modes for Weibull {
+    fn modes(&self) -> Vec<f64> {
+        if self.k > 1.0 {
+            vec![self.lambda * ((self.k - 1.0) / self.k).powf(1.0 / self.k)]
+        } else {
+            vec![0.0]
+        }
+    }
+}
+
+impl distribution::Moments for Weibull {
+    fn raw_moment(&self, n: usize) -> f64 {
+        self.lambda.powi(n as i32) * self.moment(n as f64)
+    }
+}
+
+impl distribution::Sample for Weibull {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use distribution::Inverse;
+        self.inverse(source.read::<f64>())
+    }
+}
+
+impl distribution::Skewness for Weibull {
+    fn skewness(&self) -> f64 {
+        let (g1, g2, g3) = (self.moment(1.0), self.moment(2.0), self.moment(3.0));
+        let variance = g2 - g1 * g1;
+        (g3 - 3.0 * g1 * variance - g1 * g1 * g1) / (variance * variance.sqrt())
+    }
+}
+
+impl distribution::Support for Weibull {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Included, Unbounded};
+        (Included(0.0), Unbounded)
+    }
+}
+
+impl distribution::Variance for Weibull {
+    #[inline]
+    fn variance(&self) -> f64 {
+        let g1 = self.moment(1.0);
+        self.lambda * self.lambda * (self.moment(2.0) - g1 * g1)
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($k:expr, $lambda:expr) => (Weibull::new($k, $lambda));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(2.0, 1.5);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.5397028850375964, 0.9020989791961596, -0.2847472132561959];
+        let im = vec![-0.7063693119850579, 0.37911528660844546, 0.2802228921856975];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(2.0, 1.5);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 3.5, 4.0, 4.5, 5.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            3.9770636302860879e-01,
+            5.6993812304884850e-01,
+            4.9050592156192308e-01,
+            3.0046811627745079e-01,
+            1.3817005338248070e-01,
+            4.8841703703291148e-02,
+            1.3440745030514874e-02,
+            2.9012900802565262e-03,
+            4.9363921634671824e-04,
+            6.6423726776806418e-05,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(2.0, 1.5);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 3.5, 4.0, 4.5, 5.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            1.0516068318563022e-01,
+            3.5881961157004544e-01,
+            6.3212055882855767e-01,
+            8.3098668459393388e-01,
+            9.3782347597788374e-01,
+            9.8168436111126578e-01,
+            9.9567976052590590e-01,
+            9.9918401216492780e-01,
+            9.9987659019591335e-01,
+            9.9998505466147525e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        use distribution::Exponential;
+        assert::close(new!(2.0, 1.5).entropy(), 1.0009257599989856e+00, 1e-15);
+        assert::close(
+            new!(1.0, 0.5).entropy(),
+            Exponential::new(2.0).entropy(),
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn hazard() {
+        let d = new!(1.5, 2.0);
+        let x = vec![-1.0, 0.0, 1.0, 3.0];
+        let h = vec![0.0, 0.0, 0.5303300858899106, 0.9185586535436918];
+        let c = vec![0.0, 0.0, 0.3535533905932738, 1.8371173070873836];
+        assert::close(
+            &x.iter().map(|&x| d.hazard(x)).collect::<Vec<_>>(),
+            &h,
+            1e-13,
+        );
+        assert::close(
+            &x.iter()
+                .map(|&x| d.cumulative_hazard(x))
+                .collect::<Vec<_>>(),
+            &c,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn inverse() {
+        use core::f64::INFINITY;
+        let d = new!(2.0, 1.5);
+        let p = vec![0.0, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0];
+        let x = vec![
+            0.0000000000000000e+00,
+            4.8688926896175189e-01,
+            7.0857109061615831e-01,
+            8.9583403812433238e-01,
+            1.0720809920306764e+00,
+            1.2488319167365467e+00,
+            1.4358461431214866e+00,
+            1.6458854181665732e+00,
+            1.9029543617692797e+00,
+            2.2761406940777196e+00,
+            INFINITY,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(2.0, 1.5).kurtosis(), 2.4508930068763807e-01, 1e-12);
+        assert::close(new!(0.5, 1.0).kurtosis(), 84.72, 1e-10);
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!(2.0, 1.5);
+        let x = vec![-40.0, 0.05, 0.9, 4.0, 400.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -3.114626420321486,
+            -0.5831435513142098,
+            -5.842599785647604,
+            -71105.23742959967,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-9,
+        );
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(2.0, 1.5).mean(), 1.3293403881791370e+00, 1e-15);
+        assert::close(new!(0.5, 1.0).mean(), 2.0, 1e-15);
+    }
+
+    #[test]
+    fn median() {
+        assert::close(new!(2.0, 1.5).median(), 1.2488319167365467e+00, 1e-15);
+    }
+
+    #[test]
+    fn modes() {
+        assert::close(new!(2.0, 1.5).modes(), vec![1.0606601717798212e+00], 1e-15);
+        assert_eq!(new!(0.5, 1.0).modes(), vec![0.0]);
+    }
+
+    #[test]
+    fn sample() {
+        for x in Independent(&new!(2.0, 1.5), &mut source::default(42)).take(100) {
+            assert!(x >= 0.0);
+        }
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(2.0, 1.5).skewness(), 6.3111065781893716e-01, 1e-13);
+        assert::close(new!(0.5, 1.0).skewness(), 6.6187612133993774e+00, 1e-12);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Included, Unbounded};
+        assert_eq!(new!(2.0, 1.5).support(), (Included(0.0), Unbounded));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(2.0, 1.5).variance(), 4.8285413235574132e-01, 1e-15);
+        assert::close(new!(0.5, 1.0).variance(), 20.0, 1e-13);
+    }
+}
@@ -0,0 +1,221 @@
+use alloc::{vec, vec::Vec};
+#[allow(unused_imports)]
+use special::Primitive;
+
+use source::Source;
+
+/// A Wishart distribution.
+///
+/// The distribution is supported on symmetric positive-definite matrices,
+/// which are stored in row-major order.
+#[derive(Clone, Debug)]
+pub struct Wishart {
+    nu: f64,
+    scale: Vec<f64>,
+    cholesky: Vec<f64>,
+    inverse: Vec<f64>,
+    ln_norm: f64,
+}
+
+impl Wishart {
+    /// Create a Wishart distribution with `nu` degrees of freedom and scale
+    /// matrix `scale` of order `d` given in row-major order.
+    ///
+    /// It should hold that `scale` is a symmetric positive-definite matrix and
+    /// `nu > d - 1`.
+    pub fn new(nu: f64, scale: Vec<f64>) -> Self {
+        use core::f64::consts::LN_2;
+        use distribution::cholesky;
+        let d = (scale.len() as f64).sqrt() as usize;
+        should!(d > 0 && scale.len() == d * d && nu > (d - 1) as f64);
+        let cholesky = cholesky::decompose(&scale, d);
+        should!(cholesky::is_positive_definite(&cholesky, d));
+        let ln_norm = 0.5 * nu * (cholesky::ln_det(&cholesky, d) + d as f64 * LN_2)
+            + ln_multivariate_gamma(0.5 * nu, d);
+        Wishart {
+            nu,
+            inverse: cholesky::invert(&cholesky, d),
+            scale,
+            cholesky,
+            ln_norm,
+        }
+    }
+
+    /// Return the number of degrees of freedom.
+    #[inline(always)]
+    pub fn nu(&self) -> f64 {
+        self.nu
+    }
+
+    /// Return the scale matrix in row-major order.
+    #[inline(always)]
+    pub fn scale(&self) -> &[f64] {
+        &self.scale
+    }
+
+    /// Return the order of the matrices.
+    #[inline(always)]
+    pub fn dimension(&self) -> usize {
+        (self.scale.len() as f64).sqrt() as usize
+    }
+
+    /// Compute the probability density function.
+    #[inline]
+    pub fn density(&self, x: &[f64]) -> f64 {
+        self.log_density(x).exp()
+    }
+
+    /// Compute the natural logarithm of the probability density function.
+    ///
+    /// The logarithm is negative infinity if `x` is not positive definite.
+    pub fn log_density(&self, x: &[f64]) -> f64 {
+        use distribution::cholesky;
+        let d = self.dimension();
+        should!(x.len() == d * d);
+        let l = cholesky::decompose(x, d);
+        if !cholesky::is_positive_definite(&l, d) {
+            return f64::NEG_INFINITY;
+        }
+        let trace = self.inverse.iter().zip(x).map(|(a, x)| a * x).sum::<f64>();
+        0.5 * ((self.nu - d as f64 - 1.0) * cholesky::ln_det(&l, d) - trace) - self.ln_norm
+    }
+
+    /// Compute the mean matrix in row-major order.
+    pub fn mean(&self) -> Vec<f64> {
+        self.scale.iter().map(|scale| self.nu * scale).collect()
+    }
+
+    /// Draw a sample.
+    ///
+    /// The sample is obtained via the Bartlett decomposition.
+    ///
+    /// ## References
+    ///
+    /// 1. W. B. Smith and R. R. Hocking, “Algorithm AS 53: Wishart variate
+    ///    generator,” Journal of the Royal Statistical Society. Series C
+    ///    (Applied Statistics), vol. 21, no. 3, pp. 341–345, 1972.
+    pub fn sample<S>(&self, source: &mut S) -> Vec<f64>
+    where
+        S: Source,
+    {
+        use distribution::{gamma, gaussian};
+        let d = self.dimension();
+        let mut a = vec![0.0; d * d];
+        for i in 0..d {
+            for j in 0..i {
+                a[i * d + j] = gaussian::sample(source);
+            }
+            a[i * d + i] = (2.0 * gamma::sample(0.5 * (self.nu - i as f64), source)).sqrt();
+        }
+        let mut b = vec![0.0; d * d];
+        for i in 0..d {
+            for j in 0..=i {
+                b[i * d + j] = (j..=i)
+                    .map(|k| self.cholesky[i * d + k] * a[k * d + j])
+                    .sum();
+            }
+        }
+        let mut x = vec![0.0; d * d];
+        for i in 0..d {
+            for j in 0..=i {
+                let value = (0..=j).map(|k| b[i * d + k] * b[j * d + k]).sum();
+                x[i * d + j] = value;
+                x[j * d + i] = value;
+            }
+        }
+        x
+    }
+}
+
+/// Compute the natural logarithm of the multivariate gamma function of order
+/// `d`.
+pub fn ln_multivariate_gamma(a: f64, d: usize) -> f64 {
+    use core::f64::consts::PI;
+    use special::Gamma;
+    let sum = (0..d)
+        .map(|j| Gamma::ln_gamma(a - 0.5 * j as f64).0)
+        .sum::<f64>();
+    0.25 * (d * (d - 1)) as f64 * PI.ln() + sum
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        () => (Wishart::new(5.0, vec![2.0, 0.5, 0.5, 1.0]));
+    );
+
+    #[test]
+    fn density() {
+        let d = new!();
+        let x = vec![
+            vec![8.0, 1.0, 1.0, 4.0],
+            vec![3.0, -1.0, -1.0, 2.0],
+            vec![10.0, 5.0, 5.0, 5.0],
+        ];
+        let p = vec![
+            1.3968294806325900e-03,
+            1.6647179666184470e-03,
+            1.1264753876069275e-03,
+        ];
+
+        assert::close(
+            &x.iter().map(|x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn log_density() {
+        let d = new!();
+        let x = vec![
+            vec![8.0, 1.0, 1.0, 4.0],
+            vec![3.0, -1.0, -1.0, 2.0],
+            vec![10.0, 5.0, 5.0, 5.0],
+        ];
+        let p = vec![
+            -6.5735502672650421e+00,
+            -6.3980995593160879e+00,
+            -6.7886616468819874e+00,
+        ];
+
+        assert::close(
+            &x.iter().map(|x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+        assert_eq!(d.log_density(&[1.0, 2.0, 2.0, 1.0]), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!().mean(), vec![10.0, 2.5, 2.5, 5.0]);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!();
+        let mut source = source::default(42);
+
+        let mut mean = vec![0.0; 4];
+        for _ in 0..n {
+            let x = d.sample(&mut source);
+            assert_eq!(x[1], x[2]);
+            assert!(x[0] > 0.0 && x[3] > 0.0 && x[0] * x[3] > x[1] * x[2]);
+            for (mean, x) in mean.iter_mut().zip(&x) {
+                *mean += x / n as f64;
+            }
+        }
+        assert::close(&mean, &d.mean(), 0.1);
+    }
+}
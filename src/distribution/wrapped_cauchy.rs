@@ -0,0 +1,368 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A wrapped Cauchy distribution.
+///
+/// The distribution is a Cauchy distribution wrapped around the circle and is
+/// supported on the interval `[mu - pi, mu + pi]`.
+#[derive(Clone, Copy, Debug)]
+pub struct WrappedCauchy {
+    mu: f64,
+    gamma: f64,
+    rho: f64,
+    ratio: f64,
+}
+
+impl WrappedCauchy {
+    /// Create a wrapped Cauchy distribution with location `mu` and scale
+    /// `gamma` of the unwrapped distribution.
+    ///
+    /// It should hold that `gamma > 0`.
+    #[inline]
+    pub fn new(mu: f64, gamma: f64) -> Self {
+        should!(gamma > 0.0);
+        let rho = (-gamma).exp();
+        WrappedCauchy {
+            mu,
+            gamma,
+            rho,
+            ratio: -(-gamma).exp_m1() / (1.0 + rho),
+        }
+    }
+
+    /// Return the location parameter.
+    #[inline(always)]
+    pub fn mu(&self) -> f64 {
+        self.mu
+    }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn gamma(&self) -> f64 {
+        self.gamma
+    }
+
+    /// Compute the circular variance, that is, one minus the mean resultant
+    /// length `exp(-gamma)`.
+    #[inline]
+    pub fn circular_variance(&self) -> f64 {
+        -(-self.gamma).exp_m1()
+    }
+}
+
+impl distribution::CharacteristicFunction for WrappedCauchy {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use core::f64::consts::PI;
+        use distribution::{characteristic, Continuous};
+        characteristic::continuous(|x| self.density(x), t, self.mu - PI, self.mu + PI, 0.0, 0.0)
+    }
+}
+
+impl distribution::Continuous for WrappedCauchy {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        use core::f64::consts::{FRAC_PI_2, PI};
+        let z = x - self.mu;
+        if !(-PI..=PI).contains(&z) {
+            return 0.0;
+        }
+        let rho = self.rho;
+        let cos = (FRAC_PI_2 - z).sin();
+        (1.0 - rho * rho) / (2.0 * PI * (1.0 + rho * rho - 2.0 * rho * cos))
+    }
+
+    /// Compute the natural logarithm of the probability density function.
+    ///
+    /// The function is evaluated via `1 - rho`, which retains precision when
+    /// `gamma` is small.
+    fn log_density(&self, x: f64) -> f64 {
+        use core::f64::consts::PI;
+        let z = x - self.mu;
+        if !(-PI..=PI).contains(&z) {
+            return f64::NEG_INFINITY;
+        }
+        let rho = self.rho;
+        let one_m_rho = -(-self.gamma).exp_m1();
+        let sin = (0.5 * z).sin();
+        (one_m_rho * (1.0 + rho)).ln()
+            - (2.0 * PI).ln()
+            - (one_m_rho * one_m_rho + 4.0 * rho * sin * sin).ln()
+    }
+}
+
+impl distribution::Distribution for WrappedCauchy {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        use core::f64::consts::{FRAC_1_PI, PI};
+        let z = x - self.mu;
+        if z <= -PI {
+            0.0
+        } else if z >= PI {
+            1.0
+        } else {
+            0.5 + FRAC_1_PI * ((0.5 * z).tan() / self.ratio).atan()
+        }
+    }
+}
+
+impl distribution::Entropy for WrappedCauchy {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        use core::f64::consts::PI;
+        (-2.0 * PI * (-2.0 * self.gamma).exp_m1()).ln()
+    }
+}
+
+impl distribution::Inverse for WrappedCauchy {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        use core::f64::consts::PI;
+        should!((0.0..=1.0).contains(&p));
+        if p <= 0.0 {
+            self.mu - PI
+        } else if p >= 1.0 {
+            self.mu + PI
+        } else {
+            self.mu + 2.0 * (self.ratio * (PI * (p - 0.5)).tan()).atan()
+        }
+    }
+}
+
+impl distribution::Mean for WrappedCauchy {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.mu
+    }
+}
+
+impl distribution::Median for WrappedCauchy {
+    #[inline]
+    fn median(&self) -> f64 {
+        self.mu
+    }
+}
+
+impl distribution::Modes for WrappedCauchy {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![self.mu]
+    }
+}
+
+impl distribution::Sample for WrappedCauchy {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained by wrapping a draw from the corresponding Cauchy
+    /// distribution around the circle.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use core::f64::consts::PI;
+        let x = distribution::Cauchy::new(0.0, self.gamma).sample(source);
+        let z = x - 2.0 * PI * (x / (2.0 * PI)).round();
+        self.mu + z.clamp(-PI, PI)
+    }
+}
+
+impl distribution::Support for WrappedCauchy {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::f64::consts::PI;
+        use core::ops::Bound::Included;
+        (Included(self.mu - PI), Included(self.mu + PI))
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($mu:expr, $gamma:expr) => (WrappedCauchy::new($mu, $gamma));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(1.0, 0.5);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.585887242266897, 0.9073363364411319, -0.15309186567422628];
+        let im = vec![
+            -0.4934860164229644,
+            0.28067201944923503,
+            0.33451182923926215,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn circular_variance() {
+        assert::close(
+            new!(1.0, 0.5).circular_variance(),
+            3.9346934028736658e-01,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(1.0, 0.5);
+        let x = vec![-3.0, -2.0, 0.0, 0.5, 1.0, 2.0, 3.0, 4.1, 4.2];
+        let p = vec![
+            0.0000000000000000e+00,
+            3.9164228678167178e-02,
+            1.4120816073501438e-01,
+            3.3168199037227547e-01,
+            6.4982774905717045e-01,
+            1.4120816073501438e-01,
+            5.3722214562342351e-02,
+            3.8995867042926638e-02,
+            0.0000000000000000e+00,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(1.0, 0.5);
+        let x = vec![-3.0, -2.0, 0.0, 0.5, 1.0, 2.0, 3.0, 4.1, 4.2];
+        let p = vec![
+            0.0000000000000000e+00,
+            5.5279676881791395e-03,
+            1.3415375843964750e-01,
+            2.4336876331125221e-01,
+            5.0000000000000000e-01,
+            8.6584624156035250e-01,
+            9.5034907773832022e-01,
+            9.9837849796452416e-01,
+            1.0000000000000000e+00,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(1.0, 0.5).entropy(), 1.3792019210222637e+00, 1e-15);
+    }
+
+    #[test]
+    fn inverse() {
+        let d = new!(1.0, 0.5);
+        let p = vec![
+            0.0, 0.01, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.99, 1.0,
+        ];
+        let x = vec![
+            -2.1415926535897931e+00,
+            -1.8863611428849438e+00,
+            -2.9183456848608919e-01,
+            3.4972366239939595e-01,
+            6.4779883980228059e-01,
+            8.4117690592427230e-01,
+            1.0000000000000000e+00,
+            1.1588230940757276e+00,
+            1.3522011601977193e+00,
+            1.6502763376006042e+00,
+            2.2918345684860895e+00,
+            3.8863611428849434e+00,
+            4.1415926535897931e+00,
+        ];
+
+        assert::close(
+            &p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(),
+            &x,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn log_density() {
+        use core::f64::consts::PI;
+
+        let d = new!(1.0, 0.5);
+        let x = vec![-3.0, -2.0, 2.0, 5.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -3.2399914825183083,
+            -1.9575201600193389,
+            f64::NEG_INFINITY,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+
+        let d = new!(0.0, 1e-12);
+        assert::close(d.log_density(0.0), 26.486291230079148, 1e-13);
+        assert::close(d.log_density(PI), -30.162045362897839, 1e-13);
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(1.0, 0.5).mean(), 1.0);
+    }
+
+    #[test]
+    fn median() {
+        assert_eq!(new!(1.0, 0.5).median(), 1.0);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(1.0, 0.5).modes(), vec![1.0]);
+    }
+
+    #[test]
+    fn sample() {
+        use core::f64::consts::PI;
+
+        let n = 100000;
+        let d = new!(1.0, 0.5);
+        let mut source = source::default(42);
+
+        let samples = (0..n).map(|_| d.sample(&mut source)).collect::<Vec<_>>();
+        assert!(samples.iter().all(|&x| (1.0 - PI..=1.0 + PI).contains(&x)));
+        let fraction = samples.iter().filter(|&&x| x <= 0.0).count() as f64 / n as f64;
+        assert!((fraction - d.distribution(0.0)).abs() < 0.01);
+    }
+
+    #[test]
+    fn support() {
+        use core::f64::consts::PI;
+        use core::ops::Bound::Included;
+        assert_eq!(
+            new!(1.0, 0.5).support(),
+            (Included(1.0 - PI), Included(1.0 + PI))
+        );
+    }
+}
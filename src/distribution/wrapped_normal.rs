@@ -0,0 +1,414 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+const IMAGE_THRESHOLD: f64 = 2.5;
+
+/// A wrapped normal distribution.
+///
+/// The distribution is a Gaussian distribution wrapped around the circle and
+/// is supported on the interval `[mu - pi, mu + pi]`.
+#[derive(Clone, Copy, Debug)]
+pub struct WrappedNormal {
+    mu: f64,
+    sigma: f64,
+}
+
+impl WrappedNormal {
+    /// Create a wrapped normal distribution with location `mu` and standard
+    /// deviation `sigma` of the unwrapped distribution.
+    ///
+    /// It should hold that `sigma > 0`.
+    #[inline]
+    pub fn new(mu: f64, sigma: f64) -> Self {
+        should!(sigma > 0.0);
+        WrappedNormal { mu, sigma }
+    }
+
+    /// Return the location parameter.
+    #[inline(always)]
+    pub fn mu(&self) -> f64 {
+        self.mu
+    }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn sigma(&self) -> f64 {
+        self.sigma
+    }
+
+    /// Compute the circular variance, that is, one minus the mean resultant
+    /// length.
+    #[inline]
+    pub fn circular_variance(&self) -> f64 {
+        -(-0.5 * self.sigma * self.sigma).exp_m1()
+    }
+
+    /// Compute the mean resultant length `exp(-sigma^2 / 2)`.
+    #[inline]
+    pub fn resultant_length(&self) -> f64 {
+        (-0.5 * self.sigma * self.sigma).exp()
+    }
+
+    /// Sum `term(k)` over all integers `k`, starting from zero and proceeding
+    /// outward until the terms become negligible.
+    fn sum<F>(term: F) -> f64
+    where
+        F: Fn(f64) -> f64,
+    {
+        let mut sum = term(0.0);
+        let mut k = 1.0;
+        loop {
+            let delta = term(k) + term(-k);
+            sum += delta;
+            if delta.abs() <= f64::EPSILON * sum.abs() {
+                return sum;
+            }
+            k += 1.0;
+        }
+    }
+}
+
+impl distribution::CharacteristicFunction for WrappedNormal {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by numerical integration.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use core::f64::consts::PI;
+        use distribution::{characteristic, Continuous};
+        characteristic::continuous(|x| self.density(x), t, self.mu - PI, self.mu + PI, 0.0, 0.0)
+    }
+}
+
+impl distribution::Continuous for WrappedNormal {
+    /// Compute the probability density function.
+    ///
+    /// The function is a Jacobi theta function, which is evaluated by summing
+    /// the wrapped Gaussian densities for small `sigma` and via its Fourier
+    /// series otherwise.
+    fn density(&self, x: f64) -> f64 {
+        use core::f64::consts::{FRAC_PI_2, PI};
+        let z = x - self.mu;
+        if !(-PI..=PI).contains(&z) {
+            return 0.0;
+        }
+        let sigma = self.sigma;
+        if sigma < IMAGE_THRESHOLD {
+            let norm = sigma * (2.0 * PI).sqrt();
+            Self::sum(|k| {
+                let y = (z + 2.0 * PI * k) / sigma;
+                (-0.5 * y * y).exp() / norm
+            })
+        } else {
+            let sum = Self::sum(|n| {
+                if n <= 0.0 {
+                    return 0.0;
+                }
+                (-0.5 * n * n * sigma * sigma).exp() * (FRAC_PI_2 - n * z).sin()
+            });
+            (1.0 + 2.0 * sum) / (2.0 * PI)
+        }
+    }
+
+    /// Compute the natural logarithm of the probability density function.
+    ///
+    /// For small `sigma`, the wrapped Gaussian densities are summed relative
+    /// to the central one so that the result does not underflow.
+    fn log_density(&self, x: f64) -> f64 {
+        use core::f64::consts::PI;
+        let z = x - self.mu;
+        if !(-PI..=PI).contains(&z) {
+            return f64::NEG_INFINITY;
+        }
+        let sigma = self.sigma;
+        if sigma < IMAGE_THRESHOLD {
+            let y = z / sigma;
+            let sum = Self::sum(|k| (-2.0 * PI * k * (z + PI * k) / (sigma * sigma)).exp());
+            -0.5 * y * y - (sigma * (2.0 * PI).sqrt()).ln() + sum.ln()
+        } else {
+            self.density(x).ln()
+        }
+    }
+}
+
+impl distribution::Distribution for WrappedNormal {
+    type Value = f64;
+
+    /// Compute the cumulative distribution function.
+    ///
+    /// The function is evaluated in the same way as the density.
+    fn distribution(&self, x: f64) -> f64 {
+        use core::f64::consts::{FRAC_1_SQRT_2, PI};
+        use special::Error;
+        let z = x - self.mu;
+        if z <= -PI {
+            return 0.0;
+        }
+        if z >= PI {
+            return 1.0;
+        }
+        let sigma = self.sigma;
+        let p = if sigma < IMAGE_THRESHOLD {
+            let phi = |y: f64| 0.5 * (-y * FRAC_1_SQRT_2 / sigma).compl_error();
+            Self::sum(|k| phi(z + 2.0 * PI * k) - phi(2.0 * PI * k - PI))
+        } else {
+            let sum = Self::sum(|n| {
+                if n <= 0.0 {
+                    return 0.0;
+                }
+                (-0.5 * n * n * sigma * sigma).exp() * (n * z).sin() / n
+            });
+            0.5 + z / (2.0 * PI) + sum / PI
+        };
+        p.clamp(0.0, 1.0)
+    }
+}
+
+impl distribution::Mean for WrappedNormal {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.mu
+    }
+}
+
+impl distribution::Median for WrappedNormal {
+    #[inline]
+    fn median(&self) -> f64 {
+        self.mu
+    }
+}
+
+impl distribution::Modes for WrappedNormal {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![self.mu]
+    }
+}
+
+impl distribution::Sample for WrappedNormal {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained by wrapping a draw from the corresponding
+    /// Gaussian distribution around the circle.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64
+    where
+        S: Source,
+    {
+        use core::f64::consts::PI;
+        use distribution::gaussian;
+        let x = self.sigma * gaussian::sample(source);
+        let z = x - 2.0 * PI * (x / (2.0 * PI)).round();
+        self.mu + z.clamp(-PI, PI)
+    }
+}
+
+impl distribution::Support for WrappedNormal {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::f64::consts::PI;
+        use core::ops::Bound::Included;
+        (Included(self.mu - PI), Included(self.mu + PI))
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($mu:expr, $sigma:expr) => (WrappedNormal::new($mu, $sigma));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(1.0, 1.0);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.5990314199948271, 0.9135111195692086, -0.0563193499921279];
+        let im = vec![
+            -0.5045572045939417,
+            0.28258210370422704,
+            0.12306002480577671,
+        ];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn circular_variance() {
+        assert::close(
+            new!(1.0, 1.0).circular_variance(),
+            1.0 - 6.0653065971263342e-01,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn density() {
+        let x = vec![-3.0, -2.0, 0.0, 0.5, 1.0, 2.0, 3.0, 4.1, 4.2];
+
+        let d = new!(1.0, 1.0);
+        let p = vec![
+            0.0000000000000000e+00,
+            6.2524443120396409e-03,
+            2.4197107116625602e-01,
+            3.5206534860066790e-01,
+            3.9894228253600367e-01,
+            2.4197107116625602e-01,
+            5.4032385269819107e-02,
+            5.7823462360883779e-03,
+            0.0000000000000000e+00,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+
+        let d = new!(1.0, 3.0);
+        let p = vec![
+            0.0000000000000000e+00,
+            1.5565423188998972e-01,
+            1.6106550590809210e-01,
+            1.6225816841288507e-01,
+            1.6269105136345249e-01,
+            1.6106550590809210e-01,
+            1.5768340166964911e-01,
+            1.5562190269736065e-01,
+            0.0000000000000000e+00,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn distribution() {
+        let x = vec![-3.0, -2.0, 0.0, 0.5, 1.0, 2.0, 3.0, 4.1, 4.2];
+
+        let d = new!(1.0, 1.0);
+        let p = vec![
+            0.0000000000000000e+00,
+            8.3669217537461792e-04,
+            1.5865519045319176e-01,
+            3.0853753506690251e-01,
+            5.0000000000000000e-01,
+            8.4134480954680824e-01,
+            9.7725907988215888e-01,
+            9.9976071857534510e-01,
+            1.0000000000000000e+00,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+
+        let d = new!(1.0, 3.0);
+        let p = vec![
+            0.0000000000000000e+00,
+            2.2036156457943004e-02,
+            3.3786952627370770e-01,
+            4.1872722812591900e-01,
+            5.0000000000000000e-01,
+            6.6213047372629230e-01,
+            8.2152525409352062e-01,
+            9.9352735690626315e-01,
+            1.0000000000000000e+00,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn log_density() {
+        use core::f64::consts::PI;
+
+        let d = new!(1.0, 0.5);
+        let x = vec![-3.0, -1.0, 4.0, 5.0];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -8.2257913526443811,
+            -18.197710983711797,
+            f64::NEG_INFINITY,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_density(x)).collect::<Vec<_>>(),
+            &p,
+            1e-13,
+        );
+
+        let d = new!(1.0, 3.0);
+        assert::close(d.log_density(-2.0), -1.8601181939643471, 1e-14);
+        assert::close(d.log_density(2.0), -1.8259441277520579, 1e-14);
+
+        let d = new!(0.0, 0.01);
+        assert::close(d.log_density(1.0), -4996.3137683472164, 1e-10);
+        assert::close(d.log_density(PI), -49343.642626613448, 1e-9);
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(1.0, 1.0).mean(), 1.0);
+    }
+
+    #[test]
+    fn median() {
+        assert_eq!(new!(1.0, 1.0).median(), 1.0);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(1.0, 1.0).modes(), vec![1.0]);
+    }
+
+    #[test]
+    fn resultant_length() {
+        assert::close(
+            new!(1.0, 1.0).resultant_length(),
+            6.0653065971263342e-01,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn sample() {
+        use core::f64::consts::PI;
+
+        let n = 100000;
+        let d = new!(1.0, 3.0);
+        let mut source = source::default(42);
+
+        let samples = (0..n).map(|_| d.sample(&mut source)).collect::<Vec<_>>();
+        assert!(samples.iter().all(|&x| (1.0 - PI..=1.0 + PI).contains(&x)));
+        let fraction = samples.iter().filter(|&&x| x <= 0.0).count() as f64 / n as f64;
+        assert!((fraction - d.distribution(0.0)).abs() < 0.01);
+    }
+
+    #[test]
+    fn support() {
+        use core::f64::consts::PI;
+        use core::ops::Bound::Included;
+        assert_eq!(
+            new!(1.0, 1.0).support(),
+            (Included(1.0 - PI), Included(1.0 + PI))
+        );
+    }
+}
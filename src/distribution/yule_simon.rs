@@ -0,0 +1,335 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A Yule–Simon distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct YuleSimon {
+    rho: f64,
+}
+
+impl YuleSimon {
+    /// Create a Yule–Simon distribution with shape `rho`.
+    ///
+    /// It should hold that `rho > 0`.
+    #[inline]
+    pub fn new(rho: f64) -> Self {
+        should!(rho > 0.0);
+        YuleSimon { rho }
+    }
+
+    /// Return the shape parameter.
+    #[inline(always)]
+    pub fn rho(&self) -> f64 {
+        self.rho
+    }
+}
+
+impl distribution::CharacteristicFunction for YuleSimon {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by summing the probability mass function.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Discrete};
+        characteristic::discrete(|x| self.mass(x), t, 1, usize::MAX, 1.0)
+    }
+}
+
+impl distribution::Discrete for YuleSimon {
+    #[inline]
+    fn mass(&self, x: usize) -> f64 {
+        use special::Beta;
+        if x < 1 {
+            0.0
+        } else {
+            let (a, b) = (x as f64, self.rho + 1.0);
+            self.rho * a.ln_beta(b).exp()
+        }
+    }
+
+    #[inline]
+    fn log_mass(&self, x: usize) -> f64 {
+        use special::Beta;
+        if x < 1 {
+            f64::NEG_INFINITY
+        } else {
+            let (a, b) = (x as f64, self.rho + 1.0);
+            self.rho.ln() + a.ln_beta(b)
+        }
+    }
+}
+
+impl distribution::Distribution for YuleSimon {
+    type Value = usize;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        use special::Beta;
+        if x < 1.0 {
+            0.0
+        } else {
+            let (a, b) = (x.floor(), self.rho + 1.0);
+            1.0 - a * a.ln_beta(b).exp()
+        }
+    }
+}
+
+impl distribution::Kurtosis for YuleSimon {
+    /// Compute the excess kurtosis.
+    ///
+    /// The excess kurtosis is undefined, which is signified by `NaN`, if
+    /// `rho <= 4`.
+    fn kurtosis(&self) -> f64 {
+        let rho = self.rho;
+        if rho <= 4.0 {
+            return f64::NAN;
+        }
+        rho + 3.0 + (11.0 * rho * rho * rho - 49.0 * rho - 22.0) / ((rho - 4.0) * (rho - 3.0) * rho)
+    }
+}
+
+impl distribution::Mean for YuleSimon {
+    /// Compute the expected value.
+    ///
+    /// The expected value is infinite if `rho <= 1`.
+    #[inline]
+    fn mean(&self) -> f64 {
+        if self.rho <= 1.0 {
+            f64::INFINITY
+        } else {
+            self.rho / (self.rho - 1.0)
+        }
+    }
+}
+
+impl distribution::Modes for YuleSimon {
+    #[inline]
+    fn modes(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+impl distribution::Moments for YuleSimon {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is infinite if `n >= rho`. Otherwise, it is computed from the
+    /// factorial moments of `X - 1`, which are `(k!)^2 / ((rho - 1) … (rho -
+    /// k))`.
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::moments;
+        let rho = self.rho;
+        if n as f64 >= rho {
+            return f64::INFINITY;
+        }
+        let factorial = |k: usize| {
+            (1..=k).fold(1.0, |product, j| {
+                product * (j * j) as f64 / (rho - j as f64)
+            })
+        };
+        // The Stirling numbers of the second kind convert the factorial moments.
+        let moment = |k: usize| {
+            let mut stirling = vec![1.0];
+            for i in 1..=k {
+                let mut next = vec![0.0; i + 1];
+                for j in 1..=i {
+                    let previous = if j < i { stirling[j] } else { 0.0 };
+                    next[j] = j as f64 * previous + stirling[j - 1];
+                }
+                stirling = next;
+            }
+            stirling
+                .iter()
+                .enumerate()
+                .map(|(j, &s)| s * factorial(j))
+                .sum::<f64>()
+        };
+        moments::shift(moment, 1.0, 1.0, n)
+    }
+}
+
+impl distribution::Sample for YuleSimon {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained by drawing a geometric variate whose success
+    /// probability `exp(-w)` is given by an exponential variate `w` with rate
+    /// `rho`.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> usize
+    where
+        S: Source,
+    {
+        let w = -source.read::<f64>().ln() / self.rho;
+        let ln_q = (-(-w).exp_m1()).ln();
+        (source.read::<f64>().ln() / ln_q).floor() as usize + 1
+    }
+}
+
+impl distribution::Skewness for YuleSimon {
+    /// Compute the skewness.
+    ///
+    /// The skewness is undefined, which is signified by `NaN`, if `rho <= 3`.
+    fn skewness(&self) -> f64 {
+        let rho = self.rho;
+        if rho <= 3.0 {
+            return f64::NAN;
+        }
+        (rho + 1.0).powi(2) * (rho - 2.0).sqrt() / ((rho - 3.0) * rho)
+    }
+}
+
+impl distribution::Support for YuleSimon {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Included, Unbounded};
+        (Included(1.0), Unbounded)
+    }
+}
+
+impl distribution::Variance for YuleSimon {
+    /// Compute the variance.
+    ///
+    /// The variance is infinite if `1 < rho <= 2` and undefined, which is
+    /// signified by `NaN`, if `rho <= 1`.
+    fn variance(&self) -> f64 {
+        let rho = self.rho;
+        if rho > 2.0 {
+            rho * rho / ((rho - 1.0).powi(2) * (rho - 2.0))
+        } else if rho > 1.0 {
+            f64::INFINITY
+        } else {
+            f64::NAN
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($rho:expr) => (YuleSimon::new($rho));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(2.5);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.5078555447945173, 0.8466412473445909, -0.355415027621489];
+        let im = vec![-0.6583449105756483, 0.3984727088986088, 0.5309607463527511];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(2.5);
+        let x = vec![0.0, 1.0, 1.5, 2.0, 3.0, 10.0, 100.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            7.1428571428571430e-01,
+            7.1428571428571430e-01,
+            8.7301587301587302e-01,
+            9.3073593073593075e-01,
+            9.9294973434157707e-01,
+            9.9996817538892790e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(5.0).kurtosis(), 118.8, 1e-12);
+        assert!(new!(4.0).kurtosis().is_nan());
+    }
+
+    #[test]
+    fn log_mass() {
+        let d = new!(2.5);
+        let x = vec![0, 1, 20, 10000];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -0.33647223662121295,
+            -8.576345583598417,
+            -30.11936442393288,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_mass(x)).collect::<Vec<_>>(),
+            &p,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn mass() {
+        let d = new!(2.5);
+        let x = vec![0, 1, 2, 3, 5, 10, 100];
+        let p = vec![
+            0.0000000000000000e+00,
+            7.1428571428571430e-01,
+            1.5873015873015872e-01,
+            5.7720057720057720e-02,
+            1.4208014208014208e-02,
+            1.7625664146057201e-03,
+            7.9561527680132471e-07,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.mass(x)).collect::<Vec<_>>(), &p, 1e-14);
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(2.5).mean(), 1.6666666666666667e+00, 1e-15);
+        assert_eq!(new!(1.0).mean(), f64::INFINITY);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(2.5).modes(), vec![1]);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(2.5);
+        let mut source = source::default(42);
+
+        let ones = (0..n).filter(|_| d.sample(&mut source) == 1).count() as f64 / n as f64;
+        assert!((ones - d.mass(1)).abs() < 0.01);
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(5.0).skewness(), 6.2353829072479581e+00, 1e-14);
+        assert!(new!(3.0).skewness().is_nan());
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Included, Unbounded};
+        assert_eq!(new!(2.5).support(), (Included(1.0), Unbounded));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(2.5).variance(), 5.5555555555555554e+00, 1e-14);
+        assert_eq!(new!(2.0).variance(), f64::INFINITY);
+        assert!(new!(1.0).variance().is_nan());
+    }
+}
@@ -0,0 +1,329 @@
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A zero-truncated Poisson distribution.
+///
+/// The distribution is a Poisson distribution conditioned on the outcome
+/// being positive.
+#[derive(Clone, Copy, Debug)]
+pub struct ZeroTruncatedPoisson {
+    lambda: f64,
+    ln_lambda: f64,
+    norm: f64,
+}
+
+impl ZeroTruncatedPoisson {
+    /// Create a zero-truncated Poisson distribution with rate `lambda` of the
+    /// underlying Poisson distribution.
+    ///
+    /// It should hold that `lambda > 0`.
+    #[inline]
+    pub fn new(lambda: f64) -> Self {
+        should!(lambda > 0.0);
+        ZeroTruncatedPoisson {
+            lambda,
+            ln_lambda: lambda.ln(),
+            norm: -(-lambda).exp_m1(),
+        }
+    }
+
+    /// Return the rate parameter.
+    #[inline(always)]
+    pub fn lambda(&self) -> f64 {
+        self.lambda
+    }
+
+    /// Compute the `k`th raw moment for `k <= 4`.
+    ///
+    /// The moment is the corresponding moment of the underlying Poisson
+    /// distribution, a Touchard polynomial in `lambda`, renormalized.
+    fn moment(&self, k: i32) -> f64 {
+        let lambda = self.lambda;
+        let polynomial = match k {
+            1 => 1.0,
+            2 => 1.0 + lambda,
+            3 => 1.0 + lambda * (3.0 + lambda),
+            _ => 1.0 + lambda * (7.0 + lambda * (6.0 + lambda)),
+        };
+        lambda * polynomial / self.norm
+    }
+}
+
+impl distribution::CharacteristicFunction for ZeroTruncatedPoisson {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::complex;
+        let lambda = self.lambda;
+        let (re, im) = complex::exp((-2.0 * lambda * (0.5 * t).sin().powi(2), lambda * t.sin()));
+        ((re - (-lambda).exp()) / self.norm, im / self.norm)
+    }
+}
+
+impl distribution::Discrete for ZeroTruncatedPoisson {
+    #[inline]
+    fn mass(&self, x: usize) -> f64 {
+        use special::Gamma;
+        if x == 0 {
+            return 0.0;
+        }
+        let x = x as f64;
+        (x * self.ln_lambda - self.lambda - Gamma::ln_gamma(x + 1.0).0).exp() / self.norm
+    }
+
+    #[inline]
+    fn log_mass(&self, x: usize) -> f64 {
+        use special::Gamma;
+        if x == 0 {
+            return f64::NEG_INFINITY;
+        }
+        let x = x as f64;
+        x * self.ln_lambda - self.lambda - Gamma::ln_gamma(x + 1.0).0 - self.norm.ln()
+    }
+}
+
+impl distribution::Distribution for ZeroTruncatedPoisson {
+    type Value = usize;
+
+    /// Compute the cumulative distribution function.
+    ///
+    /// The function is evaluated via the upper tail of the underlying Poisson
+    /// distribution, which is a regularized incomplete gamma function.
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        use special::Gamma;
+        if x < 1.0 {
+            return 0.0;
+        }
+        (1.0 - self.lambda.inc_gamma(x.floor() + 1.0) / self.norm).max(0.0)
+    }
+}
+
+impl distribution::Kurtosis for ZeroTruncatedPoisson {
+    fn kurtosis(&self) -> f64 {
+        let (m1, m2, m3, m4) = (
+            self.moment(1),
+            self.moment(2),
+            self.moment(3),
+            self.moment(4),
+        );
+        let variance = m2 - m1 * m1;
+        (m4 - 4.0 * m1 * m3 + 6.0 * m1 * m1 * m2 - 3.0 * m1.powi(4)) / (variance * variance) - 3.0
+    }
+}
+
+impl distribution::Mean for ZeroTruncatedPoisson {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.lambda / self.norm
+    }
+}
+
+impl distribution::Mgf for ZeroTruncatedPoisson {
+    fn mgf(&self, t: f64) -> f64 {
+        let lambda = self.lambda;
+        ((lambda * t.exp_m1()).exp() - (-lambda).exp()) / self.norm
+    }
+}
+
+impl distribution::Moments for ZeroTruncatedPoisson {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is that of the underlying Poisson distribution, whose
+    /// cumulants all equal `lambda`, renormalized.
+    fn raw_moment(&self, n: usize) -> f64 {
+        use distribution::moments;
+        if n == 0 {
+            1.0
+        } else {
+            moments::cumulants(|_| self.lambda, n) / self.norm
+        }
+    }
+}
+
+impl distribution::Sample for ZeroTruncatedPoisson {
+    /// Draw a sample.
+    ///
+    /// The arrival time of the first event of a unit-time Poisson process
+    /// conditioned on at least one event is drawn by inversion, and the
+    /// remaining events are then counted in the rest of the interval, which
+    /// avoids rejecting zero outcomes.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> usize
+    where
+        S: Source,
+    {
+        use distribution::poisson;
+        let rest = self.lambda + (-source.read::<f64>() * self.norm).ln_1p();
+        1 + poisson::sample(rest.max(0.0), source)
+    }
+}
+
+impl distribution::Skewness for ZeroTruncatedPoisson {
+    fn skewness(&self) -> f64 {
+        let (m1, m2, m3) = (self.moment(1), self.moment(2), self.moment(3));
+        let variance = m2 - m1 * m1;
+        (m3 - 3.0 * m1 * m2 + 2.0 * m1.powi(3)) / variance.powf(1.5)
+    }
+}
+
+impl distribution::Support for ZeroTruncatedPoisson {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Included, Unbounded};
+        (Included(1.0), Unbounded)
+    }
+}
+
+impl distribution::Variance for ZeroTruncatedPoisson {
+    #[inline]
+    fn variance(&self) -> f64 {
+        let mean = self.lambda / self.norm;
+        mean * (1.0 + self.lambda - mean)
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($lambda:expr) => (ZeroTruncatedPoisson::new($lambda));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(1.5);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            0.22689489056194662,
+            0.8002396329096076,
+            -0.25561822774229936,
+        ];
+        let im = vec![-0.7443141589791916, 0.5163179310129903, 0.15057625882459544];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(1.5);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 2.5, 3.0, 5.0, 8.0, 12.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            0.0000000000000000e+00,
+            4.3082537518330238e-01,
+            7.5394440657077910e-01,
+            9.1550392226451749e-01,
+            9.9426418616521495e-01,
+            9.9996429798426445e-01,
+            9.9999998995546235e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn log_mass() {
+        let d = new!(1.5);
+        let x = vec![0, 1, 5, 100, 10000];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -0.8420524329663817,
+            -4.00768374331577,
+            -324.44038228582167,
+            -78055.5242732738,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_mass(x)).collect::<Vec<_>>(),
+            &p,
+            1e-9,
+        );
+    }
+
+    #[test]
+    fn mass() {
+        let d = new!(1.5);
+        let x = vec![0, 1, 2, 3, 5, 8, 12];
+        let p = vec![
+            0.0000000000000000e+00,
+            4.3082537518330238e-01,
+            3.2311903138747677e-01,
+            1.6155951569373839e-01,
+            1.8175445515545570e-02,
+            1.8256585897311398e-04,
+            7.7797951266951976e-08,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.mass(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(1.5).kurtosis(), 1.5496655901514802e+00, 1e-13);
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(1.5).mean(), 1.9308253751833024e+00, 1e-15);
+    }
+
+    #[test]
+    fn mgf() {
+        let d = new!(1.5);
+        let t = vec![-0.7, -0.1, 0.2, 0.45];
+        let m = vec![
+            0.3177148645736598,
+            0.8287693810698752,
+            1.5070321660583457,
+            2.731858820111413,
+        ];
+        assert::close(&t.iter().map(|&t| d.mgf(t)).collect::<Vec<_>>(), &m, 1e-14);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let mut source = source::default(42);
+
+        let d = new!(1.5);
+        let mean = (0..n).map(|_| d.sample(&mut source) as f64).sum::<f64>() / n as f64;
+        assert!((mean - d.mean()).abs() < 0.02);
+
+        let d = new!(1e-6);
+        for x in Independent(&d, &mut source).take(100) {
+            assert_eq!(x, 1);
+        }
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(1.5).skewness(), 1.2150327841557029e+00, 1e-14);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Included, Unbounded};
+        assert_eq!(new!(1.5).support(), (Included(1.0), Unbounded));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(1.5).variance(), 1.0989768085065155e+00, 1e-15);
+    }
+}
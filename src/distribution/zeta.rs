@@ -0,0 +1,345 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A zeta distribution.
+///
+/// The distribution has support `{1, 2, …}` with probabilities proportional to
+/// `k^(-s)`.
+#[derive(Clone, Copy, Debug)]
+pub struct Zeta {
+    s: f64,
+    norm: f64,
+}
+
+impl Zeta {
+    /// Create a zeta distribution with exponent `s`.
+    ///
+    /// It should hold that `s > 1`.
+    #[inline]
+    pub fn new(s: f64) -> Self {
+        use distribution::harmonic::zeta;
+        should!(s > 1.0);
+        Zeta { s, norm: zeta(s) }
+    }
+
+    /// Return the exponent parameter.
+    #[inline(always)]
+    pub fn s(&self) -> f64 {
+        self.s
+    }
+
+    /// Compute the `k`th raw moment for `k < s - 1`.
+    #[inline]
+    fn moment(&self, k: i32) -> f64 {
+        use distribution::harmonic::zeta;
+        zeta(self.s - k as f64) / self.norm
+    }
+}
+
+impl distribution::CharacteristicFunction for Zeta {
+    /// Compute the characteristic function.
+    ///
+    /// The function is computed by summing the probability mass function.
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Discrete};
+        characteristic::discrete(|x| self.mass(x), t, 1, usize::MAX, 1.0)
+    }
+}
+
+impl distribution::Discrete for Zeta {
+    #[inline]
+    fn mass(&self, x: usize) -> f64 {
+        if x < 1 {
+            0.0
+        } else {
+            (x as f64).powf(-self.s) / self.norm
+        }
+    }
+
+    #[inline]
+    fn log_mass(&self, x: usize) -> f64 {
+        if x < 1 {
+            f64::NEG_INFINITY
+        } else {
+            -self.s * (x as f64).ln() - self.norm.ln()
+        }
+    }
+}
+
+impl distribution::Distribution for Zeta {
+    type Value = usize;
+
+    /// Compute the cumulative distribution function.
+    ///
+    /// The complementary probability is computed as the tail of the series,
+    /// which is summed directly near the origin and bounded by the
+    /// Euler–Maclaurin formula further out.
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        use distribution::harmonic::tail;
+        if x < 1.0 {
+            0.0
+        } else {
+            (1.0 - tail(x as usize, self.s) / self.norm).max(0.0)
+        }
+    }
+}
+
+impl distribution::Kurtosis for Zeta {
+    /// Compute the excess kurtosis.
+    ///
+    /// The excess kurtosis is undefined, which is signified by `NaN`, if
+    /// `s <= 5`.
+    fn kurtosis(&self) -> f64 {
+        if self.s <= 5.0 {
+            return f64::NAN;
+        }
+        let (m1, m2, m3, m4) = (
+            self.moment(1),
+            self.moment(2),
+            self.moment(3),
+            self.moment(4),
+        );
+        let m1_2 = m1 * m1;
+        (m4 - 4.0 * m1 * m3 + 6.0 * m1_2 * m2 - 3.0 * m1_2 * m1_2) / (m2 - m1_2).powi(2) - 3.0
+    }
+}
+
+impl distribution::Mean for Zeta {
+    /// Compute the expected value.
+    ///
+    /// The expected value is infinite if `s <= 2`.
+    #[inline]
+    fn mean(&self) -> f64 {
+        if self.s <= 2.0 {
+            f64::INFINITY
+        } else {
+            self.moment(1)
+        }
+    }
+}
+
+impl distribution::Modes for Zeta {
+    #[inline]
+    fn modes(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+impl distribution::Moments for Zeta {
+    /// Compute the `n`th raw moment.
+    ///
+    /// The moment is infinite if `n >= s - 1`.
+    fn raw_moment(&self, n: usize) -> f64 {
+        if n as f64 >= self.s - 1.0 {
+            f64::INFINITY
+        } else {
+            self.moment(n as i32)
+        }
+    }
+}
+
+impl distribution::Sample for Zeta {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained by rejection from a continuous Pareto-like
+    /// envelope.
+    ///
+    /// ## References
+    ///
+    /// 1. L. Devroye, Non-Uniform Random Variate Generation. Springer-Verlag,
+    ///    1986, pp. 550–551.
+    fn sample<S>(&self, source: &mut S) -> usize
+    where
+        S: Source,
+    {
+        let exponent = self.s - 1.0;
+        let b = 2f64.powf(exponent);
+        loop {
+            let u = source.read::<f64>();
+            let v = source.read::<f64>();
+            let x = u.powf(-1.0 / exponent).floor();
+            let t = (1.0 + 1.0 / x).powf(exponent);
+            if v * x * (t - 1.0) / (b - 1.0) <= t / b {
+                return x as usize;
+            }
+        }
+    }
+}
+
+impl distribution::Skewness for Zeta {
+    /// Compute the skewness.
+    ///
+    /// The skewness is undefined, which is signified by `NaN`, if `s <= 4`.
+    fn skewness(&self) -> f64 {
+        if self.s <= 4.0 {
+            return f64::NAN;
+        }
+        let (m1, m2, m3) = (self.moment(1), self.moment(2), self.moment(3));
+        (m3 - 3.0 * m1 * m2 + 2.0 * m1 * m1 * m1) / (m2 - m1 * m1).powf(1.5)
+    }
+}
+
+impl distribution::Support for Zeta {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::{Included, Unbounded};
+        (Included(1.0), Unbounded)
+    }
+}
+
+impl distribution::Variance for Zeta {
+    /// Compute the variance.
+    ///
+    /// The variance is infinite if `2 < s <= 3` and undefined, which is
+    /// signified by `NaN`, if `s <= 2`.
+    fn variance(&self) -> f64 {
+        if self.s > 3.0 {
+            let m1 = self.moment(1);
+            self.moment(2) - m1 * m1
+        } else if self.s > 2.0 {
+            f64::INFINITY
+        } else {
+            f64::NAN
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($s:expr) => (Zeta::new($s));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(3.5);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![0.6733216893756572, 0.9248477577595693, -0.40484295633759976];
+        let im = vec![-0.6642698559970085, 0.3337671678487034, 0.7472626982694934];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-12);
+        assert::close(&y, &im, 1e-12);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(3.5);
+        let x = vec![0.0, 1.0, 1.5, 2.0, 3.0, 5.5, 10.0, 100.0, 1e6];
+        let p = vec![
+            0.0000000000000000e+00,
+            8.8752102781925657e-01,
+            8.8752102781925657e-01,
+            9.6596754497133797e-01,
+            9.8494571179811208e-01,
+            9.9505476158741291e-01,
+            9.9900954172935408e-01,
+            9.9999649403309054e-01,
+            9.9999999999999967e-01,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(6.0).kurtosis(), 3.0619002439080361e+02, 1e-10);
+        assert!(new!(5.0).kurtosis().is_nan());
+    }
+
+    #[test]
+    fn log_mass() {
+        let d = new!(3.5);
+        let x = vec![0, 1, 20, 10000];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -0.11932306459577614,
+            -10.604386022034744,
+            -32.355514366512416,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_mass(x)).collect::<Vec<_>>(),
+            &p,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn mass() {
+        let d = new!(3.5);
+        let x = vec![0, 1, 2, 3, 5, 10, 100];
+        let p = vec![
+            0.0000000000000000e+00,
+            8.8752102781925657e-01,
+            7.8446517152081355e-02,
+            1.8978166826774116e-02,
+            3.1752917594629433e-03,
+            2.8065879192025136e-04,
+            8.8752102781925653e-08,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.mass(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(3.5).mean(), 1.1905981493617694e+00, 1e-15);
+        assert_eq!(new!(2.0).mean(), f64::INFINITY);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(3.5).modes(), vec![1]);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(3.5);
+        let mut source = source::default(42);
+
+        let ones = (0..n).filter(|_| d.sample(&mut source) == 1).count() as f64 / n as f64;
+        assert!((ones - d.mass(1)).abs() < 0.01);
+
+        for x in Independent(&new!(1.5), &mut source).take(100) {
+            assert!(x >= 1);
+        }
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(6.0).skewness(), 1.1700091181667592e+01, 1e-12);
+        assert!(new!(4.0).skewness().is_nan());
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::{Included, Unbounded};
+        assert_eq!(new!(3.5).support(), (Included(1.0), Unbounded));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(3.5).variance(), 9.0101410125136305e-01, 1e-14);
+        assert_eq!(new!(3.0).variance(), f64::INFINITY);
+        assert!(new!(2.0).variance().is_nan());
+    }
+}
@@ -0,0 +1,359 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Bound;
+#[allow(unused_imports)]
+use special::Primitive;
+
+use distribution;
+use source::Source;
+
+/// A Zipf distribution.
+///
+/// The distribution has support `{1, …, n}` with probabilities proportional to
+/// `k^(-s)`.
+#[derive(Clone, Copy, Debug)]
+pub struct Zipf {
+    n: usize,
+    s: f64,
+    norm: f64,
+    integral_first: f64,
+    integral_last: f64,
+    threshold: f64,
+}
+
+impl Zipf {
+    /// Create a Zipf distribution with `n` elements and exponent `s`.
+    ///
+    /// It should hold that `n > 0` and `s > 0`.
+    #[inline]
+    pub fn new(n: usize, s: f64) -> Self {
+        use distribution::harmonic::harmonic;
+        should!(n > 0 && s > 0.0);
+        let mut zipf = Zipf {
+            n,
+            s,
+            norm: harmonic(n, s),
+            integral_first: 0.0,
+            integral_last: 0.0,
+            threshold: 0.0,
+        };
+        zipf.integral_first = zipf.h_integral(1.5) - 1.0;
+        zipf.integral_last = zipf.h_integral(n as f64 + 0.5);
+        zipf.threshold = 2.0 - zipf.h_integral_inverse(zipf.h_integral(2.5) - zipf.h(2.0));
+        zipf
+    }
+
+    /// Return the number of elements.
+    #[inline(always)]
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// Return the exponent parameter.
+    #[inline(always)]
+    pub fn s(&self) -> f64 {
+        self.s
+    }
+
+    /// Compute the `k`th raw moment.
+    #[inline]
+    fn moment(&self, k: i32) -> f64 {
+        use distribution::harmonic::harmonic;
+        harmonic(self.n, self.s - k as f64) / self.norm
+    }
+
+    /// Compute the hat function `x^(-s)`.
+    #[inline]
+    fn h(&self, x: f64) -> f64 {
+        (-self.s * x.ln()).exp()
+    }
+
+    /// Compute an antiderivative of the hat function.
+    #[inline]
+    fn h_integral(&self, x: f64) -> f64 {
+        let ln_x = x.ln();
+        let t = (1.0 - self.s) * ln_x;
+        let ratio = if t.abs() > 1e-8 {
+            t.exp_m1() / t
+        } else {
+            1.0 + 0.5 * t * (1.0 + t / 3.0)
+        };
+        ratio * ln_x
+    }
+
+    /// Compute the inverse of the antiderivative of the hat function.
+    #[inline]
+    fn h_integral_inverse(&self, x: f64) -> f64 {
+        let t = (x * (1.0 - self.s)).max(-1.0);
+        let ratio = if t.abs() > 1e-8 {
+            t.ln_1p() / t
+        } else {
+            1.0 - t * (0.5 - t / 3.0)
+        };
+        (ratio * x).exp()
+    }
+}
+
+impl distribution::CharacteristicFunction for Zipf {
+    fn cf(&self, t: f64) -> (f64, f64) {
+        use distribution::{characteristic, Discrete};
+        characteristic::discrete(|x| self.mass(x), t, 1, self.n, f64::INFINITY)
+    }
+}
+
+impl distribution::Discrete for Zipf {
+    #[inline]
+    fn mass(&self, x: usize) -> f64 {
+        if x < 1 || x > self.n {
+            0.0
+        } else {
+            (x as f64).powf(-self.s) / self.norm
+        }
+    }
+
+    #[inline]
+    fn log_mass(&self, x: usize) -> f64 {
+        if x < 1 || x > self.n {
+            f64::NEG_INFINITY
+        } else {
+            -self.s * (x as f64).ln() - self.norm.ln()
+        }
+    }
+}
+
+impl distribution::Distribution for Zipf {
+    type Value = usize;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        use distribution::harmonic::harmonic;
+        if x < 1.0 {
+            0.0
+        } else if x >= self.n as f64 {
+            1.0
+        } else {
+            (harmonic(x as usize, self.s) / self.norm).min(1.0)
+        }
+    }
+}
+
+impl distribution::Kurtosis for Zipf {
+    fn kurtosis(&self) -> f64 {
+        let (m1, m2, m3, m4) = (
+            self.moment(1),
+            self.moment(2),
+            self.moment(3),
+            self.moment(4),
+        );
+        let m1_2 = m1 * m1;
+        (m4 - 4.0 * m1 * m3 + 6.0 * m1_2 * m2 - 3.0 * m1_2 * m1_2) / (m2 - m1_2).powi(2) - 3.0
+    }
+}
+
+impl distribution::Mean for Zipf {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.moment(1)
+    }
+}
+
+impl distribution::Modes for Zipf {
+    #[inline]
+    fn modes(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+impl distribution::Moments for Zipf {
+    fn raw_moment(&self, n: usize) -> f64 {
+        self.moment(n as i32)
+    }
+}
+
+impl distribution::Sample for Zipf {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained by the rejection-inversion method, whose cost
+    /// does not depend on `n`.
+    ///
+    /// ## References
+    ///
+    /// 1. W. Hörmann and G. Derflinger, “Rejection-inversion to generate
+    ///    variates from monotone discrete distributions,” ACM Transactions on
+    ///    Modeling and Computer Simulation, vol. 6, no. 3, pp. 169–184, 1996.
+    fn sample<S>(&self, source: &mut S) -> usize
+    where
+        S: Source,
+    {
+        loop {
+            let u = self.integral_last
+                + source.read::<f64>() * (self.integral_first - self.integral_last);
+            let x = self.h_integral_inverse(u);
+            let k = (x + 0.5).floor().clamp(1.0, self.n as f64);
+            if k - x <= self.threshold || u >= self.h_integral(k + 0.5) - self.h(k) {
+                return k as usize;
+            }
+        }
+    }
+}
+
+impl distribution::Skewness for Zipf {
+    fn skewness(&self) -> f64 {
+        let (m1, m2, m3) = (self.moment(1), self.moment(2), self.moment(3));
+        (m3 - 3.0 * m1 * m2 + 2.0 * m1 * m1 * m1) / (m2 - m1 * m1).powf(1.5)
+    }
+}
+
+impl distribution::Support for Zipf {
+    fn support(&self) -> (Bound<f64>, Bound<f64>) {
+        use core::ops::Bound::Included;
+        (Included(1.0), Included(self.n as f64))
+    }
+}
+
+impl distribution::Variance for Zipf {
+    #[inline]
+    fn variance(&self) -> f64 {
+        let m1 = self.moment(1);
+        self.moment(2) - m1 * m1
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::excessive_precision,
+    clippy::needless_borrows_for_generic_args,
+    clippy::useless_vec
+)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($n:expr, $s:expr) => (Zipf::new($n, $s));
+    );
+
+    #[test]
+    fn cf() {
+        let d = new!(10, 1.5);
+        let t = vec![-0.7, 0.3, 2.0];
+        let re = vec![
+            0.29974915038458794,
+            0.6406388078805685,
+            -0.24850858199521234,
+        ];
+        let im = vec![-0.5264272529686296, 0.5085058314740943, 0.3349186141866544];
+        let (x, y): (Vec<_>, Vec<_>) = t.iter().map(|&t| d.cf(t)).unzip();
+        assert::close(&x, &re, 1e-14);
+        assert::close(&y, &im, 1e-14);
+        assert_eq!(d.cf(0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(10, 1.5);
+        let x = vec![0.0, 1.0, 1.5, 2.0, 3.0, 5.5, 9.0, 10.0, 11.0];
+        let p = vec![
+            0.0000000000000000e+00,
+            5.0116860155416165e-01,
+            5.0116860155416165e-01,
+            6.7835845989252497e-01,
+            7.7480840223141945e-01,
+            8.8228035987623421e-01,
+            9.8415165727327447e-01,
+            1.0000000000000000e+00,
+            1.0000000000000000e+00,
+        ];
+
+        assert::close(
+            &x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(),
+            &p,
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(10, 1.5).kurtosis(), 2.0713124461612069e+00, 1e-14);
+    }
+
+    #[test]
+    fn log_mass() {
+        let d = new!(10, 1.5);
+        let x = vec![0, 1, 2, 5];
+        let p = vec![
+            f64::NEG_INFINITY,
+            -0.690812704462581,
+            -1.730533475302499,
+            -3.1049695731137312,
+        ];
+        assert::close(
+            &x.iter().map(|&x| d.log_mass(x)).collect::<Vec<_>>(),
+            &p,
+            1e-13,
+        );
+    }
+
+    #[test]
+    fn mass() {
+        let d = new!(10, 1.5);
+        let x = vec![0, 1, 2, 3, 5, 8, 10, 11];
+        let p = vec![
+            0.0000000000000000e+00,
+            5.0116860155416165e-01,
+            1.7718985833836332e-01,
+            9.6449942338894507e-02,
+            4.4825882450544488e-02,
+            2.2148732292295414e-02,
+            1.5848342726725532e-02,
+            0.0000000000000000e+00,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.mass(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let d = new!(1_000_000, 1.5);
+        assert::close(d.mass(1), 3.8308667001166274e-01, 1e-14);
+        assert::close(d.mass(1000), 1.2114264184861770e-05, 1e-14);
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(10, 1.5).mean(), 2.5163664955948892e+00, 1e-15);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(10, 1.5).modes(), vec![1]);
+    }
+
+    #[test]
+    fn sample() {
+        let n = 100000;
+        let d = new!(10, 1.5);
+        let mut source = source::default(42);
+
+        let mean = (0..n).map(|_| d.sample(&mut source) as f64).sum::<f64>() / n as f64;
+        assert!((mean - d.mean()).abs() < 0.05);
+
+        let d = new!(1_000_000_000, 0.5);
+        for x in Independent(&d, &mut source).take(100) {
+            assert!((1..=1_000_000_000).contains(&x));
+        }
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(10, 1.5).skewness(), 1.6785770495408336e+00, 1e-14);
+    }
+
+    #[test]
+    fn support() {
+        use core::ops::Bound::Included;
+        assert_eq!(new!(10, 1.5).support(), (Included(1.0), Included(10.0)));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(10, 1.5).variance(), 4.9282952177572819e+00, 1e-14);
+    }
+}
@@ -21,7 +21,7 @@ extern crate random;
 extern crate special;
 
 macro_rules! nonnan(
-    ($argument:ident) => (if $argument.is_nan() { return ::core::f64::NAN; });
+    ($argument:ident) => (if $argument.is_nan() { return f64::NAN; });
 );
 
 macro_rules! should(
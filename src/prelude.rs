@@ -5,30 +5,120 @@ pub use distribution::Distribution;
 pub use distribution::Continuous;
 pub use distribution::Discrete;
 
+pub use distribution::divergence::Bhattacharyya;
+pub use distribution::divergence::KullbackLeibler;
+pub use distribution::CharacteristicFunction;
 pub use distribution::Entropy;
+pub use distribution::Hazard;
 pub use distribution::Inverse;
 pub use distribution::Kurtosis;
 pub use distribution::Mean;
 pub use distribution::Median;
+pub use distribution::Mgf;
 pub use distribution::Modes;
+pub use distribution::Moments;
 pub use distribution::Sample;
 pub use distribution::Skewness;
+pub use distribution::Support;
+pub use distribution::Survival;
+pub use distribution::TryMean;
+pub use distribution::TryVariance;
 pub use distribution::Variance;
 
+pub use distribution::Arcsine;
+pub use distribution::Bates;
+pub use distribution::Benford;
 pub use distribution::Bernoulli;
 pub use distribution::Beta;
+pub use distribution::BetaBinomial;
+pub use distribution::BetaPrime;
 pub use distribution::Binomial;
+pub use distribution::BirnbaumSaunders;
+pub use distribution::Borel;
+pub use distribution::Burr;
 pub use distribution::Categorical;
 pub use distribution::Cauchy;
+pub use distribution::Chi;
+pub use distribution::ChiSquared;
+pub use distribution::ComPoisson;
+pub use distribution::Dirichlet;
+pub use distribution::DirichletMultinomial;
+pub use distribution::DiscreteUniform;
+pub use distribution::DiscreteWeibull;
+pub use distribution::Erlang;
+pub use distribution::ExpModifiedGaussian;
 pub use distribution::Exponential;
+pub use distribution::FisherSnedecor;
+pub use distribution::FoldedNormal;
+pub use distribution::Frechet;
 pub use distribution::Gamma;
 pub use distribution::Gaussian;
+pub use distribution::GeneralizedExtremeValue;
+pub use distribution::GeneralizedGamma;
+pub use distribution::GeneralizedNormal;
+pub use distribution::GeneralizedPareto;
+pub use distribution::Geometric;
+pub use distribution::Gompertz;
+pub use distribution::Gumbel;
+pub use distribution::HalfCauchy;
+pub use distribution::HalfNormal;
+pub use distribution::HyperbolicSecant;
+pub use distribution::Hypergeometric;
+pub use distribution::InverseGamma;
+pub use distribution::InverseGaussian;
+pub use distribution::InverseWishart;
+pub use distribution::IrwinHall;
+pub use distribution::JohnsonSU;
+pub use distribution::Kumaraswamy;
 pub use distribution::Laplace;
+pub use distribution::Levy;
+pub use distribution::Lkj;
+pub use distribution::LogGamma;
+pub use distribution::LogLogistic;
+pub use distribution::Logarithmic;
 pub use distribution::Logistic;
 pub use distribution::Lognormal;
+pub use distribution::Lomax;
+pub use distribution::MatrixNormal;
+pub use distribution::Maxwell;
+pub use distribution::Multinomial;
+pub use distribution::MultivariateGaussian;
+pub use distribution::MultivariateStudentT;
+pub use distribution::Nakagami;
+pub use distribution::NegativeBinomial;
+pub use distribution::NegativeHypergeometric;
+pub use distribution::NoncentralChiSquared;
+pub use distribution::NoncentralF;
+pub use distribution::NoncentralT;
+pub use distribution::NormalInverseGaussian;
+pub use distribution::Pareto;
 pub use distribution::Pert;
+pub use distribution::Poisson;
+pub use distribution::PoissonBinomial;
+pub use distribution::Rademacher;
+pub use distribution::RaisedCosine;
+pub use distribution::Rayleigh;
+pub use distribution::Rice;
+pub use distribution::Semicircle;
+pub use distribution::Skellam;
+pub use distribution::SkewNormal;
+pub use distribution::Stable;
+pub use distribution::StudentT;
 pub use distribution::Triangular;
+pub use distribution::TruncatedGaussian;
+pub use distribution::UQuadratic;
 pub use distribution::Uniform;
+pub use distribution::VarianceGamma;
+pub use distribution::VonMises;
+pub use distribution::VonMisesFisher;
+pub use distribution::Weibull;
+pub use distribution::Wishart;
+pub use distribution::WrappedCauchy;
+pub use distribution::WrappedNormal;
+pub use distribution::YuleSimon;
+pub use distribution::ZeroTruncatedPoisson;
+pub use distribution::Zeta;
+pub use distribution::Zipf;
 
 pub use sampler::Independent;
 